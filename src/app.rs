@@ -1,32 +1,106 @@
-use axum::http::StatusCode;
 use axum::routing::get;
-use axum::{http, Router};
+use axum::{http, middleware, Router};
 use tower_http::cors::CorsLayer;
+use tower_http::timeout::TimeoutLayer;
 use tower_http::trace;
 
-use crate::{contexts, settings, telemetry};
+use crate::{contexts, libs, openapi, settings, telemetry};
 
-pub struct App;
+pub struct App {
+    pub router: Router,
+    pub ecommerce_jobs: contexts::ecommerce::BackgroundJobs,
+}
 
 impl App {
-    pub async fn http(settings: settings::Settings) -> Router {
+    pub async fn http(settings: settings::Settings) -> Self {
         let ecommerce_http_cx = contexts::ecommerce::HttpContext::new().await;
 
-        Router::new()
+        let openapi = openapi::ApiDoc::build(vec![ecommerce_http_cx.openapi], &settings);
+
+        let router = Router::new()
             .merge(ecommerce_http_cx.router)
-            .route("/healthz", get(|| async { (StatusCode::OK, "OK") }))
-            .layer(
-                CorsLayer::new()
-                    .allow_origin(settings.cors_origin.clone())
-                    .allow_methods([http::Method::GET, http::Method::PUT])
-                    .allow_headers([http::header::CONTENT_TYPE, http::header::AUTHORIZATION]),
-            )
+            .merge(openapi::router(openapi, &settings))
+            .route("/healthz", get(openapi::healthz))
+            .fallback(libs::problem_details::fallback)
+            .layer(TimeoutLayer::new(settings.http_timeout))
+            .layer(middleware::map_response(libs::problem_details::normalize))
+            .layer(cors(&settings))
             .layer(
                 trace::TraceLayer::new_for_http()
                     .make_span_with(telemetry::setup_http_root_span)
                     .on_request(trace::DefaultOnRequest::new().level(tracing::Level::DEBUG))
                     .on_response(trace::DefaultOnResponse::new().level(tracing::Level::DEBUG)),
+            );
+
+        Self {
+            router,
+            ecommerce_jobs: ecommerce_http_cx.jobs,
+        }
+    }
+}
+
+fn cors(settings: &settings::Settings) -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(settings.cors_origin.clone())
+        .allow_methods([
+            http::Method::GET,
+            http::Method::POST,
+            http::Method::PUT,
+            http::Method::DELETE,
+        ])
+        .allow_headers([http::header::CONTENT_TYPE, http::header::AUTHORIZATION])
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{header, HeaderValue, Request, StatusCode};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const ORIGIN: &str = "https://backoffice.example.com";
+
+    fn router() -> Router {
+        let settings = settings::Settings {
+            cors_origin: vec![HeaderValue::from_static(ORIGIN)],
+            telemetry_enabled: false,
+            http_timeout: std::time::Duration::from_secs(10),
+            swagger_ui_enabled: false,
+            oauth_client_id: None,
+            oauth_authorization_url: None,
+            oauth_token_url: None,
+        };
+
+        Router::new().route("/", get(|| async { "ok" })).layer(cors(&settings))
+    }
+
+    async fn preflight(method: &str) -> (StatusCode, header::HeaderMap) {
+        let response = router()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::OPTIONS)
+                    .uri("/")
+                    .header(header::ORIGIN, ORIGIN)
+                    .header(header::ACCESS_CONTROL_REQUEST_METHOD, method)
+                    .body(Body::empty())
+                    .unwrap(),
             )
-            .fallback(|| async { (StatusCode::NOT_FOUND, "NOT_FOUND") })
+            .await
+            .unwrap();
+
+        (response.status(), response.headers().clone())
+    }
+
+    #[tokio::test]
+    async fn given_preflight_when_request_then_allow_every_method_the_api_routes() {
+        for method in ["GET", "POST", "PUT", "DELETE"] {
+            let (status, headers) = preflight(method).await;
+
+            let allowed = headers[header::ACCESS_CONTROL_ALLOW_METHODS].to_str().unwrap();
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], ORIGIN);
+            assert!(allowed.split(',').any(|allowed| allowed.trim() == method));
+        }
     }
 }
@@ -0,0 +1,104 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct AdjustStock {
+    inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+}
+
+impl AdjustStock {
+    pub fn new(
+        inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { inventory_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct AdjustStockInput {
+    pub id: String,
+    pub product_id: String,
+    pub warehouse: String,
+    /// Units added, or taken out when negative.
+    pub delta: i64,
+    pub reason: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for AdjustStock {
+    type Input = AdjustStockInput;
+    type Output = backoffice::domain::inventory::StockLevel;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let adjustment = backoffice::domain::inventory::StockAdjustment::new(
+            input.id,
+            input.product_id,
+            input.warehouse,
+            input.delta,
+            input.reason,
+        )?;
+
+        self.inventory_repository
+            .adjust(&adjustment)
+            .instrument(tracing::info_span!("Invoke InventoryRepository.adjust"))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    async fn compose_usecase_with_product() -> (AdjustStock, backoffice::domain::product::ProductId) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let inventory_repository = Arc::new(product_repository.inventory());
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(product_repository);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&product_repository).await;
+
+        (AdjustStock::new(inventory_repository), product.id)
+    }
+
+    fn input(product_id: backoffice::domain::product::ProductId, delta: i64, reason: &str) -> AdjustStockInput {
+        AdjustStockInput {
+            id: backoffice::domain::inventory::StockAdjustmentId::default().to_primitive(),
+            product_id: product_id.to_primitive(),
+            warehouse: String::from("mad-1"),
+            delta,
+            reason: String::from(reason),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_receipt_and_loss_when_exec_then_return_stock_after_each() {
+        let (usecase, product_id) = compose_usecase_with_product().await;
+
+        let received = usecase.exec(input(product_id, 10, "received")).await.unwrap();
+        let lost = usecase.exec(input(product_id, -4, "lost")).await.unwrap();
+
+        assert_eq!(received.warehouse.to_primitive(), "MAD-1");
+        assert_eq!(received.on_hand, 10);
+        assert_eq!(lost.on_hand, 6);
+        assert_eq!(lost.available(), 6);
+    }
+
+    #[tokio::test]
+    async fn given_more_units_taken_out_than_on_hand_when_exec_then_return_insufficient_stock() {
+        let (usecase, product_id) = compose_usecase_with_product().await;
+
+        let result = usecase.exec(input(product_id, -1, "damaged")).await;
+
+        assert!(matches!(result, Err(common::domain::Error::InsufficientStock)));
+    }
+}
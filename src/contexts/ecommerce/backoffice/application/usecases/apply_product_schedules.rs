@@ -0,0 +1,151 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+/// Moves every product whose `publish_at` or `unpublish_at` has passed, run periodically in the background.
+pub struct ApplyProductSchedules {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+}
+
+impl ApplyProductSchedules {
+    pub fn new(product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>) -> Self {
+        Self { product_repository }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ApplyProductSchedulesInput;
+
+#[async_trait]
+impl common::application::usecase::UseCase for ApplyProductSchedules {
+    type Input = ApplyProductSchedulesInput;
+    /// Products as they were left, in the order they got moved.
+    type Output = Vec<backoffice::domain::product::Product>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let at = backoffice::domain::product::ProductTimeStamp::default();
+        let mut applied = vec![];
+
+        loop {
+            let due = self
+                .product_repository
+                .get_scheduled(at)
+                .instrument(tracing::info_span!("Invoke ProductRepository.get_scheduled"))
+                .await?;
+
+            let mut moved = 0;
+            for product in &due {
+                let Some(updated_product) = product.apply_schedule(at) else {
+                    continue;
+                };
+
+                // a merchandiser got there first, their change wins over the schedule
+                match self
+                    .product_repository
+                    .update_status(&updated_product, product.status)
+                    .instrument(tracing::info_span!("Invoke ProductRepository.update_status"))
+                    .await
+                {
+                    Ok(()) => {
+                        moved += 1;
+                        applied.push(updated_product);
+                    }
+                    Err(common::domain::Error::ProductStatusConflict) | Err(common::domain::Error::ProductNotFound) => {
+                        tracing::warn!("skipped schedule of product {}, it changed meanwhile", product.id)
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+
+            // a full page may hide more due products, unless none of it could be moved
+            if due.len() < backoffice::domain::product::PRODUCT_PAGE_SIZE as usize || moved == 0 {
+                break;
+            }
+        }
+
+        Ok(applied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        ApplyProductSchedules,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new());
+
+        (
+            ApplyProductSchedules::new(product_repository.clone()),
+            product_repository,
+        )
+    }
+
+    #[tokio::test]
+    async fn given_due_schedules_when_exec_then_publish_and_archive_products() {
+        let (usecase, repository) = compose_fixture();
+        let to_publish = backoffice::domain::product::fixture::ProductBuilder::default()
+            .scheduled(Some(chrono::Duration::minutes(-1)), None);
+        to_publish.save(&repository).await;
+        let to_archive = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Published)
+            .scheduled(None, Some(chrono::Duration::minutes(-1)));
+        to_archive.save(&repository).await;
+        let upcoming = backoffice::domain::product::fixture::ProductBuilder::default()
+            .scheduled(Some(chrono::Duration::days(1)), None);
+        upcoming.save(&repository).await;
+
+        let applied = usecase.exec(ApplyProductSchedulesInput).await.unwrap();
+
+        assert_eq!(applied.len(), 2);
+        let status = |id| {
+            let repository = repository.clone();
+            async move { repository.get_by_id(&id).await.unwrap().unwrap().status }
+        };
+        assert_eq!(
+            status(to_publish.id).await,
+            backoffice::domain::product::ProductStatus::Published
+        );
+        assert_eq!(
+            status(to_archive.id).await,
+            backoffice::domain::product::ProductStatus::Archived
+        );
+        assert_eq!(
+            status(upcoming.id).await,
+            backoffice::domain::product::ProductStatus::Draft
+        );
+    }
+
+    #[tokio::test]
+    async fn given_more_due_products_than_page_size_when_exec_then_apply_every_one() {
+        let (usecase, repository) = compose_fixture();
+        let total = backoffice::domain::product::PRODUCT_PAGE_SIZE as usize + 1;
+        for _ in 0..total {
+            backoffice::domain::product::fixture::ProductBuilder::default()
+                .scheduled(Some(chrono::Duration::minutes(-1)), None)
+                .save(&repository)
+                .await;
+        }
+
+        let applied = usecase.exec(ApplyProductSchedulesInput).await.unwrap();
+
+        assert_eq!(applied.len(), total);
+        let drafts = repository
+            .get(Some(backoffice::domain::product::ProductStatus::Draft))
+            .await
+            .unwrap();
+        assert!(drafts.is_empty());
+    }
+}
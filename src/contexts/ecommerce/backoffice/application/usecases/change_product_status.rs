@@ -0,0 +1,117 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct ChangeProductStatus {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+}
+
+impl ChangeProductStatus {
+    pub fn new(product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>) -> Self {
+        Self { product_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct ChangeProductStatusInput {
+    pub id: String,
+    pub status: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for ChangeProductStatus {
+    type Input = ChangeProductStatusInput;
+    type Output = backoffice::domain::product::Product;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let id = backoffice::domain::product::ProductId::try_from(input.id)?;
+        let status = backoffice::domain::product::ProductStatus::try_from(input.status)?;
+
+        let product = self
+            .product_repository
+            .get_by_id(&id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        let updated_product = product.transition(status)?;
+
+        // fails with a conflict when the scheduler or someone else moved the product in the meantime
+        self.product_repository
+            .update_status(&updated_product, product.status)
+            .instrument(tracing::info_span!("Invoke ProductRepository.update_status"))
+            .await?;
+
+        Ok(updated_product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        ChangeProductStatus,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new());
+
+        (ChangeProductStatus::new(product_repository.clone()), product_repository)
+    }
+
+    fn input(id: &backoffice::domain::product::ProductId, status: &str) -> ChangeProductStatusInput {
+        ChangeProductStatusInput {
+            id: id.to_string(),
+            status: String::from(status),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_draft_when_exec_with_published_then_publish_product() {
+        let (usecase, repository) = compose_fixture();
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default();
+        builder.save(&repository).await;
+
+        let output = usecase.exec(input(&builder.id, "published")).await.unwrap();
+
+        assert_eq!(output.status, backoffice::domain::product::ProductStatus::Published);
+        let product = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+        assert_eq!(product.status, backoffice::domain::product::ProductStatus::Published);
+    }
+
+    #[tokio::test]
+    async fn given_archived_product_when_exec_with_published_then_return_invalid_transition() {
+        let (usecase, repository) = compose_fixture();
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Archived);
+        builder.save(&repository).await;
+
+        let result = usecase.exec(input(&builder.id, "published")).await;
+
+        assert!(matches!(
+            result,
+            Err(common::domain::Error::InvalidProductStatusTransition)
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_exec_then_return_product_not_found() {
+        let (usecase, _) = compose_fixture();
+
+        let result = usecase
+            .exec(input(&backoffice::domain::product::ProductId::default(), "published"))
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductNotFound)));
+    }
+}
@@ -0,0 +1,46 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct CommitReservation {
+    inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+}
+
+impl CommitReservation {
+    pub fn new(
+        inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { inventory_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct CommitReservationInput {
+    pub id: String,
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for CommitReservation {
+    type Input = CommitReservationInput;
+    type Output = backoffice::domain::inventory::Reservation;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+        let id = backoffice::domain::inventory::ReservationId::try_from(input.id)?;
+
+        self.inventory_repository
+            .commit(
+                &product_id,
+                &id,
+                backoffice::domain::product::ProductTimeStamp::default(),
+            )
+            .instrument(tracing::info_span!("Invoke InventoryRepository.commit"))
+            .await
+    }
+}
@@ -0,0 +1,44 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct DeleteProductPrice {
+    product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+}
+
+impl DeleteProductPrice {
+    pub fn new(
+        product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_price_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteProductPriceInput {
+    pub id: String,
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for DeleteProductPrice {
+    type Input = DeleteProductPriceInput;
+    type Output = ();
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let id = backoffice::domain::product_price::ProductPriceId::try_from(input.id)?;
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+
+        self.product_price_repository
+            .delete(&product_id, &id)
+            .instrument(tracing::info_span!("Invoke ProductPriceRepository.delete"))
+            .await
+    }
+}
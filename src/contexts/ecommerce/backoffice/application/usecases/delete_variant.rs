@@ -0,0 +1,40 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct DeleteVariant {
+    variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+}
+
+impl DeleteVariant {
+    pub fn new(variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>) -> Self {
+        Self { variant_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteVariantInput {
+    pub id: String,
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for DeleteVariant {
+    type Input = DeleteVariantInput;
+    type Output = ();
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let id = backoffice::domain::variant::VariantId::try_from(input.id)?;
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+
+        self.variant_repository
+            .delete(&product_id, &id)
+            .instrument(tracing::info_span!("Invoke VariantRepository.delete"))
+            .await
+    }
+}
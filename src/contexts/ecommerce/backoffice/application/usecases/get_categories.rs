@@ -0,0 +1,62 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetCategories {
+    category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+}
+
+impl GetCategories {
+    pub fn new(
+        category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { category_repository }
+    }
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetCategories {
+    type Input = ();
+    /// Every category, each parent followed by its subtree and siblings by position.
+    type Output = Vec<backoffice::domain::category::Category>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, _: Self::Input) -> Result<Self::Output, Self::Error> {
+        let categories = self
+            .category_repository
+            .get()
+            .instrument(tracing::info_span!("Invoke CategoryRepository.get"))
+            .await?;
+
+        Ok(backoffice::domain::category::Category::sort_depth_first(categories))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn given_nested_categories_when_exec_then_return_them_depth_first() {
+        let category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new().categories());
+
+        let first = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let second = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let child = backoffice::domain::category::fixture::CategoryBuilder::child_of(&first);
+        for category in [&first, &second, &child] {
+            category.save(&category_repository).await;
+        }
+
+        let categories = GetCategories::new(category_repository).exec(()).await.unwrap();
+
+        let ids: Vec<_> = categories.iter().map(|category| category.id).collect();
+        assert_eq!(ids, vec![first.id, child.id, second.id]);
+    }
+}
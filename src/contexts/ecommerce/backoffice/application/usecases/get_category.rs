@@ -0,0 +1,41 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetCategory {
+    category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+}
+
+impl GetCategory {
+    pub fn new(
+        category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { category_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetCategoryInput {
+    pub id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetCategory {
+    type Input = GetCategoryInput;
+    type Output = backoffice::domain::category::Category;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let id = backoffice::domain::category::CategoryId::try_from(input.id)?;
+
+        self.category_repository
+            .get_by_id(&id)
+            .instrument(tracing::info_span!("Invoke CategoryRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::CategoryNotFound)
+    }
+}
@@ -0,0 +1,112 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetCategoryProducts {
+    category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+}
+
+impl GetCategoryProducts {
+    pub fn new(
+        category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { category_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetCategoryProductsInput {
+    pub category_id: String,
+    /// Whether products assigned to any category below it count as well.
+    pub include_subcategories: bool,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetCategoryProducts {
+    type Input = GetCategoryProductsInput;
+    type Output = Vec<backoffice::domain::product::Product>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let category_id = backoffice::domain::category::CategoryId::try_from(input.category_id)?;
+
+        // an empty listing must not hide a mistyped category id
+        self.category_repository
+            .get_by_id(&category_id)
+            .instrument(tracing::info_span!("Invoke CategoryRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::CategoryNotFound)?;
+
+        self.category_repository
+            .get_products(&category_id, input.include_subcategories)
+            .instrument(tracing::info_span!("Invoke CategoryRepository.get_products"))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        GetCategoryProducts,
+        backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error> =
+            Arc::new(product_repository.categories());
+
+        (
+            GetCategoryProducts::new(category_repository.clone()),
+            category_repository,
+            Arc::new(product_repository),
+        )
+    }
+
+    #[tokio::test]
+    async fn given_unknown_category_when_exec_then_return_category_not_found() {
+        let (usecase, _, _) = compose_fixture();
+
+        let result = usecase
+            .exec(GetCategoryProductsInput {
+                category_id: backoffice::domain::category::CategoryId::default().to_primitive(),
+                include_subcategories: true,
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::CategoryNotFound)));
+    }
+
+    #[tokio::test]
+    async fn given_product_in_subcategory_when_exec_then_include_it_only_with_subcategories() {
+        let (usecase, category_repository, product_repository) = compose_fixture();
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let child = backoffice::domain::category::fixture::CategoryBuilder::child_of(&parent);
+        parent.save(&category_repository).await;
+        child.save(&category_repository).await;
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&product_repository).await;
+        category_repository
+            .assign_product(&child.id, &product.id)
+            .await
+            .unwrap();
+
+        let input = |include_subcategories| GetCategoryProductsInput {
+            category_id: parent.id.to_primitive(),
+            include_subcategories,
+        };
+
+        assert_eq!(usecase.exec(input(true)).await.unwrap().len(), 1);
+        assert!(usecase.exec(input(false)).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,50 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetCurrentProductPrice {
+    product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+}
+
+impl GetCurrentProductPrice {
+    pub fn new(
+        product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_price_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetCurrentProductPriceInput {
+    pub product_id: String,
+    pub currency: String,
+    /// Point in time the price must be valid at, `None` for now.
+    pub at: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetCurrentProductPrice {
+    type Input = GetCurrentProductPriceInput;
+    type Output = Option<backoffice::domain::product_price::ProductPrice>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+        let currency = common::domain::Currency::try_from(input.currency)?;
+        let at = input
+            .at
+            .map(backoffice::domain::product::ProductTimeStamp::from)
+            .unwrap_or_default();
+
+        self.product_price_repository
+            .get_current(&product_id, currency, at)
+            .instrument(tracing::info_span!("Invoke ProductPriceRepository.get_current"))
+            .await
+    }
+}
@@ -0,0 +1,63 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetProductEvents {
+    product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+}
+
+impl GetProductEvents {
+    pub fn new(
+        product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_event_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetProductEventsInput {
+    /// Sequence of the last event seen by the caller, `None` to start from the current end of the stream.
+    pub after: Option<backoffice::domain::product_event::ProductEventSequence>,
+    pub limit: i64,
+}
+
+pub struct GetProductEventsOutput {
+    pub events: Vec<backoffice::domain::product_event::ProductEvent>,
+    pub last_sequence: backoffice::domain::product_event::ProductEventSequence,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetProductEvents {
+    type Input = GetProductEventsInput;
+    type Output = GetProductEventsOutput;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        let Some(after) = input.after else {
+            let last_sequence = self
+                .product_event_repository
+                .get_last_sequence()
+                .instrument(tracing::info_span!("Invoke ProductEventRepository.get_last_sequence"))
+                .await?;
+
+            return Ok(GetProductEventsOutput {
+                events: vec![],
+                last_sequence,
+            });
+        };
+
+        let events = self
+            .product_event_repository
+            .get_after(&after, input.limit)
+            .instrument(tracing::info_span!("Invoke ProductEventRepository.get_after"))
+            .await?;
+
+        let last_sequence = events.last().map(|event| event.sequence).unwrap_or(after);
+
+        Ok(GetProductEventsOutput { events, last_sequence })
+    }
+}
@@ -0,0 +1,114 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetProductPriceHistory {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    product_price_history_repository:
+        backoffice::domain::product_price_history::DynProductPriceHistoryRepository<common::domain::Error>,
+}
+
+impl GetProductPriceHistory {
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        product_price_history_repository: backoffice::domain::product_price_history::DynProductPriceHistoryRepository<
+            common::domain::Error,
+        >,
+    ) -> Self {
+        Self {
+            product_repository,
+            product_price_history_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetProductPriceHistoryInput {
+    pub product_id: String,
+    /// Start of the period, inclusive, `None` for the whole history up to `until`.
+    pub from: Option<chrono::DateTime<chrono::offset::Utc>>,
+    /// End of the period, exclusive, `None` for every change since `from`.
+    pub until: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetProductPriceHistory {
+    type Input = GetProductPriceHistoryInput;
+    type Output = Vec<backoffice::domain::product_price_history::ProductPriceChange>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+        let from = input.from.map(backoffice::domain::product::ProductTimeStamp::from);
+        let until = input.until.map(backoffice::domain::product::ProductTimeStamp::from);
+
+        backoffice::domain::product_price_history::ProductPriceChange::validate_period(from, until)?;
+
+        // an empty history must not hide a mistyped product id
+        self.product_repository
+            .get_by_id(&product_id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        self.product_price_history_repository
+            .get_by_product_id(&product_id, from, until)
+            .instrument(tracing::info_span!(
+                "Invoke ProductPriceHistoryRepository.get_by_product_id"
+            ))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> GetProductPriceHistory {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+
+        GetProductPriceHistory::new(
+            Arc::new(product_repository.clone()),
+            Arc::new(product_repository.price_history()),
+        )
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_exec_then_return_product_not_found() {
+        let result = compose_fixture()
+            .exec(GetProductPriceHistoryInput {
+                product_id: backoffice::domain::product::ProductId::default().to_primitive(),
+                from: None,
+                until: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductNotFound)));
+    }
+
+    #[tokio::test]
+    async fn given_period_ending_before_it_starts_when_exec_then_return_period_error() {
+        let now = chrono::Utc::now();
+
+        let result = compose_fixture()
+            .exec(GetProductPriceHistoryInput {
+                product_id: backoffice::domain::product::ProductId::default().to_primitive(),
+                from: Some(now),
+                until: Some(now - chrono::Duration::days(1)),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(common::domain::Error::InvalidProductPriceHistoryPeriod)
+        ));
+    }
+}
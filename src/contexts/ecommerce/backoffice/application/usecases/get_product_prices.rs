@@ -0,0 +1,78 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetProductPrices {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+}
+
+impl GetProductPrices {
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_repository,
+            product_price_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetProductPricesInput {
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetProductPrices {
+    type Input = GetProductPricesInput;
+    type Output = Vec<backoffice::domain::product_price::ProductPrice>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+
+        // an empty list must not hide a mistyped product id
+        self.product_repository
+            .get_by_id(&product_id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        self.product_price_repository
+            .get_by_product_id(&product_id)
+            .instrument(tracing::info_span!("Invoke ProductPriceRepository.get_by_product_id"))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn given_unknown_product_when_exec_then_return_product_not_found() {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let usecase = GetProductPrices::new(
+            Arc::new(product_repository.clone()),
+            Arc::new(product_repository.prices()),
+        );
+
+        let result = usecase
+            .exec(GetProductPricesInput {
+                product_id: backoffice::domain::product::ProductId::default().to_primitive(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductNotFound)));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use axum::async_trait;
 use tracing::Instrument;
 
@@ -5,25 +7,248 @@ use crate::contexts::ecommerce::{backoffice, common};
 
 pub struct GetProducts {
     product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    currency_converter: backoffice::domain::exchange_rate::CurrencyConverter,
 }
 
 impl GetProducts {
-    pub fn new(product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>) -> Self {
-        Self { product_repository }
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        currency_converter: backoffice::domain::exchange_rate::CurrencyConverter,
+    ) -> Self {
+        Self {
+            product_repository,
+            currency_converter,
+        }
     }
 }
 
+#[derive(Debug, Default)]
+pub struct GetProductsInput {
+    /// Currency to show every price in as well, at today's reference rates.
+    pub display_currency: Option<String>,
+    /// Lifecycle status to narrow the listing to, every status when empty.
+    pub status: Option<String>,
+}
+
+/// Product of a listing, with its price in the display currency when one was asked for.
+pub struct ProductListItem {
+    pub product: backoffice::domain::product::Product,
+    pub display_price: Option<common::domain::Money>,
+}
+
 #[async_trait]
 impl common::application::usecase::UseCase for GetProducts {
-    type Input = ();
-    type Output = Vec<backoffice::domain::product::Product>;
+    type Input = GetProductsInput;
+    type Output = Vec<ProductListItem>;
 
     type Error = common::domain::Error;
 
-    async fn exec(&self, _: Self::Input) -> Result<Self::Output, Self::Error> {
-        self.product_repository
-            .get()
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let display_currency = input
+            .display_currency
+            .map(common::domain::Currency::try_from)
+            .transpose()?;
+
+        let status = input
+            .status
+            .map(backoffice::domain::product::ProductStatus::try_from)
+            .transpose()?;
+
+        let products = self
+            .product_repository
+            .get(status)
             .instrument(tracing::info_span!("Invoke ProductRepository.get"))
+            .await?;
+
+        let Some(display_currency) = display_currency else {
+            return Ok(products
+                .into_iter()
+                .map(|product| ProductListItem {
+                    product,
+                    display_price: None,
+                })
+                .collect());
+        };
+
+        let today = chrono::Utc::now().date_naive();
+        // a page holds few distinct currencies, each rate is looked up once
+        let mut rates: HashMap<common::domain::Currency, common::domain::Rational> = HashMap::new();
+        let mut items = Vec::with_capacity(products.len());
+
+        for product in products {
+            let currency = product.price.currency();
+
+            let rate = match rates.get(&currency) {
+                Some(rate) => *rate,
+                None => {
+                    let rate = self
+                        .currency_converter
+                        .rate(currency, display_currency, today)
+                        .instrument(tracing::info_span!("Invoke CurrencyConverter.rate"))
+                        .await?;
+                    rates.insert(currency, rate);
+                    rate
+                }
+            };
+
+            let display_price =
+                product
+                    .price
+                    .convert(rate, display_currency, self.currency_converter.rounding_mode())?;
+
+            items.push(ProductListItem {
+                product,
+                display_price: Some(display_price),
+            });
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    async fn compose_fixture() -> (
+        GetProducts,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let exchange_rate_repository: backoffice::domain::exchange_rate::DynExchangeRateRepository<
+            common::domain::Error,
+        > = Arc::new(product_repository.exchange_rates());
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(product_repository);
+
+        exchange_rate_repository
+            .save_many(&[backoffice::domain::exchange_rate::fixture::exchange_rate(
+                "EUR",
+                "USD",
+                "1.0772",
+                "2024-05-10",
+            )])
+            .await
+            .unwrap();
+
+        let usecase = GetProducts::new(
+            product_repository.clone(),
+            backoffice::domain::exchange_rate::CurrencyConverter::new(exchange_rate_repository),
+        );
+
+        (usecase, product_repository)
+    }
+
+    async fn save_product(
+        repository: &backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        amount: i64,
+        currency: &str,
+    ) {
+        let product = backoffice::domain::product::fixture::ProductBuilder {
+            price: common::domain::Money::new(amount, common::domain::Currency::try_from(currency).unwrap()),
+            ..Default::default()
+        };
+        product.save(repository).await;
+    }
+
+    #[tokio::test]
+    async fn given_no_display_currency_when_exec_then_leave_display_price_empty() {
+        let (usecase, repository) = compose_fixture().await;
+        save_product(&repository, 999, "EUR").await;
+
+        let items = usecase.exec(GetProductsInput::default()).await.unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert!(items[0].display_price.is_none());
+    }
+
+    #[tokio::test]
+    async fn given_display_currency_when_exec_then_convert_every_price() {
+        let (usecase, repository) = compose_fixture().await;
+        save_product(&repository, 999, "EUR").await;
+        save_product(&repository, 1_077, "USD").await;
+
+        let items = usecase
+            .exec(GetProductsInput {
+                display_currency: Some(String::from("USD")),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let display_prices: Vec<_> = items
+            .iter()
+            .map(|item| item.display_price.unwrap().to_string())
+            .collect();
+        assert_eq!(display_prices, vec!["10.76 USD", "10.77 USD"]);
+    }
+
+    #[tokio::test]
+    async fn given_display_currency_without_rate_when_exec_then_return_exchange_rate_not_found() {
+        let (usecase, repository) = compose_fixture().await;
+        save_product(&repository, 999, "EUR").await;
+
+        let result = usecase
+            .exec(GetProductsInput {
+                display_currency: Some(String::from("GBP")),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ExchangeRateNotFound)));
+    }
+
+    #[tokio::test]
+    async fn given_unknown_display_currency_when_exec_then_return_invalid_currency() {
+        let (usecase, _) = compose_fixture().await;
+
+        let result = usecase
+            .exec(GetProductsInput {
+                display_currency: Some(String::from("EURO")),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::InvalidCurrency)));
+    }
+
+    #[tokio::test]
+    async fn given_status_when_exec_then_return_only_products_in_that_status() {
+        let (usecase, repository) = compose_fixture().await;
+        save_product(&repository, 999, "EUR").await;
+        let published = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Published);
+        published.save(&repository).await;
+
+        let items = usecase
+            .exec(GetProductsInput {
+                status: Some(String::from("published")),
+                ..Default::default()
+            })
             .await
+            .unwrap();
+
+        let ids: Vec<_> = items.iter().map(|item| item.product.id).collect();
+        assert_eq!(ids, vec![published.id]);
+    }
+
+    #[tokio::test]
+    async fn given_unknown_status_when_exec_then_return_invalid_product_status() {
+        let (usecase, _) = compose_fixture().await;
+
+        let result = usecase
+            .exec(GetProductsInput {
+                status: Some(String::from("live")),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::InvalidProductStatus)));
     }
 }
@@ -0,0 +1,78 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetStock {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+}
+
+impl GetStock {
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_repository,
+            inventory_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetStockInput {
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetStock {
+    type Input = GetStockInput;
+    type Output = Vec<backoffice::domain::inventory::StockLevel>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+
+        // an empty list must not hide a mistyped product id
+        self.product_repository
+            .get_by_id(&product_id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        self.inventory_repository
+            .get_stock(&product_id, backoffice::domain::product::ProductTimeStamp::default())
+            .instrument(tracing::info_span!("Invoke InventoryRepository.get_stock"))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn given_unknown_product_when_exec_then_return_product_not_found() {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let usecase = GetStock::new(
+            Arc::new(product_repository.clone()),
+            Arc::new(product_repository.inventory()),
+        );
+
+        let result = usecase
+            .exec(GetStockInput {
+                product_id: backoffice::domain::product::ProductId::default().to_primitive(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductNotFound)));
+    }
+}
@@ -0,0 +1,52 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetStockAdjustments {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+}
+
+impl GetStockAdjustments {
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_repository,
+            inventory_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetStockAdjustmentsInput {
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetStockAdjustments {
+    type Input = GetStockAdjustmentsInput;
+    type Output = Vec<backoffice::domain::inventory::StockAdjustment>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+
+        // an empty list must not hide a mistyped product id
+        self.product_repository
+            .get_by_id(&product_id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        self.inventory_repository
+            .get_adjustments(&product_id)
+            .instrument(tracing::info_span!("Invoke InventoryRepository.get_adjustments"))
+            .await
+    }
+}
@@ -0,0 +1,52 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetVariantOptions {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+}
+
+impl GetVariantOptions {
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_repository,
+            variant_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetVariantOptionsInput {
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetVariantOptions {
+    type Input = GetVariantOptionsInput;
+    type Output = Vec<backoffice::domain::variant::VariantOption>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+
+        // an empty list must not hide a mistyped product id
+        self.product_repository
+            .get_by_id(&product_id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        self.variant_repository
+            .get_options(&product_id)
+            .instrument(tracing::info_span!("Invoke VariantRepository.get_options"))
+            .await
+    }
+}
@@ -0,0 +1,78 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct GetVariants {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+}
+
+impl GetVariants {
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_repository,
+            variant_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetVariantsInput {
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for GetVariants {
+    type Input = GetVariantsInput;
+    type Output = Vec<backoffice::domain::variant::Variant>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+
+        // an empty list must not hide a mistyped product id
+        self.product_repository
+            .get_by_id(&product_id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        self.variant_repository
+            .get_by_product_id(&product_id)
+            .instrument(tracing::info_span!("Invoke VariantRepository.get_by_product_id"))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn given_unknown_product_when_exec_then_return_product_not_found() {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let usecase = GetVariants::new(
+            Arc::new(product_repository.clone()),
+            Arc::new(product_repository.variants()),
+        );
+
+        let result = usecase
+            .exec(GetVariantsInput {
+                product_id: backoffice::domain::product::ProductId::default().to_primitive(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductNotFound)));
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::BTreeSet;
+
+use axum::async_trait;
+use serde::Serialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct ImportExchangeRates {
+    exchange_rate_repository: backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error>,
+}
+
+impl ImportExchangeRates {
+    pub fn new(
+        exchange_rate_repository: backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            exchange_rate_repository,
+        }
+    }
+}
+
+/// One rate of an imported file, as written in it.
+#[derive(Debug)]
+pub struct ExchangeRateInput {
+    pub base: String,
+    pub quote: String,
+    pub rate: String,
+    pub valid_on: chrono::NaiveDate,
+}
+
+#[derive(Debug)]
+pub struct ImportExchangeRatesInput {
+    pub rates: Vec<ExchangeRateInput>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportExchangeRatesOutput {
+    pub imported: usize,
+    /// Codes that are not, or no longer, ISO 4217 currencies, such as those replaced by the euro.
+    pub skipped_currencies: Vec<String>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for ImportExchangeRates {
+    type Input = ImportExchangeRatesInput;
+    type Output = ImportExchangeRatesOutput;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!(rates = input.rates.len(), "import exchange rates");
+
+        let mut skipped_currencies = BTreeSet::new();
+        let mut rates = Vec::with_capacity(input.rates.len());
+
+        for rate in input.rates {
+            // historical files keep the columns of retired currencies, which are of no use for conversions
+            let unknown: Vec<&String> = [&rate.base, &rate.quote]
+                .into_iter()
+                .filter(|code| common::domain::Currency::try_from(code.as_str()).is_err())
+                .collect();
+
+            if !unknown.is_empty() {
+                skipped_currencies.extend(unknown.into_iter().cloned());
+                continue;
+            }
+
+            rates.push(backoffice::domain::exchange_rate::ExchangeRate::new(
+                rate.base,
+                rate.quote,
+                rate.rate,
+                rate.valid_on,
+            )?);
+        }
+
+        self.exchange_rate_repository
+            .save_many(&rates)
+            .instrument(tracing::info_span!("Invoke ExchangeRateRepository.save_many"))
+            .await?;
+
+        Ok(ImportExchangeRatesOutput {
+            imported: rates.len(),
+            skipped_currencies: skipped_currencies.into_iter().collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        ImportExchangeRates,
+        backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error>,
+    ) {
+        let repository: backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new().exchange_rates());
+
+        (ImportExchangeRates::new(repository.clone()), repository)
+    }
+
+    fn input(quote: &str, rate: &str) -> ExchangeRateInput {
+        ExchangeRateInput {
+            base: String::from("EUR"),
+            quote: String::from(quote),
+            rate: String::from(rate),
+            valid_on: backoffice::domain::exchange_rate::fixture::date("2024-05-10"),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_retired_currencies_when_exec_then_skip_them_and_import_the_rest() {
+        let (usecase, repository) = compose_fixture();
+
+        let output = usecase
+            .exec(ImportExchangeRatesInput {
+                rates: vec![input("USD", "1.0772"), input("CYP", "0.585274"), input("JPY", "167.76")],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(output.imported, 2);
+        assert_eq!(output.skipped_currencies, vec!["CYP"]);
+        assert!(repository
+            .get_latest(
+                common::domain::Currency::EUR,
+                common::domain::Currency::try_from("JPY").unwrap(),
+                backoffice::domain::exchange_rate::fixture::date("2024-05-10"),
+            )
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn given_malformed_rate_when_exec_then_import_nothing() {
+        let (usecase, repository) = compose_fixture();
+
+        let result = usecase
+            .exec(ImportExchangeRatesInput {
+                rates: vec![input("USD", "1.0772"), input("JPY", "167,76")],
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::Validation(_))));
+        assert!(repository
+            .get_latest(
+                common::domain::Currency::EUR,
+                common::domain::Currency::try_from("USD").unwrap(),
+                backoffice::domain::exchange_rate::fixture::date("2024-05-10"),
+            )
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,119 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct MoveCategory {
+    category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+}
+
+impl MoveCategory {
+    pub fn new(
+        category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { category_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct MoveCategoryInput {
+    pub id: String,
+    /// New parent, `None` to make it a root category.
+    pub parent_id: Option<String>,
+    /// Place among the new siblings, `None` or past the last one to place it last.
+    pub position: Option<u32>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for MoveCategory {
+    type Input = MoveCategoryInput;
+    type Output = backoffice::domain::category::Category;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let id = backoffice::domain::category::CategoryId::try_from(input.id)?;
+        let parent_id = input
+            .parent_id
+            .map(backoffice::domain::category::CategoryId::try_from)
+            .transpose()
+            .map_err(|_| common::domain::Error::InvalidCategoryParentId)?;
+        let position = input
+            .position
+            .map_or(i32::MAX, |position| i32::try_from(position).unwrap_or(i32::MAX));
+
+        self.category_repository
+            .move_to(&id, parent_id.as_ref(), position)
+            .instrument(tracing::info_span!("Invoke CategoryRepository.move_to"))
+            .await?;
+
+        self.category_repository
+            .get_by_id(&id)
+            .instrument(tracing::info_span!("Invoke CategoryRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::CategoryNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        MoveCategory,
+        backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    ) {
+        let category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new().categories());
+
+        (MoveCategory::new(category_repository.clone()), category_repository)
+    }
+
+    #[tokio::test]
+    async fn given_no_position_when_exec_then_place_category_last() {
+        let (usecase, category_repository) = compose_fixture();
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let child = backoffice::domain::category::fixture::CategoryBuilder::child_of(&parent);
+        let moved = backoffice::domain::category::fixture::CategoryBuilder::default();
+        for category in [&parent, &child, &moved] {
+            category.save(&category_repository).await;
+        }
+
+        let category = usecase
+            .exec(MoveCategoryInput {
+                id: moved.id.to_primitive(),
+                parent_id: Some(parent.id.to_primitive()),
+                position: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(category.parent_id, Some(parent.id));
+        assert_eq!(category.position, 1);
+    }
+
+    #[tokio::test]
+    async fn given_own_child_as_parent_when_exec_then_return_cycle() {
+        let (usecase, category_repository) = compose_fixture();
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let child = backoffice::domain::category::fixture::CategoryBuilder::child_of(&parent);
+        parent.save(&category_repository).await;
+        child.save(&category_repository).await;
+
+        let result = usecase
+            .exec(MoveCategoryInput {
+                id: parent.id.to_primitive(),
+                parent_id: Some(child.id.to_primitive()),
+                position: Some(0),
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::CategoryCycle)));
+    }
+}
@@ -0,0 +1,46 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct ReleaseReservation {
+    inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+}
+
+impl ReleaseReservation {
+    pub fn new(
+        inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { inventory_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct ReleaseReservationInput {
+    pub id: String,
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for ReleaseReservation {
+    type Input = ReleaseReservationInput;
+    type Output = backoffice::domain::inventory::Reservation;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+        let id = backoffice::domain::inventory::ReservationId::try_from(input.id)?;
+
+        self.inventory_repository
+            .release(
+                &product_id,
+                &id,
+                backoffice::domain::product::ProductTimeStamp::default(),
+            )
+            .instrument(tracing::info_span!("Invoke InventoryRepository.release"))
+            .await
+    }
+}
@@ -0,0 +1,151 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct ReserveStock {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+}
+
+impl ReserveStock {
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_repository,
+            inventory_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ReserveStockInput {
+    pub id: String,
+    pub product_id: String,
+    pub warehouse: String,
+    pub quantity: i64,
+    /// Defaults to [`backoffice::domain::inventory::RESERVATION_DEFAULT_TTL_SECONDS`].
+    pub ttl_seconds: Option<i64>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for ReserveStock {
+    type Input = ReserveStockInput;
+    type Output = backoffice::domain::inventory::Reservation;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let reservation = backoffice::domain::inventory::Reservation::new(
+            input.id,
+            input.product_id,
+            input.warehouse,
+            input.quantity,
+            input
+                .ttl_seconds
+                .unwrap_or(backoffice::domain::inventory::RESERVATION_DEFAULT_TTL_SECONDS),
+        )?;
+
+        // without a stock row an unknown product would read as insufficient stock
+        self.product_repository
+            .get_by_id(&reservation.product_id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        self.inventory_repository
+            .reserve(&reservation)
+            .instrument(tracing::info_span!("Invoke InventoryRepository.reserve"))
+            .await?;
+
+        Ok(reservation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    async fn compose_usecase_with_stock(on_hand: i64) -> (Arc<ReserveStock>, backoffice::domain::product::ProductId) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error> =
+            Arc::new(product_repository.inventory());
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(product_repository);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&product_repository).await;
+        if on_hand > 0 {
+            backoffice::domain::inventory::fixture::StockAdjustmentBuilder::new(product.id, "MAD-1", on_hand)
+                .save(&inventory_repository)
+                .await;
+        }
+
+        (
+            Arc::new(ReserveStock::new(product_repository, inventory_repository)),
+            product.id,
+        )
+    }
+
+    fn input(product_id: backoffice::domain::product::ProductId, quantity: i64) -> ReserveStockInput {
+        ReserveStockInput {
+            id: backoffice::domain::inventory::ReservationId::default().to_primitive(),
+            product_id: product_id.to_primitive(),
+            warehouse: String::from("MAD-1"),
+            quantity,
+            ttl_seconds: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_exec_then_return_product_not_found() {
+        let (usecase, _) = compose_usecase_with_stock(0).await;
+
+        let result = usecase
+            .exec(input(backoffice::domain::product::ProductId::default(), 1))
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductNotFound)));
+    }
+
+    #[tokio::test]
+    async fn given_no_ttl_when_exec_then_expire_after_default_ttl() {
+        let (usecase, product_id) = compose_usecase_with_stock(3).await;
+
+        let reservation = usecase.exec(input(product_id, 3)).await.unwrap();
+
+        assert_eq!(
+            reservation.expires_at.to_datetime() - reservation.created_at.to_datetime(),
+            chrono::Duration::seconds(backoffice::domain::inventory::RESERVATION_DEFAULT_TTL_SECONDS)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_parallel_requests_when_exec_then_reserve_at_most_on_hand() {
+        let (usecase, product_id) = compose_usecase_with_stock(7).await;
+
+        let handles = (0..30)
+            .map(|_| {
+                let usecase = usecase.clone();
+                tokio::spawn(async move { usecase.exec(input(product_id, 1)).await })
+            })
+            .collect::<Vec<_>>();
+        let mut reserved = 0;
+        for handle in handles {
+            match handle.await.unwrap() {
+                Ok(_) => reserved += 1,
+                Err(error) => assert!(matches!(error, common::domain::Error::InsufficientStock)),
+            }
+        }
+
+        assert_eq!(reserved, 7);
+    }
+}
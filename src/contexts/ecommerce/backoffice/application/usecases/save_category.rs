@@ -0,0 +1,127 @@
+use axum::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct SaveCategory {
+    category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+}
+
+impl SaveCategory {
+    pub fn new(
+        category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { category_repository }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveCategoryInput {
+    pub id: String,
+    /// Category to create it below, `None` for a root category.
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    pub name: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for SaveCategory {
+    type Input = SaveCategoryInput;
+    /// The saved category, with the position it was given as the last child of its parent.
+    type Output = backoffice::domain::category::Category;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let parent_id = input
+            .parent_id
+            .map(backoffice::domain::category::CategoryId::try_from)
+            .transpose()
+            .map_err(|_| common::domain::Error::InvalidCategoryParentId)?;
+
+        let parent = match parent_id {
+            Some(parent_id) => Some(
+                self.category_repository
+                    .get_by_id(&parent_id)
+                    .instrument(tracing::info_span!("Invoke CategoryRepository.get_by_id"))
+                    .await?
+                    .ok_or(common::domain::Error::CategoryNotFound)?,
+            ),
+            None => None,
+        };
+
+        let category = backoffice::domain::category::Category::new(input.id, input.name, parent.as_ref())?;
+
+        self.category_repository
+            .save(&category)
+            .instrument(tracing::info_span!("Invoke CategoryRepository.save"))
+            .await?;
+
+        self.category_repository
+            .get_by_id(&category.id)
+            .instrument(tracing::info_span!("Invoke CategoryRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::CategoryNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        SaveCategory,
+        backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    ) {
+        let category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new().categories());
+
+        (SaveCategory::new(category_repository.clone()), category_repository)
+    }
+
+    fn input(parent_id: Option<String>) -> SaveCategoryInput {
+        SaveCategoryInput {
+            id: backoffice::domain::category::CategoryId::default().to_primitive(),
+            parent_id,
+            name: String::from("Shoes"),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_existing_parent_when_exec_then_append_category_below_it() {
+        let (usecase, category_repository) = compose_fixture();
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        parent.save(&category_repository).await;
+        backoffice::domain::category::fixture::CategoryBuilder::child_of(&parent)
+            .save(&category_repository)
+            .await;
+
+        let category = usecase.exec(input(Some(parent.id.to_primitive()))).await.unwrap();
+
+        assert_eq!(category.parent_id, Some(parent.id));
+        assert_eq!(category.position, 1);
+        assert_eq!(category.path.depth(), 1);
+    }
+
+    #[tokio::test]
+    async fn given_unknown_or_malformed_parent_when_exec_then_return_error() {
+        let (usecase, _) = compose_fixture();
+
+        let unknown = usecase
+            .exec(input(Some(
+                backoffice::domain::category::CategoryId::default().to_primitive(),
+            )))
+            .await;
+        let malformed = usecase.exec(input(Some(String::from("not-a-uuid")))).await;
+
+        assert!(matches!(unknown, Err(common::domain::Error::CategoryNotFound)));
+        assert!(matches!(malformed, Err(common::domain::Error::InvalidCategoryParentId)));
+    }
+}
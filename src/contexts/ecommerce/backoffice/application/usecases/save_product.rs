@@ -5,12 +5,14 @@ use tracing::Instrument;
 use crate::contexts::ecommerce::{backoffice, common};
 
 pub struct SaveProduct {
-    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    unit_of_work_factory: common::application::unit_of_work::DynUnitOfWorkFactory<common::domain::Error>,
 }
 
 impl SaveProduct {
-    pub fn new(product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>) -> Self {
-        Self { product_repository }
+    pub fn new(
+        unit_of_work_factory: common::application::unit_of_work::DynUnitOfWorkFactory<common::domain::Error>,
+    ) -> Self {
+        Self { unit_of_work_factory }
     }
 }
 
@@ -18,25 +20,92 @@ impl SaveProduct {
 pub struct SaveProductInput {
     pub id: String,
     pub name: String,
-    pub price: i32,
-    pub currency: String,
+    pub price: common::application::inputs::MoneyInput,
 }
 
 #[async_trait]
 impl common::application::usecase::UseCase for SaveProduct {
     type Input = SaveProductInput;
-    type Output = ();
+    type Output = backoffice::domain::product::Product;
 
     type Error = common::domain::Error;
 
     async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         tracing::debug!("{:?}", input);
 
-        let new_product = backoffice::domain::product::Product::new(input.id, input.name, input.price, input.currency)?;
+        let new_product =
+            backoffice::domain::product::Product::new(input.id, input.name, input.price.amount, input.price.currency)?;
 
-        self.product_repository
+        let unit_of_work = self
+            .unit_of_work_factory
+            .begin()
+            .instrument(tracing::info_span!("Invoke UnitOfWorkFactory.begin"))
+            .await?;
+
+        unit_of_work
+            .product_repository()
             .save(&new_product)
             .instrument(tracing::info_span!("Invoke ProductRepository.save"))
-            .await
+            .await?;
+
+        unit_of_work
+            .commit()
+            .instrument(tracing::info_span!("Invoke UnitOfWork.commit"))
+            .await?;
+
+        Ok(new_product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        SaveProduct,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let unit_of_work_factory = Arc::new(product_repository.unit_of_work_factory());
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(product_repository);
+
+        (SaveProduct::new(unit_of_work_factory), product_repository)
+    }
+
+    fn input(id: &backoffice::domain::product::ProductId) -> SaveProductInput {
+        SaveProductInput {
+            id: id.to_string(),
+            name: String::from("Keyboard"),
+            price: common::application::inputs::MoneyInput {
+                amount: String::from("1.00"),
+                currency: String::from("EUR"),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn given_valid_input_when_exec_then_commit_product() {
+        let (usecase, repository) = compose_fixture();
+        let id = backoffice::domain::product::ProductId::default();
+
+        usecase.exec(input(&id)).await.unwrap();
+
+        assert!(repository.get_by_id(&id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn given_existing_product_when_exec_then_return_already_exists() {
+        let (usecase, _) = compose_fixture();
+        let id = backoffice::domain::product::ProductId::default();
+
+        usecase.exec(input(&id)).await.unwrap();
+        let result = usecase.exec(input(&id)).await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductAlreadyExists)));
     }
 }
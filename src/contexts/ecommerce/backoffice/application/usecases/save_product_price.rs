@@ -0,0 +1,118 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct SaveProductPrice {
+    product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+}
+
+impl SaveProductPrice {
+    pub fn new(
+        product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_price_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SaveProductPriceInput {
+    pub id: String,
+    pub product_id: String,
+    pub price: common::application::inputs::MoneyInput,
+    pub valid_from: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub valid_until: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for SaveProductPrice {
+    type Input = SaveProductPriceInput;
+    type Output = backoffice::domain::product_price::ProductPrice;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let price = backoffice::domain::product_price::ProductPrice::new(
+            input.id,
+            input.product_id,
+            input.price.amount,
+            input.price.currency,
+            input.valid_from,
+            input.valid_until,
+        )?;
+
+        self.product_price_repository
+            .save(&price)
+            .instrument(tracing::info_span!("Invoke ProductPriceRepository.save"))
+            .await?;
+
+        Ok(price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        SaveProductPrice,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+    ) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<
+            common::domain::Error,
+        > = Arc::new(product_repository.prices());
+
+        (
+            SaveProductPrice::new(product_price_repository.clone()),
+            Arc::new(product_repository),
+            product_price_repository,
+        )
+    }
+
+    fn input(product_id: &backoffice::domain::product::ProductId, amount: &str) -> SaveProductPriceInput {
+        SaveProductPriceInput {
+            id: backoffice::domain::product_price::ProductPriceId::default().to_primitive(),
+            product_id: product_id.to_primitive(),
+            price: common::application::inputs::MoneyInput {
+                amount: String::from(amount),
+                currency: String::from("USD"),
+            },
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn given_valid_input_when_exec_then_save_price() {
+        let (usecase, product_repository, product_price_repository) = compose_fixture();
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&product_repository).await;
+
+        usecase.exec(input(&product.id, "10.99")).await.unwrap();
+
+        let prices = product_price_repository.get_by_product_id(&product.id).await.unwrap();
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0].price.to_string(), "10.99 USD");
+    }
+
+    #[tokio::test]
+    async fn given_negative_amount_when_exec_then_return_validation_error() {
+        let (usecase, product_repository, _) = compose_fixture();
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&product_repository).await;
+
+        let result = usecase.exec(input(&product.id, "-1")).await;
+
+        assert!(matches!(result, Err(common::domain::Error::Validation(_))));
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct SaveVariant {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+}
+
+impl SaveVariant {
+    pub fn new(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_repository,
+            variant_repository,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SaveVariantInput {
+    pub id: String,
+    pub product_id: String,
+    pub sku: String,
+    /// Option name to the value picked for it, one entry per option of the product.
+    pub options: BTreeMap<String, String>,
+    /// Overrides the product price, `None` to sell at the product price.
+    pub price: Option<common::application::inputs::MoneyInput>,
+    pub barcode: Option<String>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for SaveVariant {
+    type Input = SaveVariantInput;
+    type Output = backoffice::domain::variant::Variant;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let variant = backoffice::domain::variant::Variant::new(
+            input.id,
+            input.product_id,
+            input.sku,
+            input.options,
+            input.price.map(|price| (price.amount, price.currency)),
+            input.barcode,
+        )?;
+
+        // an unknown product has no options, which must not read as a mismatch of the picked ones
+        self.product_repository
+            .get_by_id(&variant.product_id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        let options = self
+            .variant_repository
+            .get_options(&variant.product_id)
+            .instrument(tracing::info_span!("Invoke VariantRepository.get_options"))
+            .await?;
+        backoffice::domain::variant::VariantOption::validate_selection(&options, &variant.options)?;
+
+        self.variant_repository
+            .save(&variant)
+            .instrument(tracing::info_span!("Invoke VariantRepository.save"))
+            .await?;
+
+        Ok(variant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        SaveVariant,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+    ) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error> =
+            Arc::new(product_repository.variants());
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(product_repository);
+
+        (
+            SaveVariant::new(product_repository.clone(), variant_repository.clone()),
+            product_repository,
+            variant_repository,
+        )
+    }
+
+    async fn save_product_with_colours(
+        product_repository: &backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        variant_repository: &backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+    ) -> backoffice::domain::product::ProductId {
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(product_repository).await;
+
+        let colour = backoffice::domain::variant::VariantOption::new(
+            String::from("colour"),
+            vec![String::from("Sunburst"), String::from("Black")],
+        )
+        .unwrap();
+        variant_repository.save_options(&product.id, &[colour]).await.unwrap();
+
+        product.id
+    }
+
+    fn input(product_id: &backoffice::domain::product::ProductId, colour: &str) -> SaveVariantInput {
+        SaveVariantInput {
+            id: backoffice::domain::variant::VariantId::default().to_primitive(),
+            product_id: product_id.to_primitive(),
+            sku: String::from("strat-sb"),
+            options: BTreeMap::from([(String::from("colour"), String::from(colour))]),
+            price: Some(common::application::inputs::MoneyInput {
+                amount: String::from("3799.00"),
+                currency: String::from("EUR"),
+            }),
+            barcode: Some(String::from("4006381333931")),
+        }
+    }
+
+    #[tokio::test]
+    async fn given_valid_input_when_exec_then_save_variant() {
+        let (usecase, product_repository, variant_repository) = compose_fixture();
+        let product_id = save_product_with_colours(&product_repository, &variant_repository).await;
+
+        usecase.exec(input(&product_id, "Sunburst")).await.unwrap();
+
+        let variants = variant_repository.get_by_product_id(&product_id).await.unwrap();
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].sku.to_primitive(), "STRAT-SB");
+        assert_eq!(variants[0].price.unwrap().to_string(), "3799.00 EUR");
+    }
+
+    #[tokio::test]
+    async fn given_value_outside_product_options_when_exec_then_return_invalid_variant_options() {
+        let (usecase, product_repository, variant_repository) = compose_fixture();
+        let product_id = save_product_with_colours(&product_repository, &variant_repository).await;
+
+        let result = usecase.exec(input(&product_id, "Olympic White")).await;
+
+        assert!(matches!(result, Err(common::domain::Error::InvalidVariantOptions)));
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_exec_then_return_product_not_found() {
+        let (usecase, _, _) = compose_fixture();
+
+        let result = usecase
+            .exec(input(&backoffice::domain::product::ProductId::default(), "Sunburst"))
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductNotFound)));
+    }
+}
@@ -0,0 +1,141 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct ScheduleProduct {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+}
+
+impl ScheduleProduct {
+    pub fn new(product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>) -> Self {
+        Self { product_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScheduleProductInput {
+    pub id: String,
+    pub publish_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub unpublish_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for ScheduleProduct {
+    type Input = ScheduleProductInput;
+    type Output = backoffice::domain::product::Product;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let id = backoffice::domain::product::ProductId::try_from(input.id)?;
+
+        let product = self
+            .product_repository
+            .get_by_id(&id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        let scheduled_product = product.schedule(input.publish_at, input.unpublish_at)?;
+
+        // the schedule was checked against this status, it must not have moved on in the meantime
+        self.product_repository
+            .update_status(&scheduled_product, product.status)
+            .instrument(tracing::info_span!("Invoke ProductRepository.update_status"))
+            .await?;
+
+        Ok(scheduled_product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        ScheduleProduct,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new());
+
+        (ScheduleProduct::new(product_repository.clone()), product_repository)
+    }
+
+    #[tokio::test]
+    async fn given_draft_when_exec_then_store_schedule_and_keep_status() {
+        let (usecase, repository) = compose_fixture();
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default();
+        builder.save(&repository).await;
+
+        let publish_at = chrono::Utc::now() + chrono::Duration::days(1);
+        let unpublish_at = publish_at + chrono::Duration::days(30);
+
+        usecase
+            .exec(ScheduleProductInput {
+                id: builder.id.to_string(),
+                publish_at: Some(publish_at),
+                unpublish_at: Some(unpublish_at),
+            })
+            .await
+            .unwrap();
+
+        let product = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+        assert_eq!(product.status, backoffice::domain::product::ProductStatus::Draft);
+        assert_eq!(
+            product.publish_at.map(|publish_at| publish_at.to_datetime()),
+            Some(publish_at)
+        );
+        assert_eq!(
+            product.unpublish_at.map(|unpublish_at| unpublish_at.to_datetime()),
+            Some(unpublish_at)
+        );
+    }
+
+    #[tokio::test]
+    async fn given_unpublish_before_publish_when_exec_then_return_validation_error() {
+        let (usecase, repository) = compose_fixture();
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default();
+        builder.save(&repository).await;
+
+        let publish_at = chrono::Utc::now() + chrono::Duration::days(1);
+
+        let result = usecase
+            .exec(ScheduleProductInput {
+                id: builder.id.to_string(),
+                publish_at: Some(publish_at),
+                unpublish_at: Some(publish_at - chrono::Duration::hours(1)),
+            })
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn given_archived_product_when_exec_then_return_invalid_transition() {
+        let (usecase, repository) = compose_fixture();
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Archived);
+        builder.save(&repository).await;
+
+        let result = usecase
+            .exec(ScheduleProductInput {
+                id: builder.id.to_string(),
+                publish_at: Some(chrono::Utc::now() + chrono::Duration::days(1)),
+                unpublish_at: None,
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(common::domain::Error::InvalidProductStatusTransition)
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+use axum::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct SetVariantOptions {
+    variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+}
+
+impl SetVariantOptions {
+    pub fn new(variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>) -> Self {
+        Self { variant_repository }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VariantOptionInput {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SetVariantOptionsInput {
+    pub product_id: String,
+    /// Every option of the product in display order, replacing the current ones.
+    pub options: Vec<VariantOptionInput>,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for SetVariantOptions {
+    type Input = SetVariantOptionsInput;
+    type Output = Vec<backoffice::domain::variant::VariantOption>;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+        let options = input
+            .options
+            .into_iter()
+            .map(|option| backoffice::domain::variant::VariantOption::new(option.name, option.values))
+            .collect::<Result<Vec<_>, _>>()?;
+        backoffice::domain::variant::VariantOption::validate_definitions(&options)?;
+
+        // dropping an option or a value some variant still picks would leave that variant unsellable
+        let variants = self
+            .variant_repository
+            .get_by_product_id(&product_id)
+            .instrument(tracing::info_span!("Invoke VariantRepository.get_by_product_id"))
+            .await?;
+        if variants.iter().any(|variant| {
+            backoffice::domain::variant::VariantOption::validate_selection(&options, &variant.options).is_err()
+        }) {
+            return Err(common::domain::Error::VariantOptionsInUse).inspect_err(|err| tracing::error!("{err}"));
+        }
+
+        self.variant_repository
+            .save_options(&product_id, &options)
+            .instrument(tracing::info_span!("Invoke VariantRepository.save_options"))
+            .await?;
+
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        SetVariantOptions,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+    ) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error> =
+            Arc::new(product_repository.variants());
+
+        (
+            SetVariantOptions::new(variant_repository.clone()),
+            Arc::new(product_repository),
+            variant_repository,
+        )
+    }
+
+    fn input(product_id: &backoffice::domain::product::ProductId, values: &[&str]) -> SetVariantOptionsInput {
+        SetVariantOptionsInput {
+            product_id: product_id.to_primitive(),
+            options: vec![VariantOptionInput {
+                name: String::from("colour"),
+                values: values.iter().map(|value| value.to_string()).collect(),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn given_valid_input_when_exec_then_replace_options() {
+        let (usecase, product_repository, variant_repository) = compose_fixture();
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&product_repository).await;
+
+        usecase
+            .exec(input(&product.id, &[" Sunburst ", "Black"]))
+            .await
+            .unwrap();
+
+        let options = variant_repository.get_options(&product.id).await.unwrap();
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].values, vec!["Sunburst", "Black"]);
+    }
+
+    #[tokio::test]
+    async fn given_value_picked_by_a_variant_when_exec_without_it_then_return_options_in_use() {
+        let (usecase, product_repository, variant_repository) = compose_fixture();
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&product_repository).await;
+
+        usecase.exec(input(&product.id, &["Sunburst", "Black"])).await.unwrap();
+        backoffice::domain::variant::fixture::VariantBuilder::new(product.id)
+            .option("colour", "Black")
+            .save(&variant_repository)
+            .await;
+
+        let result = usecase.exec(input(&product.id, &["Sunburst"])).await;
+
+        assert!(matches!(result, Err(common::domain::Error::VariantOptionsInUse)));
+        assert_eq!(
+            variant_repository.get_options(&product.id).await.unwrap()[0].values,
+            vec!["Sunburst", "Black"]
+        );
+    }
+}
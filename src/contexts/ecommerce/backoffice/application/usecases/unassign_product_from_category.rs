@@ -0,0 +1,42 @@
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct UnassignProductFromCategory {
+    category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+}
+
+impl UnassignProductFromCategory {
+    pub fn new(
+        category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    ) -> Self {
+        Self { category_repository }
+    }
+}
+
+#[derive(Debug)]
+pub struct UnassignProductFromCategoryInput {
+    pub category_id: String,
+    pub product_id: String,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for UnassignProductFromCategory {
+    type Input = UnassignProductFromCategoryInput;
+    type Output = ();
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let category_id = backoffice::domain::category::CategoryId::try_from(input.category_id)?;
+        let product_id = backoffice::domain::product::ProductId::try_from(input.product_id)?;
+
+        self.category_repository
+            .unassign_product(&category_id, &product_id)
+            .instrument(tracing::info_span!("Invoke CategoryRepository.unassign_product"))
+            .await
+    }
+}
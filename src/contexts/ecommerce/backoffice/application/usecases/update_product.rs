@@ -0,0 +1,135 @@
+use axum::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub struct UpdateProduct {
+    unit_of_work_factory: common::application::unit_of_work::DynUnitOfWorkFactory<common::domain::Error>,
+}
+
+impl UpdateProduct {
+    pub fn new(
+        unit_of_work_factory: common::application::unit_of_work::DynUnitOfWorkFactory<common::domain::Error>,
+    ) -> Self {
+        Self { unit_of_work_factory }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateProductInput {
+    pub id: String,
+    pub name: String,
+    pub price: common::application::inputs::MoneyInput,
+}
+
+#[async_trait]
+impl common::application::usecase::UseCase for UpdateProduct {
+    type Input = UpdateProductInput;
+    type Output = backoffice::domain::product::Product;
+
+    type Error = common::domain::Error;
+
+    async fn exec(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        tracing::debug!("{:?}", input);
+
+        let id = backoffice::domain::product::ProductId::try_from(input.id)?;
+
+        let unit_of_work = self
+            .unit_of_work_factory
+            .begin()
+            .instrument(tracing::info_span!("Invoke UnitOfWorkFactory.begin"))
+            .await?;
+
+        let product = unit_of_work
+            .product_repository()
+            .get_by_id(&id)
+            .instrument(tracing::info_span!("Invoke ProductRepository.get_by_id"))
+            .await?
+            .ok_or(common::domain::Error::ProductNotFound)?;
+
+        let updated_product = product.update(input.name, input.price.amount, input.price.currency)?;
+
+        unit_of_work
+            .product_repository()
+            .update(&updated_product)
+            .instrument(tracing::info_span!("Invoke ProductRepository.update"))
+            .await?;
+
+        unit_of_work
+            .commit()
+            .instrument(tracing::info_span!("Invoke UnitOfWork.commit"))
+            .await?;
+
+        Ok(updated_product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::common::application::usecase::UseCase;
+
+    use super::*;
+
+    fn compose_fixture() -> (
+        UpdateProduct,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let unit_of_work_factory = Arc::new(product_repository.unit_of_work_factory());
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(product_repository);
+
+        (UpdateProduct::new(unit_of_work_factory), product_repository)
+    }
+
+    fn input(id: &backoffice::domain::product::ProductId, amount: &str) -> UpdateProductInput {
+        UpdateProductInput {
+            id: id.to_string(),
+            name: String::from("Keyboard"),
+            price: common::application::inputs::MoneyInput {
+                amount: String::from(amount),
+                currency: String::from("EUR"),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn given_saved_product_when_exec_then_commit_new_name_and_price() {
+        let (usecase, repository) = compose_fixture();
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default();
+        builder.save(&repository).await;
+
+        usecase.exec(input(&builder.id, "12.99")).await.unwrap();
+
+        let product = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+        assert_eq!(product.name.to_primitive(), "Keyboard");
+        assert_eq!(product.price.to_string(), "12.99 EUR");
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_exec_then_return_product_not_found() {
+        let (usecase, _) = compose_fixture();
+
+        let result = usecase
+            .exec(input(&backoffice::domain::product::ProductId::default(), "12.99"))
+            .await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductNotFound)));
+    }
+
+    #[tokio::test]
+    async fn given_invalid_price_when_exec_then_return_validation_error_and_keep_product() {
+        let (usecase, repository) = compose_fixture();
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default();
+        builder.save(&repository).await;
+
+        let result = usecase.exec(input(&builder.id, "-1.00")).await;
+
+        assert!(matches!(result, Err(common::domain::Error::Validation(_))));
+        let product = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+        assert_eq!(product.price, builder.price);
+    }
+}
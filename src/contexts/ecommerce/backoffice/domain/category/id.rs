@@ -0,0 +1,72 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CategoryId(uuid::Uuid);
+
+impl CategoryId {
+    fn validate(value: impl Into<String>) -> Result<uuid::Uuid, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate CategoryId").entered();
+
+        uuid::Uuid::parse_str(&value.into())
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|_| common::domain::Error::InvalidCategoryId)
+    }
+
+    pub fn to_uuid(self) -> uuid::Uuid {
+        let _e = tracing::debug_span!("Transform CategoryId to uuid").entered();
+
+        self.0
+    }
+
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform CategoryId to primitive").entered();
+
+        self.0.to_string()
+    }
+}
+
+impl Default for CategoryId {
+    fn default() -> Self {
+        let _e = tracing::debug_span!("New CategoryId").entered();
+
+        Self(uuid::Uuid::new_v4())
+    }
+}
+
+impl Display for CategoryId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display CategoryId").entered();
+
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<uuid::Uuid> for CategoryId {
+    fn from(value: uuid::Uuid) -> Self {
+        let _e = tracing::debug_span!("Cast CategoryId from uuid::Uuid").entered();
+
+        Self(value)
+    }
+}
+
+impl TryFrom<&str> for CategoryId {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast CategoryId from &str").entered();
+
+        Ok(Self(Self::validate(value)?))
+    }
+}
+
+impl TryFrom<String> for CategoryId {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast CategoryId from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
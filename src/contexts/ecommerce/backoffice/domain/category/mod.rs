@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+pub use id::*;
+pub use name::*;
+pub use path::*;
+pub use repository::*;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+mod id;
+mod name;
+mod path;
+mod repository;
+
+/// Node of the catalogue taxonomy. Categories form a tree, products are assigned to any number of them.
+#[derive(Clone)]
+pub struct Category {
+    pub id: CategoryId,
+    pub parent_id: Option<CategoryId>,
+    pub name: CategoryName,
+    /// Place among the children of the same parent, counted from 0.
+    pub position: i32,
+    pub path: CategoryPath,
+    pub created_at: backoffice::domain::product::ProductTimeStamp,
+    pub updated_at: backoffice::domain::product::ProductTimeStamp,
+}
+
+impl Category {
+    /// New category below `parent`, or a root one without. Its position is assigned when saved.
+    pub fn new(id: String, name: String, parent: Option<&Category>) -> Result<Self, common::domain::Error> {
+        let id = CategoryId::try_from(id);
+        let name = CategoryName::try_from(name);
+
+        let errors: Vec<common::domain::Error> = [id.as_ref().err(), name.as_ref().err()]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect();
+
+        if !errors.is_empty() {
+            return Err(common::domain::Error::Validation(errors));
+        }
+
+        let id = id?;
+        let now = backoffice::domain::product::ProductTimeStamp::default();
+
+        Ok(Self {
+            id,
+            parent_id: parent.map(|parent| parent.id),
+            name: name?,
+            position: 0,
+            path: parent.map_or_else(|| CategoryPath::root(id), |parent| parent.path.child(id)),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Checks that the category may move below `parent`, which must not lie in its own subtree.
+    pub fn validate_parent(&self, parent: Option<&Category>) -> Result<(), common::domain::Error> {
+        let _e = tracing::debug_span!("Validate Category parent").entered();
+
+        match parent {
+            Some(parent) if parent.path.contains(&self.id) => {
+                Err(common::domain::Error::CategoryCycle).inspect_err(|err| tracing::error!("{err}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Orders categories as the tree reads from top to bottom, every parent followed by its subtree and
+    /// children by position.
+    pub fn sort_depth_first(categories: Vec<Category>) -> Vec<Category> {
+        let mut children: HashMap<Option<CategoryId>, Vec<Category>> = HashMap::new();
+        for category in categories {
+            children.entry(category.parent_id).or_default().push(category);
+        }
+        for siblings in children.values_mut() {
+            // stable, so siblings sharing a position keep the order they came in
+            siblings.sort_by_key(|category| category.position);
+        }
+
+        let mut sorted = Vec::new();
+        let mut pending: Vec<Category> = children.remove(&None).unwrap_or_default().into_iter().rev().collect();
+
+        while let Some(category) = pending.pop() {
+            pending.extend(
+                children
+                    .remove(&Some(category.id))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .rev(),
+            );
+            sorted.push(category);
+        }
+
+        sorted
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use crate::contexts::ecommerce::common;
+    use crate::libs;
+
+    use super::*;
+
+    pub struct CategoryBuilder {
+        pub id: CategoryId,
+        pub parent_id: Option<CategoryId>,
+        pub name: CategoryName,
+        pub path: CategoryPath,
+    }
+
+    impl Default for CategoryBuilder {
+        /// Root category with a random name.
+        fn default() -> Self {
+            let id = CategoryId::default();
+            let random_name = libs::random::generate_alphanumeric_string(None);
+
+            Self {
+                id,
+                parent_id: None,
+                name: CategoryName::try_from(random_name).unwrap(),
+                path: CategoryPath::root(id),
+            }
+        }
+    }
+
+    impl CategoryBuilder {
+        pub fn child_of(parent: &CategoryBuilder) -> Self {
+            let root = Self::default();
+
+            Self {
+                parent_id: Some(parent.id),
+                path: parent.path.child(root.id),
+                ..root
+            }
+        }
+
+        pub fn to_entity(&self) -> Category {
+            let now = backoffice::domain::product::ProductTimeStamp::default();
+
+            Category {
+                id: self.id,
+                parent_id: self.parent_id,
+                name: self.name.clone(),
+                position: 0,
+                path: self.path.clone(),
+                created_at: now,
+                updated_at: now,
+            }
+        }
+
+        pub async fn save(&self, repository: &DynCategoryRepository<common::domain::Error>) {
+            repository.save(&self.to_entity()).await.unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_several_invalid_fields_when_new_then_return_all_validation_errors() {
+        let error = Category::new(String::from("not-a-uuid"), String::new(), None)
+            .err()
+            .unwrap();
+
+        let common::domain::Error::Validation(errors) = error else {
+            panic!("expected validation error, got {error}");
+        };
+
+        let fields: Vec<_> = errors.iter().filter_map(|error| error.field()).collect();
+        assert_eq!(fields, vec!["id", "name"]);
+    }
+
+    #[test]
+    fn given_parent_when_new_then_extend_its_path() {
+        let parent = fixture::CategoryBuilder::default().to_entity();
+        let id = CategoryId::default();
+
+        let category = Category::new(id.to_primitive(), String::from("Shoes"), Some(&parent)).unwrap();
+
+        assert_eq!(category.parent_id, Some(parent.id));
+        assert_eq!(category.path, parent.path.child(id));
+    }
+
+    #[test]
+    fn given_own_descendant_when_validate_parent_then_return_cycle_error() {
+        let root = fixture::CategoryBuilder::default();
+        let child = fixture::CategoryBuilder::child_of(&root);
+        let other = fixture::CategoryBuilder::default();
+
+        let root = root.to_entity();
+
+        assert!(matches!(
+            root.validate_parent(Some(&child.to_entity())),
+            Err(common::domain::Error::CategoryCycle)
+        ));
+        assert!(matches!(
+            root.validate_parent(Some(&root)),
+            Err(common::domain::Error::CategoryCycle)
+        ));
+        assert!(root.validate_parent(Some(&other.to_entity())).is_ok());
+        assert!(root.validate_parent(None).is_ok());
+    }
+
+    #[test]
+    fn given_categories_when_sort_depth_first_then_return_parents_before_children_by_position() {
+        let root = fixture::CategoryBuilder::default();
+        let first = fixture::CategoryBuilder::child_of(&root);
+        let second = fixture::CategoryBuilder::child_of(&root);
+        let grandchild = fixture::CategoryBuilder::child_of(&second);
+        let other_root = fixture::CategoryBuilder::default();
+
+        let with_position = |builder: &fixture::CategoryBuilder, position| Category {
+            position,
+            ..builder.to_entity()
+        };
+
+        let sorted = Category::sort_depth_first(vec![
+            with_position(&grandchild, 0),
+            with_position(&first, 1),
+            with_position(&other_root, 1),
+            with_position(&second, 0),
+            with_position(&root, 0),
+        ]);
+
+        let ids: Vec<_> = sorted.iter().map(|category| category.id).collect();
+        assert_eq!(ids, vec![root.id, second.id, grandchild.id, first.id, other_root.id]);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+use super::*;
+
+const SEPARATOR: char = '/';
+
+/// Ids of a category and of all its ancestors, from the root down, stored as `/<root id>/.../<id>/`.
+///
+/// The trailing separator makes the path of a category a string prefix of the paths of its whole
+/// subtree and of nothing else, which is what subtree queries match on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategoryPath(Vec<CategoryId>);
+
+impl CategoryPath {
+    pub fn root(id: CategoryId) -> Self {
+        let _e = tracing::debug_span!("New root CategoryPath").entered();
+
+        Self(vec![id])
+    }
+
+    pub fn child(&self, id: CategoryId) -> Self {
+        let _e = tracing::debug_span!("New child CategoryPath").entered();
+
+        let mut ids = self.0.clone();
+        ids.push(id);
+
+        Self(ids)
+    }
+
+    /// Whether `id` is the category itself or one of its ancestors.
+    pub fn contains(&self, id: &CategoryId) -> bool {
+        self.0.contains(id)
+    }
+
+    /// Whether this path lies in the subtree rooted at `ancestor`, itself included.
+    pub fn starts_with(&self, ancestor: &CategoryPath) -> bool {
+        self.0.starts_with(&ancestor.0)
+    }
+
+    /// Moves this path from below `from` to below `to`, as when the subtree rooted at `from` moves.
+    /// The path must lie in that subtree.
+    pub fn rebase(&self, from: &CategoryPath, to: &CategoryPath) -> Self {
+        let _e = tracing::debug_span!("Rebase CategoryPath").entered();
+
+        let mut ids = to.0.clone();
+        ids.extend_from_slice(&self.0[from.0.len()..]);
+
+        Self(ids)
+    }
+
+    /// Number of ancestors, 0 for a root category.
+    pub fn depth(&self) -> usize {
+        self.0.len() - 1
+    }
+
+    pub fn to_primitive(&self) -> String {
+        let _e = tracing::debug_span!("Transform CategoryPath to primitive").entered();
+
+        self.to_string()
+    }
+}
+
+impl Display for CategoryPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display CategoryPath").entered();
+
+        write!(f, "{SEPARATOR}")?;
+        for id in &self.0 {
+            write!(f, "{id}{SEPARATOR}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for CategoryPath {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast CategoryPath from &str").entered();
+
+        let ids = value
+            .strip_prefix(SEPARATOR)
+            .and_then(|value| value.strip_suffix(SEPARATOR))
+            .filter(|value| !value.is_empty())
+            .ok_or(common::domain::Error::InvalidCategoryId)
+            .inspect_err(|err| tracing::error!("{err}"))?
+            .split(SEPARATOR)
+            .map(CategoryId::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(ids))
+    }
+}
+
+impl TryFrom<String> for CategoryPath {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast CategoryPath from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_path_when_to_primitive_and_back_then_return_same_path() {
+        let path = CategoryPath::root(CategoryId::default()).child(CategoryId::default());
+
+        let primitive = path.to_primitive();
+
+        assert!(primitive.starts_with('/') && primitive.ends_with('/'));
+        assert_eq!(CategoryPath::try_from(primitive).unwrap(), path);
+    }
+
+    #[test]
+    fn given_malformed_path_when_try_from_then_return_invalid_category_id() {
+        for value in ["", "/", "//", "no-separators", "/not-a-uuid/"] {
+            assert!(matches!(
+                CategoryPath::try_from(value),
+                Err(common::domain::Error::InvalidCategoryId)
+            ));
+        }
+    }
+
+    #[test]
+    fn given_subtree_when_starts_with_then_match_only_descendants_and_itself() {
+        let root = CategoryPath::root(CategoryId::default());
+        let child = root.child(CategoryId::default());
+        let sibling = CategoryPath::root(CategoryId::default());
+
+        assert!(child.starts_with(&root));
+        assert!(root.starts_with(&root));
+        assert!(!root.starts_with(&child));
+        assert!(!sibling.starts_with(&root));
+        assert_eq!(child.depth(), 1);
+    }
+
+    #[test]
+    fn given_subtree_moved_when_rebase_then_keep_the_ids_below_it() {
+        let moved = CategoryPath::root(CategoryId::default()).child(CategoryId::default());
+        let descendant = moved.child(CategoryId::default());
+        let target = CategoryPath::root(CategoryId::default()).child(moved.0[1]);
+
+        let rebased = descendant.rebase(&moved, &target);
+
+        assert_eq!(rebased, target.child(descendant.0[2]));
+    }
+}
@@ -0,0 +1,336 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::backoffice;
+
+use super::*;
+
+pub type DynCategoryRepository<E> = Arc<dyn CategoryRepository<Error = E> + Send + Sync + 'static>;
+
+#[async_trait]
+pub trait CategoryRepository {
+    type Error;
+
+    /// Every category in creation order, `Category::sort_depth_first` arranges them as a tree.
+    async fn get(&self) -> Result<Vec<Category>, Self::Error>;
+    async fn get_by_id(&self, id: &CategoryId) -> Result<Option<Category>, Self::Error>;
+
+    /// Creates the category as the last child of its parent, whatever its `position` and `path` say.
+    /// `CategoryNotFound` when the parent does not exist.
+    async fn save(&self, category: &Category) -> Result<(), Self::Error>;
+
+    /// Moves the category with its whole subtree below `parent_id`, or to the roots without, at
+    /// `position` among its new siblings. Positions past the last sibling place it last.
+    ///
+    /// `CategoryCycle` when `parent_id` lies in the subtree being moved.
+    async fn move_to(&self, id: &CategoryId, parent_id: Option<&CategoryId>, position: i32) -> Result<(), Self::Error>;
+
+    /// Assigns the product to the category, assigning it twice keeps a single assignment.
+    async fn assign_product(
+        &self,
+        id: &CategoryId,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<(), Self::Error>;
+    async fn unassign_product(
+        &self,
+        id: &CategoryId,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<(), Self::Error>;
+
+    /// First page of the products assigned to the category or, with `include_subcategories`, to any
+    /// category of its subtree, each once and in creation order.
+    async fn get_products(
+        &self,
+        id: &CategoryId,
+        include_subcategories: bool,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error>;
+}
+
+/// Behaviour every `CategoryRepository` implementation must share, run against each of them.
+///
+/// Every function receives the product repository of the same store, since products get assigned.
+#[cfg(test)]
+pub mod conformance {
+    use crate::contexts::ecommerce::{backoffice, common};
+
+    use super::*;
+
+    type Repository = DynCategoryRepository<common::domain::Error>;
+    type ProductRepository = backoffice::domain::product::DynProductRepository<common::domain::Error>;
+
+    async fn save_children(
+        repository: &Repository,
+        parent: &fixture::CategoryBuilder,
+    ) -> Vec<fixture::CategoryBuilder> {
+        let mut children = Vec::new();
+        for _ in 0..3 {
+            let child = fixture::CategoryBuilder::child_of(parent);
+            child.save(repository).await;
+            children.push(child);
+        }
+
+        children
+    }
+
+    async fn save_child_ids(repository: &Repository, parent: &fixture::CategoryBuilder) -> Vec<CategoryId> {
+        save_children(repository, parent)
+            .await
+            .iter()
+            .map(|child| child.id)
+            .collect()
+    }
+
+    async fn children_of(repository: &Repository, parent_id: Option<CategoryId>) -> Vec<(CategoryId, i32)> {
+        let mut children: Vec<_> = repository
+            .get()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|category| category.parent_id == parent_id)
+            .map(|category| (category.id, category.position))
+            .collect();
+        children.sort_by_key(|(_, position)| *position);
+
+        children
+    }
+
+    /// Pauses after saving so products saved in a row never share a millisecond creation time.
+    async fn save_product(products: &ProductRepository) -> backoffice::domain::product::ProductId {
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(products).await;
+        tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+
+        product.id
+    }
+
+    pub async fn given_saved_categories_when_get_by_id_then_return_same_category(
+        repository: Repository,
+        _: ProductRepository,
+    ) {
+        let root = fixture::CategoryBuilder::default();
+        let child = fixture::CategoryBuilder::child_of(&root);
+        root.save(&repository).await;
+        child.save(&repository).await;
+
+        let category = repository.get_by_id(&child.id).await.unwrap().unwrap();
+
+        assert_eq!(category.id, child.id);
+        assert_eq!(category.parent_id, Some(root.id));
+        assert!(category.name == child.name);
+        assert_eq!(category.position, 0);
+        assert_eq!(category.path, child.path);
+        assert!(repository.get_by_id(&CategoryId::default()).await.unwrap().is_none());
+        assert_eq!(repository.get().await.unwrap().len(), 2);
+    }
+
+    pub async fn given_existing_id_when_save_then_return_already_exists(repository: Repository, _: ProductRepository) {
+        let category = fixture::CategoryBuilder::default();
+        category.save(&repository).await;
+
+        let result = repository.save(&category.to_entity()).await;
+
+        assert!(matches!(result, Err(common::domain::Error::CategoryAlreadyExists)));
+    }
+
+    pub async fn given_unknown_parent_when_save_then_return_category_not_found(
+        repository: Repository,
+        _: ProductRepository,
+    ) {
+        let parent = fixture::CategoryBuilder::default();
+        let child = fixture::CategoryBuilder::child_of(&parent);
+
+        let result = repository.save(&child.to_entity()).await;
+
+        assert!(matches!(result, Err(common::domain::Error::CategoryNotFound)));
+    }
+
+    pub async fn given_siblings_when_save_then_append_them_in_order(repository: Repository, _: ProductRepository) {
+        let root = fixture::CategoryBuilder::default();
+        root.save(&repository).await;
+
+        let ids = save_child_ids(&repository, &root).await;
+
+        assert_eq!(
+            children_of(&repository, Some(root.id)).await,
+            vec![(ids[0], 0), (ids[1], 1), (ids[2], 2)]
+        );
+    }
+
+    pub async fn given_siblings_when_move_to_within_parent_then_reorder_them(
+        repository: Repository,
+        _: ProductRepository,
+    ) {
+        let root = fixture::CategoryBuilder::default();
+        root.save(&repository).await;
+        let ids = save_child_ids(&repository, &root).await;
+
+        repository.move_to(&ids[2], Some(&root.id), 0).await.unwrap();
+        assert_eq!(
+            children_of(&repository, Some(root.id)).await,
+            vec![(ids[2], 0), (ids[0], 1), (ids[1], 2)]
+        );
+
+        repository.move_to(&ids[2], Some(&root.id), 99).await.unwrap();
+        assert_eq!(
+            children_of(&repository, Some(root.id)).await,
+            vec![(ids[0], 0), (ids[1], 1), (ids[2], 2)]
+        );
+    }
+
+    pub async fn given_subtree_when_move_to_other_parent_then_rewrite_paths_and_positions(
+        repository: Repository,
+        _: ProductRepository,
+    ) {
+        let source = fixture::CategoryBuilder::default();
+        let target = fixture::CategoryBuilder::default();
+        source.save(&repository).await;
+        target.save(&repository).await;
+        let source_children = save_children(&repository, &source).await;
+        let target_children = save_child_ids(&repository, &target).await;
+
+        let grandchild = fixture::CategoryBuilder::child_of(&source_children[0]);
+        grandchild.save(&repository).await;
+
+        let source_children: Vec<_> = source_children.iter().map(|child| child.id).collect();
+        let moved = source_children[0];
+
+        repository.move_to(&moved, Some(&target.id), 1).await.unwrap();
+
+        assert_eq!(
+            children_of(&repository, Some(source.id)).await,
+            vec![(source_children[1], 0), (source_children[2], 1)]
+        );
+        assert_eq!(
+            children_of(&repository, Some(target.id)).await,
+            vec![
+                (target_children[0], 0),
+                (moved, 1),
+                (target_children[1], 2),
+                (target_children[2], 3)
+            ]
+        );
+
+        let grandchild = repository.get_by_id(&grandchild.id).await.unwrap().unwrap();
+        assert_eq!(
+            grandchild.path,
+            CategoryPath::root(target.id).child(moved).child(grandchild.id)
+        );
+        assert_eq!(grandchild.parent_id, Some(moved));
+
+        repository.move_to(&moved, None, 0).await.unwrap();
+
+        let grandchild = repository.get_by_id(&grandchild.id).await.unwrap().unwrap();
+        assert_eq!(grandchild.path, CategoryPath::root(moved).child(grandchild.id));
+        assert_eq!(children_of(&repository, None).await[0], (moved, 0));
+    }
+
+    pub async fn given_own_descendant_as_parent_when_move_to_then_return_cycle_and_keep_tree(
+        repository: Repository,
+        _: ProductRepository,
+    ) {
+        let root = fixture::CategoryBuilder::default();
+        let child = fixture::CategoryBuilder::child_of(&root);
+        root.save(&repository).await;
+        child.save(&repository).await;
+
+        for parent in [&child.id, &root.id] {
+            let result = repository.move_to(&root.id, Some(parent), 0).await;
+
+            assert!(matches!(result, Err(common::domain::Error::CategoryCycle)));
+        }
+
+        let category = repository.get_by_id(&root.id).await.unwrap().unwrap();
+        assert_eq!(category.parent_id, None);
+        assert_eq!(category.path, root.path);
+    }
+
+    pub async fn given_unknown_category_or_parent_when_move_to_then_return_category_not_found(
+        repository: Repository,
+        _: ProductRepository,
+    ) {
+        let category = fixture::CategoryBuilder::default();
+        category.save(&repository).await;
+
+        let unknown = CategoryId::default();
+
+        assert!(matches!(
+            repository.move_to(&unknown, None, 0).await,
+            Err(common::domain::Error::CategoryNotFound)
+        ));
+        assert!(matches!(
+            repository.move_to(&category.id, Some(&unknown), 0).await,
+            Err(common::domain::Error::CategoryNotFound)
+        ));
+    }
+
+    pub async fn given_products_in_subtree_when_get_products_then_return_each_once_in_creation_order(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let root = fixture::CategoryBuilder::default();
+        let child = fixture::CategoryBuilder::child_of(&root);
+        let other = fixture::CategoryBuilder::default();
+        for category in [&root, &child, &other] {
+            category.save(&repository).await;
+        }
+
+        let p1 = save_product(&products).await;
+        let p2 = save_product(&products).await;
+        let p3 = save_product(&products).await;
+        let unassigned = save_product(&products).await;
+
+        for (category, product) in [
+            (&child, &p3),
+            (&root, &p1),
+            (&child, &p1),
+            (&child, &p2),
+            (&child, &p2),
+            (&other, &unassigned),
+        ] {
+            repository.assign_product(&category.id, product).await.unwrap();
+        }
+
+        let ids = |products: Vec<backoffice::domain::product::Product>| -> Vec<_> {
+            products.iter().map(|product| product.id).collect()
+        };
+
+        assert_eq!(
+            ids(repository.get_products(&root.id, true).await.unwrap()),
+            vec![p1, p2, p3]
+        );
+        assert_eq!(ids(repository.get_products(&root.id, false).await.unwrap()), vec![p1]);
+        assert_eq!(
+            ids(repository.get_products(&child.id, true).await.unwrap()),
+            vec![p1, p2, p3]
+        );
+
+        repository.unassign_product(&child.id, &p1).await.unwrap();
+        repository.unassign_product(&child.id, &p1).await.unwrap();
+
+        assert_eq!(
+            ids(repository.get_products(&child.id, false).await.unwrap()),
+            vec![p2, p3]
+        );
+    }
+
+    pub async fn given_unknown_category_or_product_when_assign_product_then_return_not_found(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let category = fixture::CategoryBuilder::default();
+        category.save(&repository).await;
+        let product_id = save_product(&products).await;
+
+        assert!(matches!(
+            repository.assign_product(&CategoryId::default(), &product_id).await,
+            Err(common::domain::Error::CategoryNotFound)
+        ));
+        assert!(matches!(
+            repository
+                .assign_product(&category.id, &backoffice::domain::product::ProductId::default())
+                .await,
+            Err(common::domain::Error::ProductNotFound)
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common;
+
+use super::*;
+
+/// Converts money between currencies at the stored reference rates, with exact arithmetic and a
+/// single rounding to the minor unit of the target currency at the end.
+///
+/// A pair without a rate of its own is converted through EUR, the base of every ECB rate.
+#[derive(Clone)]
+pub struct CurrencyConverter {
+    exchange_rate_repository: DynExchangeRateRepository<common::domain::Error>,
+    rounding: common::domain::RoundingMode,
+}
+
+impl CurrencyConverter {
+    pub fn new(exchange_rate_repository: DynExchangeRateRepository<common::domain::Error>) -> Self {
+        Self {
+            exchange_rate_repository,
+            rounding: common::domain::RoundingMode::default(),
+        }
+    }
+
+    pub fn with_rounding_mode(mut self, rounding: common::domain::RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn rounding_mode(&self) -> common::domain::RoundingMode {
+        self.rounding
+    }
+
+    /// Units of `to` one unit of `from` buys on `on`.
+    pub async fn rate(
+        &self,
+        from: common::domain::Currency,
+        to: common::domain::Currency,
+        on: chrono::NaiveDate,
+    ) -> Result<common::domain::Rational, common::domain::Error> {
+        if from == to {
+            return Ok(common::domain::Rational::ONE);
+        }
+
+        if let Some(rate) = self.pair_rate(from, to, on).await? {
+            return Ok(rate);
+        }
+
+        let reference = common::domain::Currency::EUR;
+        if from == reference || to == reference {
+            return Err(common::domain::Error::ExchangeRateNotFound).inspect_err(|err| tracing::error!("{err}"));
+        }
+
+        let (Some(into_reference), Some(out_of_reference)) = (
+            self.pair_rate(from, reference, on).await?,
+            self.pair_rate(reference, to, on).await?,
+        ) else {
+            return Err(common::domain::Error::ExchangeRateNotFound).inspect_err(|err| tracing::error!("{err}"));
+        };
+
+        into_reference
+            .checked_mul(out_of_reference)
+            .ok_or(common::domain::Error::MoneyOverflow)
+            .inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    /// `money` in `to` at the rate of `on`.
+    pub async fn convert(
+        &self,
+        money: common::domain::Money,
+        to: common::domain::Currency,
+        on: chrono::NaiveDate,
+    ) -> Result<common::domain::Money, common::domain::Error> {
+        let rate = self.rate(money.currency(), to, on).await?;
+
+        money.convert(rate, to, self.rounding)
+    }
+
+    /// Rate stored for the pair in either direction, inverted when only the opposite one is known.
+    async fn pair_rate(
+        &self,
+        from: common::domain::Currency,
+        to: common::domain::Currency,
+        on: chrono::NaiveDate,
+    ) -> Result<Option<common::domain::Rational>, common::domain::Error> {
+        let direct = self
+            .exchange_rate_repository
+            .get_latest(from, to, on)
+            .instrument(tracing::info_span!("Invoke ExchangeRateRepository.get_latest"))
+            .await?;
+
+        if let Some(direct) = direct {
+            return Ok(Some(direct.rate.to_rational()));
+        }
+
+        let inverse = self
+            .exchange_rate_repository
+            .get_latest(to, from, on)
+            .instrument(tracing::info_span!("Invoke ExchangeRateRepository.get_latest"))
+            .await?;
+
+        // a stored rate is never zero, so it always has an inverse
+        Ok(inverse.and_then(|inverse| inverse.rate.to_rational().checked_recip()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::backoffice;
+
+    use super::*;
+
+    async fn compose_fixture(rounding: common::domain::RoundingMode) -> CurrencyConverter {
+        let repository: DynExchangeRateRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new().exchange_rates());
+
+        repository
+            .save_many(&[
+                fixture::exchange_rate("EUR", "USD", "1.0772", "2024-05-10"),
+                fixture::exchange_rate("EUR", "JPY", "167.76", "2024-05-10"),
+                fixture::exchange_rate("EUR", "KWD", "0.3306", "2024-05-10"),
+            ])
+            .await
+            .unwrap();
+
+        CurrencyConverter::new(repository).with_rounding_mode(rounding)
+    }
+
+    fn money(minor_units: i64, code: &str) -> common::domain::Money {
+        common::domain::Money::new(minor_units, common::domain::Currency::try_from(code).unwrap())
+    }
+
+    fn currency(code: &str) -> common::domain::Currency {
+        common::domain::Currency::try_from(code).unwrap()
+    }
+
+    #[tokio::test]
+    async fn given_stored_pair_when_convert_then_use_rate_in_both_directions() {
+        let converter = compose_fixture(common::domain::RoundingMode::HalfEven).await;
+        let on = fixture::date("2024-05-13");
+
+        // 9.99 EUR * 1.0772 = 10.761228 USD
+        assert_eq!(
+            converter.convert(money(999, "EUR"), currency("USD"), on).await.unwrap(),
+            money(1_076, "USD")
+        );
+        // 10.77 USD / 1.0772 = 9.99814333... EUR
+        assert_eq!(
+            converter
+                .convert(money(1_077, "USD"), currency("EUR"), on)
+                .await
+                .unwrap(),
+            money(1_000, "EUR")
+        );
+    }
+
+    #[tokio::test]
+    async fn given_pair_without_rate_when_convert_then_cross_through_eur() {
+        let converter = compose_fixture(common::domain::RoundingMode::Down).await;
+
+        // 100.00 USD / 1.0772 * 167.76 = 15573.7096... JPY
+        assert_eq!(
+            converter
+                .convert(money(10_000, "USD"), currency("JPY"), fixture::date("2024-05-10"))
+                .await
+                .unwrap(),
+            money(15_573, "JPY")
+        );
+        // 1000 JPY / 167.76 * 0.3306 = 1.970672... KWD
+        assert_eq!(
+            converter
+                .convert(money(1_000, "JPY"), currency("KWD"), fixture::date("2024-05-10"))
+                .await
+                .unwrap(),
+            money(1_970, "KWD")
+        );
+    }
+
+    #[tokio::test]
+    async fn given_rounding_modes_when_convert_then_round_final_amount_accordingly() {
+        // 0.50 USD / 1.0772 * 167.76 = 77.868... JPY
+        for (rounding, expected) in [
+            (common::domain::RoundingMode::HalfEven, 78),
+            (common::domain::RoundingMode::Down, 77),
+        ] {
+            let converter = compose_fixture(rounding).await;
+
+            assert_eq!(
+                converter
+                    .convert(money(50, "USD"), currency("JPY"), fixture::date("2024-05-10"))
+                    .await
+                    .unwrap(),
+                money(expected, "JPY")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn given_no_rate_on_or_before_date_when_convert_then_return_not_found() {
+        let converter = compose_fixture(common::domain::RoundingMode::HalfEven).await;
+
+        for (from, to, on) in [
+            ("EUR", "USD", "2024-05-09"),
+            ("USD", "JPY", "2024-05-09"),
+            ("EUR", "GBP", "2024-05-10"),
+            ("GBP", "USD", "2024-05-10"),
+        ] {
+            assert!(
+                matches!(
+                    converter
+                        .convert(money(100, from), currency(to), fixture::date(on))
+                        .await,
+                    Err(common::domain::Error::ExchangeRateNotFound)
+                ),
+                "{from} {to} {on}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn given_same_currency_when_convert_then_return_same_money() {
+        let converter = compose_fixture(common::domain::RoundingMode::HalfEven).await;
+
+        assert_eq!(
+            converter
+                .convert(money(999, "GBP"), currency("GBP"), fixture::date("2024-05-10"))
+                .await
+                .unwrap(),
+            money(999, "GBP")
+        );
+    }
+}
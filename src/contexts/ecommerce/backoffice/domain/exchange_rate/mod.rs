@@ -0,0 +1,115 @@
+pub use converter::*;
+pub use rate::*;
+pub use repository::*;
+
+use crate::contexts::ecommerce::common;
+
+mod converter;
+mod rate;
+mod repository;
+
+/// Reference rate of a day, `rate` units of `quote` buy one unit of `base`.
+///
+/// The ECB publishes every rate against EUR as base, one set per working day, so a rate stays in use
+/// until the next one for the same pair is published.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExchangeRate {
+    pub base: common::domain::Currency,
+    pub quote: common::domain::Currency,
+    pub rate: Rate,
+    pub valid_on: chrono::NaiveDate,
+}
+
+impl ExchangeRate {
+    pub fn new(
+        base: String,
+        quote: String,
+        rate: String,
+        valid_on: chrono::NaiveDate,
+    ) -> Result<Self, common::domain::Error> {
+        let base = common::domain::Currency::try_from(base);
+        let quote = common::domain::Currency::try_from(quote);
+        let rate = Rate::try_from(rate);
+
+        let errors: Vec<common::domain::Error> = [base.as_ref().err(), quote.as_ref().err(), rate.as_ref().err()]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect();
+
+        if !errors.is_empty() {
+            return Err(common::domain::Error::Validation(errors));
+        }
+
+        let (base, quote, rate) = (base?, quote?, rate?);
+
+        // a currency is always worth exactly itself
+        if base == quote {
+            return Err(common::domain::Error::Validation(vec![
+                common::domain::Error::InvalidExchangeRate,
+            ]));
+        }
+
+        Ok(Self {
+            base,
+            quote,
+            rate,
+            valid_on,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use super::*;
+
+    /// Rate of `quote` against `base` published on `valid_on`, given as `YYYY-MM-DD`.
+    pub fn exchange_rate(base: &str, quote: &str, rate: &str, valid_on: &str) -> ExchangeRate {
+        ExchangeRate::new(
+            String::from(base),
+            String::from(quote),
+            String::from(rate),
+            date(valid_on),
+        )
+        .unwrap()
+    }
+
+    pub fn date(value: &str) -> chrono::NaiveDate {
+        chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_invalid_currencies_and_rate_when_new_then_return_every_error() {
+        let error = ExchangeRate::new(
+            String::from("EUR"),
+            String::from("usd"),
+            String::from("N/A"),
+            fixture::date("2024-05-10"),
+        )
+        .err()
+        .unwrap();
+
+        let common::domain::Error::Validation(errors) = error else {
+            panic!("expected validation error, got {error}");
+        };
+
+        let codes: Vec<_> = errors.iter().map(|error| error.code()).collect();
+        assert_eq!(codes, vec!["INVALID_CURRENCY", "INVALID_EXCHANGE_RATE"]);
+    }
+
+    #[test]
+    fn given_same_base_and_quote_when_new_then_return_validation_error() {
+        assert!(ExchangeRate::new(
+            String::from("EUR"),
+            String::from("EUR"),
+            String::from("1"),
+            fixture::date("2024-05-10"),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+/// More fraction digits than any published reference rate.
+const MAX_SCALE: u32 = 12;
+
+/// Positive decimal rate exactly as published, e.g. `1.0772`, never turned into a float.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rate {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Rate {
+    fn validate(value: &str) -> Result<Self, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate Rate").entered();
+
+        Self::parse(value)
+            .ok_or(common::domain::Error::InvalidExchangeRate)
+            .inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+        let is_digits = |value: &str| value.bytes().all(|byte| byte.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) || value.ends_with('.') {
+            return None;
+        }
+
+        let scale = u32::try_from(fraction.len()).ok().filter(|scale| *scale <= MAX_SCALE)?;
+        let mantissa: i128 = format!("{integer}{fraction}").parse().ok()?;
+
+        if mantissa == 0 {
+            return None;
+        }
+
+        Some(Self { mantissa, scale })
+    }
+
+    pub fn to_rational(self) -> common::domain::Rational {
+        let _e = tracing::debug_span!("Transform Rate to rational").entered();
+
+        common::domain::Rational::new(self.mantissa, 10_i128.pow(self.scale)).expect("scale is bounded")
+    }
+
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform Rate to primitive").entered();
+
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", self.mantissa, width = scale + 1);
+
+        if scale == 0 {
+            return digits;
+        }
+
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+
+        format!("{integer}.{fraction}")
+    }
+}
+
+impl Display for Rate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_primitive())
+    }
+}
+
+impl TryFrom<&str> for Rate {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast Rate from &str").entered();
+
+        Self::validate(value)
+    }
+}
+
+impl TryFrom<String> for Rate {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_published_rates_when_try_from_then_keep_exact_digits() {
+        for value in ["1.0772", "167.76", "0.86008", "4", "0.000123456789"] {
+            assert_eq!(Rate::try_from(value).unwrap().to_primitive(), value);
+        }
+
+        assert_eq!(
+            Rate::try_from("1.0772").unwrap().to_rational(),
+            common::domain::Rational::new(2_693, 2_500).unwrap()
+        );
+    }
+
+    #[test]
+    fn given_malformed_zero_or_too_precise_rates_when_try_from_then_return_err() {
+        for value in [
+            "",
+            "N/A",
+            "1,07",
+            "-1.5",
+            ".5",
+            "1.",
+            "0",
+            "0.000",
+            "1e3",
+            "0.0000000000001",
+        ] {
+            assert!(Rate::try_from(value).is_err(), "{value}");
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::common;
+
+use super::*;
+
+pub type DynExchangeRateRepository<E> = Arc<dyn ExchangeRateRepository<Error = E> + Send + Sync + 'static>;
+
+#[async_trait]
+pub trait ExchangeRateRepository {
+    type Error;
+
+    /// Latest rate of the pair published on or before `on`.
+    async fn get_latest(
+        &self,
+        base: common::domain::Currency,
+        quote: common::domain::Currency,
+        on: chrono::NaiveDate,
+    ) -> Result<Option<ExchangeRate>, Self::Error>;
+
+    /// Stores all rates at once, replacing those already known for the same pair and day so that a
+    /// file can be imported again.
+    async fn save_many(&self, rates: &[ExchangeRate]) -> Result<(), Self::Error>;
+}
+
+/// Behaviour every `ExchangeRateRepository` implementation must share, run against each of them.
+#[cfg(test)]
+pub mod conformance {
+    use crate::contexts::ecommerce::common;
+
+    use super::*;
+
+    type Repository = DynExchangeRateRepository<common::domain::Error>;
+
+    fn currency(code: &str) -> common::domain::Currency {
+        common::domain::Currency::try_from(code).unwrap()
+    }
+
+    pub async fn given_no_rates_when_get_latest_then_return_none(repository: Repository) {
+        assert!(repository
+            .get_latest(currency("EUR"), currency("USD"), fixture::date("2024-05-10"))
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    pub async fn given_daily_rates_when_get_latest_then_return_last_published_on_or_before_date(
+        repository: Repository,
+    ) {
+        repository
+            .save_many(&[
+                fixture::exchange_rate("EUR", "USD", "1.0772", "2024-05-08"),
+                fixture::exchange_rate("EUR", "USD", "1.0765", "2024-05-10"),
+                fixture::exchange_rate("EUR", "JPY", "167.76", "2024-05-09"),
+            ])
+            .await
+            .unwrap();
+
+        let latest = |on: &'static str| {
+            let repository = repository.clone();
+            async move {
+                repository
+                    .get_latest(currency("EUR"), currency("USD"), fixture::date(on))
+                    .await
+                    .unwrap()
+                    .map(|rate| rate.rate.to_primitive())
+            }
+        };
+
+        assert_eq!(latest("2024-05-07").await, None);
+        assert_eq!(latest("2024-05-08").await.as_deref(), Some("1.0772"));
+        assert_eq!(latest("2024-05-09").await.as_deref(), Some("1.0772"));
+        assert_eq!(latest("2024-05-13").await.as_deref(), Some("1.0765"));
+    }
+
+    pub async fn given_saved_rate_when_get_latest_then_return_same_rate(repository: Repository) {
+        let rate = fixture::exchange_rate("EUR", "IDR", "17358.02000", "2024-05-10");
+        repository.save_many(std::slice::from_ref(&rate)).await.unwrap();
+
+        let saved = repository
+            .get_latest(rate.base, rate.quote, rate.valid_on)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(saved, rate);
+    }
+
+    pub async fn given_saved_rates_when_save_many_same_day_then_replace_them(repository: Repository) {
+        repository
+            .save_many(&[fixture::exchange_rate("EUR", "USD", "1.0772", "2024-05-10")])
+            .await
+            .unwrap();
+        repository
+            .save_many(&[fixture::exchange_rate("EUR", "USD", "1.0780", "2024-05-10")])
+            .await
+            .unwrap();
+
+        let saved = repository
+            .get_latest(currency("EUR"), currency("USD"), fixture::date("2024-05-10"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(saved.rate.to_primitive(), "1.0780");
+    }
+
+    pub async fn given_same_pair_and_day_twice_when_save_many_then_keep_last(repository: Repository) {
+        repository
+            .save_many(&[
+                fixture::exchange_rate("EUR", "USD", "1.0772", "2024-05-10"),
+                fixture::exchange_rate("EUR", "USD", "1.0780", "2024-05-10"),
+            ])
+            .await
+            .unwrap();
+
+        let saved = repository
+            .get_latest(currency("EUR"), currency("USD"), fixture::date("2024-05-10"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(saved.rate.to_primitive(), "1.0780");
+    }
+
+    pub async fn given_no_rates_when_save_many_nothing_then_succeed(repository: Repository) {
+        repository.save_many(&[]).await.unwrap();
+    }
+}
@@ -0,0 +1,91 @@
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::*;
+
+/// Change of the on-hand quantity of a product in one warehouse, kept as the audit trail of its stock.
+#[derive(Clone)]
+pub struct StockAdjustment {
+    pub id: StockAdjustmentId,
+    pub product_id: backoffice::domain::product::ProductId,
+    pub warehouse: WarehouseCode,
+    /// Units added, or taken out when negative.
+    pub delta: i64,
+    pub reason: AdjustmentReason,
+    pub created_at: backoffice::domain::product::ProductTimeStamp,
+}
+
+impl StockAdjustment {
+    pub fn new(
+        id: String,
+        product_id: String,
+        warehouse: String,
+        delta: i64,
+        reason: String,
+    ) -> Result<Self, common::domain::Error> {
+        let id = StockAdjustmentId::try_from(id);
+        let product_id = backoffice::domain::product::ProductId::try_from(product_id);
+        let warehouse = WarehouseCode::try_from(warehouse);
+        let delta = Self::validate_delta(delta);
+        let reason = AdjustmentReason::try_from(reason);
+
+        let errors: Vec<common::domain::Error> = [
+            id.as_ref().err(),
+            product_id.as_ref().err(),
+            warehouse.as_ref().err(),
+            delta.as_ref().err(),
+            reason.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+        if !errors.is_empty() {
+            return Err(common::domain::Error::Validation(errors));
+        }
+
+        Ok(Self {
+            id: id?,
+            product_id: product_id?,
+            warehouse: warehouse?,
+            delta: delta?,
+            reason: reason?,
+            created_at: backoffice::domain::product::ProductTimeStamp::default(),
+        })
+    }
+
+    fn validate_delta(delta: i64) -> Result<i64, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate StockAdjustment delta").entered();
+
+        if delta != 0 && delta.abs() <= STOCK_QUANTITY_MAX {
+            return Ok(delta);
+        }
+
+        Err(common::domain::Error::InvalidStockAdjustmentDelta).inspect_err(|err| tracing::error!("{err}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_several_invalid_fields_when_new_then_return_all_validation_errors() {
+        let error = StockAdjustment::new(
+            StockAdjustmentId::default().to_primitive(),
+            backoffice::domain::product::ProductId::default().to_primitive(),
+            String::from("MAD 1"),
+            0,
+            String::from("stolen"),
+        )
+        .err()
+        .unwrap();
+
+        let common::domain::Error::Validation(errors) = error else {
+            panic!("expected validation error, got {error}");
+        };
+
+        let fields: Vec<_> = errors.iter().filter_map(|error| error.field()).collect();
+        assert_eq!(fields, vec!["warehouse", "delta", "reason"]);
+    }
+}
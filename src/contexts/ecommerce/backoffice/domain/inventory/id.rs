@@ -0,0 +1,141 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StockAdjustmentId(uuid::Uuid);
+
+impl StockAdjustmentId {
+    fn validate(value: impl Into<String>) -> Result<uuid::Uuid, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate StockAdjustmentId").entered();
+
+        uuid::Uuid::parse_str(&value.into())
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|_| common::domain::Error::InvalidStockAdjustmentId)
+    }
+
+    pub fn to_uuid(self) -> uuid::Uuid {
+        let _e = tracing::debug_span!("Transform StockAdjustmentId to uuid").entered();
+
+        self.0
+    }
+
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform StockAdjustmentId to primitive").entered();
+
+        self.0.to_string()
+    }
+}
+
+impl Default for StockAdjustmentId {
+    fn default() -> Self {
+        let _e = tracing::debug_span!("New StockAdjustmentId").entered();
+
+        Self(uuid::Uuid::new_v4())
+    }
+}
+
+impl Display for StockAdjustmentId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display StockAdjustmentId").entered();
+
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<uuid::Uuid> for StockAdjustmentId {
+    fn from(value: uuid::Uuid) -> Self {
+        let _e = tracing::debug_span!("Cast StockAdjustmentId from uuid::Uuid").entered();
+
+        Self(value)
+    }
+}
+
+impl TryFrom<&str> for StockAdjustmentId {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast StockAdjustmentId from &str").entered();
+
+        Ok(Self(Self::validate(value)?))
+    }
+}
+
+impl TryFrom<String> for StockAdjustmentId {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast StockAdjustmentId from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReservationId(uuid::Uuid);
+
+impl ReservationId {
+    fn validate(value: impl Into<String>) -> Result<uuid::Uuid, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate ReservationId").entered();
+
+        uuid::Uuid::parse_str(&value.into())
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|_| common::domain::Error::InvalidReservationId)
+    }
+
+    pub fn to_uuid(self) -> uuid::Uuid {
+        let _e = tracing::debug_span!("Transform ReservationId to uuid").entered();
+
+        self.0
+    }
+
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform ReservationId to primitive").entered();
+
+        self.0.to_string()
+    }
+}
+
+impl Default for ReservationId {
+    fn default() -> Self {
+        let _e = tracing::debug_span!("New ReservationId").entered();
+
+        Self(uuid::Uuid::new_v4())
+    }
+}
+
+impl Display for ReservationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display ReservationId").entered();
+
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<uuid::Uuid> for ReservationId {
+    fn from(value: uuid::Uuid) -> Self {
+        let _e = tracing::debug_span!("Cast ReservationId from uuid::Uuid").entered();
+
+        Self(value)
+    }
+}
+
+impl TryFrom<&str> for ReservationId {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ReservationId from &str").entered();
+
+        Ok(Self(Self::validate(value)?))
+    }
+}
+
+impl TryFrom<String> for ReservationId {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ReservationId from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
@@ -0,0 +1,131 @@
+pub use adjustment::*;
+pub use id::*;
+pub use reason::*;
+pub use repository::*;
+pub use reservation::*;
+pub use warehouse::*;
+
+use crate::contexts::ecommerce::backoffice;
+
+mod adjustment;
+mod id;
+mod reason;
+mod repository;
+mod reservation;
+mod warehouse;
+
+/// Upper bound of any quantity of units, far beyond a real warehouse so that sums never overflow.
+pub const STOCK_QUANTITY_MAX: i64 = 1_000_000_000;
+
+/// Stock of a product in one warehouse.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockLevel {
+    pub product_id: backoffice::domain::product::ProductId,
+    pub warehouse: WarehouseCode,
+    /// Units physically in the warehouse, committed reservations already taken out.
+    pub on_hand: i64,
+    /// Units held by reservations still pending.
+    pub reserved: i64,
+}
+
+impl StockLevel {
+    /// Units that can still be reserved.
+    pub fn available(&self) -> i64 {
+        self.on_hand - self.reserved
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use crate::contexts::ecommerce::{backoffice, common};
+
+    use super::*;
+
+    pub struct StockAdjustmentBuilder {
+        pub id: StockAdjustmentId,
+        pub product_id: backoffice::domain::product::ProductId,
+        pub warehouse: WarehouseCode,
+        pub delta: i64,
+        pub reason: AdjustmentReason,
+    }
+
+    impl StockAdjustmentBuilder {
+        /// Receipt of `delta` units of `product_id` in `warehouse`.
+        pub fn new(product_id: backoffice::domain::product::ProductId, warehouse: &str, delta: i64) -> Self {
+            Self {
+                id: StockAdjustmentId::default(),
+                product_id,
+                warehouse: WarehouseCode::try_from(warehouse).unwrap(),
+                delta,
+                reason: AdjustmentReason::Received,
+            }
+        }
+
+        pub fn reason(mut self, reason: AdjustmentReason) -> Self {
+            self.reason = reason;
+            self
+        }
+
+        pub fn to_entity(&self) -> StockAdjustment {
+            StockAdjustment {
+                id: self.id,
+                product_id: self.product_id,
+                warehouse: self.warehouse.clone(),
+                delta: self.delta,
+                reason: self.reason,
+                created_at: backoffice::domain::product::ProductTimeStamp::default(),
+            }
+        }
+
+        pub async fn save(&self, repository: &DynInventoryRepository<common::domain::Error>) -> StockLevel {
+            repository.adjust(&self.to_entity()).await.unwrap()
+        }
+    }
+
+    pub struct ReservationBuilder {
+        pub id: ReservationId,
+        pub product_id: backoffice::domain::product::ProductId,
+        pub warehouse: WarehouseCode,
+        pub quantity: i64,
+        pub ttl_seconds: i64,
+    }
+
+    impl ReservationBuilder {
+        /// Pending reservation of `quantity` units of `product_id` in `warehouse`.
+        pub fn new(product_id: backoffice::domain::product::ProductId, warehouse: &str, quantity: i64) -> Self {
+            Self {
+                id: ReservationId::default(),
+                product_id,
+                warehouse: WarehouseCode::try_from(warehouse).unwrap(),
+                quantity,
+                ttl_seconds: RESERVATION_DEFAULT_TTL_SECONDS,
+            }
+        }
+
+        pub fn ttl_seconds(mut self, ttl_seconds: i64) -> Self {
+            self.ttl_seconds = ttl_seconds;
+            self
+        }
+
+        pub fn to_entity(&self) -> Reservation {
+            let now = backoffice::domain::product::ProductTimeStamp::default();
+
+            Reservation {
+                id: self.id,
+                product_id: self.product_id,
+                warehouse: self.warehouse.clone(),
+                quantity: self.quantity,
+                status: ReservationStatus::Pending,
+                expires_at: (now.to_datetime() + chrono::Duration::seconds(self.ttl_seconds)).into(),
+                created_at: now,
+            }
+        }
+
+        pub async fn save(&self, repository: &DynInventoryRepository<common::domain::Error>) -> Reservation {
+            let reservation = self.to_entity();
+            repository.reserve(&reservation).await.unwrap();
+
+            reservation
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+/// Why the on-hand quantity of a warehouse changed outside of a committed reservation.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AdjustmentReason {
+    /// Goods arrived from a supplier.
+    Received,
+    /// A customer sent goods back in sellable condition.
+    Returned,
+    Damaged,
+    Lost,
+    /// A stock count found a different quantity than recorded.
+    Correction,
+}
+
+impl AdjustmentReason {
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform AdjustmentReason to primitive").entered();
+
+        self.to_string()
+    }
+}
+
+impl Display for AdjustmentReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display AdjustmentReason").entered();
+
+        match self {
+            Self::Received => write!(f, "received"),
+            Self::Returned => write!(f, "returned"),
+            Self::Damaged => write!(f, "damaged"),
+            Self::Lost => write!(f, "lost"),
+            Self::Correction => write!(f, "correction"),
+        }
+    }
+}
+
+impl TryFrom<&str> for AdjustmentReason {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast AdjustmentReason from &str").entered();
+
+        match value {
+            "received" => Ok(AdjustmentReason::Received),
+            "returned" => Ok(AdjustmentReason::Returned),
+            "damaged" => Ok(AdjustmentReason::Damaged),
+            "lost" => Ok(AdjustmentReason::Lost),
+            "correction" => Ok(AdjustmentReason::Correction),
+            _ => Err(common::domain::Error::InvalidStockAdjustmentReason).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+}
+
+impl TryFrom<String> for AdjustmentReason {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast AdjustmentReason from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
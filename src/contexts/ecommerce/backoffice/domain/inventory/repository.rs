@@ -0,0 +1,451 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::backoffice;
+
+use super::*;
+
+pub type DynInventoryRepository<E> = Arc<dyn InventoryRepository<Error = E> + Send + Sync + 'static>;
+
+/// Stock, adjustments and reservations of products.
+///
+/// Every write checks what is available and applies its change atomically, so concurrent calls never
+/// reserve or take out more units than the warehouse holds.
+#[async_trait]
+pub trait InventoryRepository {
+    type Error;
+
+    /// Stock of a product in every warehouse it was ever adjusted in, by warehouse code. Only
+    /// reservations still pending at `at` count as reserved.
+    async fn get_stock(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Vec<StockLevel>, Self::Error>;
+
+    /// Adjustments of a product, oldest first.
+    async fn get_adjustments(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<StockAdjustment>, Self::Error>;
+
+    /// Applies the adjustment to the on-hand quantity and returns the resulting stock. Fails with
+    /// `InsufficientStock` when fewer units than reserved would be left.
+    async fn adjust(&self, adjustment: &StockAdjustment) -> Result<StockLevel, Self::Error>;
+
+    async fn get_reservation(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &ReservationId,
+    ) -> Result<Option<Reservation>, Self::Error>;
+
+    /// Holds the units of a pending reservation. Fails with `InsufficientStock` when fewer units are
+    /// available at its creation.
+    async fn reserve(&self, reservation: &Reservation) -> Result<(), Self::Error>;
+
+    /// Takes the units of a reservation pending at `at` out of the on-hand quantity for good.
+    async fn commit(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Reservation, Self::Error>;
+
+    /// Gives the units of a reservation back, see `Reservation::release`.
+    async fn release(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Reservation, Self::Error>;
+}
+
+/// Behaviour every `InventoryRepository` implementation must share, run against each of them.
+///
+/// Every function receives the product repository of the same store, since stock belongs to a product.
+#[cfg(test)]
+pub mod conformance {
+    use crate::contexts::ecommerce::{backoffice, common};
+
+    use super::*;
+
+    type Repository = DynInventoryRepository<common::domain::Error>;
+    type ProductRepository = backoffice::domain::product::DynProductRepository<common::domain::Error>;
+
+    async fn save_product(products: &ProductRepository) -> backoffice::domain::product::ProductId {
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(products).await;
+
+        product.id
+    }
+
+    fn now() -> backoffice::domain::product::ProductTimeStamp {
+        backoffice::domain::product::ProductTimeStamp::default()
+    }
+
+    fn levels(stock: &[StockLevel]) -> Vec<(String, i64, i64)> {
+        stock
+            .iter()
+            .map(|level| (level.warehouse.to_primitive(), level.on_hand, level.reserved))
+            .collect()
+    }
+
+    pub async fn given_no_stock_when_get_then_return_empty_vecs(repository: Repository, products: ProductRepository) {
+        let product_id = save_product(&products).await;
+
+        assert!(repository.get_stock(&product_id, now()).await.unwrap().is_empty());
+        assert!(repository.get_adjustments(&product_id).await.unwrap().is_empty());
+    }
+
+    pub async fn given_adjustments_when_get_stock_then_sum_them_per_warehouse_in_code_order(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+
+        let received = fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 10);
+        let damaged = fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", -3).reason(AdjustmentReason::Damaged);
+        let other_warehouse = fixture::StockAdjustmentBuilder::new(product_id, "BCN-1", 5);
+        received.save(&repository).await;
+        let level = damaged.save(&repository).await;
+        other_warehouse.save(&repository).await;
+        fixture::StockAdjustmentBuilder::new(other_product_id, "MAD-1", 1)
+            .save(&repository)
+            .await;
+
+        assert_eq!(levels(&[level]), vec![(String::from("MAD-1"), 7, 0)]);
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("BCN-1"), 5, 0), (String::from("MAD-1"), 7, 0)]
+        );
+
+        let adjustments = repository.get_adjustments(&product_id).await.unwrap();
+        assert_eq!(
+            adjustments
+                .iter()
+                .map(|adjustment| (
+                    adjustment.id,
+                    adjustment.warehouse.to_primitive(),
+                    adjustment.delta,
+                    adjustment.reason
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (received.id, String::from("MAD-1"), 10, AdjustmentReason::Received),
+                (damaged.id, String::from("MAD-1"), -3, AdjustmentReason::Damaged),
+                (other_warehouse.id, String::from("BCN-1"), 5, AdjustmentReason::Received),
+            ]
+        );
+    }
+
+    pub async fn given_reserved_stock_when_adjust_below_it_then_return_insufficient_stock_and_keep_stock(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+        fixture::ReservationBuilder::new(product_id, "MAD-1", 3)
+            .save(&repository)
+            .await;
+
+        for adjustment in [
+            fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", -3).reason(AdjustmentReason::Lost),
+            fixture::StockAdjustmentBuilder::new(product_id, "BCN-1", -1).reason(AdjustmentReason::Correction),
+        ] {
+            assert!(matches!(
+                repository.adjust(&adjustment.to_entity()).await,
+                Err(common::domain::Error::InsufficientStock)
+            ));
+        }
+
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), 5, 3)]
+        );
+        assert_eq!(repository.get_adjustments(&product_id).await.unwrap().len(), 1);
+    }
+
+    pub async fn given_saved_adjustment_when_adjust_with_same_id_then_return_already_exists(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        let adjustment = fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5);
+        adjustment.save(&repository).await;
+
+        assert!(matches!(
+            repository.adjust(&adjustment.to_entity()).await,
+            Err(common::domain::Error::StockAdjustmentAlreadyExists)
+        ));
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), 5, 0)]
+        );
+    }
+
+    pub async fn given_unknown_product_when_adjust_then_return_product_not_found(
+        repository: Repository,
+        _products: ProductRepository,
+    ) {
+        let adjustment =
+            fixture::StockAdjustmentBuilder::new(backoffice::domain::product::ProductId::default(), "MAD-1", 5);
+
+        assert!(matches!(
+            repository.adjust(&adjustment.to_entity()).await,
+            Err(common::domain::Error::ProductNotFound)
+        ));
+    }
+
+    pub async fn given_stock_when_reserve_then_hold_it_until_released(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+        let reservation = fixture::ReservationBuilder::new(product_id, "MAD-1", 3)
+            .save(&repository)
+            .await;
+
+        let stock = repository.get_stock(&product_id, now()).await.unwrap();
+        assert_eq!(levels(&stock), vec![(String::from("MAD-1"), 5, 3)]);
+        assert_eq!(stock[0].available(), 2);
+
+        let found = repository
+            .get_reservation(&product_id, &reservation.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.warehouse, reservation.warehouse);
+        assert_eq!(found.quantity, 3);
+        assert_eq!(found.status, ReservationStatus::Pending);
+        assert_eq!(found.expires_at.to_primitive(), reservation.expires_at.to_primitive());
+
+        for (warehouse, quantity) in [("MAD-1", 3), ("BCN-1", 1)] {
+            let reservation = fixture::ReservationBuilder::new(product_id, warehouse, quantity);
+            assert!(matches!(
+                repository.reserve(&reservation.to_entity()).await,
+                Err(common::domain::Error::InsufficientStock)
+            ));
+        }
+
+        let released = repository.release(&product_id, &reservation.id, now()).await.unwrap();
+        assert_eq!(released.status, ReservationStatus::Released);
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), 5, 0)]
+        );
+    }
+
+    pub async fn given_pending_reservation_when_commit_then_take_it_out_of_on_hand_once(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+        let reservation = fixture::ReservationBuilder::new(product_id, "MAD-1", 2)
+            .save(&repository)
+            .await;
+
+        let committed = repository.commit(&product_id, &reservation.id, now()).await.unwrap();
+        assert_eq!(committed.status, ReservationStatus::Committed);
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), 3, 0)]
+        );
+
+        assert!(matches!(
+            repository.commit(&product_id, &reservation.id, now()).await,
+            Err(common::domain::Error::ReservationNotPending)
+        ));
+        assert!(matches!(
+            repository.release(&product_id, &reservation.id, now()).await,
+            Err(common::domain::Error::ReservationNotPending)
+        ));
+        assert_eq!(
+            repository
+                .get_reservation(&product_id, &reservation.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .status,
+            ReservationStatus::Committed
+        );
+    }
+
+    pub async fn given_expired_reservation_when_reserve_then_ignore_it_and_refuse_its_commit(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+        let expired = fixture::ReservationBuilder::new(product_id, "MAD-1", 5)
+            .ttl_seconds(-60)
+            .save(&repository)
+            .await;
+
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), 5, 0)]
+        );
+        fixture::ReservationBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+
+        assert!(matches!(
+            repository.commit(&product_id, &expired.id, now()).await,
+            Err(common::domain::Error::ReservationExpired)
+        ));
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), 5, 5)]
+        );
+    }
+
+    pub async fn given_saved_reservation_when_reserve_with_same_id_then_return_already_exists(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+        let reservation = fixture::ReservationBuilder::new(product_id, "MAD-1", 1);
+        reservation.save(&repository).await;
+
+        assert!(matches!(
+            repository.reserve(&reservation.to_entity()).await,
+            Err(common::domain::Error::ReservationAlreadyExists)
+        ));
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), 5, 1)]
+        );
+    }
+
+    pub async fn given_reservation_of_other_product_when_commit_or_release_then_return_not_found(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+
+        fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+        let reservation = fixture::ReservationBuilder::new(product_id, "MAD-1", 1)
+            .save(&repository)
+            .await;
+
+        for id in [reservation.id, ReservationId::default()] {
+            assert!(repository
+                .get_reservation(&other_product_id, &id)
+                .await
+                .unwrap()
+                .is_none());
+            assert!(matches!(
+                repository.commit(&other_product_id, &id, now()).await,
+                Err(common::domain::Error::ReservationNotFound)
+            ));
+            assert!(matches!(
+                repository.release(&other_product_id, &id, now()).await,
+                Err(common::domain::Error::ReservationNotFound)
+            ));
+        }
+    }
+
+    pub async fn given_stock_when_reserve_concurrently_then_never_oversell(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+
+        let attempts = (0..20).map(|_| {
+            let repository = repository.clone();
+            let reservation = fixture::ReservationBuilder::new(product_id, "MAD-1", 1).to_entity();
+
+            tokio::spawn(async move { repository.reserve(&reservation).await })
+        });
+        let results: Vec<_> = futures::future::join_all(attempts)
+            .await
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 5);
+        assert!(results
+            .iter()
+            .filter_map(|result| result.as_ref().err())
+            .all(|err| matches!(err, common::domain::Error::InsufficientStock)));
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), 5, 5)]
+        );
+    }
+
+    pub async fn given_pending_reservation_when_commit_and_release_concurrently_then_apply_only_one(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&repository)
+            .await;
+        let reservation = fixture::ReservationBuilder::new(product_id, "MAD-1", 2)
+            .save(&repository)
+            .await;
+
+        let attempts = (0..10).map(|attempt| {
+            let repository = repository.clone();
+            let id = reservation.id;
+
+            tokio::spawn(async move {
+                match attempt % 2 {
+                    0 => repository.commit(&product_id, &id, now()).await,
+                    _ => repository.release(&product_id, &id, now()).await,
+                }
+            })
+        });
+        let results: Vec<_> = futures::future::join_all(attempts)
+            .await
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        let applied: Vec<_> = results.iter().filter_map(|result| result.as_ref().ok()).collect();
+        assert_eq!(applied.len(), 1);
+        assert!(results
+            .iter()
+            .filter_map(|result| result.as_ref().err())
+            .all(|err| matches!(err, common::domain::Error::ReservationNotPending)));
+
+        let on_hand = match applied[0].status {
+            ReservationStatus::Committed => 3,
+            _ => 5,
+        };
+        assert_eq!(
+            levels(&repository.get_stock(&product_id, now()).await.unwrap()),
+            vec![(String::from("MAD-1"), on_hand, 0)]
+        );
+    }
+}
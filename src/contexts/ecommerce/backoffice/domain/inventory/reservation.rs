@@ -0,0 +1,266 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::*;
+
+/// How long a reservation holds its stock when the caller does not say.
+pub const RESERVATION_DEFAULT_TTL_SECONDS: i64 = 15 * 60;
+const RESERVATION_MAX_TTL_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ReservationStatus {
+    Pending,
+    Committed,
+    Released,
+    /// Pending past its expiry. Never stored, a pending reservation just stops counting once it expires.
+    Expired,
+}
+
+impl ReservationStatus {
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform ReservationStatus to primitive").entered();
+
+        self.to_string()
+    }
+}
+
+impl Display for ReservationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display ReservationStatus").entered();
+
+        match self {
+            Self::Pending => write!(f, "pending"),
+            Self::Committed => write!(f, "committed"),
+            Self::Released => write!(f, "released"),
+            Self::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+impl TryFrom<&str> for ReservationStatus {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ReservationStatus from &str").entered();
+
+        match value {
+            "pending" => Ok(ReservationStatus::Pending),
+            "committed" => Ok(ReservationStatus::Committed),
+            "released" => Ok(ReservationStatus::Released),
+            _ => Err(common::domain::Error::InvalidReservationStatus).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+}
+
+impl TryFrom<String> for ReservationStatus {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ReservationStatus from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Units of a product held in one warehouse for a checkout, so that nobody else can sell them.
+///
+/// A pending reservation counts against the available stock until `expires_at`. Committing it takes the
+/// units out of the on-hand quantity for good, releasing it gives them back.
+#[derive(Clone)]
+pub struct Reservation {
+    pub id: ReservationId,
+    pub product_id: backoffice::domain::product::ProductId,
+    pub warehouse: WarehouseCode,
+    pub quantity: i64,
+    pub status: ReservationStatus,
+    pub expires_at: backoffice::domain::product::ProductTimeStamp,
+    pub created_at: backoffice::domain::product::ProductTimeStamp,
+}
+
+impl Reservation {
+    /// Pending reservation expiring `ttl_seconds` from now.
+    pub fn new(
+        id: String,
+        product_id: String,
+        warehouse: String,
+        quantity: i64,
+        ttl_seconds: i64,
+    ) -> Result<Self, common::domain::Error> {
+        let id = ReservationId::try_from(id);
+        let product_id = backoffice::domain::product::ProductId::try_from(product_id);
+        let warehouse = WarehouseCode::try_from(warehouse);
+        let quantity = Self::validate_quantity(quantity);
+        let ttl = Self::validate_ttl(ttl_seconds);
+
+        let errors: Vec<common::domain::Error> = [
+            id.as_ref().err(),
+            product_id.as_ref().err(),
+            warehouse.as_ref().err(),
+            quantity.as_ref().err(),
+            ttl.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+        if !errors.is_empty() {
+            return Err(common::domain::Error::Validation(errors));
+        }
+
+        let now = backoffice::domain::product::ProductTimeStamp::default();
+
+        Ok(Self {
+            id: id?,
+            product_id: product_id?,
+            warehouse: warehouse?,
+            quantity: quantity?,
+            status: ReservationStatus::Pending,
+            expires_at: (now.to_datetime() + ttl?).into(),
+            created_at: now,
+        })
+    }
+
+    fn validate_quantity(quantity: i64) -> Result<i64, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate Reservation quantity").entered();
+
+        if let 1..=STOCK_QUANTITY_MAX = quantity {
+            return Ok(quantity);
+        }
+
+        Err(common::domain::Error::InvalidReservationQuantity).inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    fn validate_ttl(ttl_seconds: i64) -> Result<chrono::Duration, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate Reservation ttl").entered();
+
+        if let 1..=RESERVATION_MAX_TTL_SECONDS = ttl_seconds {
+            return Ok(chrono::Duration::seconds(ttl_seconds));
+        }
+
+        Err(common::domain::Error::InvalidReservationTtl).inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    /// Status as seen at `at`, `Expired` for a reservation still pending past its expiry.
+    pub fn status_at(&self, at: backoffice::domain::product::ProductTimeStamp) -> ReservationStatus {
+        match self.status {
+            ReservationStatus::Pending if self.expires_at <= at => ReservationStatus::Expired,
+            status => status,
+        }
+    }
+
+    /// Committed copy of a reservation pending at `at`.
+    pub fn commit(&self, at: backoffice::domain::product::ProductTimeStamp) -> Result<Self, common::domain::Error> {
+        let _e = tracing::debug_span!("Commit Reservation").entered();
+
+        match self.status_at(at) {
+            ReservationStatus::Pending => Ok(Self {
+                status: ReservationStatus::Committed,
+                ..self.clone()
+            }),
+            ReservationStatus::Expired => {
+                Err(common::domain::Error::ReservationExpired).inspect_err(|err| tracing::error!("{err}"))
+            }
+            _ => Err(common::domain::Error::ReservationNotPending).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+
+    /// Released copy of a reservation not committed or released yet. Releasing an expired one is allowed,
+    /// it only records what already happened to its stock.
+    pub fn release(&self, at: backoffice::domain::product::ProductTimeStamp) -> Result<Self, common::domain::Error> {
+        let _e = tracing::debug_span!("Release Reservation").entered();
+
+        match self.status_at(at) {
+            ReservationStatus::Pending | ReservationStatus::Expired => Ok(Self {
+                status: ReservationStatus::Released,
+                ..self.clone()
+            }),
+            _ => Err(common::domain::Error::ReservationNotPending).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reservation() -> Reservation {
+        Reservation::new(
+            ReservationId::default().to_primitive(),
+            backoffice::domain::product::ProductId::default().to_primitive(),
+            String::from("MAD-1"),
+            2,
+            60,
+        )
+        .unwrap()
+    }
+
+    fn later(reservation: &Reservation, seconds: i64) -> backoffice::domain::product::ProductTimeStamp {
+        (reservation.created_at.to_datetime() + chrono::Duration::seconds(seconds)).into()
+    }
+
+    #[test]
+    fn given_invalid_quantity_and_ttl_when_new_then_return_both_validation_errors() {
+        let error = Reservation::new(
+            ReservationId::default().to_primitive(),
+            backoffice::domain::product::ProductId::default().to_primitive(),
+            String::from("MAD-1"),
+            0,
+            RESERVATION_MAX_TTL_SECONDS + 1,
+        )
+        .err()
+        .unwrap();
+
+        let common::domain::Error::Validation(errors) = error else {
+            panic!("expected validation error, got {error}");
+        };
+
+        let fields: Vec<_> = errors.iter().filter_map(|error| error.field()).collect();
+        assert_eq!(fields, vec!["quantity", "ttl_seconds"]);
+    }
+
+    #[test]
+    fn given_pending_reservation_when_status_at_then_expire_it_at_its_expiry() {
+        let reservation = reservation();
+
+        assert_eq!(
+            reservation.status_at(later(&reservation, 59)),
+            ReservationStatus::Pending
+        );
+        assert_eq!(
+            reservation.status_at(later(&reservation, 60)),
+            ReservationStatus::Expired
+        );
+    }
+
+    #[test]
+    fn given_expired_reservation_when_commit_then_return_expired_but_allow_release() {
+        let reservation = reservation();
+        let at = later(&reservation, 60);
+
+        assert!(matches!(
+            reservation.commit(at),
+            Err(common::domain::Error::ReservationExpired)
+        ));
+        assert_eq!(reservation.release(at).unwrap().status, ReservationStatus::Released);
+    }
+
+    #[test]
+    fn given_committed_reservation_when_commit_or_release_then_return_not_pending() {
+        let committed = reservation()
+            .commit(backoffice::domain::product::ProductTimeStamp::default())
+            .unwrap();
+        let at = later(&committed, 3600);
+
+        assert_eq!(committed.status_at(at), ReservationStatus::Committed);
+        assert!(matches!(
+            committed.commit(at),
+            Err(common::domain::Error::ReservationNotPending)
+        ));
+        assert!(matches!(
+            committed.release(at),
+            Err(common::domain::Error::ReservationNotPending)
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+/// Short code of the warehouse holding the stock, e.g. `MAD-1`. Stored uppercase like SKUs, so that
+/// `mad-1` and `MAD-1` are the same warehouse.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WarehouseCode(String);
+
+impl WarehouseCode {
+    fn validate(value: impl Into<String>) -> Result<String, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate WarehouseCode").entered();
+
+        let value = value.into().trim().to_uppercase();
+
+        const WAREHOUSE_CODE_MIN_LENGTH: usize = 1;
+        const WAREHOUSE_CODE_MAX_LENGTH: usize = 32;
+
+        let allowed = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_');
+
+        if let WAREHOUSE_CODE_MIN_LENGTH..=WAREHOUSE_CODE_MAX_LENGTH = value.len() {
+            if value.chars().all(allowed) {
+                return Ok(value);
+            }
+        }
+
+        Err(common::domain::Error::InvalidWarehouseCode).inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    pub fn to_primitive(&self) -> String {
+        let _e = tracing::debug_span!("Transform WarehouseCode to primitive").entered();
+
+        self.0.clone()
+    }
+}
+
+impl Display for WarehouseCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display WarehouseCode").entered();
+
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<&str> for WarehouseCode {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast WarehouseCode from &str").entered();
+
+        Ok(Self(Self::validate(value)?))
+    }
+}
+
+impl TryFrom<String> for WarehouseCode {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast WarehouseCode from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_lowercase_code_when_try_from_then_return_uppercase_code() {
+        assert_eq!(WarehouseCode::try_from(" mad-1 ").unwrap().to_primitive(), "MAD-1");
+    }
+
+    #[test]
+    fn given_empty_or_too_long_or_spaced_code_when_try_from_then_return_invalid_code() {
+        for value in [String::new(), "W".repeat(33), String::from("MAD 1")] {
+            assert!(matches!(
+                WarehouseCode::try_from(value),
+                Err(common::domain::Error::InvalidWarehouseCode)
+            ));
+        }
+    }
+}
@@ -1 +1,8 @@
+pub mod category;
+pub mod exchange_rate;
+pub mod inventory;
 pub mod product;
+pub mod product_event;
+pub mod product_price;
+pub mod product_price_history;
+pub mod variant;
@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 
 use crate::contexts::ecommerce::common;
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ProductId(uuid::Uuid);
 
 impl ProductId {
@@ -1,37 +1,72 @@
-pub use currency::*;
 pub use id::*;
 pub use name::*;
-pub use price::*;
 pub use repository::*;
+pub use status::*;
 pub use timestamp::*;
 
 use crate::contexts::ecommerce::common;
 
-mod currency;
 mod id;
 mod name;
-mod price;
 mod repository;
+mod status;
 mod timestamp;
 
+/// Product of the catalogue.
+///
+/// New products start as drafts. `publish_at` publishes a draft once reached and `unpublish_at` archives
+/// the product once reached, both applied by `apply_schedule`.
+#[derive(Clone)]
 pub struct Product {
     pub id: ProductId,
     pub name: ProductName,
-    pub price: ProductPrice,
-    pub currency: ProductCurrency,
+    pub price: common::domain::Money,
+    pub status: ProductStatus,
+    pub publish_at: Option<ProductTimeStamp>,
+    pub unpublish_at: Option<ProductTimeStamp>,
     pub created_at: ProductTimeStamp,
     pub updated_at: ProductTimeStamp,
 }
 
 impl Product {
-    pub fn new(id: String, name: String, price: i32, currency: String) -> Result<Self, common::domain::Error> {
+    /// `price` is a decimal amount in `currency`, e.g. `"3600.00"` EUR or `"3600"` JPY.
+    pub fn new(id: String, name: String, price: String, currency: String) -> Result<Self, common::domain::Error> {
         let now = ProductTimeStamp::default();
 
+        let id = ProductId::try_from(id);
+        let name = ProductName::try_from(name);
+        let currency =
+            common::domain::Currency::try_from(currency).map_err(|_| common::domain::Error::InvalidProductCurrency);
+        // the amount can only be checked against the minor unit of a known currency
+        let price = currency
+            .as_ref()
+            .ok()
+            .map(|currency| Self::validate_price(&price, *currency))
+            .transpose();
+
+        // report every invalid field at once instead of stopping at the first one
+        let errors: Vec<common::domain::Error> = [
+            id.as_ref().err(),
+            name.as_ref().err(),
+            price.as_ref().err(),
+            currency.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+        if !errors.is_empty() {
+            return Err(common::domain::Error::Validation(errors));
+        }
+
         let product = Self {
-            id: ProductId::try_from(id)?,
-            name: ProductName::try_from(name)?,
-            price: ProductPrice::try_from(price)?,
-            currency: ProductCurrency::try_from(currency)?,
+            id: id?,
+            name: name?,
+            price: price?.ok_or(common::domain::Error::InvalidProductPrice)?,
+            status: ProductStatus::Draft,
+            publish_at: None,
+            unpublish_at: None,
             updated_at: now,
             created_at: now,
         };
@@ -41,6 +76,118 @@ impl Product {
         Ok(product)
     }
 
+    /// The same product with a new name and price, checked as by `new`. Status and schedule stay as they were.
+    pub fn update(&self, name: String, price: String, currency: String) -> Result<Self, common::domain::Error> {
+        let updated = Self::new(self.id.to_primitive(), name, price, currency)?;
+
+        Ok(Self {
+            status: self.status,
+            publish_at: self.publish_at,
+            unpublish_at: self.unpublish_at,
+            created_at: self.created_at,
+            ..updated
+        })
+    }
+
+    /// The same product moved to `status`, `InvalidProductStatusTransition` when the lifecycle forbids it.
+    ///
+    /// Publishing drops `publish_at` and archiving drops the whole schedule, as neither has anything left to do.
+    pub fn transition(&self, status: ProductStatus) -> Result<Self, common::domain::Error> {
+        let _e = tracing::debug_span!("Transition Product status").entered();
+
+        if !self.status.can_transition_to(status) {
+            return Err(common::domain::Error::InvalidProductStatusTransition)
+                .inspect_err(|err| tracing::error!("{err}"));
+        }
+
+        let (publish_at, unpublish_at) = match status {
+            ProductStatus::Draft => (self.publish_at, self.unpublish_at),
+            ProductStatus::Published => (None, self.unpublish_at),
+            ProductStatus::Archived => (None, None),
+        };
+
+        Ok(Self {
+            status,
+            publish_at,
+            unpublish_at,
+            updated_at: ProductTimeStamp::default(),
+            ..self.clone()
+        })
+    }
+
+    /// The same product with a new schedule, `None` clearing either side.
+    ///
+    /// Only drafts can be scheduled for publishing and archived products cannot be scheduled at all, both
+    /// fail with `InvalidProductStatusTransition`. `unpublish_at` must come after `publish_at`.
+    pub fn schedule(
+        &self,
+        publish_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+        unpublish_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+    ) -> Result<Self, common::domain::Error> {
+        let _e = tracing::debug_span!("Schedule Product").entered();
+
+        if let (Some(publish_at), Some(unpublish_at)) = (publish_at, unpublish_at) {
+            if publish_at >= unpublish_at {
+                return Err(common::domain::Error::Validation(vec![
+                    common::domain::Error::InvalidProductSchedule,
+                ]))
+                .inspect_err(|err| tracing::error!("{err}"));
+            }
+        }
+
+        let publishable = publish_at.is_none() || self.status.can_transition_to(ProductStatus::Published);
+        let unpublishable = unpublish_at.is_none() || self.status != ProductStatus::Archived;
+        if !publishable || !unpublishable {
+            return Err(common::domain::Error::InvalidProductStatusTransition)
+                .inspect_err(|err| tracing::error!("{err}"));
+        }
+
+        Ok(Self {
+            publish_at: publish_at.map(ProductTimeStamp::from),
+            unpublish_at: unpublish_at.map(ProductTimeStamp::from),
+            updated_at: ProductTimeStamp::default(),
+            ..self.clone()
+        })
+    }
+
+    /// The product after every transition its schedule makes by `at`, `None` when none is due yet.
+    pub fn apply_schedule(&self, at: ProductTimeStamp) -> Option<Self> {
+        let _e = tracing::debug_span!("Apply Product schedule").entered();
+
+        let mut product = self.clone();
+
+        // a product past both dates gets published and archived in one go, each transition drops its date
+        while let Some(status) = product.scheduled_status(at) {
+            product = product.transition(status).ok()?;
+        }
+
+        (product.status != self.status).then_some(product)
+    }
+
+    fn scheduled_status(&self, at: ProductTimeStamp) -> Option<ProductStatus> {
+        match self.status {
+            ProductStatus::Draft if self.publish_at.is_some_and(|publish_at| publish_at <= at) => {
+                Some(ProductStatus::Published)
+            }
+            ProductStatus::Published if self.unpublish_at.is_some_and(|unpublish_at| unpublish_at <= at) => {
+                Some(ProductStatus::Archived)
+            }
+            _ => None,
+        }
+    }
+
+    fn validate_price(
+        amount: &str,
+        currency: common::domain::Currency,
+    ) -> Result<common::domain::Money, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate Product price").entered();
+
+        match common::domain::Money::parse(amount, currency) {
+            Ok(price) if price.minor_units() >= 0 => Ok(price),
+            _ => Err(common::domain::Error::InvalidProductPrice).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+
     pub fn validate(&self) -> Result<(), common::domain::Error> {
         let _e = tracing::debug_span!("Validate Product").entered();
 
@@ -63,8 +210,10 @@ pub mod fixture {
     pub struct ProductBuilder {
         pub id: ProductId,
         pub name: ProductName,
-        pub price: ProductPrice,
-        pub currency: ProductCurrency,
+        pub price: common::domain::Money,
+        pub status: ProductStatus,
+        pub publish_at: Option<ProductTimeStamp>,
+        pub unpublish_at: Option<ProductTimeStamp>,
         pub created_at: ProductTimeStamp,
         pub updated_at: ProductTimeStamp,
     }
@@ -74,13 +223,16 @@ pub mod fixture {
             let now = ProductTimeStamp::default();
 
             let random_name = libs::random::generate_alphanumeric_string(None);
-            let random_price = libs::random::generate_int_from_range(Some(PRODUCT_PRICE_MIN), Some(PRODUCT_PRICE_MAX));
+            let random_price = libs::random::generate_int_from_range(Some(0), Some(i32::MAX));
+            let currency = common::domain::Currency::try_from("EUR").unwrap();
 
             Self {
                 id: ProductId::default(),
                 name: ProductName::try_from(random_name).unwrap(),
-                price: ProductPrice::try_from(random_price).unwrap(),
-                currency: ProductCurrency::Eur,
+                price: common::domain::Money::new(i64::from(random_price), currency),
+                status: ProductStatus::Draft,
+                publish_at: None,
+                unpublish_at: None,
                 updated_at: now,
                 created_at: now,
             }
@@ -88,12 +240,32 @@ pub mod fixture {
     }
 
     impl ProductBuilder {
+        pub fn status(mut self, status: ProductStatus) -> Self {
+            self.status = status;
+            self
+        }
+
+        /// Schedules the product `publish_in` and `unpublish_in` from now, negative durations lying in the past.
+        pub fn scheduled(
+            mut self,
+            publish_in: Option<chrono::Duration>,
+            unpublish_in: Option<chrono::Duration>,
+        ) -> Self {
+            let now = chrono::Utc::now();
+
+            self.publish_at = publish_in.map(|duration| ProductTimeStamp::from(now + duration));
+            self.unpublish_at = unpublish_in.map(|duration| ProductTimeStamp::from(now + duration));
+            self
+        }
+
         pub fn to_entity(&self) -> Product {
             let entity = Product {
                 id: self.id,
                 name: self.name.clone(),
                 price: self.price,
-                currency: self.currency,
+                status: self.status,
+                publish_at: self.publish_at,
+                unpublish_at: self.unpublish_at,
                 updated_at: self.updated_at,
                 created_at: self.created_at,
             };
@@ -107,3 +279,137 @@ pub mod fixture {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_several_invalid_fields_when_new_then_return_all_validation_errors() {
+        let error = Product::new(
+            String::from("not-a-uuid"),
+            String::new(),
+            String::from("-1.00"),
+            String::from("EUR"),
+        )
+        .err()
+        .unwrap();
+
+        let common::domain::Error::Validation(errors) = error else {
+            panic!("expected validation error, got {error}");
+        };
+
+        let fields: Vec<_> = errors.iter().filter_map(|error| error.field()).collect();
+        assert_eq!(fields, vec!["id", "name", "price/amount"]);
+    }
+
+    #[test]
+    fn given_valid_fields_when_new_then_return_product() {
+        let id = ProductId::default().to_primitive();
+
+        assert!(Product::new(id, String::from("Guitar"), String::from("0"), String::from("USD")).is_ok());
+    }
+
+    #[test]
+    fn given_amount_finer_than_currency_minor_unit_when_new_then_return_price_error() {
+        let new = |price: &str, currency: &str| {
+            Product::new(
+                ProductId::default().to_primitive(),
+                String::from("Guitar"),
+                String::from(price),
+                String::from(currency),
+            )
+        };
+
+        assert_eq!(new("1500", "JPY").unwrap().price.minor_units(), 1500);
+        assert_eq!(new("1.500", "KWD").unwrap().price.minor_units(), 1500);
+        assert!(new("1500.5", "JPY").is_err());
+        assert!(new("1.5", "XYZ").is_err());
+    }
+
+    #[test]
+    fn given_product_when_update_then_keep_id_and_creation_time() {
+        let product = fixture::ProductBuilder::default().to_entity();
+
+        let updated = product
+            .update(String::from("Renamed"), String::from("1500"), String::from("JPY"))
+            .unwrap();
+
+        assert_eq!(updated.id, product.id);
+        assert!(updated.created_at == product.created_at);
+        assert_eq!(updated.name.to_primitive(), "Renamed");
+        assert_eq!(updated.price.to_string(), "1500 JPY");
+        assert!(product
+            .update(String::new(), String::from("1"), String::from("EUR"))
+            .is_err());
+    }
+
+    #[test]
+    fn given_product_with_status_when_update_then_keep_status_and_schedule() {
+        let product = fixture::ProductBuilder::default()
+            .status(ProductStatus::Published)
+            .scheduled(None, Some(chrono::Duration::days(1)))
+            .to_entity();
+
+        let updated = product
+            .update(String::from("Renamed"), String::from("10"), String::from("EUR"))
+            .unwrap();
+
+        assert_eq!(updated.status, ProductStatus::Published);
+        assert!(updated.unpublish_at == product.unpublish_at);
+    }
+
+    #[test]
+    fn given_archived_product_when_transition_to_published_then_return_invalid_transition() {
+        let product = fixture::ProductBuilder::default()
+            .status(ProductStatus::Archived)
+            .to_entity();
+
+        assert!(matches!(
+            product.transition(ProductStatus::Published),
+            Err(common::domain::Error::InvalidProductStatusTransition)
+        ));
+        assert_eq!(
+            product.transition(ProductStatus::Draft).unwrap().status,
+            ProductStatus::Draft
+        );
+    }
+
+    #[test]
+    fn given_invalid_schedules_when_schedule_then_reject_them() {
+        let draft = fixture::ProductBuilder::default().to_entity();
+        let published = fixture::ProductBuilder::default()
+            .status(ProductStatus::Published)
+            .to_entity();
+        let now = chrono::Utc::now();
+
+        assert!(matches!(
+            draft.schedule(Some(now), Some(now)),
+            Err(common::domain::Error::Validation(_))
+        ));
+        assert!(matches!(
+            published.schedule(Some(now), None),
+            Err(common::domain::Error::InvalidProductStatusTransition)
+        ));
+        assert!(published.schedule(None, Some(now)).is_ok());
+    }
+
+    #[test]
+    fn given_schedule_when_apply_schedule_then_make_only_due_transitions() {
+        let hour = chrono::Duration::hours(1);
+        let at = ProductTimeStamp::default();
+        let apply = |publish_in, unpublish_in| {
+            fixture::ProductBuilder::default()
+                .scheduled(publish_in, unpublish_in)
+                .to_entity()
+                .apply_schedule(at)
+                .map(|product| product.status)
+        };
+
+        assert_eq!(apply(None, None), None);
+        assert_eq!(apply(Some(hour), None), None);
+        assert_eq!(apply(Some(-hour), None), Some(ProductStatus::Published));
+        assert_eq!(apply(Some(-hour), Some(hour)), Some(ProductStatus::Published));
+        assert_eq!(apply(Some(-hour * 2), Some(-hour)), Some(ProductStatus::Archived));
+    }
+}
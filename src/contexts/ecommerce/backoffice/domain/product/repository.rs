@@ -4,13 +4,270 @@ use axum::async_trait;
 
 use super::*;
 
+pub const PRODUCT_PAGE_SIZE: i64 = 50;
+
 pub type DynProductRepository<E> = Arc<dyn ProductRepository<Error = E> + Send + Sync + 'static>;
 
 #[async_trait]
 pub trait ProductRepository {
     type Error;
 
-    async fn get(&self) -> Result<Vec<Product>, Self::Error>;
+    /// First page of products in creation order, only those in `status` when one is given.
+    async fn get(&self, status: Option<ProductStatus>) -> Result<Vec<Product>, Self::Error>;
     async fn get_by_id(&self, id: &ProductId) -> Result<Option<Product>, Self::Error>;
+    async fn get_many_by_ids(&self, ids: &[ProductId]) -> Result<Vec<Product>, Self::Error>;
     async fn save(&self, product: &Product) -> Result<(), Self::Error>;
+    /// Replaces the name and price of a saved product, `ProductNotFound` when there is none.
+    async fn update(&self, product: &Product) -> Result<(), Self::Error>;
+    /// Replaces the status and schedule of a saved product still in status `from`, `ProductStatusConflict`
+    /// when it moved on meanwhile and `ProductNotFound` when there is none.
+    async fn update_status(&self, product: &Product, from: ProductStatus) -> Result<(), Self::Error>;
+    /// First page of products whose schedule makes a transition due at `at`, oldest first.
+    async fn get_scheduled(&self, at: ProductTimeStamp) -> Result<Vec<Product>, Self::Error>;
+}
+
+/// Behaviour every `ProductRepository` implementation must share, run against each of them.
+#[cfg(test)]
+pub mod conformance {
+    use crate::contexts::ecommerce::common;
+
+    use super::*;
+
+    type Repository = DynProductRepository<common::domain::Error>;
+
+    pub async fn given_empty_store_when_get_then_return_empty_vec(repository: Repository) {
+        assert!(repository.get(None).await.unwrap().is_empty());
+    }
+
+    /// Backends stamping the creation time to the millisecond tie on saves within the same one, hence the pauses.
+    pub async fn given_products_when_get_then_return_them_in_creation_order(repository: Repository) {
+        let p1 = fixture::ProductBuilder::default();
+        let p2 = fixture::ProductBuilder::default();
+        let p3 = fixture::ProductBuilder::default();
+
+        for product in [&p1, &p2, &p3] {
+            product.save(&repository).await;
+            tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        }
+
+        let ids: Vec<ProductId> = repository
+            .get(None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|product| product.id)
+            .collect();
+
+        assert_eq!(ids, vec![p1.id, p2.id, p3.id]);
+    }
+
+    /// Backends stamping the creation time themselves only tie when they run it within a single transaction.
+    pub async fn given_products_created_at_same_time_when_get_then_order_them_by_id(repository: Repository) {
+        let created_at = ProductTimeStamp::default();
+        let mut products: Vec<_> = (0..3)
+            .map(|_| fixture::ProductBuilder {
+                created_at,
+                ..Default::default()
+            })
+            .collect();
+        // saved highest id first, so that insertion order and id order disagree
+        products.sort_by_key(|product| std::cmp::Reverse(product.id.to_uuid()));
+
+        for product in &products {
+            product.save(&repository).await;
+        }
+
+        let got = repository.get(None).await.unwrap();
+        let mut expected = got.clone();
+        expected.sort_by_key(|product| (product.created_at.to_datetime(), product.id.to_uuid()));
+
+        let ids = |products: &[Product]| products.iter().map(|product| product.id).collect::<Vec<_>>();
+        assert_eq!(got.len(), products.len());
+        assert_eq!(ids(&got), ids(&expected));
+    }
+
+    pub async fn given_more_products_than_page_size_when_get_then_return_one_page(repository: Repository) {
+        for _ in 0..=PRODUCT_PAGE_SIZE {
+            fixture::ProductBuilder::default().save(&repository).await;
+        }
+
+        assert_eq!(repository.get(None).await.unwrap().len() as i64, PRODUCT_PAGE_SIZE);
+    }
+
+    pub async fn given_empty_store_when_get_by_id_then_return_none(repository: Repository) {
+        let id = ProductId::default();
+
+        assert!(repository.get_by_id(&id).await.unwrap().is_none());
+    }
+
+    pub async fn given_saved_product_when_get_by_id_then_return_same_product(repository: Repository) {
+        let builder = fixture::ProductBuilder::default();
+        builder.save(&repository).await;
+
+        let product = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+
+        assert_eq!(product.id, builder.id);
+        assert_eq!(product.name.to_primitive(), builder.name.to_primitive());
+        assert_eq!(product.price, builder.price);
+    }
+
+    pub async fn given_price_beyond_i32_in_three_decimal_currency_when_save_then_keep_exact_amount(
+        repository: Repository,
+    ) {
+        let mut builder = fixture::ProductBuilder::default();
+        let currency = common::domain::Currency::try_from("KWD").unwrap();
+        builder.price = common::domain::Money::new(i64::from(i32::MAX) * 1_000 + 7, currency);
+        builder.save(&repository).await;
+
+        let product = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+
+        assert_eq!(product.price, builder.price);
+        assert_eq!(product.price.to_decimal_string(), "2147483647.007");
+    }
+
+    pub async fn given_products_when_get_many_by_ids_then_return_only_matching(repository: Repository) {
+        let p1 = fixture::ProductBuilder::default();
+        let p2 = fixture::ProductBuilder::default();
+        let p3 = fixture::ProductBuilder::default();
+
+        tokio::join!(p1.save(&repository), p2.save(&repository), p3.save(&repository));
+
+        let missing = ProductId::default();
+        let products = repository.get_many_by_ids(&[p1.id, p3.id, missing]).await.unwrap();
+
+        assert_eq!(products.len(), 2);
+        assert!(products.iter().any(|product| product.id == p1.id));
+        assert!(products.iter().any(|product| product.id == p3.id));
+    }
+
+    pub async fn given_saved_product_when_save_with_same_id_then_return_already_exists(repository: Repository) {
+        let product = fixture::ProductBuilder::default();
+
+        assert!(repository.save(&product.to_entity()).await.is_ok());
+        assert!(matches!(
+            repository.save(&product.to_entity()).await,
+            Err(common::domain::Error::ProductAlreadyExists)
+        ));
+    }
+
+    pub async fn given_saved_product_when_update_then_return_new_name_and_price(repository: Repository) {
+        let builder = fixture::ProductBuilder::default();
+        builder.save(&repository).await;
+
+        let mut product = builder.to_entity();
+        product.name = ProductName::try_from(String::from("Renamed")).unwrap();
+        product.price = common::domain::Money::new(1_500, common::domain::Currency::try_from("JPY").unwrap());
+        repository.update(&product).await.unwrap();
+
+        let updated = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+
+        assert_eq!(updated.name.to_primitive(), "Renamed");
+        assert_eq!(updated.price, product.price);
+    }
+
+    pub async fn given_unknown_product_when_update_then_return_not_found(repository: Repository) {
+        let product = fixture::ProductBuilder::default();
+
+        assert!(matches!(
+            repository.update(&product.to_entity()).await,
+            Err(common::domain::Error::ProductNotFound)
+        ));
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_none());
+    }
+
+    pub async fn given_products_in_every_status_when_get_with_status_then_return_only_matching(repository: Repository) {
+        let draft = fixture::ProductBuilder::default();
+        let published = fixture::ProductBuilder::default().status(ProductStatus::Published);
+        let archived = fixture::ProductBuilder::default().status(ProductStatus::Archived);
+
+        draft.save(&repository).await;
+        published.save(&repository).await;
+        archived.save(&repository).await;
+
+        let ids = |products: Vec<Product>| products.iter().map(|product| product.id).collect::<Vec<_>>();
+
+        assert_eq!(
+            ids(repository.get(Some(ProductStatus::Published)).await.unwrap()),
+            vec![published.id]
+        );
+        assert_eq!(
+            ids(repository.get(Some(ProductStatus::Draft)).await.unwrap()),
+            vec![draft.id]
+        );
+        assert_eq!(repository.get(None).await.unwrap().len(), 3);
+    }
+
+    pub async fn given_saved_product_when_update_status_then_return_new_status_and_schedule(repository: Repository) {
+        let builder = fixture::ProductBuilder::default().scheduled(Some(chrono::Duration::hours(1)), None);
+        builder.save(&repository).await;
+
+        let saved = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+        assert_eq!(saved.status, ProductStatus::Draft);
+        assert!(saved.publish_at.is_some());
+
+        let product = saved
+            .schedule(None, Some(chrono::Utc::now() + chrono::Duration::days(1)))
+            .unwrap()
+            .transition(ProductStatus::Published)
+            .unwrap();
+        repository.update_status(&product, ProductStatus::Draft).await.unwrap();
+
+        let updated = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+        assert_eq!(updated.status, ProductStatus::Published);
+        assert!(updated.publish_at.is_none());
+        assert_eq!(
+            updated.unpublish_at.unwrap().to_primitive(),
+            product.unpublish_at.unwrap().to_primitive()
+        );
+        assert_eq!(updated.name.to_primitive(), builder.name.to_primitive());
+    }
+
+    pub async fn given_status_changed_meanwhile_when_update_status_then_return_conflict(repository: Repository) {
+        let builder = fixture::ProductBuilder::default();
+        builder.save(&repository).await;
+
+        let archived = builder.to_entity().transition(ProductStatus::Archived).unwrap();
+        let published = builder.to_entity().transition(ProductStatus::Published).unwrap();
+        repository.update_status(&archived, ProductStatus::Draft).await.unwrap();
+
+        assert!(matches!(
+            repository.update_status(&published, ProductStatus::Draft).await,
+            Err(common::domain::Error::ProductStatusConflict)
+        ));
+        assert!(matches!(
+            repository
+                .update_status(&fixture::ProductBuilder::default().to_entity(), ProductStatus::Draft)
+                .await,
+            Err(common::domain::Error::ProductNotFound)
+        ));
+
+        let product = repository.get_by_id(&builder.id).await.unwrap().unwrap();
+        assert_eq!(product.status, ProductStatus::Archived);
+    }
+
+    pub async fn given_scheduled_products_when_get_scheduled_then_return_only_due_ones(repository: Repository) {
+        let hour = chrono::Duration::hours(1);
+        let due_publish = fixture::ProductBuilder::default().scheduled(Some(-hour), None);
+        let due_unpublish = fixture::ProductBuilder::default()
+            .status(ProductStatus::Published)
+            .scheduled(None, Some(-hour));
+        let later = fixture::ProductBuilder::default().scheduled(Some(hour), Some(hour * 2));
+        // an unpublish date only falls due once the draft is published
+        let unpublish_of_draft = fixture::ProductBuilder::default().scheduled(None, Some(-hour));
+
+        due_publish.save(&repository).await;
+        due_unpublish.save(&repository).await;
+        later.save(&repository).await;
+        unpublish_of_draft.save(&repository).await;
+
+        let ids: Vec<ProductId> = repository
+            .get_scheduled(ProductTimeStamp::default())
+            .await
+            .unwrap()
+            .iter()
+            .map(|product| product.id)
+            .collect();
+
+        assert_eq!(ids, vec![due_publish.id, due_unpublish.id]);
+    }
 }
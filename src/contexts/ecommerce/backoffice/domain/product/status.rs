@@ -0,0 +1,97 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+/// Stage of a product in the catalogue lifecycle, only published products are live.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ProductStatus {
+    Draft,
+    Published,
+    Archived,
+}
+
+impl ProductStatus {
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform ProductStatus to primitive").entered();
+
+        self.to_string()
+    }
+
+    /// Drafts get published or archived, published products archived or taken back to draft, and archived
+    /// ones only revived as drafts. Staying in the same status is no transition.
+    pub fn can_transition_to(self, to: Self) -> bool {
+        matches!(
+            (self, to),
+            (Self::Draft, Self::Published)
+                | (Self::Draft, Self::Archived)
+                | (Self::Published, Self::Draft)
+                | (Self::Published, Self::Archived)
+                | (Self::Archived, Self::Draft)
+        )
+    }
+}
+
+impl Display for ProductStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display ProductStatus").entered();
+
+        match self {
+            Self::Draft => write!(f, "draft"),
+            Self::Published => write!(f, "published"),
+            Self::Archived => write!(f, "archived"),
+        }
+    }
+}
+
+impl TryFrom<&str> for ProductStatus {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ProductStatus from &str").entered();
+
+        match value {
+            "draft" => Ok(ProductStatus::Draft),
+            "published" => Ok(ProductStatus::Published),
+            "archived" => Ok(ProductStatus::Archived),
+            _ => Err(common::domain::Error::InvalidProductStatus).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+}
+
+impl TryFrom<String> for ProductStatus {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ProductStatus from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_every_pair_of_statuses_when_can_transition_to_then_allow_only_lifecycle_moves() {
+        let statuses = [ProductStatus::Draft, ProductStatus::Published, ProductStatus::Archived];
+
+        let allowed: Vec<String> = statuses
+            .iter()
+            .flat_map(|from| statuses.iter().map(move |to| (*from, *to)))
+            .filter(|(from, to)| from.can_transition_to(*to))
+            .map(|(from, to)| format!("{from}->{to}"))
+            .collect();
+
+        assert_eq!(
+            allowed,
+            vec![
+                "draft->published",
+                "draft->archived",
+                "published->draft",
+                "published->archived",
+                "archived->draft",
+            ]
+        );
+    }
+}
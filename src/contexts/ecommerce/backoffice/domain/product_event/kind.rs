@@ -0,0 +1,55 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ProductEventKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl ProductEventKind {
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform ProductEventKind to primitive").entered();
+
+        self.to_string()
+    }
+}
+
+impl Display for ProductEventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display ProductEventKind").entered();
+
+        match self {
+            Self::Created => write!(f, "created"),
+            Self::Updated => write!(f, "updated"),
+            Self::Deleted => write!(f, "deleted"),
+        }
+    }
+}
+
+impl TryFrom<&str> for ProductEventKind {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ProductEventKind from &str").entered();
+
+        match value {
+            "created" => Ok(ProductEventKind::Created),
+            "updated" => Ok(ProductEventKind::Updated),
+            "deleted" => Ok(ProductEventKind::Deleted),
+            _ => Err(common::domain::Error::InvalidProductEventKind).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+}
+
+impl TryFrom<String> for ProductEventKind {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ProductEventKind from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
@@ -0,0 +1,18 @@
+pub use kind::*;
+pub use repository::*;
+pub use sequence::*;
+
+use crate::contexts::ecommerce::backoffice;
+
+mod kind;
+mod repository;
+mod sequence;
+
+#[derive(Clone)]
+pub struct ProductEvent {
+    pub sequence: ProductEventSequence,
+    pub kind: ProductEventKind,
+    pub product_id: backoffice::domain::product::ProductId,
+    pub payload: serde_json::Value,
+    pub occurred_at: backoffice::domain::product::ProductTimeStamp,
+}
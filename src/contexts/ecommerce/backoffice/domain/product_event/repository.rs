@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use super::*;
+
+pub type DynProductEventRepository<E> = Arc<dyn ProductEventRepository<Error = E> + Send + Sync + 'static>;
+
+#[async_trait]
+pub trait ProductEventRepository {
+    type Error;
+
+    /// Events following the one at `sequence` in stream order, which is commit order.
+    ///
+    /// Sequences follow that order only where writers commit one at a time. Events become readable
+    /// once no running transaction can commit one before them, so a stream never skips an event.
+    async fn get_after(&self, sequence: &ProductEventSequence, limit: i64) -> Result<Vec<ProductEvent>, Self::Error>;
+    /// Sequence of the last readable event in stream order, zero while there is none.
+    async fn get_last_sequence(&self) -> Result<ProductEventSequence, Self::Error>;
+}
+
+#[cfg(test)]
+pub mod conformance {
+    use crate::contexts::ecommerce::{backoffice, common};
+
+    use super::*;
+
+    type Repository = DynProductEventRepository<common::domain::Error>;
+    type ProductRepository = backoffice::domain::product::DynProductRepository<common::domain::Error>;
+
+    /// First event of `product_id` matching `predicate`, waiting for events of other writers to settle.
+    async fn wait_for_event(
+        repository: &Repository,
+        product_id: &backoffice::domain::product::ProductId,
+        predicate: impl Fn(&ProductEvent) -> bool,
+    ) -> ProductEvent {
+        for _ in 0..500 {
+            let events = repository
+                .get_after(&ProductEventSequence::default(), 100)
+                .await
+                .unwrap();
+            if let Some(event) = events
+                .into_iter()
+                .find(|event| event.product_id == *product_id && predicate(event))
+            {
+                return event;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        panic!("no matching event of product {}", product_id.to_primitive());
+    }
+
+    /// Payloads hold the product columns clients may see, whatever else the row or the entity carries.
+    pub async fn given_saved_product_when_get_after_then_return_payload_of_public_columns(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Published)
+            .scheduled(None, Some(chrono::Duration::days(1)));
+        product.save(&products).await;
+
+        let event = wait_for_event(&repository, &product.id, |_| true).await;
+        let payload = &event.payload;
+
+        assert!(payload["unpublish_at"].is_string());
+        assert!(payload["created_at"].is_string());
+        assert!(payload["updated_at"].is_string());
+        assert_eq!(
+            *payload,
+            serde_json::json!({
+                "id": product.id.to_primitive(),
+                "name": product.name.to_primitive(),
+                "price": product.price.minor_units(),
+                "currency": product.price.currency().to_primitive(),
+                "status": "published",
+                "publish_at": null,
+                "unpublish_at": payload["unpublish_at"],
+                "created_at": payload["created_at"],
+                "updated_at": payload["updated_at"],
+            })
+        );
+    }
+
+    pub async fn given_status_transition_when_get_after_then_return_payload_with_new_status(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&products).await;
+
+        let archived = product
+            .to_entity()
+            .transition(backoffice::domain::product::ProductStatus::Archived)
+            .unwrap();
+        products
+            .update_status(&archived, backoffice::domain::product::ProductStatus::Draft)
+            .await
+            .unwrap();
+
+        let event = wait_for_event(&repository, &product.id, |event| {
+            event.kind == ProductEventKind::Updated
+        })
+        .await;
+
+        assert_eq!(event.payload["status"], "archived");
+    }
+}
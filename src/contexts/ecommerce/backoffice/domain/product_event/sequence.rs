@@ -0,0 +1,60 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default)]
+pub struct ProductEventSequence(i64);
+
+impl ProductEventSequence {
+    fn validate(value: impl Into<i64>) -> Result<i64, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate ProductEventSequence").entered();
+
+        let value = value.into();
+
+        if value >= 0 {
+            return Ok(value);
+        }
+
+        Err(common::domain::Error::InvalidProductEventSequence).inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    pub fn to_primitive(self) -> i64 {
+        let _e = tracing::debug_span!("Transform ProductEventSequence to primitive").entered();
+
+        self.0
+    }
+}
+
+impl Display for ProductEventSequence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display ProductEventSequence").entered();
+
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<i64> for ProductEventSequence {
+    type Error = common::domain::Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ProductEventSequence from i64").entered();
+
+        Ok(Self(Self::validate(value)?))
+    }
+}
+
+impl TryFrom<&str> for ProductEventSequence {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast ProductEventSequence from &str").entered();
+
+        let value = value
+            .trim()
+            .parse::<i64>()
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|_| common::domain::Error::InvalidProductEventSequence)?;
+
+        Self::try_from(value)
+    }
+}
@@ -0,0 +1,209 @@
+pub use id::*;
+pub use repository::*;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+mod id;
+mod repository;
+
+/// Entry of a product price list, the price of the product in one currency for a window of time.
+///
+/// An open `valid_from` or `valid_until` leaves the window unbounded on that side, so a base price has
+/// neither while a promotion sets both. `valid_until` itself is no longer part of the window.
+#[derive(Clone)]
+pub struct ProductPrice {
+    pub id: ProductPriceId,
+    pub product_id: backoffice::domain::product::ProductId,
+    pub price: common::domain::Money,
+    pub valid_from: Option<backoffice::domain::product::ProductTimeStamp>,
+    pub valid_until: Option<backoffice::domain::product::ProductTimeStamp>,
+    pub created_at: backoffice::domain::product::ProductTimeStamp,
+}
+
+impl ProductPrice {
+    /// `price` is a decimal amount in `currency`, as for `Product::new`.
+    pub fn new(
+        id: String,
+        product_id: String,
+        price: String,
+        currency: String,
+        valid_from: Option<chrono::DateTime<chrono::offset::Utc>>,
+        valid_until: Option<chrono::DateTime<chrono::offset::Utc>>,
+    ) -> Result<Self, common::domain::Error> {
+        let id = ProductPriceId::try_from(id);
+        let product_id = backoffice::domain::product::ProductId::try_from(product_id);
+        let currency =
+            common::domain::Currency::try_from(currency).map_err(|_| common::domain::Error::InvalidProductCurrency);
+        let price = currency
+            .as_ref()
+            .ok()
+            .map(|currency| Self::validate_price(&price, *currency))
+            .transpose();
+        let validity = Self::validate_validity(valid_from, valid_until);
+
+        let errors: Vec<common::domain::Error> = [
+            id.as_ref().err(),
+            product_id.as_ref().err(),
+            price.as_ref().err(),
+            currency.as_ref().err(),
+            validity.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+        if !errors.is_empty() {
+            return Err(common::domain::Error::Validation(errors));
+        }
+
+        Ok(Self {
+            id: id?,
+            product_id: product_id?,
+            price: price?.ok_or(common::domain::Error::InvalidProductPrice)?,
+            valid_from: valid_from.map(backoffice::domain::product::ProductTimeStamp::from),
+            valid_until: valid_until.map(backoffice::domain::product::ProductTimeStamp::from),
+            created_at: backoffice::domain::product::ProductTimeStamp::default(),
+        })
+    }
+
+    fn validate_price(
+        amount: &str,
+        currency: common::domain::Currency,
+    ) -> Result<common::domain::Money, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate ProductPrice price").entered();
+
+        match common::domain::Money::parse(amount, currency) {
+            Ok(price) if price.minor_units() >= 0 => Ok(price),
+            _ => Err(common::domain::Error::InvalidProductPrice).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+
+    fn validate_validity(
+        valid_from: Option<chrono::DateTime<chrono::offset::Utc>>,
+        valid_until: Option<chrono::DateTime<chrono::offset::Utc>>,
+    ) -> Result<(), common::domain::Error> {
+        let _e = tracing::debug_span!("Validate ProductPrice validity").entered();
+
+        match (valid_from, valid_until) {
+            (Some(valid_from), Some(valid_until)) if valid_from >= valid_until => {
+                Err(common::domain::Error::InvalidProductPriceValidity).inspect_err(|err| tracing::error!("{err}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn is_valid_at(&self, at: backoffice::domain::product::ProductTimeStamp) -> bool {
+        self.valid_from.map_or(true, |valid_from| valid_from <= at)
+            && self.valid_until.map_or(true, |valid_until| at < valid_until)
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use crate::contexts::ecommerce::{backoffice, common};
+
+    use super::*;
+
+    pub struct ProductPriceBuilder {
+        pub id: ProductPriceId,
+        pub product_id: backoffice::domain::product::ProductId,
+        pub price: common::domain::Money,
+        pub valid_from: Option<backoffice::domain::product::ProductTimeStamp>,
+        pub valid_until: Option<backoffice::domain::product::ProductTimeStamp>,
+        pub created_at: backoffice::domain::product::ProductTimeStamp,
+    }
+
+    impl ProductPriceBuilder {
+        /// Base price of `product_id` in `currency`, valid at any time.
+        pub fn new(product_id: backoffice::domain::product::ProductId, amount: i64, currency: &str) -> Self {
+            Self {
+                id: ProductPriceId::default(),
+                product_id,
+                price: common::domain::Money::new(amount, common::domain::Currency::try_from(currency).unwrap()),
+                valid_from: None,
+                valid_until: None,
+                created_at: backoffice::domain::product::ProductTimeStamp::default(),
+            }
+        }
+
+        /// Restricts the price to `[valid_from, valid_until)`, given as RFC 3339 strings.
+        pub fn valid(mut self, valid_from: Option<&str>, valid_until: Option<&str>) -> Self {
+            self.valid_from = valid_from.map(timestamp);
+            self.valid_until = valid_until.map(timestamp);
+            self
+        }
+
+        pub fn to_entity(&self) -> ProductPrice {
+            ProductPrice {
+                id: self.id,
+                product_id: self.product_id,
+                price: self.price,
+                valid_from: self.valid_from,
+                valid_until: self.valid_until,
+                created_at: self.created_at,
+            }
+        }
+
+        pub async fn save(&self, repository: &DynProductPriceRepository<common::domain::Error>) {
+            repository.save(&self.to_entity()).await.unwrap()
+        }
+    }
+
+    pub fn timestamp(value: &str) -> backoffice::domain::product::ProductTimeStamp {
+        chrono::DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&chrono::offset::Utc)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new(valid_from: Option<&str>, valid_until: Option<&str>) -> Result<ProductPrice, common::domain::Error> {
+        let datetime = |value: &str| fixture::timestamp(value).to_datetime();
+
+        ProductPrice::new(
+            ProductPriceId::default().to_primitive(),
+            backoffice::domain::product::ProductId::default().to_primitive(),
+            String::from("9.99"),
+            String::from("EUR"),
+            valid_from.map(datetime),
+            valid_until.map(datetime),
+        )
+    }
+
+    #[test]
+    fn given_window_ending_before_it_starts_when_new_then_return_validity_error() {
+        let error = new(Some("2024-02-01T00:00:00Z"), Some("2024-01-01T00:00:00Z"))
+            .err()
+            .unwrap();
+
+        let common::domain::Error::Validation(errors) = error else {
+            panic!("expected validation error, got {error}");
+        };
+
+        let fields: Vec<_> = errors.iter().filter_map(|error| error.field()).collect();
+        assert_eq!(fields, vec!["valid_until"]);
+    }
+
+    #[test]
+    fn given_window_when_is_valid_at_then_include_start_and_exclude_end() {
+        let price = new(Some("2024-01-01T00:00:00Z"), Some("2024-02-01T00:00:00Z")).unwrap();
+
+        assert!(!price.is_valid_at(fixture::timestamp("2023-12-31T23:59:59Z")));
+        assert!(price.is_valid_at(fixture::timestamp("2024-01-01T00:00:00Z")));
+        assert!(price.is_valid_at(fixture::timestamp("2024-01-31T23:59:59Z")));
+        assert!(!price.is_valid_at(fixture::timestamp("2024-02-01T00:00:00Z")));
+    }
+
+    #[test]
+    fn given_open_window_when_is_valid_at_then_always_valid() {
+        let price = new(None, None).unwrap();
+
+        assert!(price.is_valid_at(fixture::timestamp("1970-01-01T00:00:00Z")));
+        assert!(price.is_valid_at(backoffice::domain::product::ProductTimeStamp::default()));
+    }
+}
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::*;
+
+pub type DynProductPriceRepository<E> = Arc<dyn ProductPriceRepository<Error = E> + Send + Sync + 'static>;
+
+#[async_trait]
+pub trait ProductPriceRepository {
+    type Error;
+
+    /// Whole price list of a product, by currency and then by start of validity.
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<ProductPrice>, Self::Error>;
+
+    /// Price valid at `at` in `currency`. Where windows overlap the one that started last wins, so a
+    /// promotion takes over from the base price for as long as it lasts.
+    async fn get_current(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        currency: common::domain::Currency,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Option<ProductPrice>, Self::Error>;
+
+    /// Creates the price or replaces the one with the same id on the same product.
+    async fn save(&self, price: &ProductPrice) -> Result<(), Self::Error>;
+    async fn delete(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &ProductPriceId,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Behaviour every `ProductPriceRepository` implementation must share, run against each of them.
+///
+/// Every function receives the product repository of the same store, since prices belong to a product.
+#[cfg(test)]
+pub mod conformance {
+    use crate::contexts::ecommerce::{backoffice, common};
+
+    use super::*;
+
+    type Repository = DynProductPriceRepository<common::domain::Error>;
+    type ProductRepository = backoffice::domain::product::DynProductRepository<common::domain::Error>;
+
+    async fn save_product(products: &ProductRepository) -> backoffice::domain::product::ProductId {
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(products).await;
+
+        product.id
+    }
+
+    fn currency(code: &str) -> common::domain::Currency {
+        common::domain::Currency::try_from(code).unwrap()
+    }
+
+    pub async fn given_no_prices_when_get_by_product_id_then_return_empty_vec(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        assert!(repository.get_by_product_id(&product_id).await.unwrap().is_empty());
+    }
+
+    pub async fn given_prices_of_several_products_when_get_by_product_id_then_return_only_its_list_in_order(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+
+        let usd = fixture::ProductPriceBuilder::new(product_id, 1_099, "USD");
+        let eur_promotion = fixture::ProductPriceBuilder::new(product_id, 799, "EUR")
+            .valid(Some("2024-11-29T00:00:00Z"), Some("2024-12-02T00:00:00Z"));
+        let eur = fixture::ProductPriceBuilder::new(product_id, 999, "EUR");
+        let other = fixture::ProductPriceBuilder::new(other_product_id, 500, "EUR");
+
+        for price in [&usd, &eur_promotion, &eur, &other] {
+            price.save(&repository).await;
+        }
+
+        let ids: Vec<ProductPriceId> = repository
+            .get_by_product_id(&product_id)
+            .await
+            .unwrap()
+            .iter()
+            .map(|price| price.id)
+            .collect();
+
+        assert_eq!(ids, vec![eur.id, eur_promotion.id, usd.id]);
+    }
+
+    pub async fn given_saved_price_when_get_by_product_id_then_return_same_price(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let builder = fixture::ProductPriceBuilder::new(product_id, i64::from(i32::MAX) * 1_000 + 7, "KWD")
+            .valid(Some("2024-01-01T00:00:00Z"), Some("2024-02-01T12:30:00Z"));
+        builder.save(&repository).await;
+
+        let prices = repository.get_by_product_id(&product_id).await.unwrap();
+
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0].id, builder.id);
+        assert_eq!(prices[0].product_id, product_id);
+        assert_eq!(prices[0].price, builder.price);
+        assert!(prices[0].valid_from == builder.valid_from);
+        assert!(prices[0].valid_until == builder.valid_until);
+    }
+
+    pub async fn given_base_price_and_promotion_when_get_current_then_return_promotion_only_within_its_window(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let base = fixture::ProductPriceBuilder::new(product_id, 999, "EUR");
+        let promotion = fixture::ProductPriceBuilder::new(product_id, 799, "EUR")
+            .valid(Some("2024-11-29T00:00:00Z"), Some("2024-12-02T00:00:00Z"));
+        base.save(&repository).await;
+        promotion.save(&repository).await;
+
+        let current_at = |at: &'static str| {
+            let repository = repository.clone();
+            async move {
+                repository
+                    .get_current(&product_id, currency("EUR"), fixture::timestamp(at))
+                    .await
+                    .unwrap()
+                    .map(|price| price.id)
+            }
+        };
+
+        assert_eq!(current_at("2024-11-28T23:59:59Z").await, Some(base.id));
+        assert_eq!(current_at("2024-11-29T00:00:00Z").await, Some(promotion.id));
+        assert_eq!(current_at("2024-12-01T23:59:59Z").await, Some(promotion.id));
+        assert_eq!(current_at("2024-12-02T00:00:00Z").await, Some(base.id));
+    }
+
+    pub async fn given_price_in_other_currency_or_outside_window_when_get_current_then_return_none(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        fixture::ProductPriceBuilder::new(product_id, 1_099, "USD")
+            .save(&repository)
+            .await;
+        fixture::ProductPriceBuilder::new(product_id, 799, "EUR")
+            .valid(Some("2024-11-29T00:00:00Z"), None)
+            .save(&repository)
+            .await;
+
+        let at = fixture::timestamp("2024-01-01T00:00:00Z");
+
+        assert!(repository
+            .get_current(&product_id, currency("EUR"), at)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(repository
+            .get_current(&product_id, currency("GBP"), at)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    pub async fn given_saved_price_when_save_with_same_id_then_replace_it(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let mut builder = fixture::ProductPriceBuilder::new(product_id, 999, "EUR");
+        builder.save(&repository).await;
+
+        builder.price = common::domain::Money::new(1_500, currency("JPY"));
+        builder = builder.valid(None, Some("2030-01-01T00:00:00Z"));
+        builder.save(&repository).await;
+
+        let prices = repository.get_by_product_id(&product_id).await.unwrap();
+
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0].price, builder.price);
+        assert!(prices[0].valid_until == builder.valid_until);
+    }
+
+    pub async fn given_price_of_other_product_when_save_with_same_id_then_return_already_exists(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+
+        let builder = fixture::ProductPriceBuilder::new(product_id, 999, "EUR");
+        builder.save(&repository).await;
+
+        let mut stolen = builder.to_entity();
+        stolen.product_id = other_product_id;
+
+        assert!(matches!(
+            repository.save(&stolen).await,
+            Err(common::domain::Error::ProductPriceAlreadyExists)
+        ));
+        assert!(repository
+            .get_by_product_id(&other_product_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    pub async fn given_unknown_product_when_save_then_return_product_not_found(
+        repository: Repository,
+        _products: ProductRepository,
+    ) {
+        let builder = fixture::ProductPriceBuilder::new(backoffice::domain::product::ProductId::default(), 999, "EUR");
+
+        assert!(matches!(
+            repository.save(&builder.to_entity()).await,
+            Err(common::domain::Error::ProductNotFound)
+        ));
+    }
+
+    pub async fn given_saved_price_when_delete_then_remove_only_it(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let kept = fixture::ProductPriceBuilder::new(product_id, 999, "EUR");
+        let deleted = fixture::ProductPriceBuilder::new(product_id, 1_099, "USD");
+        kept.save(&repository).await;
+        deleted.save(&repository).await;
+
+        repository.delete(&product_id, &deleted.id).await.unwrap();
+
+        let prices = repository.get_by_product_id(&product_id).await.unwrap();
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0].id, kept.id);
+
+        assert!(matches!(
+            repository.delete(&product_id, &deleted.id).await,
+            Err(common::domain::Error::ProductPriceNotFound)
+        ));
+    }
+
+    pub async fn given_price_of_other_product_when_delete_then_return_not_found(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+        let builder = fixture::ProductPriceBuilder::new(product_id, 999, "EUR");
+        builder.save(&repository).await;
+
+        assert!(matches!(
+            repository.delete(&other_product_id, &builder.id).await,
+            Err(common::domain::Error::ProductPriceNotFound)
+        ));
+        assert_eq!(repository.get_by_product_id(&product_id).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,81 @@
+pub use repository::*;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+mod repository;
+
+/// One change of a product's price or currency, recorded by the store on every write that makes one.
+///
+/// The first change of a product carries the price it was created with and no `old_price`.
+#[derive(Clone)]
+pub struct ProductPriceChange {
+    pub product_id: backoffice::domain::product::ProductId,
+    pub old_price: Option<common::domain::Money>,
+    pub new_price: common::domain::Money,
+    /// Caller the write was made on behalf of, `None` for writes made outside of a request.
+    pub changed_by: Option<String>,
+    pub changed_at: backoffice::domain::product::ProductTimeStamp,
+}
+
+impl ProductPriceChange {
+    /// Whether the change falls in `[from, until)`, an open side leaves the period unbounded.
+    pub fn is_within(
+        &self,
+        from: Option<backoffice::domain::product::ProductTimeStamp>,
+        until: Option<backoffice::domain::product::ProductTimeStamp>,
+    ) -> bool {
+        from.map_or(true, |from| from <= self.changed_at) && until.map_or(true, |until| self.changed_at < until)
+    }
+
+    pub fn validate_period(
+        from: Option<backoffice::domain::product::ProductTimeStamp>,
+        until: Option<backoffice::domain::product::ProductTimeStamp>,
+    ) -> Result<(), common::domain::Error> {
+        let _e = tracing::debug_span!("Validate ProductPriceChange period").entered();
+
+        match (from, until) {
+            (Some(from), Some(until)) if from >= until => {
+                Err(common::domain::Error::InvalidProductPriceHistoryPeriod).inspect_err(|err| tracing::error!("{err}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(value: &str) -> backoffice::domain::product::ProductTimeStamp {
+        backoffice::domain::product_price::fixture::timestamp(value)
+    }
+
+    #[test]
+    fn given_period_when_is_within_then_include_start_and_exclude_end() {
+        let change = ProductPriceChange {
+            product_id: backoffice::domain::product::ProductId::default(),
+            old_price: None,
+            new_price: common::domain::Money::new(999, common::domain::Currency::EUR),
+            changed_by: None,
+            changed_at: timestamp("2024-01-01T00:00:00Z"),
+        };
+
+        assert!(change.is_within(None, None));
+        assert!(change.is_within(Some(timestamp("2024-01-01T00:00:00Z")), None));
+        assert!(!change.is_within(None, Some(timestamp("2024-01-01T00:00:00Z"))));
+        assert!(!change.is_within(Some(timestamp("2024-01-01T00:00:01Z")), None));
+    }
+
+    #[test]
+    fn given_period_ending_before_it_starts_when_validate_period_then_return_period_error() {
+        let from = Some(timestamp("2024-02-01T00:00:00Z"));
+        let until = Some(timestamp("2024-01-01T00:00:00Z"));
+
+        assert!(matches!(
+            ProductPriceChange::validate_period(from, until),
+            Err(common::domain::Error::InvalidProductPriceHistoryPeriod)
+        ));
+        assert!(ProductPriceChange::validate_period(until, from).is_ok());
+        assert!(ProductPriceChange::validate_period(from, None).is_ok());
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::backoffice;
+
+use super::*;
+
+pub type DynProductPriceHistoryRepository<E> =
+    Arc<dyn ProductPriceHistoryRepository<Error = E> + Send + Sync + 'static>;
+
+/// Read side of the price history, the store itself records a change whenever a product write
+/// alters its price or currency.
+#[async_trait]
+pub trait ProductPriceHistoryRepository {
+    type Error;
+
+    /// Changes of a product in the order they were made, restricted to `[from, until)`.
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        from: Option<backoffice::domain::product::ProductTimeStamp>,
+        until: Option<backoffice::domain::product::ProductTimeStamp>,
+    ) -> Result<Vec<ProductPriceChange>, Self::Error>;
+}
+
+/// Behaviour every `ProductPriceHistoryRepository` implementation must share, run against each of them.
+///
+/// Every function receives the product repository of the same store, whose writes feed the history.
+#[cfg(test)]
+pub mod conformance {
+    use std::time::Duration;
+
+    use crate::contexts::ecommerce::{backoffice, common};
+    use crate::libs;
+
+    use super::*;
+
+    type Repository = DynProductPriceHistoryRepository<common::domain::Error>;
+    type ProductRepository = backoffice::domain::product::DynProductRepository<common::domain::Error>;
+
+    fn money(amount: i64, currency: &str) -> common::domain::Money {
+        common::domain::Money::new(amount, common::domain::Currency::try_from(currency).unwrap())
+    }
+
+    async fn save_product(products: &ProductRepository, amount: i64) -> backoffice::domain::product::Product {
+        let builder = backoffice::domain::product::fixture::ProductBuilder {
+            price: money(amount, "EUR"),
+            ..Default::default()
+        };
+        builder.save(products).await;
+
+        builder.to_entity()
+    }
+
+    async fn update_price(
+        products: &ProductRepository,
+        product: &mut backoffice::domain::product::Product,
+        price: common::domain::Money,
+        caller: &str,
+    ) {
+        product.price = price;
+
+        libs::database::with_caller(Some(String::from(caller)), products.update(product))
+            .await
+            .unwrap();
+    }
+
+    pub async fn given_new_product_when_get_by_product_id_then_return_initial_price(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default();
+        libs::database::with_caller(Some(String::from("alice")), builder.save(&products)).await;
+
+        let history = repository.get_by_product_id(&builder.id, None, None).await.unwrap();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].product_id, builder.id);
+        assert!(history[0].old_price.is_none());
+        assert_eq!(history[0].new_price, builder.price);
+        assert_eq!(history[0].changed_by.as_deref(), Some("alice"));
+    }
+
+    pub async fn given_price_and_currency_updates_when_get_by_product_id_then_return_changes_in_order(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let mut product = save_product(&products, 999).await;
+        update_price(&products, &mut product, money(1_299, "EUR"), "alice").await;
+        update_price(&products, &mut product, money(1_299, "USD"), "bob").await;
+
+        let history = repository.get_by_product_id(&product.id, None, None).await.unwrap();
+
+        let changes: Vec<_> = history
+            .iter()
+            .map(|change| {
+                (
+                    change.old_price.map(|price| price.to_string()),
+                    change.new_price.to_string(),
+                    change.changed_by.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (None, String::from("9.99 EUR"), None),
+                (
+                    Some(String::from("9.99 EUR")),
+                    String::from("12.99 EUR"),
+                    Some(String::from("alice"))
+                ),
+                (
+                    Some(String::from("12.99 EUR")),
+                    String::from("12.99 USD"),
+                    Some(String::from("bob"))
+                ),
+            ]
+        );
+    }
+
+    pub async fn given_update_keeping_price_when_get_by_product_id_then_record_nothing(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let mut product = save_product(&products, 999).await;
+        product.name = backoffice::domain::product::ProductName::try_from(String::from("Renamed")).unwrap();
+        products.update(&product).await.unwrap();
+
+        let history = repository.get_by_product_id(&product.id, None, None).await.unwrap();
+
+        assert_eq!(history.len(), 1);
+    }
+
+    pub async fn given_changes_of_several_products_when_get_by_product_id_then_return_only_its_history(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let mut product = save_product(&products, 999).await;
+        let mut other = save_product(&products, 500).await;
+        update_price(&products, &mut other, money(600, "EUR"), "alice").await;
+
+        assert_eq!(
+            repository
+                .get_by_product_id(&product.id, None, None)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        update_price(&products, &mut product, money(1_099, "EUR"), "alice").await;
+
+        assert_eq!(
+            repository
+                .get_by_product_id(&product.id, None, None)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    pub async fn given_period_when_get_by_product_id_then_return_changes_from_start_until_before_end(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let mut product = save_product(&products, 100).await;
+        // apart by more than the timestamp precision of every store
+        for amount in [200, 300] {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            update_price(&products, &mut product, money(amount, "EUR"), "alice").await;
+        }
+
+        let history = repository.get_by_product_id(&product.id, None, None).await.unwrap();
+        assert_eq!(history.len(), 3);
+
+        let amounts_within = |from: Option<usize>, until: Option<usize>| {
+            let repository = repository.clone();
+            let (from, until) = (
+                from.map(|index| history[index].changed_at),
+                until.map(|index| history[index].changed_at),
+            );
+
+            async move {
+                repository
+                    .get_by_product_id(&product.id, from, until)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|change| change.new_price.minor_units())
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(amounts_within(Some(1), None).await, vec![200, 300]);
+        assert_eq!(amounts_within(None, Some(1)).await, vec![100]);
+        assert_eq!(amounts_within(Some(1), Some(2)).await, vec![200]);
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+/// EAN-8, UPC-A or EAN-13 code, the GTIN formats printed on retail packaging.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Barcode(String);
+
+impl Barcode {
+    fn validate(value: impl Into<String>) -> Result<String, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate Barcode").entered();
+
+        let value = value.into();
+
+        let digits: Option<Vec<u32>> = value.chars().map(|c| c.to_digit(10)).collect();
+
+        match digits {
+            Some(digits) if matches!(digits.len(), 8 | 12 | 13) && Self::has_valid_check_digit(&digits) => Ok(value),
+            _ => Err(common::domain::Error::InvalidVariantBarcode).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+
+    /// GTIN mod 10 check: from the right, the digits before the check digit weigh 3, 1, 3, ... and the
+    /// check digit brings their sum up to a multiple of 10.
+    fn has_valid_check_digit(digits: &[u32]) -> bool {
+        let Some((check_digit, payload)) = digits.split_last() else {
+            return false;
+        };
+
+        let sum: u32 = payload
+            .iter()
+            .rev()
+            .zip([3, 1].into_iter().cycle())
+            .map(|(digit, weight)| digit * weight)
+            .sum();
+
+        (10 - sum % 10) % 10 == *check_digit
+    }
+
+    pub fn to_primitive(&self) -> String {
+        let _e = tracing::debug_span!("Transform Barcode to primitive").entered();
+
+        self.0.clone()
+    }
+}
+
+impl Display for Barcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display Barcode").entered();
+
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<&str> for Barcode {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast Barcode from &str").entered();
+
+        Ok(Self(Self::validate(value)?))
+    }
+}
+
+impl TryFrom<String> for Barcode {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast Barcode from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_valid_ean_8_upc_a_and_ean_13_when_try_from_then_return_barcode() {
+        for value in ["96385074", "036000291452", "4006381333931"] {
+            assert_eq!(Barcode::try_from(value).unwrap().to_primitive(), value);
+        }
+    }
+
+    #[test]
+    fn given_wrong_check_digit_when_try_from_then_return_invalid_barcode() {
+        for value in ["96385075", "036000291453", "4006381333932"] {
+            assert!(matches!(
+                Barcode::try_from(value),
+                Err(common::domain::Error::InvalidVariantBarcode)
+            ));
+        }
+    }
+
+    #[test]
+    fn given_unsupported_length_or_non_digits_when_try_from_then_return_invalid_barcode() {
+        for value in ["", "1234567", "40063813339310", "40063813339A1"] {
+            assert!(matches!(
+                Barcode::try_from(value),
+                Err(common::domain::Error::InvalidVariantBarcode)
+            ));
+        }
+    }
+}
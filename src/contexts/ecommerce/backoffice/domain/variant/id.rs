@@ -0,0 +1,72 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VariantId(uuid::Uuid);
+
+impl VariantId {
+    fn validate(value: impl Into<String>) -> Result<uuid::Uuid, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate VariantId").entered();
+
+        uuid::Uuid::parse_str(&value.into())
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|_| common::domain::Error::InvalidVariantId)
+    }
+
+    pub fn to_uuid(self) -> uuid::Uuid {
+        let _e = tracing::debug_span!("Transform VariantId to uuid").entered();
+
+        self.0
+    }
+
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform VariantId to primitive").entered();
+
+        self.0.to_string()
+    }
+}
+
+impl Default for VariantId {
+    fn default() -> Self {
+        let _e = tracing::debug_span!("New VariantId").entered();
+
+        Self(uuid::Uuid::new_v4())
+    }
+}
+
+impl Display for VariantId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display VariantId").entered();
+
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<uuid::Uuid> for VariantId {
+    fn from(value: uuid::Uuid) -> Self {
+        let _e = tracing::debug_span!("Cast VariantId from uuid::Uuid").entered();
+
+        Self(value)
+    }
+}
+
+impl TryFrom<&str> for VariantId {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast VariantId from &str").entered();
+
+        Ok(Self(Self::validate(value)?))
+    }
+}
+
+impl TryFrom<String> for VariantId {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast VariantId from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+
+pub use barcode::*;
+pub use id::*;
+pub use option::*;
+pub use repository::*;
+pub use sku::*;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+mod barcode;
+mod id;
+mod option;
+mod repository;
+mod sku;
+
+/// Sellable version of a product, one value picked for each of the product's options.
+#[derive(Clone)]
+pub struct Variant {
+    pub id: VariantId,
+    pub product_id: backoffice::domain::product::ProductId,
+    pub sku: Sku,
+    /// Option name to the value picked for it, one entry per option of the product.
+    pub options: BTreeMap<String, String>,
+    /// Overrides the product price, `None` to sell at the product price.
+    pub price: Option<common::domain::Money>,
+    pub barcode: Option<Barcode>,
+    pub created_at: backoffice::domain::product::ProductTimeStamp,
+    pub updated_at: backoffice::domain::product::ProductTimeStamp,
+}
+
+impl Variant {
+    /// `price` is a decimal amount and its currency, as for `ProductPrice::new`. The options are checked
+    /// against the product's definitions by `VariantOption::validate_selection`, not here.
+    pub fn new(
+        id: String,
+        product_id: String,
+        sku: String,
+        options: BTreeMap<String, String>,
+        price: Option<(String, String)>,
+        barcode: Option<String>,
+    ) -> Result<Self, common::domain::Error> {
+        let id = VariantId::try_from(id);
+        let product_id = backoffice::domain::product::ProductId::try_from(product_id);
+        let sku = Sku::try_from(sku);
+        let (price, currency) = match price {
+            Some((amount, currency)) => {
+                let currency = common::domain::Currency::try_from(currency)
+                    .map_err(|_| common::domain::Error::InvalidProductCurrency);
+                let price = currency
+                    .as_ref()
+                    .ok()
+                    .map(|currency| Self::validate_price(&amount, *currency))
+                    .transpose();
+
+                (price, currency.map(Some))
+            }
+            None => (Ok(None), Ok(None)),
+        };
+        let barcode = barcode.map(Barcode::try_from).transpose();
+
+        let errors: Vec<common::domain::Error> = [
+            id.as_ref().err(),
+            product_id.as_ref().err(),
+            sku.as_ref().err(),
+            price.as_ref().err(),
+            currency.as_ref().err(),
+            barcode.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+        if !errors.is_empty() {
+            return Err(common::domain::Error::Validation(errors));
+        }
+
+        let now = backoffice::domain::product::ProductTimeStamp::default();
+
+        Ok(Self {
+            id: id?,
+            product_id: product_id?,
+            sku: sku?,
+            options,
+            price: price?,
+            barcode: barcode?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    fn validate_price(
+        amount: &str,
+        currency: common::domain::Currency,
+    ) -> Result<common::domain::Money, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate Variant price").entered();
+
+        match common::domain::Money::parse(amount, currency) {
+            Ok(price) if price.minor_units() >= 0 => Ok(price),
+            _ => Err(common::domain::Error::InvalidProductPrice).inspect_err(|err| tracing::error!("{err}")),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use crate::contexts::ecommerce::common;
+    use crate::libs;
+
+    use super::*;
+
+    pub struct VariantBuilder {
+        pub id: VariantId,
+        pub product_id: backoffice::domain::product::ProductId,
+        pub sku: Sku,
+        pub options: BTreeMap<String, String>,
+        pub price: Option<common::domain::Money>,
+        pub barcode: Option<Barcode>,
+    }
+
+    impl VariantBuilder {
+        /// Variant of `product_id` with a random SKU, no options and the product price.
+        pub fn new(product_id: backoffice::domain::product::ProductId) -> Self {
+            Self {
+                id: VariantId::default(),
+                product_id,
+                sku: Sku::try_from(libs::random::generate_alphanumeric_string(None)).unwrap(),
+                options: BTreeMap::new(),
+                price: None,
+                barcode: None,
+            }
+        }
+
+        /// Picks `value` for the option `name`.
+        pub fn option(mut self, name: &str, value: &str) -> Self {
+            self.options.insert(name.to_string(), value.to_string());
+            self
+        }
+
+        pub fn to_entity(&self) -> Variant {
+            let now = backoffice::domain::product::ProductTimeStamp::default();
+
+            Variant {
+                id: self.id,
+                product_id: self.product_id,
+                sku: self.sku.clone(),
+                options: self.options.clone(),
+                price: self.price,
+                barcode: self.barcode.clone(),
+                created_at: now,
+                updated_at: now,
+            }
+        }
+
+        pub async fn save(&self, repository: &DynVariantRepository<common::domain::Error>) {
+            repository.save(&self.to_entity()).await.unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_several_invalid_fields_when_new_then_return_all_validation_errors() {
+        let error = Variant::new(
+            VariantId::default().to_primitive(),
+            backoffice::domain::product::ProductId::default().to_primitive(),
+            String::from("NOT A SKU"),
+            BTreeMap::new(),
+            Some((String::from("-1"), String::from("EUR"))),
+            Some(String::from("4006381333932")),
+        )
+        .err()
+        .unwrap();
+
+        let common::domain::Error::Validation(errors) = error else {
+            panic!("expected validation error, got {error}");
+        };
+
+        let fields: Vec<_> = errors.iter().filter_map(|error| error.field()).collect();
+        assert_eq!(fields, vec!["sku", "price/amount", "barcode"]);
+    }
+
+    #[test]
+    fn given_price_override_when_new_then_parse_it_in_its_currency() {
+        let variant = Variant::new(
+            VariantId::default().to_primitive(),
+            backoffice::domain::product::ProductId::default().to_primitive(),
+            String::from("strat-sb"),
+            BTreeMap::from([(String::from("colour"), String::from("Sunburst"))]),
+            Some((String::from("3799.00"), String::from("EUR"))),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(variant.sku.to_primitive(), "STRAT-SB");
+        assert_eq!(variant.price.unwrap().to_string(), "3799.00 EUR");
+    }
+}
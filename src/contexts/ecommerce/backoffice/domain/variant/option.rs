@@ -0,0 +1,132 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::contexts::ecommerce::common;
+
+const OPTION_MAX_LENGTH: usize = 64;
+
+/// Dimension a product varies along, e.g. `colour` with `["Sunburst", "Olympic White"]`, and the values
+/// its variants may pick from, in display order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariantOption {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+impl VariantOption {
+    /// Names and values are trimmed; both must be non-empty and values unique within the option.
+    pub fn new(name: String, values: Vec<String>) -> Result<Self, common::domain::Error> {
+        let _e = tracing::debug_span!("New VariantOption").entered();
+
+        let name = Self::validate_text(name)?;
+        let values = values
+            .into_iter()
+            .map(Self::validate_text)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let unique: HashSet<_> = values.iter().collect();
+        if values.is_empty() || unique.len() != values.len() {
+            return Err(common::domain::Error::InvalidVariantOption).inspect_err(|err| tracing::error!("{err}"));
+        }
+
+        Ok(Self { name, values })
+    }
+
+    fn validate_text(value: String) -> Result<String, common::domain::Error> {
+        let value = value.trim();
+
+        if let 1..=OPTION_MAX_LENGTH = value.len() {
+            return Ok(value.to_string());
+        }
+
+        Err(common::domain::Error::InvalidVariantOption).inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    /// Checks that the options of a product do not define the same name twice.
+    pub fn validate_definitions(options: &[VariantOption]) -> Result<(), common::domain::Error> {
+        let _e = tracing::debug_span!("Validate VariantOption definitions").entered();
+
+        let names: HashSet<_> = options.iter().map(|option| option.name.as_str()).collect();
+        if names.len() != options.len() {
+            return Err(common::domain::Error::InvalidVariantOption).inspect_err(|err| tracing::error!("{err}"));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `selection` picks exactly one allowed value for every option and names no other.
+    pub fn validate_selection(
+        options: &[VariantOption],
+        selection: &BTreeMap<String, String>,
+    ) -> Result<(), common::domain::Error> {
+        let _e = tracing::debug_span!("Validate VariantOption selection").entered();
+
+        let matches = options.len() == selection.len()
+            && options.iter().all(|option| {
+                selection
+                    .get(&option.name)
+                    .is_some_and(|value| option.values.contains(value))
+            });
+
+        if !matches {
+            return Err(common::domain::Error::InvalidVariantOptions).inspect_err(|err| tracing::error!("{err}"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colour() -> VariantOption {
+        VariantOption::new(
+            String::from("colour"),
+            vec![String::from("Sunburst"), String::from("Olympic White")],
+        )
+        .unwrap()
+    }
+
+    fn selection(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn given_no_or_repeated_values_when_new_then_return_invalid_option() {
+        for values in [vec![], vec![String::from("Red"), String::from(" Red ")]] {
+            assert!(matches!(
+                VariantOption::new(String::from("colour"), values),
+                Err(common::domain::Error::InvalidVariantOption)
+            ));
+        }
+    }
+
+    #[test]
+    fn given_repeated_name_when_validate_definitions_then_return_invalid_option() {
+        assert!(matches!(
+            VariantOption::validate_definitions(&[colour(), colour()]),
+            Err(common::domain::Error::InvalidVariantOption)
+        ));
+    }
+
+    #[test]
+    fn given_selections_when_validate_selection_then_accept_only_one_allowed_value_per_option() {
+        let options = [colour()];
+
+        assert!(VariantOption::validate_selection(&options, &selection(&[("colour", "Sunburst")])).is_ok());
+
+        for invalid in [
+            selection(&[]),
+            selection(&[("colour", "Red")]),
+            selection(&[("colour", "Sunburst"), ("size", "L")]),
+        ] {
+            assert!(matches!(
+                VariantOption::validate_selection(&options, &invalid),
+                Err(common::domain::Error::InvalidVariantOptions)
+            ));
+        }
+    }
+}
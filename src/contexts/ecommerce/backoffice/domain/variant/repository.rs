@@ -0,0 +1,301 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::backoffice;
+
+use super::*;
+
+pub type DynVariantRepository<E> = Arc<dyn VariantRepository<Error = E> + Send + Sync + 'static>;
+
+#[async_trait]
+pub trait VariantRepository {
+    type Error;
+
+    /// Option definitions of a product, in display order.
+    async fn get_options(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<VariantOption>, Self::Error>;
+
+    /// Replaces every option definition of a product at once.
+    async fn save_options(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        options: &[VariantOption],
+    ) -> Result<(), Self::Error>;
+
+    /// Variants of a product, oldest first.
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<Variant>, Self::Error>;
+
+    /// Creates the variant or replaces the one with the same id on the same product. SKUs are unique
+    /// across all products.
+    async fn save(&self, variant: &Variant) -> Result<(), Self::Error>;
+    async fn delete(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &VariantId,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Behaviour every `VariantRepository` implementation must share, run against each of them.
+///
+/// Every function receives the product repository of the same store, since variants belong to a product.
+#[cfg(test)]
+pub mod conformance {
+    use crate::contexts::ecommerce::{backoffice, common};
+
+    use super::*;
+
+    type Repository = DynVariantRepository<common::domain::Error>;
+    type ProductRepository = backoffice::domain::product::DynProductRepository<common::domain::Error>;
+
+    async fn save_product(products: &ProductRepository) -> backoffice::domain::product::ProductId {
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(products).await;
+
+        product.id
+    }
+
+    fn option(name: &str, values: &[&str]) -> VariantOption {
+        VariantOption::new(name.to_string(), values.iter().map(|value| value.to_string()).collect()).unwrap()
+    }
+
+    pub async fn given_no_variants_when_get_then_return_empty_vecs(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+
+        assert!(repository.get_options(&product_id).await.unwrap().is_empty());
+        assert!(repository.get_by_product_id(&product_id).await.unwrap().is_empty());
+    }
+
+    pub async fn given_saved_options_when_save_options_then_replace_them_in_order(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+
+        repository
+            .save_options(
+                &product_id,
+                &[option("colour", &["Sunburst"]), option("neck", &["Maple"])],
+            )
+            .await
+            .unwrap();
+        repository
+            .save_options(&other_product_id, &[option("colour", &["Black"])])
+            .await
+            .unwrap();
+
+        let replacement = vec![
+            option("size", &["S", "M", "L"]),
+            option("colour", &["Sunburst", "Olympic White"]),
+        ];
+        repository.save_options(&product_id, &replacement).await.unwrap();
+
+        assert_eq!(repository.get_options(&product_id).await.unwrap(), replacement);
+        assert_eq!(
+            repository.get_options(&other_product_id).await.unwrap(),
+            vec![option("colour", &["Black"])]
+        );
+    }
+
+    pub async fn given_unknown_product_when_save_options_then_return_product_not_found(
+        repository: Repository,
+        _products: ProductRepository,
+    ) {
+        assert!(matches!(
+            repository
+                .save_options(
+                    &backoffice::domain::product::ProductId::default(),
+                    &[option("colour", &["Sunburst"])]
+                )
+                .await,
+            Err(common::domain::Error::ProductNotFound)
+        ));
+    }
+
+    pub async fn given_saved_variants_when_get_by_product_id_then_return_only_its_variants_in_order(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+
+        let first = fixture::VariantBuilder::new(product_id).option("colour", "Sunburst");
+        let other = fixture::VariantBuilder::new(other_product_id);
+        let second = fixture::VariantBuilder::new(product_id).option("colour", "Olympic White");
+
+        for variant in [&first, &other, &second] {
+            variant.save(&repository).await;
+        }
+
+        let ids: Vec<VariantId> = repository
+            .get_by_product_id(&product_id)
+            .await
+            .unwrap()
+            .iter()
+            .map(|variant| variant.id)
+            .collect();
+
+        assert_eq!(ids, vec![first.id, second.id]);
+    }
+
+    pub async fn given_saved_variant_when_get_by_product_id_then_return_same_variant(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let mut builder = fixture::VariantBuilder::new(product_id)
+            .option("colour", "Sunburst")
+            .option("size", "L");
+        builder.price = Some(common::domain::Money::new(
+            i64::from(i32::MAX) * 1_000 + 7,
+            common::domain::Currency::try_from("KWD").unwrap(),
+        ));
+        builder.barcode = Some(Barcode::try_from("4006381333931").unwrap());
+        builder.save(&repository).await;
+
+        let variants = repository.get_by_product_id(&product_id).await.unwrap();
+
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].id, builder.id);
+        assert_eq!(variants[0].product_id, product_id);
+        assert_eq!(variants[0].sku, builder.sku);
+        assert_eq!(variants[0].options, builder.options);
+        assert_eq!(variants[0].price, builder.price);
+        assert_eq!(variants[0].barcode, builder.barcode);
+    }
+
+    pub async fn given_saved_variant_when_save_with_same_id_then_replace_it(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let mut builder = fixture::VariantBuilder::new(product_id).option("colour", "Sunburst");
+        builder.price = Some(common::domain::Money::new(999, common::domain::Currency::EUR));
+        builder.save(&repository).await;
+
+        builder.sku = Sku::try_from("STRAT-OW-RENAMED").unwrap();
+        builder = builder.option("colour", "Olympic White");
+        builder.price = None;
+        builder.save(&repository).await;
+
+        let variants = repository.get_by_product_id(&product_id).await.unwrap();
+
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].sku, builder.sku);
+        assert_eq!(variants[0].options, builder.options);
+        assert_eq!(variants[0].price, None);
+    }
+
+    pub async fn given_sku_taken_by_any_product_when_save_then_return_sku_already_exists(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+
+        let taken = fixture::VariantBuilder::new(product_id);
+        taken.save(&repository).await;
+
+        for owner in [product_id, other_product_id] {
+            let mut duplicate = fixture::VariantBuilder::new(owner);
+            duplicate.sku = taken.sku.clone();
+
+            assert!(matches!(
+                repository.save(&duplicate.to_entity()).await,
+                Err(common::domain::Error::VariantSkuAlreadyExists)
+            ));
+        }
+        assert!(repository
+            .get_by_product_id(&other_product_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    pub async fn given_variant_of_other_product_when_save_with_same_id_then_return_already_exists(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+
+        let builder = fixture::VariantBuilder::new(product_id);
+        builder.save(&repository).await;
+
+        let mut stolen = builder.to_entity();
+        stolen.product_id = other_product_id;
+
+        assert!(matches!(
+            repository.save(&stolen).await,
+            Err(common::domain::Error::VariantAlreadyExists)
+        ));
+        assert!(repository
+            .get_by_product_id(&other_product_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    pub async fn given_unknown_product_when_save_variant_then_return_product_not_found(
+        repository: Repository,
+        _products: ProductRepository,
+    ) {
+        let builder = fixture::VariantBuilder::new(backoffice::domain::product::ProductId::default());
+
+        assert!(matches!(
+            repository.save(&builder.to_entity()).await,
+            Err(common::domain::Error::ProductNotFound)
+        ));
+    }
+
+    pub async fn given_saved_variant_when_delete_then_remove_only_it_and_free_its_sku(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let kept = fixture::VariantBuilder::new(product_id);
+        let deleted = fixture::VariantBuilder::new(product_id);
+        kept.save(&repository).await;
+        deleted.save(&repository).await;
+
+        repository.delete(&product_id, &deleted.id).await.unwrap();
+
+        let variants = repository.get_by_product_id(&product_id).await.unwrap();
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].id, kept.id);
+
+        assert!(matches!(
+            repository.delete(&product_id, &deleted.id).await,
+            Err(common::domain::Error::VariantNotFound)
+        ));
+
+        let mut reused = fixture::VariantBuilder::new(product_id);
+        reused.sku = deleted.sku.clone();
+        reused.save(&repository).await;
+    }
+
+    pub async fn given_variant_of_other_product_when_delete_then_return_not_found(
+        repository: Repository,
+        products: ProductRepository,
+    ) {
+        let product_id = save_product(&products).await;
+        let other_product_id = save_product(&products).await;
+        let builder = fixture::VariantBuilder::new(product_id);
+        builder.save(&repository).await;
+
+        assert!(matches!(
+            repository.delete(&other_product_id, &builder.id).await,
+            Err(common::domain::Error::VariantNotFound)
+        ));
+        assert_eq!(repository.get_by_product_id(&product_id).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+/// Stock keeping unit, unique across every product. Stored uppercase so that `abc-1` and `ABC-1` cannot
+/// name two different variants.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Sku(String);
+
+impl Sku {
+    fn validate(value: impl Into<String>) -> Result<String, common::domain::Error> {
+        let _e = tracing::debug_span!("Validate Sku").entered();
+
+        let value = value.into().trim().to_uppercase();
+
+        const SKU_MIN_LENGTH: usize = 1;
+        const SKU_MAX_LENGTH: usize = 64;
+
+        let allowed = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.');
+
+        if let SKU_MIN_LENGTH..=SKU_MAX_LENGTH = value.len() {
+            if value.chars().all(allowed) {
+                return Ok(value);
+            }
+        }
+
+        Err(common::domain::Error::InvalidVariantSku).inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    pub fn to_primitive(&self) -> String {
+        let _e = tracing::debug_span!("Transform Sku to primitive").entered();
+
+        self.0.clone()
+    }
+}
+
+impl Display for Sku {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let _e = tracing::debug_span!("Display Sku").entered();
+
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<&str> for Sku {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast Sku from &str").entered();
+
+        Ok(Self(Self::validate(value)?))
+    }
+}
+
+impl TryFrom<String> for Sku {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast Sku from String").entered();
+
+        Self::try_from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_lowercase_sku_with_spaces_around_when_try_from_then_return_trimmed_uppercase_sku() {
+        let sku = Sku::try_from("  strat-am-3ts ").unwrap();
+
+        assert_eq!(sku.to_primitive(), "STRAT-AM-3TS");
+    }
+
+    #[test]
+    fn given_empty_or_too_long_or_spaced_sku_when_try_from_then_return_invalid_sku() {
+        for value in [String::new(), "A".repeat(65), String::from("STRAT AM")] {
+            assert!(matches!(
+                Sku::try_from(value),
+                Err(common::domain::Error::InvalidVariantSku)
+            ));
+        }
+    }
+}
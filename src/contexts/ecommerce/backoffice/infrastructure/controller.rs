@@ -1,41 +1,133 @@
 use std::sync::Arc;
 
-use async_graphql::{EmptySubscription, Schema};
-use axum::routing::get;
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post, put};
 use axum::Router;
 
-use crate::contexts::ecommerce::{backoffice, common};
+use crate::contexts::ecommerce::{backoffice, common, settings};
 
 pub struct HttpController;
 
 impl HttpController {
-    pub fn build(services: common::infrastructure::DependencyContainer) -> Router {
-        let cloned_services = services.clone();
+    pub fn build(services: common::infrastructure::DependencyContainer, settings: &settings::Settings) -> Router {
+        let graphql_schema = Arc::new(backoffice::infrastructure::graphql::build_schema(
+            services.clone(),
+            &settings.graphql,
+        ));
 
-        let graphql_schema = Arc::new(
-            Schema::build(
-                backoffice::infrastructure::graphql::QueryRoot,
-                backoffice::infrastructure::graphql::MutationRoot,
-                EmptySubscription,
-            )
-            .data(cloned_services)
-            .finish(),
-        );
+        let mut graphql_route = post(backoffice::infrastructure::graphql::handler);
+        if settings.graphql.playground_enabled {
+            graphql_route = graphql_route.get(backoffice::infrastructure::graphql::playground);
+        }
+
+        let mut router = Router::new().route("/graphql", graphql_route.with_state(graphql_schema.clone()));
+        if settings.graphql.introspection_enabled {
+            router = router.route(
+                "/graphql/schema.graphql",
+                get(backoffice::infrastructure::graphql::sdl).with_state(graphql_schema),
+            );
+        }
 
-        Router::new()
+        router
             .route(
-                "/graphql",
-                get(backoffice::infrastructure::graphql::playground)
-                    .post(backoffice::infrastructure::graphql::handler)
-                    .with_state(graphql_schema),
+                "/exchange-rates",
+                post(backoffice::infrastructure::http::import_exchange_rates).layer(DefaultBodyLimit::max(
+                    backoffice::infrastructure::http::EXCHANGE_RATE_FILE_LIMIT,
+                )),
+            )
+            .nest(
+                "/category",
+                Router::new()
+                    .route(
+                        "/",
+                        get(backoffice::infrastructure::http::get_categories)
+                            .put(backoffice::infrastructure::http::save_category),
+                    )
+                    .route("/:category_id", get(backoffice::infrastructure::http::get_category))
+                    .route(
+                        "/:category_id/position",
+                        put(backoffice::infrastructure::http::move_category),
+                    )
+                    .route(
+                        "/:category_id/products",
+                        get(backoffice::infrastructure::http::get_category_products),
+                    )
+                    .route(
+                        "/:category_id/products/:product_id",
+                        put(backoffice::infrastructure::http::assign_product_to_category)
+                            .delete(backoffice::infrastructure::http::unassign_product_from_category),
+                    ),
             )
             .nest(
                 "/product",
-                Router::new().route(
-                    "/",
-                    get(backoffice::infrastructure::http::get_products)
-                        .put(backoffice::infrastructure::http::save_product),
-                ),
+                Router::new()
+                    .route(
+                        "/",
+                        get(backoffice::infrastructure::http::get_products)
+                            .put(backoffice::infrastructure::http::save_product),
+                    )
+                    .route("/events", get(backoffice::infrastructure::http::get_product_events))
+                    .route("/:product_id", put(backoffice::infrastructure::http::update_product))
+                    .route(
+                        "/:product_id/status",
+                        put(backoffice::infrastructure::http::change_product_status),
+                    )
+                    .route(
+                        "/:product_id/schedule",
+                        put(backoffice::infrastructure::http::schedule_product),
+                    )
+                    .route(
+                        "/:product_id/price-history",
+                        get(backoffice::infrastructure::http::get_product_price_history),
+                    )
+                    .route(
+                        "/:product_id/prices",
+                        get(backoffice::infrastructure::http::get_product_prices),
+                    )
+                    .route(
+                        "/:product_id/prices/current",
+                        get(backoffice::infrastructure::http::get_current_product_price),
+                    )
+                    .route(
+                        "/:product_id/prices/:id",
+                        put(backoffice::infrastructure::http::save_product_price)
+                            .delete(backoffice::infrastructure::http::delete_product_price),
+                    )
+                    .route(
+                        "/:product_id/options",
+                        get(backoffice::infrastructure::http::get_variant_options)
+                            .put(backoffice::infrastructure::http::set_variant_options),
+                    )
+                    .route(
+                        "/:product_id/variants",
+                        get(backoffice::infrastructure::http::get_variants),
+                    )
+                    .route(
+                        "/:product_id/variants/:id",
+                        put(backoffice::infrastructure::http::save_variant)
+                            .delete(backoffice::infrastructure::http::delete_variant),
+                    )
+                    .route("/:product_id/stock", get(backoffice::infrastructure::http::get_stock))
+                    .route(
+                        "/:product_id/stock/adjustments",
+                        get(backoffice::infrastructure::http::get_stock_adjustments),
+                    )
+                    .route(
+                        "/:product_id/stock/adjustments/:id",
+                        put(backoffice::infrastructure::http::adjust_stock),
+                    )
+                    .route(
+                        "/:product_id/reservations/:id",
+                        put(backoffice::infrastructure::http::reserve_stock),
+                    )
+                    .route(
+                        "/:product_id/reservations/:id/commit",
+                        post(backoffice::infrastructure::http::commit_reservation),
+                    )
+                    .route(
+                        "/:product_id/reservations/:id/release",
+                        post(backoffice::infrastructure::http::release_reservation),
+                    ),
             )
             .with_state(services)
     }
@@ -0,0 +1,141 @@
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::BASE;
+
+/// Date formats of the daily file (`10 May 2024`) and of the historical one (`2024-05-10`).
+const DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%d %B %Y"];
+
+/// Reads a `Date, USD, JPY, ...` file with one row of rates per day.
+///
+/// Empty cells and `N/A`, used for currencies not quoted on that day, are left out, as is the empty
+/// column the trailing comma of every line produces.
+pub fn parse_csv(
+    content: &str,
+) -> Result<Vec<backoffice::application::usecases::ExchangeRateInput>, common::domain::Error> {
+    let _e = tracing::debug_span!("Parse ECB csv").entered();
+
+    let invalid = |reason: String| {
+        tracing::error!("{reason}");
+        common::domain::Error::InvalidExchangeRateFile(reason)
+    };
+
+    let mut lines = content
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    let Some((_, header)) = lines.next() else {
+        return Err(invalid(String::from("empty file")));
+    };
+
+    let mut columns = header.split(',').map(str::trim);
+    if !columns.next().is_some_and(|column| column.eq_ignore_ascii_case("date")) {
+        return Err(invalid(String::from("header must start with a Date column")));
+    }
+    let currencies: Vec<&str> = columns.collect();
+
+    let mut rates = Vec::new();
+
+    for (number, line) in lines {
+        let mut cells = line.split(',').map(str::trim);
+
+        let date = cells.next().unwrap_or_default();
+        let valid_on = DATE_FORMATS
+            .iter()
+            .find_map(|format| chrono::NaiveDate::parse_from_str(date, format).ok())
+            .ok_or_else(|| invalid(format!("line {number}: invalid date {date:?}")))?;
+
+        let cells: Vec<&str> = cells.collect();
+        if cells.len() > currencies.len() {
+            return Err(invalid(format!("line {number}: more cells than header columns")));
+        }
+
+        for (currency, rate) in currencies.iter().zip(cells) {
+            if rate.is_empty() || rate == "N/A" {
+                continue;
+            }
+
+            if currency.is_empty() {
+                return Err(invalid(format!("line {number}: rate without currency column")));
+            }
+
+            rates.push(backoffice::application::usecases::ExchangeRateInput {
+                base: String::from(BASE),
+                quote: currency.to_string(),
+                rate: rate.to_string(),
+                valid_on,
+            });
+        }
+    }
+
+    if rates.is_empty() {
+        return Err(invalid(String::from("no rates found")));
+    }
+
+    Ok(rates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(rates: &[backoffice::application::usecases::ExchangeRateInput]) -> Vec<String> {
+        rates
+            .iter()
+            .map(|rate| format!("{} {}/{} {}", rate.valid_on, rate.base, rate.quote, rate.rate))
+            .collect()
+    }
+
+    #[test]
+    fn given_daily_file_when_parse_csv_then_return_rates_of_the_day() {
+        let content = "Date, USD, JPY, BGN, \r\n10 May 2024, 1.0772, 167.76, 1.9558, \r\n";
+
+        let rates = parse_csv(content).unwrap();
+
+        assert_eq!(
+            summary(&rates),
+            vec![
+                "2024-05-10 EUR/USD 1.0772",
+                "2024-05-10 EUR/JPY 167.76",
+                "2024-05-10 EUR/BGN 1.9558",
+            ]
+        );
+    }
+
+    #[test]
+    fn given_historical_file_when_parse_csv_then_skip_missing_rates() {
+        let content = "Date,USD,CYP,\n2024-05-10,1.0772,N/A,\n2007-12-31,1.4721,0.585274,\n";
+
+        let rates = parse_csv(content).unwrap();
+
+        assert_eq!(
+            summary(&rates),
+            vec![
+                "2024-05-10 EUR/USD 1.0772",
+                "2007-12-31 EUR/USD 1.4721",
+                "2007-12-31 EUR/CYP 0.585274",
+            ]
+        );
+    }
+
+    #[test]
+    fn given_malformed_files_when_parse_csv_then_return_file_error() {
+        for content in [
+            "",
+            "USD,JPY\n1.0772,167.76\n",
+            "Date,USD\nyesterday,1.0772\n",
+            "Date,USD\n2024-05-10,1.0772,167.76\n",
+            "Date,USD\n",
+        ] {
+            assert!(
+                matches!(
+                    parse_csv(content),
+                    Err(common::domain::Error::InvalidExchangeRateFile(_))
+                ),
+                "{content:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Readers for the euro foreign exchange reference rate files published by the ECB, in the XML and
+//! CSV flavours of both the daily and the historical downloads. Every rate in them is against EUR.
+pub use csv::*;
+pub use xml::*;
+
+mod csv;
+mod xml;
+
+/// Base currency of every ECB reference rate.
+const BASE: &str = "EUR";
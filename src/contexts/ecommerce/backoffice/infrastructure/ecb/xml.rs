@@ -0,0 +1,128 @@
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::BASE;
+
+/// Reads the `Cube` elements of an `eurofxref` envelope, a `time` cube per day holding one
+/// `currency`/`rate` cube per currency, which takes its date from that enclosing cube.
+pub fn parse_xml(
+    content: &str,
+) -> Result<Vec<backoffice::application::usecases::ExchangeRateInput>, common::domain::Error> {
+    let _e = tracing::debug_span!("Parse ECB xml").entered();
+
+    let invalid = |reason: String| {
+        tracing::error!("{reason}");
+        common::domain::Error::InvalidExchangeRateFile(reason)
+    };
+
+    let document = roxmltree::Document::parse(content).map_err(|err| invalid(err.to_string()))?;
+
+    let mut rates = Vec::new();
+
+    // the element may carry the namespace of the envelope, only its local name matters
+    for cube in document
+        .descendants()
+        .filter(|node| node.is_element() && node.tag_name().name() == "Cube")
+    {
+        if let Some(time) = cube.attribute("time") {
+            parse_date(time).map_err(invalid)?;
+        }
+
+        let (currency, rate) = match (cube.attribute("currency"), cube.attribute("rate")) {
+            (Some(currency), Some(rate)) => (currency, rate),
+            (None, None) => continue,
+            _ => {
+                return Err(invalid(String::from(
+                    "Cube with a currency but no rate, or the reverse",
+                )))
+            }
+        };
+
+        let time = cube
+            .parent_element()
+            .filter(|parent| parent.tag_name().name() == "Cube")
+            .and_then(|parent| parent.attribute("time"))
+            .ok_or_else(|| invalid(format!("rate of {currency} outside of a time Cube")))?;
+
+        rates.push(backoffice::application::usecases::ExchangeRateInput {
+            base: String::from(BASE),
+            quote: currency.to_string(),
+            rate: rate.to_string(),
+            valid_on: parse_date(time).map_err(invalid)?,
+        });
+    }
+
+    if rates.is_empty() {
+        return Err(invalid(String::from("no rates found")));
+    }
+
+    Ok(rates)
+}
+
+fn parse_date(time: &str) -> Result<chrono::NaiveDate, String> {
+    chrono::NaiveDate::parse_from_str(time, "%Y-%m-%d").map_err(|_| format!("invalid time {time:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAILY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+	<gesmes:subject>Reference rates</gesmes:subject>
+	<gesmes:Sender>
+		<gesmes:name>European Central Bank</gesmes:name>
+	</gesmes:Sender>
+	<Cube>
+		<!-- rates of <today> -->
+		<Cube time='2024-05-10'>
+			<Cube currency='USD' rate='1.0772'/>
+			<Cube currency="JPY" rate="167.76" />
+		</Cube>
+		<Cube time='2024-05-09'>
+			<Cube currency='USD' rate='1.0765'/>
+		</Cube>
+	</Cube>
+</gesmes:Envelope>"#;
+
+    #[test]
+    fn given_ecb_envelope_when_parse_xml_then_return_rates_by_day() {
+        let rates = parse_xml(DAILY).unwrap();
+
+        let summary: Vec<String> = rates
+            .iter()
+            .map(|rate| format!("{} {}/{} {}", rate.valid_on, rate.base, rate.quote, rate.rate))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                "2024-05-10 EUR/USD 1.0772",
+                "2024-05-10 EUR/JPY 167.76",
+                "2024-05-09 EUR/USD 1.0765",
+            ]
+        );
+    }
+
+    #[test]
+    fn given_malformed_files_when_parse_xml_then_return_file_error() {
+        for content in [
+            "",
+            "<Cube><Cube time='2024-05-10'></Cube></Cube>",
+            "<Cube currency='USD' rate='1.0772'/>",
+            "<Cube time='10/05/2024'><Cube currency='USD' rate='1.0772'/></Cube>",
+            "<Cube time='2024-05-10'><Cube currency='USD'/></Cube>",
+            "<Cube time='2024-05-10'><Cube currency=USD rate='1.0772'/></Cube>",
+            "<Cube time='2024-05-10'><Cube currency='USD' rate='1.0772'",
+            "<Cube><Cube time='2024-05-10'></Cube><Cube currency='USD' rate='1.0772'/></Cube>",
+            "<Cube time='2024-05-10'><Rates><Cube currency='USD' rate='1.0772'/></Rates></Cube>",
+        ] {
+            assert!(
+                matches!(
+                    parse_xml(content),
+                    Err(common::domain::Error::InvalidExchangeRateFile(_))
+                ),
+                "{content:?}"
+            );
+        }
+    }
+}
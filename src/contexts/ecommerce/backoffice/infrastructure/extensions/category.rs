@@ -0,0 +1,186 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use sqlx::postgres::PgRow;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Error, FromRow, Row};
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaType, Type};
+use utoipa::openapi::{KnownFormat, RefOr, SchemaFormat};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::contexts::ecommerce::backoffice;
+
+impl Serialize for backoffice::domain::category::Category {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize Category").entered();
+
+        let mut state = serializer.serialize_struct("Category", 7)?;
+
+        state.serialize_field("id", &self.id.to_primitive())?;
+        state.serialize_field("parent_id", &self.parent_id.map(|parent_id| parent_id.to_primitive()))?;
+        state.serialize_field("name", &self.name.to_primitive())?;
+        state.serialize_field("position", &self.position)?;
+        state.serialize_field("depth", &self.path.depth())?;
+        state.serialize_field("created_at", &self.created_at.to_primitive())?;
+        state.serialize_field("updated_at", &self.updated_at.to_primitive())?;
+
+        state.end()
+    }
+}
+
+fn id_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid)))
+        .examples(["3f1e8c2a-7b4d-4e5f-9a6b-1c2d3e4f5a6b"])
+}
+
+fn parent_id_schema() -> ObjectBuilder {
+    id_schema()
+        .schema_type(SchemaType::from_iter([Type::String, Type::Null]))
+        .description(Some("Parent category, null for a root category."))
+}
+
+fn position_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::Integer)
+        .minimum(Some(0))
+        .description(Some("Place among the children of the same parent, counted from 0."))
+}
+
+fn timestamp_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+}
+
+fn name_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .min_length(Some(1))
+        .max_length(Some(256))
+        .examples(["Running shoes"])
+}
+
+impl PartialSchema for backoffice::domain::category::Category {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("id", id_schema())
+            .required("id")
+            .property("parent_id", parent_id_schema())
+            .required("parent_id")
+            .property("name", name_schema())
+            .required("name")
+            .property("position", position_schema())
+            .required("position")
+            .property(
+                "depth",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .minimum(Some(0))
+                    .description(Some("Number of ancestors, 0 for a root category.")),
+            )
+            .required("depth")
+            .property("created_at", timestamp_schema())
+            .required("created_at")
+            .property("updated_at", timestamp_schema())
+            .required("updated_at")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::category::Category {}
+
+impl PartialSchema for backoffice::application::usecases::SaveCategoryInput {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("id", id_schema())
+            .required("id")
+            .property("parent_id", parent_id_schema())
+            .property("name", name_schema())
+            .required("name")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::application::usecases::SaveCategoryInput {}
+
+impl PartialSchema for backoffice::infrastructure::http::MoveCategoryBody {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("parent_id", parent_id_schema())
+            .property(
+                "position",
+                position_schema()
+                    .schema_type(SchemaType::from_iter([Type::Integer, Type::Null]))
+                    .description(Some("Place among the new siblings, missing or null to place it last.")),
+            )
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::infrastructure::http::MoveCategoryBody {}
+
+fn category_from_columns(
+    id: uuid::Uuid,
+    parent_id: Option<uuid::Uuid>,
+    name: String,
+    position: i32,
+    path: String,
+    created_at: chrono::DateTime<chrono::offset::Utc>,
+    updated_at: chrono::DateTime<chrono::offset::Utc>,
+) -> Result<backoffice::domain::category::Category, Error> {
+    let name = backoffice::domain::category::CategoryName::try_from(name).map_err(|_| Error::TypeNotFound {
+        type_name: String::from("CategoryName"),
+    })?;
+    let path = backoffice::domain::category::CategoryPath::try_from(path).map_err(|_| Error::TypeNotFound {
+        type_name: String::from("CategoryPath"),
+    })?;
+
+    Ok(backoffice::domain::category::Category {
+        id: backoffice::domain::category::CategoryId::from(id),
+        parent_id: parent_id.map(backoffice::domain::category::CategoryId::from),
+        name,
+        position,
+        path,
+        created_at: backoffice::domain::product::ProductTimeStamp::from(created_at),
+        updated_at: backoffice::domain::product::ProductTimeStamp::from(updated_at),
+    })
+}
+
+impl FromRow<'_, PgRow> for backoffice::domain::category::Category {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast Category from PgRow").entered();
+
+        category_from_columns(
+            row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::category::Category {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast Category from SqliteRow").entered();
+
+        let id: uuid::fmt::Hyphenated = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let parent_id: Option<uuid::fmt::Hyphenated> = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+
+        category_from_columns(
+            id.into_uuid(),
+            parent_id.map(|parent_id| parent_id.into_uuid()),
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
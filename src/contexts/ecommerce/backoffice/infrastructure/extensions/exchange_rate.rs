@@ -0,0 +1,85 @@
+use sqlx::postgres::PgRow;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Error, FromRow, Row};
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+impl PartialSchema for backoffice::application::usecases::ImportExchangeRatesOutput {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property(
+                "imported",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .description(Some(
+                        "Rates stored, new or replacing those of the same currency and day.",
+                    ))
+                    .examples([32]),
+            )
+            .required("imported")
+            .property(
+                "skipped_currencies",
+                ArrayBuilder::new()
+                    .items(ObjectBuilder::new().schema_type(Type::String).examples(["CYP"]))
+                    .description(Some("Codes in the file that are not current ISO 4217 currencies.")),
+            )
+            .required("skipped_currencies")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::application::usecases::ImportExchangeRatesOutput {}
+
+/// Shared by both backends, which hand the rate over as text so that no digit is lost on the way.
+fn exchange_rate_from_columns(
+    base: String,
+    quote: String,
+    rate: String,
+    valid_on: chrono::NaiveDate,
+) -> Result<backoffice::domain::exchange_rate::ExchangeRate, Error> {
+    let currency = |code: String| {
+        common::domain::Currency::try_from(code).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("Currency"),
+        })
+    };
+
+    let rate = backoffice::domain::exchange_rate::Rate::try_from(rate).map_err(|_| Error::TypeNotFound {
+        type_name: String::from("Rate"),
+    })?;
+
+    Ok(backoffice::domain::exchange_rate::ExchangeRate {
+        base: currency(base)?,
+        quote: currency(quote)?,
+        rate,
+        valid_on,
+    })
+}
+
+impl FromRow<'_, PgRow> for backoffice::domain::exchange_rate::ExchangeRate {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast ExchangeRate from PgRow").entered();
+
+        let base: String = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let quote: String = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let rate: String = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let valid_on: chrono::NaiveDate = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+
+        exchange_rate_from_columns(base, quote, rate, valid_on).inspect_err(|err| tracing::error!("{err}"))
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::exchange_rate::ExchangeRate {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast ExchangeRate from SqliteRow").entered();
+
+        let base: String = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let quote: String = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let rate: String = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let valid_on: chrono::NaiveDate = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+
+        exchange_rate_from_columns(base, quote, rate, valid_on).inspect_err(|err| tracing::error!("{err}"))
+    }
+}
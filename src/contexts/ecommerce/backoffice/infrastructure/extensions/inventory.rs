@@ -0,0 +1,415 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use sqlx::postgres::PgRow;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Error, FromRow, Row};
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::openapi::{KnownFormat, RefOr, SchemaFormat};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::contexts::ecommerce::backoffice;
+
+impl Serialize for backoffice::domain::inventory::StockLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize StockLevel").entered();
+
+        let mut state = serializer.serialize_struct("StockLevel", 5)?;
+
+        state.serialize_field("product_id", &self.product_id.to_primitive())?;
+        state.serialize_field("warehouse", &self.warehouse.to_primitive())?;
+        state.serialize_field("on_hand", &self.on_hand)?;
+        state.serialize_field("reserved", &self.reserved)?;
+        state.serialize_field("available", &self.available())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for backoffice::domain::inventory::StockAdjustment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize StockAdjustment").entered();
+
+        let mut state = serializer.serialize_struct("StockAdjustment", 6)?;
+
+        state.serialize_field("id", &self.id.to_primitive())?;
+        state.serialize_field("product_id", &self.product_id.to_primitive())?;
+        state.serialize_field("warehouse", &self.warehouse.to_primitive())?;
+        state.serialize_field("delta", &self.delta)?;
+        state.serialize_field("reason", &self.reason.to_primitive())?;
+        state.serialize_field("created_at", &self.created_at.to_primitive())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for backoffice::domain::inventory::Reservation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize Reservation").entered();
+
+        let mut state = serializer.serialize_struct("Reservation", 7)?;
+
+        // as seen when the response is written, so a reservation that ran out shows as expired
+        let status = self.status_at(backoffice::domain::product::ProductTimeStamp::default());
+
+        state.serialize_field("id", &self.id.to_primitive())?;
+        state.serialize_field("product_id", &self.product_id.to_primitive())?;
+        state.serialize_field("warehouse", &self.warehouse.to_primitive())?;
+        state.serialize_field("quantity", &self.quantity)?;
+        state.serialize_field("status", &status.to_primitive())?;
+        state.serialize_field("expires_at", &self.expires_at.to_primitive())?;
+        state.serialize_field("created_at", &self.created_at.to_primitive())?;
+
+        state.end()
+    }
+}
+
+fn id_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid)))
+        .examples(["8d7c5a1e-2f4b-4c3d-9e8f-7a6b5c4d3e2f"])
+}
+
+fn timestamp_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+}
+
+fn warehouse_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .min_length(Some(1))
+        .max_length(Some(32))
+        .pattern(Some("^[A-Za-z0-9_-]+$"))
+        .examples(["MAD-1"])
+        .description(Some("Code of the warehouse, stored uppercase."))
+}
+
+fn quantity_schema(minimum: i64) -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::Integer)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+        .minimum(Some(minimum as f64))
+}
+
+fn reason_schema() -> ObjectBuilder {
+    ObjectBuilder::new().schema_type(Type::String).enum_values(Some([
+        "received",
+        "returned",
+        "damaged",
+        "lost",
+        "correction",
+    ]))
+}
+
+impl PartialSchema for backoffice::domain::inventory::StockLevel {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("product_id", id_schema())
+            .required("product_id")
+            .property("warehouse", warehouse_schema())
+            .required("warehouse")
+            .property(
+                "on_hand",
+                quantity_schema(0).description(Some("Units in the warehouse, committed reservations taken out.")),
+            )
+            .required("on_hand")
+            .property(
+                "reserved",
+                quantity_schema(0).description(Some("Units held by pending reservations.")),
+            )
+            .required("reserved")
+            .property(
+                "available",
+                quantity_schema(0).description(Some("Units that can still be reserved.")),
+            )
+            .required("available")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::inventory::StockLevel {}
+
+impl PartialSchema for backoffice::domain::inventory::StockAdjustment {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("id", id_schema())
+            .required("id")
+            .property("product_id", id_schema())
+            .required("product_id")
+            .property("warehouse", warehouse_schema())
+            .required("warehouse")
+            .property(
+                "delta",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                    .examples([-3])
+                    .description(Some("Units added, or taken out when negative.")),
+            )
+            .required("delta")
+            .property("reason", reason_schema())
+            .required("reason")
+            .property("created_at", timestamp_schema())
+            .required("created_at")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::inventory::StockAdjustment {}
+
+impl PartialSchema for backoffice::domain::inventory::Reservation {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("id", id_schema())
+            .required("id")
+            .property("product_id", id_schema())
+            .required("product_id")
+            .property("warehouse", warehouse_schema())
+            .required("warehouse")
+            .property("quantity", quantity_schema(1))
+            .required("quantity")
+            .property(
+                "status",
+                ObjectBuilder::new().schema_type(Type::String).enum_values(Some([
+                    "pending",
+                    "committed",
+                    "released",
+                    "expired",
+                ])),
+            )
+            .required("status")
+            .property(
+                "expires_at",
+                timestamp_schema().description(Some("A pending reservation stops holding its units at this time.")),
+            )
+            .required("expires_at")
+            .property("created_at", timestamp_schema())
+            .required("created_at")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::inventory::Reservation {}
+
+impl PartialSchema for backoffice::infrastructure::http::AdjustStockBody {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("warehouse", warehouse_schema())
+            .required("warehouse")
+            .property(
+                "delta",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                    .examples([12])
+                    .description(Some("Units added, or taken out when negative. Never 0.")),
+            )
+            .required("delta")
+            .property("reason", reason_schema())
+            .required("reason")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::infrastructure::http::AdjustStockBody {}
+
+impl PartialSchema for backoffice::infrastructure::http::ReserveStockBody {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("warehouse", warehouse_schema())
+            .required("warehouse")
+            .property("quantity", quantity_schema(1))
+            .required("quantity")
+            .property(
+                "ttl_seconds",
+                quantity_schema(1)
+                    .maximum(Some(604_800_f64))
+                    .description(Some("Seconds the units stay held, 900 when missing.")),
+            )
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::infrastructure::http::ReserveStockBody {}
+
+fn stock_level_from_columns(
+    product_id: uuid::Uuid,
+    warehouse: String,
+    on_hand: i64,
+    reserved: i64,
+) -> Result<backoffice::domain::inventory::StockLevel, Error> {
+    let warehouse =
+        backoffice::domain::inventory::WarehouseCode::try_from(warehouse).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("WarehouseCode"),
+        })?;
+
+    Ok(backoffice::domain::inventory::StockLevel {
+        product_id: backoffice::domain::product::ProductId::from(product_id),
+        warehouse,
+        on_hand,
+        reserved,
+    })
+}
+
+impl FromRow<'_, PgRow> for backoffice::domain::inventory::StockLevel {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast StockLevel from PgRow").entered();
+
+        stock_level_from_columns(
+            row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::inventory::StockLevel {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast StockLevel from SqliteRow").entered();
+
+        let product_id: uuid::fmt::Hyphenated = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+
+        stock_level_from_columns(
+            product_id.into_uuid(),
+            row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+fn adjustment_from_columns(
+    id: uuid::Uuid,
+    product_id: uuid::Uuid,
+    warehouse: String,
+    delta: i64,
+    reason: String,
+    created_at: chrono::DateTime<chrono::offset::Utc>,
+) -> Result<backoffice::domain::inventory::StockAdjustment, Error> {
+    let warehouse =
+        backoffice::domain::inventory::WarehouseCode::try_from(warehouse).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("WarehouseCode"),
+        })?;
+    let reason =
+        backoffice::domain::inventory::AdjustmentReason::try_from(reason).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("AdjustmentReason"),
+        })?;
+
+    Ok(backoffice::domain::inventory::StockAdjustment {
+        id: backoffice::domain::inventory::StockAdjustmentId::from(id),
+        product_id: backoffice::domain::product::ProductId::from(product_id),
+        warehouse,
+        delta,
+        reason,
+        created_at: backoffice::domain::product::ProductTimeStamp::from(created_at),
+    })
+}
+
+impl FromRow<'_, PgRow> for backoffice::domain::inventory::StockAdjustment {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast StockAdjustment from PgRow").entered();
+
+        adjustment_from_columns(
+            row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::inventory::StockAdjustment {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast StockAdjustment from SqliteRow").entered();
+
+        let id: uuid::fmt::Hyphenated = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let product_id: uuid::fmt::Hyphenated = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+
+        adjustment_from_columns(
+            id.into_uuid(),
+            product_id.into_uuid(),
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+fn reservation_from_columns(
+    id: uuid::Uuid,
+    product_id: uuid::Uuid,
+    warehouse: String,
+    quantity: i64,
+    status: String,
+    expires_at: chrono::DateTime<chrono::offset::Utc>,
+    created_at: chrono::DateTime<chrono::offset::Utc>,
+) -> Result<backoffice::domain::inventory::Reservation, Error> {
+    let warehouse =
+        backoffice::domain::inventory::WarehouseCode::try_from(warehouse).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("WarehouseCode"),
+        })?;
+    let status =
+        backoffice::domain::inventory::ReservationStatus::try_from(status).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("ReservationStatus"),
+        })?;
+
+    Ok(backoffice::domain::inventory::Reservation {
+        id: backoffice::domain::inventory::ReservationId::from(id),
+        product_id: backoffice::domain::product::ProductId::from(product_id),
+        warehouse,
+        quantity,
+        status,
+        expires_at: backoffice::domain::product::ProductTimeStamp::from(expires_at),
+        created_at: backoffice::domain::product::ProductTimeStamp::from(created_at),
+    })
+}
+
+impl FromRow<'_, PgRow> for backoffice::domain::inventory::Reservation {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast Reservation from PgRow").entered();
+
+        reservation_from_columns(
+            row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::inventory::Reservation {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast Reservation from SqliteRow").entered();
+
+        let id: uuid::fmt::Hyphenated = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let product_id: uuid::fmt::Hyphenated = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+
+        reservation_from_columns(
+            id.into_uuid(),
+            product_id.into_uuid(),
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
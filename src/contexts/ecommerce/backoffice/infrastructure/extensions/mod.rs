@@ -1,3 +1,8 @@
-pub use product::*;
-
+mod category;
+mod exchange_rate;
+mod inventory;
 mod product;
+mod product_event;
+mod product_price;
+mod product_price_history;
+mod variant;
@@ -1,9 +1,9 @@
-use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
 use sqlx::postgres::PgRow;
+use sqlx::sqlite::SqliteRow;
 use sqlx::{Error, FromRow, Row};
 
-use crate::contexts::ecommerce::backoffice;
+use crate::contexts::ecommerce::{backoffice, common};
 
 impl Serialize for backoffice::domain::product::Product {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -12,16 +12,18 @@ impl Serialize for backoffice::domain::product::Product {
     {
         let _e = tracing::debug_span!("Serialize Product").entered();
 
-        let mut state = serializer.serialize_struct("Product", 6)?;
+        backoffice::infrastructure::http::Product::from(self).serialize(serializer)
+    }
+}
 
-        state.serialize_field("id", &self.id.to_primitive())?;
-        state.serialize_field("name", &self.name.to_primitive())?;
-        state.serialize_field("price", &self.price.to_primitive())?;
-        state.serialize_field("currency", &self.currency.to_primitive())?;
-        state.serialize_field("updated_at", &self.updated_at.to_primitive())?;
-        state.serialize_field("created_at", &self.created_at.to_primitive())?;
+impl Serialize for backoffice::application::usecases::ProductListItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize ProductListItem").entered();
 
-        state.end()
+        backoffice::infrastructure::http::ProductListItem::from(self).serialize(serializer)
     }
 }
 
@@ -37,16 +39,63 @@ impl FromRow<'_, PgRow> for backoffice::domain::product::Product {
             type_name: String::from("ProductName"),
         })?;
 
-        let price: i32 = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
-        let price = backoffice::domain::product::ProductPrice::try_from(price).map_err(|_| Error::TypeNotFound {
-            type_name: String::from("ProductPrice"),
+        let price: i64 = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let currency: String = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+        let currency = common::domain::Currency::try_from(currency).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("Currency"),
+        })?;
+        let price = common::domain::Money::new(price, currency);
+
+        let created_at: chrono::DateTime<chrono::offset::Utc> =
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?;
+        let created_at = backoffice::domain::product::ProductTimeStamp::from(created_at);
+
+        let updated_at: chrono::DateTime<chrono::offset::Utc> =
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?;
+        let updated_at = backoffice::domain::product::ProductTimeStamp::from(updated_at);
+
+        let status: String = row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?;
+        let status = backoffice::domain::product::ProductStatus::try_from(status).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("ProductStatus"),
         })?;
 
+        let publish_at: Option<chrono::DateTime<chrono::offset::Utc>> =
+            row.try_get(7).inspect_err(|err| tracing::error!("{err}"))?;
+        let unpublish_at: Option<chrono::DateTime<chrono::offset::Utc>> =
+            row.try_get(8).inspect_err(|err| tracing::error!("{err}"))?;
+
+        Ok(backoffice::domain::product::Product {
+            id,
+            name,
+            price,
+            status,
+            publish_at: publish_at.map(backoffice::domain::product::ProductTimeStamp::from),
+            unpublish_at: unpublish_at.map(backoffice::domain::product::ProductTimeStamp::from),
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::product::Product {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast Product from SqliteRow").entered();
+
+        // sqlite keeps uuids as hyphenated text, every other column decodes as in postgres
+        let id: uuid::fmt::Hyphenated = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let id = backoffice::domain::product::ProductId::from(id.into_uuid());
+
+        let name: String = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let name = backoffice::domain::product::ProductName::try_from(name).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("ProductName"),
+        })?;
+
+        let price: i64 = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
         let currency: String = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
-        let currency =
-            backoffice::domain::product::ProductCurrency::try_from(currency).map_err(|_| Error::TypeNotFound {
-                type_name: String::from("ProductCurrency"),
-            })?;
+        let currency = common::domain::Currency::try_from(currency).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("Currency"),
+        })?;
+        let price = common::domain::Money::new(price, currency);
 
         let created_at: chrono::DateTime<chrono::offset::Utc> =
             row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?;
@@ -56,11 +105,23 @@ impl FromRow<'_, PgRow> for backoffice::domain::product::Product {
             row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?;
         let updated_at = backoffice::domain::product::ProductTimeStamp::from(updated_at);
 
+        let status: String = row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?;
+        let status = backoffice::domain::product::ProductStatus::try_from(status).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("ProductStatus"),
+        })?;
+
+        let publish_at: Option<chrono::DateTime<chrono::offset::Utc>> =
+            row.try_get(7).inspect_err(|err| tracing::error!("{err}"))?;
+        let unpublish_at: Option<chrono::DateTime<chrono::offset::Utc>> =
+            row.try_get(8).inspect_err(|err| tracing::error!("{err}"))?;
+
         Ok(backoffice::domain::product::Product {
             id,
             name,
             price,
-            currency,
+            status,
+            publish_at: publish_at.map(backoffice::domain::product::ProductTimeStamp::from),
+            unpublish_at: unpublish_at.map(backoffice::domain::product::ProductTimeStamp::from),
             created_at,
             updated_at,
         })
@@ -0,0 +1,208 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use sqlx::postgres::PgRow;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Error, FromRow, Row};
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaType, Type};
+use utoipa::openapi::{KnownFormat, RefOr, SchemaFormat};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::contexts::ecommerce::backoffice;
+
+impl Serialize for backoffice::domain::product_event::ProductEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize ProductEvent").entered();
+
+        let mut state = serializer.serialize_struct("ProductEvent", 5)?;
+
+        state.serialize_field("sequence", &self.sequence.to_primitive())?;
+        state.serialize_field("kind", &self.kind.to_primitive())?;
+        state.serialize_field("product_id", &self.product_id.to_primitive())?;
+        state.serialize_field("product", &self.payload)?;
+        state.serialize_field("occurred_at", &self.occurred_at.to_primitive())?;
+
+        state.end()
+    }
+}
+
+impl PartialSchema for backoffice::domain::product_event::ProductEvent {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property(
+                "sequence",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                    .minimum(Some(0))
+                    .examples([42]),
+            )
+            .required("sequence")
+            .property(
+                "kind",
+                ObjectBuilder::new().schema_type(Type::String).enum_values(Some([
+                    backoffice::domain::product_event::ProductEventKind::Created.to_primitive(),
+                    backoffice::domain::product_event::ProductEventKind::Updated.to_primitive(),
+                    backoffice::domain::product_event::ProductEventKind::Deleted.to_primitive(),
+                ])),
+            )
+            .required("kind")
+            .property(
+                "product_id",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid))),
+            )
+            .required("product_id")
+            .property(
+                "product",
+                ObjectBuilder::new()
+                    .description(Some("Product columns as the event left them."))
+                    .property(
+                        "id",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid))),
+                    )
+                    .required("id")
+                    .property("name", ObjectBuilder::new().schema_type(Type::String))
+                    .required("name")
+                    .property(
+                        "price",
+                        ObjectBuilder::new()
+                            .schema_type(Type::Integer)
+                            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                            .description(Some("Amount in minor units of the currency."))
+                            .examples([129999]),
+                    )
+                    .required("price")
+                    .property(
+                        "currency",
+                        ObjectBuilder::new().schema_type(Type::String).examples(["EUR"]),
+                    )
+                    .required("currency")
+                    .property(
+                        "status",
+                        ObjectBuilder::new().schema_type(Type::String).enum_values(Some([
+                            "draft",
+                            "published",
+                            "archived",
+                        ])),
+                    )
+                    .required("status")
+                    .property(
+                        "publish_at",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::from_iter([Type::String, Type::Null]))
+                            .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime))),
+                    )
+                    .required("publish_at")
+                    .property(
+                        "unpublish_at",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::from_iter([Type::String, Type::Null]))
+                            .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime))),
+                    )
+                    .required("unpublish_at")
+                    .property(
+                        "created_at",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime))),
+                    )
+                    .required("created_at")
+                    .property(
+                        "updated_at",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime))),
+                    )
+                    .required("updated_at"),
+            )
+            .required("product")
+            .property(
+                "occurred_at",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime))),
+            )
+            .required("occurred_at")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::product_event::ProductEvent {}
+
+impl FromRow<'_, PgRow> for backoffice::domain::product_event::ProductEvent {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast ProductEvent from PgRow").entered();
+
+        let sequence: i64 = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let sequence = backoffice::domain::product_event::ProductEventSequence::try_from(sequence).map_err(|_| {
+            Error::TypeNotFound {
+                type_name: String::from("ProductEventSequence"),
+            }
+        })?;
+
+        let product_id: uuid::Uuid = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let product_id = backoffice::domain::product::ProductId::from(product_id);
+
+        let kind: String = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let kind =
+            backoffice::domain::product_event::ProductEventKind::try_from(kind).map_err(|_| Error::TypeNotFound {
+                type_name: String::from("ProductEventKind"),
+            })?;
+
+        let payload: serde_json::Value = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+
+        let occurred_at: chrono::DateTime<chrono::offset::Utc> =
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?;
+        let occurred_at = backoffice::domain::product::ProductTimeStamp::from(occurred_at);
+
+        Ok(backoffice::domain::product_event::ProductEvent {
+            sequence,
+            kind,
+            product_id,
+            payload,
+            occurred_at,
+        })
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::product_event::ProductEvent {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast ProductEvent from SqliteRow").entered();
+
+        let sequence: i64 = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let sequence = backoffice::domain::product_event::ProductEventSequence::try_from(sequence).map_err(|_| {
+            Error::TypeNotFound {
+                type_name: String::from("ProductEventSequence"),
+            }
+        })?;
+
+        let product_id: uuid::fmt::Hyphenated = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let product_id = backoffice::domain::product::ProductId::from(product_id.into_uuid());
+
+        let kind: String = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let kind =
+            backoffice::domain::product_event::ProductEventKind::try_from(kind).map_err(|_| Error::TypeNotFound {
+                type_name: String::from("ProductEventKind"),
+            })?;
+
+        let payload: serde_json::Value = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+
+        let occurred_at: chrono::DateTime<chrono::offset::Utc> =
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?;
+        let occurred_at = backoffice::domain::product::ProductTimeStamp::from(occurred_at);
+
+        Ok(backoffice::domain::product_event::ProductEvent {
+            sequence,
+            kind,
+            product_id,
+            payload,
+            occurred_at,
+        })
+    }
+}
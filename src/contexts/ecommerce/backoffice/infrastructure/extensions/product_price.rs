@@ -0,0 +1,170 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use sqlx::postgres::PgRow;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Error, FromRow, Row};
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaType, Type};
+use utoipa::openapi::{KnownFormat, RefOr, SchemaFormat};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+impl Serialize for backoffice::domain::product_price::ProductPrice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize ProductPrice").entered();
+
+        let mut state = serializer.serialize_struct("ProductPrice", 6)?;
+
+        state.serialize_field("id", &self.id.to_primitive())?;
+        state.serialize_field("product_id", &self.product_id.to_primitive())?;
+        state.serialize_field("price", &self.price)?;
+        state.serialize_field(
+            "valid_from",
+            &self.valid_from.map(|valid_from| valid_from.to_primitive()),
+        )?;
+        state.serialize_field(
+            "valid_until",
+            &self.valid_until.map(|valid_until| valid_until.to_primitive()),
+        )?;
+        state.serialize_field("created_at", &self.created_at.to_primitive())?;
+
+        state.end()
+    }
+}
+
+fn id_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid)))
+        .examples(["0b0c3fd4-51f3-4c8c-9d0c-6f3d0e5e9a12"])
+}
+
+fn timestamp_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+}
+
+fn validity_schema(schema: ObjectBuilder) -> ObjectBuilder {
+    schema
+        .property(
+            "valid_from",
+            timestamp_schema()
+                .schema_type(SchemaType::from_iter([Type::String, Type::Null]))
+                .description(Some("Start of validity, inclusive. Missing or null for no start.")),
+        )
+        .property(
+            "valid_until",
+            timestamp_schema()
+                .schema_type(SchemaType::from_iter([Type::String, Type::Null]))
+                .description(Some("End of validity, exclusive. Missing or null for no end.")),
+        )
+}
+
+impl PartialSchema for backoffice::domain::product_price::ProductPrice {
+    fn schema() -> RefOr<Schema> {
+        let schema = ObjectBuilder::new()
+            .property("id", id_schema())
+            .required("id")
+            .property("product_id", id_schema())
+            .required("product_id")
+            .property("price", common::infrastructure::money_schema())
+            .required("price");
+
+        validity_schema(schema)
+            .required("valid_from")
+            .required("valid_until")
+            .property("created_at", timestamp_schema())
+            .required("created_at")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::product_price::ProductPrice {}
+
+impl PartialSchema for backoffice::infrastructure::http::SaveProductPriceBody {
+    fn schema() -> RefOr<Schema> {
+        let schema = ObjectBuilder::new()
+            .property("price", common::infrastructure::money_schema())
+            .required("price");
+
+        validity_schema(schema).into()
+    }
+}
+
+impl ToSchema for backoffice::infrastructure::http::SaveProductPriceBody {}
+
+impl FromRow<'_, PgRow> for backoffice::domain::product_price::ProductPrice {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast ProductPrice from PgRow").entered();
+
+        let id: uuid::Uuid = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let id = backoffice::domain::product_price::ProductPriceId::from(id);
+
+        let product_id: uuid::Uuid = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let product_id = backoffice::domain::product::ProductId::from(product_id);
+
+        let price: i64 = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let currency: String = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+        let currency = common::domain::Currency::try_from(currency).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("Currency"),
+        })?;
+        let price = common::domain::Money::new(price, currency);
+
+        let valid_from: Option<chrono::DateTime<chrono::offset::Utc>> =
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?;
+        let valid_until: Option<chrono::DateTime<chrono::offset::Utc>> =
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?;
+
+        let created_at: chrono::DateTime<chrono::offset::Utc> =
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?;
+
+        Ok(backoffice::domain::product_price::ProductPrice {
+            id,
+            product_id,
+            price,
+            valid_from: valid_from.map(backoffice::domain::product::ProductTimeStamp::from),
+            valid_until: valid_until.map(backoffice::domain::product::ProductTimeStamp::from),
+            created_at: backoffice::domain::product::ProductTimeStamp::from(created_at),
+        })
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::product_price::ProductPrice {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast ProductPrice from SqliteRow").entered();
+
+        let id: uuid::fmt::Hyphenated = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let id = backoffice::domain::product_price::ProductPriceId::from(id.into_uuid());
+
+        let product_id: uuid::fmt::Hyphenated = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let product_id = backoffice::domain::product::ProductId::from(product_id.into_uuid());
+
+        let price: i64 = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let currency: String = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+        let currency = common::domain::Currency::try_from(currency).map_err(|_| Error::TypeNotFound {
+            type_name: String::from("Currency"),
+        })?;
+        let price = common::domain::Money::new(price, currency);
+
+        let valid_from: Option<chrono::DateTime<chrono::offset::Utc>> =
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?;
+        let valid_until: Option<chrono::DateTime<chrono::offset::Utc>> =
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?;
+
+        let created_at: chrono::DateTime<chrono::offset::Utc> =
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?;
+
+        Ok(backoffice::domain::product_price::ProductPrice {
+            id,
+            product_id,
+            price,
+            valid_from: valid_from.map(backoffice::domain::product::ProductTimeStamp::from),
+            valid_until: valid_until.map(backoffice::domain::product::ProductTimeStamp::from),
+            created_at: backoffice::domain::product::ProductTimeStamp::from(created_at),
+        })
+    }
+}
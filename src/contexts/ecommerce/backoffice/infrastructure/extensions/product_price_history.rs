@@ -0,0 +1,144 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use sqlx::postgres::PgRow;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Error, FromRow, Row};
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaType, Type};
+use utoipa::openapi::{KnownFormat, RefOr, SchemaFormat};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+impl Serialize for backoffice::domain::product_price_history::ProductPriceChange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize ProductPriceChange").entered();
+
+        let mut state = serializer.serialize_struct("ProductPriceChange", 5)?;
+
+        state.serialize_field("product_id", &self.product_id.to_primitive())?;
+        state.serialize_field("old_price", &self.old_price)?;
+        state.serialize_field("new_price", &self.new_price)?;
+        state.serialize_field("changed_by", &self.changed_by)?;
+        state.serialize_field("changed_at", &self.changed_at.to_primitive())?;
+
+        state.end()
+    }
+}
+
+impl PartialSchema for backoffice::domain::product_price_history::ProductPriceChange {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property(
+                "product_id",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid)))
+                    .examples(["4548cc0d-2379-427f-93e2-44ac0a0333c6"]),
+            )
+            .required("product_id")
+            .property(
+                "old_price",
+                common::infrastructure::money_schema()
+                    .schema_type(SchemaType::from_iter([Type::Object, Type::Null]))
+                    .description(Some("Price before the change, null for the price the product was created with.")),
+            )
+            .required("old_price")
+            .property("new_price", common::infrastructure::money_schema())
+            .required("new_price")
+            .property(
+                "changed_by",
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::from_iter([Type::String, Type::Null]))
+                    .description(Some(
+                        "Subject of the identity the change was made on behalf of, null when made outside of a request.",
+                    )),
+            )
+            .required("changed_by")
+            .property(
+                "changed_at",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime))),
+            )
+            .required("changed_at")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::product_price_history::ProductPriceChange {}
+
+/// Shared by both backends once the columns are decoded, `old_price` and `old_currency` are null together.
+fn price_change_from_columns(
+    product_id: uuid::Uuid,
+    old_price: Option<(i64, String)>,
+    new_price: (i64, String),
+    changed_by: Option<String>,
+    changed_at: chrono::DateTime<chrono::offset::Utc>,
+) -> Result<backoffice::domain::product_price_history::ProductPriceChange, Error> {
+    let money = |(amount, currency): (i64, String)| {
+        common::domain::Currency::try_from(currency)
+            .map(|currency| common::domain::Money::new(amount, currency))
+            .map_err(|_| Error::TypeNotFound {
+                type_name: String::from("Currency"),
+            })
+    };
+
+    Ok(backoffice::domain::product_price_history::ProductPriceChange {
+        product_id: backoffice::domain::product::ProductId::from(product_id),
+        old_price: old_price.map(money).transpose()?,
+        new_price: money(new_price)?,
+        changed_by,
+        changed_at: backoffice::domain::product::ProductTimeStamp::from(changed_at),
+    })
+}
+
+impl FromRow<'_, PgRow> for backoffice::domain::product_price_history::ProductPriceChange {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast ProductPriceChange from PgRow").entered();
+
+        let product_id: uuid::Uuid = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let old_price: Option<i64> = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let old_currency: Option<String> = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let new_price: i64 = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+        let new_currency: String = row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?;
+        let changed_by: Option<String> = row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?;
+        let changed_at: chrono::DateTime<chrono::offset::Utc> =
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?;
+
+        price_change_from_columns(
+            product_id,
+            old_price.zip(old_currency),
+            (new_price, new_currency),
+            changed_by,
+            changed_at,
+        )
+        .inspect_err(|err| tracing::error!("{err}"))
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::product_price_history::ProductPriceChange {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast ProductPriceChange from SqliteRow").entered();
+
+        let product_id: uuid::fmt::Hyphenated = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let old_price: Option<i64> = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+        let old_currency: Option<String> = row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?;
+        let new_price: i64 = row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?;
+        let new_currency: String = row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?;
+        let changed_by: Option<String> = row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?;
+        let changed_at: chrono::DateTime<chrono::offset::Utc> =
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?;
+
+        price_change_from_columns(
+            product_id.into_uuid(),
+            old_price.zip(old_currency),
+            (new_price, new_currency),
+            changed_by,
+            changed_at,
+        )
+        .inspect_err(|err| tracing::error!("{err}"))
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use sqlx::postgres::PgRow;
+use sqlx::sqlite::SqliteRow;
+use sqlx::types::Json;
+use sqlx::{Error, FromRow, Row};
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema, SchemaType, Type};
+use utoipa::openapi::{KnownFormat, RefOr, SchemaFormat};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+impl Serialize for backoffice::domain::variant::VariantOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize VariantOption").entered();
+
+        let mut state = serializer.serialize_struct("VariantOption", 2)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("values", &self.values)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for backoffice::domain::variant::Variant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize Variant").entered();
+
+        let mut state = serializer.serialize_struct("Variant", 8)?;
+
+        state.serialize_field("id", &self.id.to_primitive())?;
+        state.serialize_field("product_id", &self.product_id.to_primitive())?;
+        state.serialize_field("sku", &self.sku.to_primitive())?;
+        state.serialize_field("options", &self.options)?;
+        state.serialize_field("price", &self.price)?;
+        state.serialize_field("barcode", &self.barcode.as_ref().map(|barcode| barcode.to_primitive()))?;
+        state.serialize_field("created_at", &self.created_at.to_primitive())?;
+        state.serialize_field("updated_at", &self.updated_at.to_primitive())?;
+
+        state.end()
+    }
+}
+
+fn id_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid)))
+        .examples(["8d7c5a1e-2f4b-4c3d-9e8f-7a6b5c4d3e2f"])
+}
+
+fn timestamp_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+}
+
+fn text_schema(example: &str) -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .min_length(Some(1))
+        .max_length(Some(64))
+        .examples([example])
+}
+
+fn sku_schema() -> ObjectBuilder {
+    text_schema("STRAT-SB-MAPLE")
+        .pattern(Some("^[A-Za-z0-9._-]+$"))
+        .description(Some("Unique across all products, stored uppercase."))
+}
+
+fn options_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .additional_properties(Some(text_schema("Sunburst")))
+        .description(Some(
+            "Option name to the value picked for it, one entry per option of the product.",
+        ))
+}
+
+fn price_schema() -> ObjectBuilder {
+    common::infrastructure::money_schema()
+        .schema_type(SchemaType::from_iter([Type::Object, Type::Null]))
+        .description(Some(
+            "Overrides the product price, missing or null to sell at the product price.",
+        ))
+}
+
+fn barcode_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(SchemaType::from_iter([Type::String, Type::Null]))
+        .pattern(Some("^([0-9]{8}|[0-9]{12,13})$"))
+        .examples(["4006381333931"])
+        .description(Some("EAN-8, UPC-A or EAN-13 with a valid check digit."))
+}
+
+impl PartialSchema for backoffice::domain::variant::VariantOption {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("name", text_schema("colour"))
+            .required("name")
+            .property(
+                "values",
+                ArrayBuilder::new()
+                    .items(text_schema("Sunburst"))
+                    .min_items(Some(1))
+                    .unique_items(true)
+                    .description(Some("Values a variant may pick, in display order.")),
+            )
+            .required("values")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::variant::VariantOption {}
+
+impl PartialSchema for backoffice::application::usecases::VariantOptionInput {
+    fn schema() -> RefOr<Schema> {
+        backoffice::domain::variant::VariantOption::schema()
+    }
+}
+
+impl ToSchema for backoffice::application::usecases::VariantOptionInput {}
+
+impl PartialSchema for backoffice::domain::variant::Variant {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("id", id_schema())
+            .required("id")
+            .property("product_id", id_schema())
+            .required("product_id")
+            .property("sku", sku_schema())
+            .required("sku")
+            .property("options", options_schema())
+            .required("options")
+            .property("price", price_schema())
+            .required("price")
+            .property("barcode", barcode_schema())
+            .required("barcode")
+            .property("created_at", timestamp_schema())
+            .required("created_at")
+            .property("updated_at", timestamp_schema())
+            .required("updated_at")
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::domain::variant::Variant {}
+
+impl PartialSchema for backoffice::infrastructure::http::SaveVariantBody {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .property("sku", sku_schema())
+            .required("sku")
+            .property("options", options_schema())
+            .property("price", price_schema())
+            .property("barcode", barcode_schema())
+            .into()
+    }
+}
+
+impl ToSchema for backoffice::infrastructure::http::SaveVariantBody {}
+
+fn option_from_columns(
+    name: String,
+    values: Json<Vec<String>>,
+) -> Result<backoffice::domain::variant::VariantOption, Error> {
+    backoffice::domain::variant::VariantOption::new(name, values.0).map_err(|_| Error::TypeNotFound {
+        type_name: String::from("VariantOption"),
+    })
+}
+
+impl FromRow<'_, PgRow> for backoffice::domain::variant::VariantOption {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast VariantOption from PgRow").entered();
+
+        option_from_columns(
+            row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::variant::VariantOption {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast VariantOption from SqliteRow").entered();
+
+        option_from_columns(
+            row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn variant_from_columns(
+    id: uuid::Uuid,
+    product_id: uuid::Uuid,
+    sku: String,
+    options: Json<BTreeMap<String, String>>,
+    price: Option<i64>,
+    currency: Option<String>,
+    barcode: Option<String>,
+    created_at: chrono::DateTime<chrono::offset::Utc>,
+    updated_at: chrono::DateTime<chrono::offset::Utc>,
+) -> Result<backoffice::domain::variant::Variant, Error> {
+    let sku = backoffice::domain::variant::Sku::try_from(sku).map_err(|_| Error::TypeNotFound {
+        type_name: String::from("Sku"),
+    })?;
+    let price = match (price, currency) {
+        (Some(price), Some(currency)) => {
+            let currency = common::domain::Currency::try_from(currency).map_err(|_| Error::TypeNotFound {
+                type_name: String::from("Currency"),
+            })?;
+
+            Some(common::domain::Money::new(price, currency))
+        }
+        _ => None,
+    };
+    let barcode = barcode
+        .map(backoffice::domain::variant::Barcode::try_from)
+        .transpose()
+        .map_err(|_| Error::TypeNotFound {
+            type_name: String::from("Barcode"),
+        })?;
+
+    Ok(backoffice::domain::variant::Variant {
+        id: backoffice::domain::variant::VariantId::from(id),
+        product_id: backoffice::domain::product::ProductId::from(product_id),
+        sku,
+        options: options.0,
+        price,
+        barcode,
+        created_at: backoffice::domain::product::ProductTimeStamp::from(created_at),
+        updated_at: backoffice::domain::product::ProductTimeStamp::from(updated_at),
+    })
+}
+
+impl FromRow<'_, PgRow> for backoffice::domain::variant::Variant {
+    fn from_row(row: &'_ PgRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast Variant from PgRow").entered();
+
+        variant_from_columns(
+            row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(7).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(8).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
+
+impl FromRow<'_, SqliteRow> for backoffice::domain::variant::Variant {
+    fn from_row(row: &'_ SqliteRow) -> Result<Self, Error> {
+        let _e = tracing::debug_span!("Cast Variant from SqliteRow").entered();
+
+        let id: uuid::fmt::Hyphenated = row.try_get(0).inspect_err(|err| tracing::error!("{err}"))?;
+        let product_id: uuid::fmt::Hyphenated = row.try_get(1).inspect_err(|err| tracing::error!("{err}"))?;
+
+        variant_from_columns(
+            id.into_uuid(),
+            product_id.into_uuid(),
+            row.try_get(2).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(3).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(4).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(5).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(6).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(7).inspect_err(|err| tracing::error!("{err}"))?,
+            row.try_get(8).inspect_err(|err| tracing::error!("{err}"))?,
+        )
+    }
+}
@@ -2,11 +2,13 @@ use std::sync::Arc;
 
 use async_graphql::http::{playground_source, GraphQLPlaygroundConfig};
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
-use axum::extract::State;
+use axum::extract::{OriginalUri, State};
+use axum::http::header;
 use axum::response::{Html, IntoResponse};
 use tracing::Instrument;
 
 use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
 
 #[axum::debug_handler]
 pub async fn handler(
@@ -16,18 +18,115 @@ pub async fn handler(
 ) -> GraphQLResponse {
     let mut graphql_request = req.into_inner();
     let graphql_request_query = graphql_request.query.clone();
+    let caller = identity_claims.sub.clone();
     graphql_request.data.insert(identity_claims);
 
-    schema
-        .execute(graphql_request)
-        .instrument(tracing::debug_span!("Executing schema", query = graphql_request_query))
-        .await
-        .into()
+    libs::database::with_caller(
+        caller,
+        schema
+            .execute(graphql_request)
+            .instrument(tracing::debug_span!("Executing schema", query = graphql_request_query)),
+    )
+    .await
+    .into()
 }
 
 #[axum::debug_handler]
-pub async fn playground() -> impl IntoResponse {
-    let config = GraphQLPlaygroundConfig::new("/ecommerce/backoffice/graphql").with_header("authorization", "Bearer -");
+pub async fn playground(OriginalUri(uri): OriginalUri) -> impl IntoResponse {
+    // the playground is mounted on the same path as the endpoint, wherever the router is nested
+    let config = GraphQLPlaygroundConfig::new(uri.path()).with_header("authorization", "Bearer -");
 
     Html(playground_source(config))
 }
+
+#[axum::debug_handler]
+pub async fn sdl(State(schema): State<Arc<backoffice::infrastructure::graphql::SchemaRoot>>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], schema.sdl())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use crate::contexts::ecommerce::settings::{GraphQLSettings, Settings};
+    use crate::libs;
+
+    use super::*;
+
+    async fn router(graphql: GraphQLSettings) -> Router {
+        let fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        let settings = Settings {
+            graphql,
+            database_url: String::new(),
+            database_retry_policy: libs::postgres::retry::RetryPolicy::default(),
+            database_pool: libs::postgres::PoolSettings::default(),
+            database_replica: None,
+            currency_rounding: common::domain::RoundingMode::default(),
+            product_schedule_interval: backoffice::infrastructure::DEFAULT_PRODUCT_SCHEDULE_INTERVAL,
+        };
+
+        Router::new().nest(
+            "/backoffice",
+            backoffice::infrastructure::HttpController::build(fixture.services, &settings),
+        )
+    }
+
+    async fn get(router: Router, uri: &str) -> (StatusCode, String) {
+        let response = router
+            .oneshot(Request::builder().uri(uri).method("GET").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_playground_disabled_when_get_then_return_405() {
+        let (status, _) = get(router(GraphQLSettings::default()).await, "/backoffice/graphql").await;
+
+        assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_playground_enabled_when_get_then_return_playground_pointing_to_request_path() {
+        let settings = GraphQLSettings {
+            playground_enabled: true,
+            ..GraphQLSettings::default()
+        };
+
+        let (status, body) = get(router(settings).await, "/backoffice/graphql").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("\"/backoffice/graphql\""));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_introspection_enabled_when_get_sdl_then_return_schema() {
+        let settings = GraphQLSettings {
+            introspection_enabled: true,
+            ..GraphQLSettings::default()
+        };
+
+        let (status, body) = get(router(settings).await, "/backoffice/graphql/schema.graphql").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, include_str!("schema.graphql"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_introspection_disabled_when_get_sdl_then_return_404() {
+        let (status, _) = get(
+            router(GraphQLSettings::default()).await,
+            "/backoffice/graphql/schema.graphql",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,162 @@
+use async_graphql::InputObject;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+#[derive(InputObject)]
+pub struct ProductInput {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub price: MoneyInput,
+}
+
+#[derive(InputObject)]
+pub struct ProductPriceInput {
+    pub id: uuid::Uuid,
+    pub product_id: uuid::Uuid,
+    pub price: MoneyInput,
+    /// Start of validity, inclusive, null for no start.
+    pub valid_from: Option<chrono::DateTime<chrono::offset::Utc>>,
+    /// End of validity, exclusive, null for no end.
+    pub valid_until: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+#[derive(InputObject)]
+pub struct CategoryInput {
+    pub id: uuid::Uuid,
+    /// Category to create it below, null for a root category.
+    pub parent_id: Option<uuid::Uuid>,
+    pub name: String,
+}
+
+#[derive(InputObject)]
+pub struct MoveCategoryInput {
+    pub id: uuid::Uuid,
+    /// New parent, null to make it a root category.
+    pub parent_id: Option<uuid::Uuid>,
+    /// Place among the new siblings, null or past the last one to place it last.
+    pub position: Option<u32>,
+}
+
+#[derive(InputObject)]
+pub struct ProductOptionsInput {
+    pub product_id: uuid::Uuid,
+    /// Every option of the product in display order, replacing the current ones.
+    pub options: Vec<VariantOptionInput>,
+}
+
+#[derive(InputObject)]
+pub struct VariantOptionInput {
+    pub name: String,
+    /// Values a variant may pick, in display order.
+    pub values: Vec<String>,
+}
+
+#[derive(InputObject)]
+pub struct VariantInput {
+    pub id: uuid::Uuid,
+    pub product_id: uuid::Uuid,
+    pub sku: String,
+    /// One value for each option of the product.
+    pub options: Vec<SelectedOptionInput>,
+    /// Overrides the product price, null to sell at the product price.
+    pub price: Option<MoneyInput>,
+    /// EAN-8, UPC-A or EAN-13 with a valid check digit.
+    pub barcode: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct SelectedOptionInput {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(InputObject)]
+pub struct MoneyInput {
+    /// Decimal amount, with at most as many fraction digits as the currency minor unit.
+    pub amount: String,
+    pub currency: backoffice::infrastructure::graphql::Currency,
+}
+
+impl From<MoneyInput> for common::application::inputs::MoneyInput {
+    fn from(value: MoneyInput) -> Self {
+        Self {
+            amount: value.amount,
+            currency: value.currency.0.to_primitive(),
+        }
+    }
+}
+
+impl From<ProductInput> for backoffice::application::usecases::SaveProductInput {
+    fn from(value: ProductInput) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name,
+            price: value.price.into(),
+        }
+    }
+}
+
+impl From<ProductPriceInput> for backoffice::application::usecases::SaveProductPriceInput {
+    fn from(value: ProductPriceInput) -> Self {
+        Self {
+            id: value.id.to_string(),
+            product_id: value.product_id.to_string(),
+            price: value.price.into(),
+            valid_from: value.valid_from,
+            valid_until: value.valid_until,
+        }
+    }
+}
+
+impl From<CategoryInput> for backoffice::application::usecases::SaveCategoryInput {
+    fn from(value: CategoryInput) -> Self {
+        Self {
+            id: value.id.to_string(),
+            parent_id: value.parent_id.map(|parent_id| parent_id.to_string()),
+            name: value.name,
+        }
+    }
+}
+
+impl From<MoveCategoryInput> for backoffice::application::usecases::MoveCategoryInput {
+    fn from(value: MoveCategoryInput) -> Self {
+        Self {
+            id: value.id.to_string(),
+            parent_id: value.parent_id.map(|parent_id| parent_id.to_string()),
+            position: value.position,
+        }
+    }
+}
+
+impl From<ProductOptionsInput> for backoffice::application::usecases::SetVariantOptionsInput {
+    fn from(value: ProductOptionsInput) -> Self {
+        Self {
+            product_id: value.product_id.to_string(),
+            options: value
+                .options
+                .into_iter()
+                .map(|option| backoffice::application::usecases::VariantOptionInput {
+                    name: option.name,
+                    values: option.values,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<VariantInput> for backoffice::application::usecases::SaveVariantInput {
+    fn from(value: VariantInput) -> Self {
+        Self {
+            id: value.id.to_string(),
+            product_id: value.product_id.to_string(),
+            sku: value.sku,
+            options: value
+                .options
+                .into_iter()
+                .map(|option| (option.name, option.value))
+                .collect(),
+            price: value.price.map(Into::into),
+            barcode: value.barcode,
+        }
+    }
+}
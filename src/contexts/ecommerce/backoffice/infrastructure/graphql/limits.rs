@@ -0,0 +1,163 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory, NextRequest, NextValidation};
+use async_graphql::{ErrorExtensionValues, Response, ServerError, ValidationResult};
+use axum::async_trait;
+
+/// Enforces the depth and complexity limits from the figures validation computes, instead of the
+/// schema's own `limit_depth` and `limit_complexity`, so that the errors carry a code and the limit.
+#[derive(Clone, Copy)]
+pub struct QueryLimits {
+    depth_limit: usize,
+    complexity_limit: usize,
+    timeout: Duration,
+}
+
+impl QueryLimits {
+    pub fn new(depth_limit: usize, complexity_limit: usize, timeout: Duration) -> Self {
+        Self {
+            depth_limit,
+            complexity_limit,
+            timeout,
+        }
+    }
+
+    fn error(message: impl Into<String>, code: &str, limit: u64) -> ServerError {
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set("code", code);
+        extensions.set("limit", limit);
+
+        let mut error = ServerError::new(message, None);
+        error.extensions = Some(extensions);
+        error
+    }
+}
+
+impl ExtensionFactory for QueryLimits {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(*self)
+    }
+}
+
+#[async_trait]
+impl Extension for QueryLimits {
+    async fn request(&self, ctx: &ExtensionContext<'_>, next: NextRequest<'_>) -> Response {
+        let Ok(response) = tokio::time::timeout(self.timeout, next.run(ctx)).await else {
+            tracing::error!("graphql request exceeded {:?}", self.timeout);
+
+            return Response::from_errors(vec![Self::error(
+                "Query execution timed out.",
+                "TIMEOUT",
+                self.timeout.as_millis() as u64,
+            )]);
+        };
+
+        response
+    }
+
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+
+        if result.complexity > self.complexity_limit {
+            tracing::error!(
+                "graphql query complexity {} exceeds {}",
+                result.complexity,
+                self.complexity_limit
+            );
+
+            return Err(vec![Self::error(
+                "Query is too complex.",
+                "QUERY_TOO_COMPLEX",
+                self.complexity_limit as u64,
+            )]);
+        }
+
+        if result.depth > self.depth_limit {
+            tracing::error!("graphql query depth {} exceeds {}", result.depth, self.depth_limit);
+
+            return Err(vec![Self::error(
+                "Query is nested too deep.",
+                "QUERY_TOO_DEEP",
+                self.depth_limit as u64,
+            )]);
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+    use super::*;
+
+    struct SlowQuery;
+
+    #[Object]
+    impl SlowQuery {
+        async fn slow(&self) -> bool {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            true
+        }
+
+        async fn nested(&self) -> SlowQuery {
+            SlowQuery
+        }
+    }
+
+    async fn execute_with_limits(depth_limit: usize, complexity_limit: usize, query: &str) -> serde_json::Value {
+        let schema = Schema::build(SlowQuery, EmptyMutation, EmptySubscription)
+            .extension(QueryLimits::new(depth_limit, complexity_limit, Duration::from_secs(5)))
+            .finish();
+
+        let response = schema.execute(query).await;
+
+        serde_json::to_value(response.errors[0].extensions.clone().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn given_query_deeper_than_limit_when_execute_then_return_too_deep_error() {
+        let extensions = execute_with_limits(2, 100, "{ nested { nested { nested { slow } } } }").await;
+
+        assert_eq!(extensions["code"], "QUERY_TOO_DEEP");
+        assert_eq!(extensions["limit"], 2);
+    }
+
+    #[tokio::test]
+    async fn given_query_more_complex_than_limit_when_execute_then_return_too_complex_error() {
+        let extensions = execute_with_limits(10, 2, "{ a: slow b: slow c: slow }").await;
+
+        assert_eq!(extensions["code"], "QUERY_TOO_COMPLEX");
+        assert_eq!(extensions["limit"], 2);
+    }
+
+    #[tokio::test]
+    async fn given_query_slower_than_timeout_when_execute_then_return_timeout_error() {
+        let schema = Schema::build(SlowQuery, EmptyMutation, EmptySubscription)
+            .extension(QueryLimits::new(10, 100, Duration::from_millis(10)))
+            .finish();
+
+        let response = schema.execute("{ slow }").await;
+
+        let extensions = serde_json::to_value(response.errors[0].extensions.clone().unwrap()).unwrap();
+        assert_eq!(extensions["code"], "TIMEOUT");
+        assert_eq!(extensions["limit"], 10);
+    }
+
+    #[tokio::test]
+    async fn given_query_faster_than_timeout_when_execute_then_return_data() {
+        let schema = Schema::build(SlowQuery, EmptyMutation, EmptySubscription)
+            .extension(QueryLimits::new(10, 100, Duration::from_secs(5)))
+            .finish();
+
+        let response = schema.execute("{ slow }").await;
+
+        assert!(response.errors.is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest};
+use async_graphql::{Pos, Request, ServerResult};
+use axum::async_trait;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct ProductLoader {
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+}
+
+impl ProductLoader {
+    pub fn new(product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>) -> Self {
+        Self { product_repository }
+    }
+}
+
+#[async_trait]
+impl Loader<backoffice::domain::product::ProductId> for ProductLoader {
+    type Value = backoffice::infrastructure::graphql::Product;
+    type Error = common::domain::Error;
+
+    async fn load(
+        &self,
+        keys: &[backoffice::domain::product::ProductId],
+    ) -> Result<HashMap<backoffice::domain::product::ProductId, Self::Value>, Self::Error> {
+        let products = self
+            .product_repository
+            .get_many_by_ids(keys)
+            .instrument(tracing::debug_span!("Load products", count = keys.len()))
+            .await?;
+
+        Ok(products
+            .into_iter()
+            .map(|product| (product.id, backoffice::infrastructure::graphql::Product::from(product)))
+            .collect())
+    }
+}
+
+/// Registers a fresh set of data loaders on every request, so batched lookups never leak between callers.
+pub struct DataLoaders;
+
+impl ExtensionFactory for DataLoaders {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(DataLoaders)
+    }
+}
+
+#[async_trait]
+impl Extension for DataLoaders {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let services = ctx
+            .data::<common::infrastructure::DependencyContainer>()
+            .map_err(|err| err.into_server_error(Pos::default()))?;
+
+        // batches load on spawned tasks, which must keep the caller to honour read-your-writes
+        let caller = libs::database::current_caller();
+        let product_loader = DataLoader::new(ProductLoader::new(services.product_repository.clone()), move |load| {
+            tokio::spawn(libs::database::with_caller(caller.clone(), load))
+        });
+
+        next.run(ctx, request.data(product_loader)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    struct CountingProductRepository {
+        inner: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl backoffice::domain::product::ProductRepository for CountingProductRepository {
+        type Error = common::domain::Error;
+
+        async fn get(
+            &self,
+            status: Option<backoffice::domain::product::ProductStatus>,
+        ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+            self.inner.get(status).await
+        }
+
+        async fn get_by_id(
+            &self,
+            id: &backoffice::domain::product::ProductId,
+        ) -> Result<Option<backoffice::domain::product::Product>, Self::Error> {
+            self.inner.get_by_id(id).await
+        }
+
+        async fn get_many_by_ids(
+            &self,
+            ids: &[backoffice::domain::product::ProductId],
+        ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.inner.get_many_by_ids(ids).await
+        }
+
+        async fn save(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+            self.inner.save(product).await
+        }
+
+        async fn update(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+            self.inner.update(product).await
+        }
+
+        async fn update_status(
+            &self,
+            product: &backoffice::domain::product::Product,
+            from: backoffice::domain::product::ProductStatus,
+        ) -> Result<(), Self::Error> {
+            self.inner.update_status(product, from).await
+        }
+
+        async fn get_scheduled(
+            &self,
+            at: backoffice::domain::product::ProductTimeStamp,
+        ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+            self.inner.get_scheduled(at).await
+        }
+    }
+
+    #[tokio::test]
+    async fn given_concurrent_loads_when_load_one_then_batch_into_single_query() {
+        let repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new());
+
+        let p1 = backoffice::domain::product::fixture::ProductBuilder::default();
+        let p2 = backoffice::domain::product::fixture::ProductBuilder::default();
+        tokio::join!(p1.save(&repository), p2.save(&repository));
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let loader = DataLoader::new(
+            ProductLoader::new(Arc::new(CountingProductRepository {
+                inner: repository,
+                calls: calls.clone(),
+            })),
+            tokio::spawn,
+        );
+
+        let missing = backoffice::domain::product::ProductId::default();
+        let (r1, r2, r3) = tokio::join!(loader.load_one(p1.id), loader.load_one(p2.id), loader.load_one(missing));
+
+        assert_eq!(r1.unwrap().unwrap().id, p1.id.to_uuid());
+        assert_eq!(r2.unwrap().unwrap().id, p2.id.to_uuid());
+        assert!(r3.unwrap().is_none());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,7 +1,17 @@
 pub use handlers::*;
+pub use inputs::*;
+pub use limits::*;
+pub use loaders::*;
 pub use objects::*;
+pub use persisted_queries::*;
 pub use query::*;
+pub use schema::*;
 
 mod handlers;
+mod inputs;
+mod limits;
+mod loaders;
 mod objects;
+mod persisted_queries;
 mod query;
+mod schema;
@@ -1,26 +1,548 @@
-use async_graphql::SimpleObject;
+use std::borrow::Cow;
 
-use crate::contexts::ecommerce::backoffice;
+use async_graphql::parser::types::Field;
+use async_graphql::registry::{Deprecation, MetaEnumValue, MetaType, MetaTypeId, Registry};
+use async_graphql::{
+    ComplexObject, Context, ContextSelectionSet, Enum, ErrorExtensions, InputType, InputValueError, InputValueResult,
+    Name, OutputType, Positioned, ServerResult, SimpleObject, Value,
+};
+use axum::async_trait;
+use tracing::Instrument;
 
-#[derive(SimpleObject)]
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
 pub struct Product {
-    pub id: String,
+    pub id: uuid::Uuid,
     pub name: String,
-    pub price: i32,
-    pub currency: String,
-    pub created_at: String,
-    pub updated_at: String,
+    pub price: Money,
+    pub status: ProductStatus,
+    /// A draft gets published once this time is reached.
+    pub publish_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+    /// A published product gets archived once this time is reached.
+    pub unpublish_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub created_at: chrono::DateTime<chrono::offset::Utc>,
+    pub updated_at: chrono::DateTime<chrono::offset::Utc>,
 }
 
 impl From<backoffice::domain::product::Product> for Product {
     fn from(value: backoffice::domain::product::Product) -> Self {
         Self {
-            id: value.id.to_primitive(),
+            id: value.id.to_uuid(),
             name: value.name.to_primitive(),
-            price: value.price.to_primitive(),
-            currency: value.currency.to_primitive(),
-            created_at: value.created_at.to_primitive(),
-            updated_at: value.updated_at.to_primitive(),
+            price: Money::from(value.price),
+            status: value.status.into(),
+            publish_at: value.publish_at.map(|publish_at| publish_at.to_datetime()),
+            unpublish_at: value.unpublish_at.map(|unpublish_at| unpublish_at.to_datetime()),
+            created_at: value.created_at.to_datetime(),
+            updated_at: value.updated_at.to_datetime(),
         }
     }
 }
+
+/// Lifecycle stage of a product. Only published products are live.
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+pub enum ProductStatus {
+    Draft,
+    Published,
+    Archived,
+}
+
+impl From<backoffice::domain::product::ProductStatus> for ProductStatus {
+    fn from(value: backoffice::domain::product::ProductStatus) -> Self {
+        match value {
+            backoffice::domain::product::ProductStatus::Draft => Self::Draft,
+            backoffice::domain::product::ProductStatus::Published => Self::Published,
+            backoffice::domain::product::ProductStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+impl From<ProductStatus> for backoffice::domain::product::ProductStatus {
+    fn from(value: ProductStatus) -> Self {
+        match value {
+            ProductStatus::Draft => Self::Draft,
+            ProductStatus::Published => Self::Published,
+            ProductStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+#[ComplexObject]
+impl Product {
+    /// Changes of the price, oldest first, within `[from, until)` when given.
+    async fn price_history<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        from: Option<chrono::DateTime<chrono::offset::Utc>>,
+        until: Option<chrono::DateTime<chrono::offset::Utc>>,
+    ) -> async_graphql::Result<Vec<ProductPriceChange>> {
+        let services = backoffice::infrastructure::graphql::authorize(
+            ctx,
+            common::domain::Permissions::EcommerceBackofficeProductPriceRead,
+        )?;
+
+        let changes = services
+            .get_product_price_history_usecase
+            .exec(backoffice::application::usecases::GetProductPriceHistoryInput {
+                product_id: self.id.to_string(),
+                from,
+                until,
+            })
+            .instrument(tracing::debug_span!(
+                "Execute use case",
+                name = "GetProductPriceHistory"
+            ))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(changes.into_iter().map(ProductPriceChange::from).collect())
+    }
+
+    /// Options the product varies along, in display order.
+    async fn options<'ctx>(&self, ctx: &Context<'ctx>) -> async_graphql::Result<Vec<VariantOption>> {
+        let services = backoffice::infrastructure::graphql::authorize(
+            ctx,
+            common::domain::Permissions::EcommerceBackofficeVariantRead,
+        )?;
+
+        let options = services
+            .get_variant_options_usecase
+            .exec(backoffice::application::usecases::GetVariantOptionsInput {
+                product_id: self.id.to_string(),
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetVariantOptions"))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(options.into_iter().map(VariantOption::from).collect())
+    }
+
+    /// Variants of the product, oldest first.
+    async fn variants<'ctx>(&self, ctx: &Context<'ctx>) -> async_graphql::Result<Vec<Variant>> {
+        let services = backoffice::infrastructure::graphql::authorize(
+            ctx,
+            common::domain::Permissions::EcommerceBackofficeVariantRead,
+        )?;
+
+        let variants = services
+            .get_variants_usecase
+            .exec(backoffice::application::usecases::GetVariantsInput {
+                product_id: self.id.to_string(),
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetVariants"))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(variants.into_iter().map(Variant::from).collect())
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct Money {
+    /// Decimal amount with as many fraction digits as the currency minor unit, e.g. `3600.00` or `3600` for JPY.
+    pub amount: String,
+    pub currency: Currency,
+}
+
+impl From<common::domain::Money> for Money {
+    fn from(value: common::domain::Money) -> Self {
+        Self {
+            amount: value.to_decimal_string(),
+            currency: Currency(value.currency()),
+        }
+    }
+}
+
+/// GraphQL enum of the ISO 4217 alphabetic codes, one value per currency the domain knows. The list
+/// lives in the domain table, so the enum is registered by hand rather than derived.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Currency(pub common::domain::Currency);
+
+impl Currency {
+    fn meta_type(_registry: &mut Registry) -> MetaType {
+        MetaType::Enum {
+            name: String::from("Currency"),
+            description: Some(String::from("ISO 4217 alphabetic code.")),
+            enum_values: common::domain::Currency::all()
+                .iter()
+                .map(|currency| {
+                    let value = MetaEnumValue {
+                        name: currency.code().to_string(),
+                        description: None,
+                        deprecation: Deprecation::NoDeprecated,
+                        visible: None,
+                        inaccessible: false,
+                        tags: vec![],
+                    };
+                    (currency.code().to_string(), value)
+                })
+                .collect(),
+            visible: None,
+            inaccessible: false,
+            tags: vec![],
+            rust_typename: Some(std::any::type_name::<Self>()),
+        }
+    }
+}
+
+impl InputType for Currency {
+    type RawValueType = Self;
+
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("Currency")
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_input_type::<Self, _>(MetaTypeId::Enum, Self::meta_type)
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        // literals arrive as enum values, variables as strings
+        let code = match &value {
+            Some(Value::Enum(name)) => name.as_str(),
+            Some(Value::String(code)) => code.as_str(),
+            _ => return Err(InputValueError::expected_type(value.unwrap_or_default())),
+        };
+
+        common::domain::Currency::try_from(code)
+            .map(Self)
+            .map_err(|_| InputValueError::custom(format_args!(r#"Enumeration type does not contain value "{code}"."#)))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Enum(Name::new(self.0.code()))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl OutputType for Currency {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("Currency")
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Enum, Self::meta_type)
+    }
+
+    async fn resolve(&self, _ctx: &ContextSelectionSet<'_>, _field: &Positioned<Field>) -> ServerResult<Value> {
+        Ok(Value::Enum(Name::new(self.0.code())))
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ProductPrice {
+    pub id: uuid::Uuid,
+    pub product_id: uuid::Uuid,
+    pub price: Money,
+    /// Start of validity, inclusive, null for no start.
+    pub valid_from: Option<chrono::DateTime<chrono::offset::Utc>>,
+    /// End of validity, exclusive, null for no end.
+    pub valid_until: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub created_at: chrono::DateTime<chrono::offset::Utc>,
+}
+
+impl From<backoffice::domain::product_price::ProductPrice> for ProductPrice {
+    fn from(value: backoffice::domain::product_price::ProductPrice) -> Self {
+        Self {
+            id: value.id.to_uuid(),
+            product_id: value.product_id.to_uuid(),
+            price: Money::from(value.price),
+            valid_from: value.valid_from.map(|valid_from| valid_from.to_datetime()),
+            valid_until: value.valid_until.map(|valid_until| valid_until.to_datetime()),
+            created_at: value.created_at.to_datetime(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ProductPriceChange {
+    pub product_id: uuid::Uuid,
+    /// Price before the change, null for the price the product was created with.
+    pub old_price: Option<Money>,
+    pub new_price: Money,
+    /// Caller the change was made on behalf of, null when made outside of a request.
+    pub changed_by: Option<String>,
+    pub changed_at: chrono::DateTime<chrono::offset::Utc>,
+}
+
+impl From<backoffice::domain::product_price_history::ProductPriceChange> for ProductPriceChange {
+    fn from(value: backoffice::domain::product_price_history::ProductPriceChange) -> Self {
+        Self {
+            product_id: value.product_id.to_uuid(),
+            old_price: value.old_price.map(Money::from),
+            new_price: Money::from(value.new_price),
+            changed_by: value.changed_by,
+            changed_at: value.changed_at.to_datetime(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Category {
+    pub id: uuid::Uuid,
+    /// Parent category, null for a root category.
+    pub parent_id: Option<uuid::Uuid>,
+    pub name: String,
+    /// Place among the children of the same parent, counted from 0.
+    pub position: i32,
+    /// Number of ancestors, 0 for a root category.
+    pub depth: usize,
+    pub created_at: chrono::DateTime<chrono::offset::Utc>,
+    pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+}
+
+impl From<backoffice::domain::category::Category> for Category {
+    fn from(value: backoffice::domain::category::Category) -> Self {
+        Self {
+            id: value.id.to_uuid(),
+            parent_id: value.parent_id.map(|parent_id| parent_id.to_uuid()),
+            name: value.name.to_primitive(),
+            position: value.position,
+            depth: value.path.depth(),
+            created_at: value.created_at.to_datetime(),
+            updated_at: value.updated_at.to_datetime(),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Category {
+    /// First page of the products assigned to the category or, unless `include_subcategories` is false,
+    /// to any category below it, each once and oldest first.
+    #[graphql(complexity = "backoffice::domain::product::PRODUCT_PAGE_SIZE as usize * child_complexity")]
+    async fn products<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        #[graphql(default = true)] include_subcategories: bool,
+    ) -> async_graphql::Result<Vec<Product>> {
+        let services = backoffice::infrastructure::graphql::authorize(
+            ctx,
+            common::domain::Permissions::EcommerceBackofficeCategoryRead,
+        )?;
+
+        let products = services
+            .get_category_products_usecase
+            .exec(backoffice::application::usecases::GetCategoryProductsInput {
+                category_id: self.id.to_string(),
+                include_subcategories,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetCategoryProducts"))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(products.into_iter().map(Product::from).collect())
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct VariantOption {
+    pub name: String,
+    /// Values a variant may pick, in display order.
+    pub values: Vec<String>,
+}
+
+impl From<backoffice::domain::variant::VariantOption> for VariantOption {
+    fn from(value: backoffice::domain::variant::VariantOption) -> Self {
+        Self {
+            name: value.name,
+            values: value.values,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct SelectedOption {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct Variant {
+    pub id: uuid::Uuid,
+    pub product_id: uuid::Uuid,
+    /// Unique across all products.
+    pub sku: String,
+    /// Value picked for each option of the product, by option name.
+    pub options: Vec<SelectedOption>,
+    /// Overrides the product price, null to sell at the product price.
+    pub price: Option<Money>,
+    /// EAN-8, UPC-A or EAN-13.
+    pub barcode: Option<String>,
+    pub created_at: chrono::DateTime<chrono::offset::Utc>,
+    pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+}
+
+impl From<backoffice::domain::variant::Variant> for Variant {
+    fn from(value: backoffice::domain::variant::Variant) -> Self {
+        Self {
+            id: value.id.to_uuid(),
+            product_id: value.product_id.to_uuid(),
+            sku: value.sku.to_primitive(),
+            options: value
+                .options
+                .into_iter()
+                .map(|(name, value)| SelectedOption { name, value })
+                .collect(),
+            price: value.price.map(Money::from),
+            barcode: value.barcode.map(|barcode| barcode.to_primitive()),
+            created_at: value.created_at.to_datetime(),
+            updated_at: value.updated_at.to_datetime(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct SetProductOptionsPayload {
+    pub options: Option<Vec<VariantOption>>,
+    pub user_errors: Vec<UserError>,
+}
+
+impl MutationPayload for SetProductOptionsPayload {
+    type Value = Vec<VariantOption>;
+
+    fn new(options: Option<Self::Value>, user_errors: Vec<UserError>) -> Self {
+        Self { options, user_errors }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct SaveVariantPayload {
+    pub variant: Option<Variant>,
+    pub user_errors: Vec<UserError>,
+}
+
+impl MutationPayload for SaveVariantPayload {
+    type Value = Variant;
+
+    fn new(variant: Option<Self::Value>, user_errors: Vec<UserError>) -> Self {
+        Self { variant, user_errors }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct SaveCategoryPayload {
+    pub category: Option<Category>,
+    pub user_errors: Vec<UserError>,
+}
+
+impl MutationPayload for SaveCategoryPayload {
+    type Value = Category;
+
+    fn new(category: Option<Self::Value>, user_errors: Vec<UserError>) -> Self {
+        Self { category, user_errors }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct MoveCategoryPayload {
+    pub category: Option<Category>,
+    pub user_errors: Vec<UserError>,
+}
+
+impl MutationPayload for MoveCategoryPayload {
+    type Value = Category;
+
+    fn new(category: Option<Self::Value>, user_errors: Vec<UserError>) -> Self {
+        Self { category, user_errors }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct SaveProductPricePayload {
+    pub product_price: Option<ProductPrice>,
+    pub user_errors: Vec<UserError>,
+}
+
+impl MutationPayload for SaveProductPricePayload {
+    type Value = ProductPrice;
+
+    fn new(product_price: Option<Self::Value>, user_errors: Vec<UserError>) -> Self {
+        Self {
+            product_price,
+            user_errors,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct SaveProductPayload {
+    pub product: Option<Product>,
+    pub user_errors: Vec<UserError>,
+}
+
+impl MutationPayload for SaveProductPayload {
+    type Value = Product;
+
+    fn new(product: Option<Self::Value>, user_errors: Vec<UserError>) -> Self {
+        Self { product, user_errors }
+    }
+}
+
+/// Payload of a mutation, holding what it wrote or the user errors that kept it from writing.
+pub trait MutationPayload: Sized {
+    type Value;
+
+    fn new(value: Option<Self::Value>, user_errors: Vec<UserError>) -> Self;
+
+    /// Turns the use case outcome into the payload, user errors rejecting `input` come back as such and any
+    /// other error as a GraphQL error.
+    fn from_result(result: Result<Self::Value, common::domain::Error>) -> async_graphql::Result<Self> {
+        match result {
+            Ok(value) => Ok(Self::new(Some(value), vec![])),
+            Err(err) => {
+                let user_errors = UserError::from_domain("input", &err);
+                if user_errors.is_empty() {
+                    return Err(err.extend());
+                }
+
+                Ok(Self::new(None, user_errors))
+            }
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct UserError {
+    /// Path to the offending input field, e.g. `["input", "price"]`.
+    pub field: Vec<String>,
+    pub code: String,
+    pub message: String,
+}
+
+impl UserError {
+    /// Expands a domain error into the input fields it refers to, empty when it is not a user error.
+    pub fn from_domain(input: &str, error: &common::domain::Error) -> Vec<Self> {
+        let field = match error {
+            common::domain::Error::Validation(errors) => {
+                return errors
+                    .iter()
+                    .flat_map(|error| Self::from_domain(input, error))
+                    .collect();
+            }
+            common::domain::Error::ProductAlreadyExists
+            | common::domain::Error::ProductPriceAlreadyExists
+            | common::domain::Error::CategoryAlreadyExists
+            | common::domain::Error::VariantAlreadyExists => "id",
+            _ => match error.field() {
+                Some(field) => field,
+                None => return vec![],
+            },
+        };
+
+        vec![Self {
+            field: std::iter::once(String::from(input))
+                .chain(common::infrastructure::graphql_field_path(field))
+                .collect(),
+            code: error.code().to_string(),
+            message: error.to_string(),
+        }]
+    }
+}
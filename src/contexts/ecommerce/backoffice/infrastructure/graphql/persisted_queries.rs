@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest};
+use async_graphql::{ErrorExtensionValues, Request, ServerError, ServerResult};
+use axum::async_trait;
+use lru::LruCache;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+// message expected by Apollo clients to retry the request with the full query text
+const NOT_FOUND_MESSAGE: &str = "PersistedQueryNotFound";
+const SUPPORTED_VERSION: i32 = 1;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedQuery {
+    version: i32,
+    sha256_hash: String,
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    operations: Vec<ManifestOperation>,
+}
+
+#[derive(Deserialize)]
+struct ManifestOperation {
+    id: String,
+    body: String,
+}
+
+/// Automatic persisted queries with an optional strict allow-list.
+///
+/// Queries listed in the manifest are always resolvable by hash. In strict mode any query
+/// outside the manifest is rejected; otherwise unknown queries are registered in an LRU cache.
+#[derive(Clone)]
+pub struct PersistedQueries {
+    cache: Arc<Mutex<LruCache<String, String>>>,
+    manifest: Arc<HashMap<String, String>>,
+    strict: bool,
+}
+
+impl PersistedQueries {
+    pub fn new(cache_size: usize, manifest: HashMap<String, String>, strict: bool) -> Self {
+        Self {
+            cache: Arc::new(Mutex::new(LruCache::new(cache_size.max(1)))),
+            manifest: Arc::new(manifest),
+            strict,
+        }
+    }
+
+    /// Reads an Apollo persisted query manifest, keyed by the SHA-256 hash of every operation body.
+    pub fn load_manifest(path: &Path) -> Result<HashMap<String, String>, String> {
+        let _e = tracing::debug_span!("Load persisted query manifest", path = %path.display()).entered();
+
+        let content = std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let manifest: Manifest = serde_json::from_str(&content).map_err(|err| format!("{}: {err}", path.display()))?;
+
+        manifest
+            .operations
+            .into_iter()
+            .map(|operation| {
+                if Self::hash(&operation.body) != operation.id {
+                    return Err(format!(
+                        "{}: hash mismatch for operation {}",
+                        path.display(),
+                        operation.id
+                    ));
+                }
+
+                Ok((operation.id, operation.body))
+            })
+            .collect()
+    }
+
+    fn hash(query: &str) -> String {
+        format!("{:x}", Sha256::digest(query.as_bytes()))
+    }
+
+    fn lookup(&self, hash: &str) -> Option<String> {
+        if let Some(query) = self.manifest.get(hash) {
+            return Some(query.clone());
+        }
+
+        if self.strict {
+            return None;
+        }
+
+        self.cache.lock().unwrap().get(hash).cloned()
+    }
+
+    fn error(message: impl Into<String>, code: &str) -> ServerError {
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set("code", code);
+
+        let mut error = ServerError::new(message, None);
+        error.extensions = Some(extensions);
+        error
+    }
+}
+
+impl ExtensionFactory for PersistedQueries {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(self.clone())
+    }
+}
+
+#[async_trait]
+impl Extension for PersistedQueries {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        mut request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let persisted_query = match request.extensions.remove("persistedQuery") {
+            Some(value) => Some(
+                value
+                    .into_json()
+                    .ok()
+                    .and_then(|value| serde_json::from_value::<PersistedQuery>(value).ok())
+                    .ok_or_else(|| Self::error("Invalid persisted query extension.", "INVALID_PERSISTED_QUERY"))?,
+            ),
+            None => None,
+        };
+
+        let Some(persisted_query) = persisted_query else {
+            if self.strict && !self.manifest.contains_key(&Self::hash(&request.query)) {
+                tracing::error!("query not present in persisted query manifest");
+                return Err(Self::error("Query is not allowed.", "PERSISTED_QUERY_NOT_ALLOWED"));
+            }
+
+            return next.run(ctx, request).await;
+        };
+
+        if persisted_query.version != SUPPORTED_VERSION {
+            return Err(Self::error(
+                format!("Unsupported persisted query version {}.", persisted_query.version),
+                "PERSISTED_QUERY_NOT_SUPPORTED",
+            ));
+        }
+
+        if request.query.is_empty() {
+            let Some(query) = self.lookup(&persisted_query.sha256_hash) else {
+                tracing::debug!("persisted query {} not found", persisted_query.sha256_hash);
+                return Err(Self::error(NOT_FOUND_MESSAGE, "PERSISTED_QUERY_NOT_FOUND"));
+            };
+
+            request.query = query;
+            return next.run(ctx, request).await;
+        }
+
+        if Self::hash(&request.query) != persisted_query.sha256_hash {
+            tracing::error!("persisted query hash does not match query");
+            return Err(Self::error(
+                "Provided sha256Hash does not match query.",
+                "INVALID_PERSISTED_QUERY_HASH",
+            ));
+        }
+
+        if !self.manifest.contains_key(&persisted_query.sha256_hash) {
+            if self.strict {
+                tracing::error!(
+                    "persisted query {} not present in manifest",
+                    persisted_query.sha256_hash
+                );
+                return Err(Self::error("Query is not allowed.", "PERSISTED_QUERY_NOT_ALLOWED"));
+            }
+
+            self.cache
+                .lock()
+                .unwrap()
+                .put(persisted_query.sha256_hash, request.query.clone());
+        }
+
+        next.run(ctx, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, Value};
+    use serde_json::json;
+
+    use super::*;
+
+    const QUERY: &str = "{ ping }";
+
+    struct PingQuery;
+
+    #[Object]
+    impl PingQuery {
+        async fn ping(&self) -> bool {
+            true
+        }
+    }
+
+    fn schema(extension: PersistedQueries) -> Schema<PingQuery, EmptyMutation, EmptySubscription> {
+        Schema::build(PingQuery, EmptyMutation, EmptySubscription)
+            .extension(extension)
+            .finish()
+    }
+
+    fn request(query: &str, hash: &str) -> Request {
+        let mut request = Request::new(query);
+        request.extensions.insert(
+            String::from("persistedQuery"),
+            Value::from_json(json!({ "version": 1, "sha256Hash": hash })).unwrap(),
+        );
+        request
+    }
+
+    fn error_code(response: &async_graphql::Response) -> serde_json::Value {
+        serde_json::to_value(response.errors[0].extensions.clone().unwrap()).unwrap()["code"].clone()
+    }
+
+    #[tokio::test]
+    async fn given_unknown_hash_when_execute_then_return_not_found_error() {
+        let schema = schema(PersistedQueries::new(10, HashMap::new(), false));
+
+        let response = schema.execute(request("", &PersistedQueries::hash(QUERY))).await;
+
+        assert_eq!(response.errors[0].message, NOT_FOUND_MESSAGE);
+        assert_eq!(error_code(&response), "PERSISTED_QUERY_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn given_registered_query_when_execute_by_hash_then_return_data() {
+        let schema = schema(PersistedQueries::new(10, HashMap::new(), false));
+        let hash = PersistedQueries::hash(QUERY);
+
+        let registration = schema.execute(request(QUERY, &hash)).await;
+        assert!(registration.errors.is_empty());
+
+        let response = schema.execute(request("", &hash)).await;
+
+        assert!(response.errors.is_empty());
+        assert_eq!(response.data.into_json().unwrap(), json!({ "ping": true }));
+    }
+
+    #[tokio::test]
+    async fn given_mismatched_hash_when_execute_then_return_invalid_hash_error() {
+        let schema = schema(PersistedQueries::new(10, HashMap::new(), false));
+
+        let response = schema
+            .execute(request(QUERY, &PersistedQueries::hash("{ other }")))
+            .await;
+
+        assert_eq!(error_code(&response), "INVALID_PERSISTED_QUERY_HASH");
+    }
+
+    #[tokio::test]
+    async fn given_strict_mode_when_execute_manifest_hash_then_return_data() {
+        let hash = PersistedQueries::hash(QUERY);
+        let schema = schema(PersistedQueries::new(
+            10,
+            HashMap::from([(hash.clone(), QUERY.to_string())]),
+            true,
+        ));
+
+        let response = schema.execute(request("", &hash)).await;
+
+        assert!(response.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn given_strict_mode_when_execute_query_outside_manifest_then_return_not_allowed_error() {
+        let schema = schema(PersistedQueries::new(10, HashMap::new(), true));
+
+        let plain = schema.execute(QUERY).await;
+        assert_eq!(error_code(&plain), "PERSISTED_QUERY_NOT_ALLOWED");
+
+        let registration = schema.execute(request(QUERY, &PersistedQueries::hash(QUERY))).await;
+        assert_eq!(error_code(&registration), "PERSISTED_QUERY_NOT_ALLOWED");
+    }
+
+    #[test]
+    fn given_manifest_file_when_load_then_index_operations_by_hash() {
+        let path = std::env::temp_dir().join(format!("persisted-queries-{}.json", uuid::Uuid::new_v4()));
+        let manifest = json!({
+            "format": "apollo-persisted-query-manifest",
+            "version": 1,
+            "operations": [{ "id": PersistedQueries::hash(QUERY), "name": "Ping", "type": "query", "body": QUERY }]
+        });
+        std::fs::write(&path, manifest.to_string()).unwrap();
+
+        let operations = PersistedQueries::load_manifest(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(operations.get(&PersistedQueries::hash(QUERY)).unwrap(), QUERY);
+    }
+
+    #[test]
+    fn given_manifest_with_wrong_hash_when_load_then_return_err() {
+        let path = std::env::temp_dir().join(format!("persisted-queries-{}.json", uuid::Uuid::new_v4()));
+        let manifest = json!({ "operations": [{ "id": "deadbeef", "body": QUERY }] });
+        std::fs::write(&path, manifest.to_string()).unwrap();
+
+        let operations = PersistedQueries::load_manifest(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(operations.is_err());
+    }
+}
@@ -1,36 +1,162 @@
-use async_graphql::{Context, EmptySubscription};
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{Context, EmptySubscription, ErrorExtensions};
 use async_graphql::{Object, Schema};
 use tracing::Instrument;
 
+use crate::contexts::ecommerce::backoffice::infrastructure::graphql::MutationPayload;
 use crate::contexts::ecommerce::common::application::usecase::UseCase;
 use crate::contexts::ecommerce::{backoffice, common};
 
 pub type SchemaRoot = Schema<QueryRoot, MutationRoot, EmptySubscription>;
 
+/// Services of the request, once its caller turned out to hold `permission`.
+pub(super) fn authorize<'ctx>(
+    ctx: &Context<'ctx>,
+    permission: common::domain::Permissions,
+) -> async_graphql::Result<&'ctx common::infrastructure::DependencyContainer> {
+    let claims = ctx.data::<common::infrastructure::IdentityClaims>()?;
+    claims.check_permission(permission).map_err(|err| err.extend())?;
+
+    ctx.data::<common::infrastructure::DependencyContainer>()
+}
+
 pub struct QueryRoot;
 
 #[Object]
 impl QueryRoot {
+    #[graphql(complexity = "backoffice::domain::product::PRODUCT_PAGE_SIZE as usize * child_complexity")]
     pub async fn products<'ctx>(
         &self,
         ctx: &Context<'ctx>,
+        #[graphql(desc = "Lifecycle status to narrow the listing to")] status: Option<
+            backoffice::infrastructure::graphql::ProductStatus,
+        >,
     ) -> async_graphql::Result<Vec<backoffice::infrastructure::graphql::Product>> {
-        let claims = ctx.data::<common::infrastructure::IdentityClaims>()?;
-        claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductRead)?;
-
-        let services = ctx.data::<common::infrastructure::DependencyContainer>()?;
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeProductRead)?;
 
         let products = services
             .get_products_usecase
-            .exec(())
+            .exec(backoffice::application::usecases::GetProductsInput {
+                status: status.map(|status| backoffice::domain::product::ProductStatus::from(status).to_primitive()),
+                ..Default::default()
+            })
             .instrument(tracing::debug_span!("Execute use case", name = "GetProducts"))
-            .await?;
+            .await
+            .map_err(|err| err.extend())?;
 
         Ok(products
             .into_iter()
-            .map(backoffice::infrastructure::graphql::Product::from)
+            .map(|item| backoffice::infrastructure::graphql::Product::from(item.product))
+            .collect())
+    }
+
+    pub async fn product<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        id: uuid::Uuid,
+    ) -> async_graphql::Result<Option<backoffice::infrastructure::graphql::Product>> {
+        let claims = ctx.data::<common::infrastructure::IdentityClaims>()?;
+        claims
+            .check_permission(common::domain::Permissions::EcommerceBackofficeProductRead)
+            .map_err(|err| err.extend())?;
+
+        let loader = ctx.data::<DataLoader<backoffice::infrastructure::graphql::ProductLoader>>()?;
+
+        loader
+            .load_one(backoffice::domain::product::ProductId::from(id))
+            .await
+            .map_err(|err| err.extend())
+    }
+
+    /// Price list of a product, by currency and start of validity.
+    pub async fn product_prices<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        product_id: uuid::Uuid,
+    ) -> async_graphql::Result<Vec<backoffice::infrastructure::graphql::ProductPrice>> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeProductPriceRead)?;
+
+        let prices = services
+            .get_product_prices_usecase
+            .exec(backoffice::application::usecases::GetProductPricesInput {
+                product_id: product_id.to_string(),
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetProductPrices"))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(prices
+            .into_iter()
+            .map(backoffice::infrastructure::graphql::ProductPrice::from)
             .collect())
     }
+
+    /// Price valid in `currency` at `at`, or now when omitted. The latest started one wins where windows overlap.
+    pub async fn current_product_price<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        product_id: uuid::Uuid,
+        currency: backoffice::infrastructure::graphql::Currency,
+        at: Option<chrono::DateTime<chrono::offset::Utc>>,
+    ) -> async_graphql::Result<Option<backoffice::infrastructure::graphql::ProductPrice>> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeProductPriceRead)?;
+
+        let price = services
+            .get_current_product_price_usecase
+            .exec(backoffice::application::usecases::GetCurrentProductPriceInput {
+                product_id: product_id.to_string(),
+                currency: currency.0.to_primitive(),
+                at,
+            })
+            .instrument(tracing::debug_span!(
+                "Execute use case",
+                name = "GetCurrentProductPrice"
+            ))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(price.map(backoffice::infrastructure::graphql::ProductPrice::from))
+    }
+
+    /// Every category, each parent followed by its subtree and siblings by position.
+    pub async fn categories<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+    ) -> async_graphql::Result<Vec<backoffice::infrastructure::graphql::Category>> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeCategoryRead)?;
+
+        let categories = services
+            .get_categories_usecase
+            .exec(())
+            .instrument(tracing::debug_span!("Execute use case", name = "GetCategories"))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(categories
+            .into_iter()
+            .map(backoffice::infrastructure::graphql::Category::from)
+            .collect())
+    }
+
+    pub async fn category<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        id: uuid::Uuid,
+    ) -> async_graphql::Result<Option<backoffice::infrastructure::graphql::Category>> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeCategoryRead)?;
+
+        let result = services
+            .get_category_usecase
+            .exec(backoffice::application::usecases::GetCategoryInput { id: id.to_string() })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetCategory"))
+            .await;
+
+        match result {
+            Ok(category) => Ok(Some(category.into())),
+            Err(common::domain::Error::CategoryNotFound) => Ok(None),
+            Err(err) => Err(err.extend()),
+        }
+    }
 }
 
 pub struct MutationRoot;
@@ -40,26 +166,196 @@ impl MutationRoot {
     async fn save_product<'ctx>(
         &self,
         ctx: &Context<'ctx>,
-        id: String,
-        name: String,
-        price: i32,
-        currency: String,
+        input: backoffice::infrastructure::graphql::ProductInput,
+    ) -> async_graphql::Result<backoffice::infrastructure::graphql::SaveProductPayload> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeProductCreate)?;
+
+        let result = services
+            .save_product_usecase
+            .exec(input.into())
+            .instrument(tracing::debug_span!("Execute use case", name = "SaveProduct"))
+            .await;
+
+        backoffice::infrastructure::graphql::SaveProductPayload::from_result(result.map(Into::into))
+    }
+
+    /// Creates or replaces an entry of a product price list.
+    async fn save_product_price<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        input: backoffice::infrastructure::graphql::ProductPriceInput,
+    ) -> async_graphql::Result<backoffice::infrastructure::graphql::SaveProductPricePayload> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeProductPriceWrite)?;
+
+        let result = services
+            .save_product_price_usecase
+            .exec(input.into())
+            .instrument(tracing::debug_span!("Execute use case", name = "SaveProductPrice"))
+            .await;
+
+        backoffice::infrastructure::graphql::SaveProductPricePayload::from_result(result.map(Into::into))
+    }
+
+    /// Removes an entry of a product price list, returning its id.
+    async fn delete_product_price<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        product_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> async_graphql::Result<uuid::Uuid> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeProductPriceWrite)?;
+
+        services
+            .delete_product_price_usecase
+            .exec(backoffice::application::usecases::DeleteProductPriceInput {
+                id: id.to_string(),
+                product_id: product_id.to_string(),
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "DeleteProductPrice"))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(id)
+    }
+
+    /// Replaces every option of a product at once. Options or values still picked by a variant cannot go.
+    async fn set_product_options<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        input: backoffice::infrastructure::graphql::ProductOptionsInput,
+    ) -> async_graphql::Result<backoffice::infrastructure::graphql::SetProductOptionsPayload> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeVariantWrite)?;
+
+        let result = services
+            .set_variant_options_usecase
+            .exec(input.into())
+            .instrument(tracing::debug_span!("Execute use case", name = "SetVariantOptions"))
+            .await;
+
+        backoffice::infrastructure::graphql::SetProductOptionsPayload::from_result(
+            result.map(|options| options.into_iter().map(Into::into).collect()),
+        )
+    }
+
+    /// Creates or replaces a variant of a product, its SKU must not be used by any other variant.
+    async fn save_variant<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        input: backoffice::infrastructure::graphql::VariantInput,
+    ) -> async_graphql::Result<backoffice::infrastructure::graphql::SaveVariantPayload> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeVariantWrite)?;
+
+        let result = services
+            .save_variant_usecase
+            .exec(input.into())
+            .instrument(tracing::debug_span!("Execute use case", name = "SaveVariant"))
+            .await;
+
+        backoffice::infrastructure::graphql::SaveVariantPayload::from_result(result.map(Into::into))
+    }
+
+    /// Removes a variant of a product, returning its id.
+    async fn delete_variant<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        product_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> async_graphql::Result<uuid::Uuid> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeVariantWrite)?;
+
+        services
+            .delete_variant_usecase
+            .exec(backoffice::application::usecases::DeleteVariantInput {
+                id: id.to_string(),
+                product_id: product_id.to_string(),
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "DeleteVariant"))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(id)
+    }
+
+    /// Creates a category as the last child of its parent, or as the last root category without one.
+    async fn save_category<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        input: backoffice::infrastructure::graphql::CategoryInput,
+    ) -> async_graphql::Result<backoffice::infrastructure::graphql::SaveCategoryPayload> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeCategoryWrite)?;
+
+        let result = services
+            .save_category_usecase
+            .exec(input.into())
+            .instrument(tracing::debug_span!("Execute use case", name = "SaveCategory"))
+            .await;
+
+        backoffice::infrastructure::graphql::SaveCategoryPayload::from_result(result.map(Into::into))
+    }
+
+    /// Moves a category, with its whole subtree, below another parent or to the roots.
+    async fn move_category<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        input: backoffice::infrastructure::graphql::MoveCategoryInput,
+    ) -> async_graphql::Result<backoffice::infrastructure::graphql::MoveCategoryPayload> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeCategoryWrite)?;
+
+        let result = services
+            .move_category_usecase
+            .exec(input.into())
+            .instrument(tracing::debug_span!("Execute use case", name = "MoveCategory"))
+            .await;
+
+        backoffice::infrastructure::graphql::MoveCategoryPayload::from_result(result.map(Into::into))
+    }
+
+    /// Assigns a product to a category, assigning it again changes nothing.
+    async fn assign_product_to_category<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        category_id: uuid::Uuid,
+        product_id: uuid::Uuid,
     ) -> async_graphql::Result<bool> {
-        let claims = ctx.data::<common::infrastructure::IdentityClaims>()?;
-        claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductCreate)?;
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeCategoryWrite)?;
 
-        let services = ctx.data::<common::infrastructure::DependencyContainer>()?;
+        services
+            .assign_product_to_category_usecase
+            .exec(backoffice::application::usecases::AssignProductToCategoryInput {
+                category_id: category_id.to_string(),
+                product_id: product_id.to_string(),
+            })
+            .instrument(tracing::debug_span!(
+                "Execute use case",
+                name = "AssignProductToCategory"
+            ))
+            .await
+            .map_err(|err| err.extend())?;
+
+        Ok(true)
+    }
+
+    /// Removes a product from a category, removing one that is not assigned changes nothing.
+    async fn unassign_product_from_category<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        category_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+    ) -> async_graphql::Result<bool> {
+        let services = authorize(ctx, common::domain::Permissions::EcommerceBackofficeCategoryWrite)?;
 
         services
-            .save_product_usecase
-            .exec(backoffice::application::usecases::SaveProductInput {
-                id,
-                name,
-                price,
-                currency,
+            .unassign_product_from_category_usecase
+            .exec(backoffice::application::usecases::UnassignProductFromCategoryInput {
+                category_id: category_id.to_string(),
+                product_id: product_id.to_string(),
             })
-            .instrument(tracing::debug_span!("Execute use case", name = "SaveProduct"))
-            .await?;
+            .instrument(tracing::debug_span!(
+                "Execute use case",
+                name = "UnassignProductFromCategory"
+            ))
+            .await
+            .map_err(|err| err.extend())?;
 
         Ok(true)
     }
@@ -69,7 +365,6 @@ impl MutationRoot {
 mod tests {
     use std::sync::Arc;
 
-    use async_graphql::{EmptySubscription, Schema};
     use axum::body::Body;
     use axum::http::{Request, StatusCode};
     use axum::routing::post;
@@ -78,17 +373,40 @@ mod tests {
     use tower::ServiceExt;
 
     use crate::contexts::ecommerce::common;
+    use crate::contexts::ecommerce::settings::GraphQLSettings;
+    use crate::libs;
 
     use super::*;
 
     const PATH: &str = "/graphql";
 
+    const SAVE_PRODUCT_MUTATION: &str = r#"
+        mutation Mutation($input: ProductInput!) {
+            saveProduct(input: $input) {
+                product { id name price { amount currency } createdAt updatedAt }
+                userErrors { field code message }
+            }
+        }
+    "#;
+
+    const SAVE_PRODUCT_PRICE_MUTATION: &str = r#"
+        mutation Mutation($input: ProductPriceInput!) {
+            saveProductPrice(input: $input) {
+                productPrice { id price { amount currency } validFrom validUntil }
+                userErrors { field code message }
+            }
+        }
+    "#;
+
     fn router(services: common::infrastructure::DependencyContainer) -> Router {
-        let schema = Arc::new(
-            Schema::build(QueryRoot, MutationRoot, EmptySubscription)
-                .data(services)
-                .finish(),
-        );
+        router_with_settings(services, GraphQLSettings::default())
+    }
+
+    fn router_with_settings(
+        services: common::infrastructure::DependencyContainer,
+        settings: GraphQLSettings,
+    ) -> Router {
+        let schema = Arc::new(backoffice::infrastructure::graphql::build_schema(services, &settings));
 
         Router::new().route(
             PATH,
@@ -96,9 +414,29 @@ mod tests {
         )
     }
 
+    async fn send(router: Router, token: String, body: Value) -> Value {
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri(PATH)
+                    .method("POST")
+                    .header(http::header::AUTHORIZATION, token)
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn given_empty_database_when_request_then_return_200() {
-        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::new().await;
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
         fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
             .to_string()
             .as_str()]);
@@ -139,7 +477,7 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn given_products_on_database_when_request_then_return_200() {
-        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::new().await;
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
         fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
             .to_string()
             .as_str()]);
@@ -190,4 +528,767 @@ mod tests {
             })
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_in_several_statuses_when_request_with_status_then_return_matching() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        backoffice::domain::product::fixture::ProductBuilder::default()
+            .save(&fixture.services.product_repository)
+            .await;
+        let published = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Published);
+        published.save(&fixture.services.product_repository).await;
+
+        let body = json!({
+            "query": r#"query { products(status: PUBLISHED) { id status publishAt unpublishAt }}"#
+        });
+
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(PATH)
+                    .method("POST")
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            body["data"]["products"],
+            json!([{
+                "id": published.id.to_primitive(),
+                "status": "PUBLISHED",
+                "publishAt": null,
+                "unpublishAt": null
+            }])
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_on_database_when_request_product_by_id_then_return_matching_or_null() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let missing = backoffice::domain::product::ProductId::default();
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": "query Query($a: UUID!, $b: UUID!) { a: product(id: $a) { id name } b: product(id: $b) { id } }",
+                "variables": { "a": product.id.to_primitive(), "b": missing.to_primitive() }
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            body,
+            json!({
+                "data": {
+                    "a": { "id": product.id.to_primitive(), "name": product.name.to_primitive() },
+                    "b": null
+                }
+            })
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_query_deeper_than_limit_when_request_then_return_query_too_deep_error() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let settings = GraphQLSettings {
+            depth_limit: 1,
+            ..Default::default()
+        };
+
+        let body = send(
+            router_with_settings(fixture.services, settings),
+            fixture.token,
+            json!({ "query": "query Query { products { id }}" }),
+        )
+        .await;
+
+        assert_eq!(body["errors"][0]["extensions"]["code"], "QUERY_TOO_DEEP");
+        assert_eq!(body["errors"][0]["extensions"]["limit"], 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_query_more_complex_than_limit_when_request_then_return_query_too_complex_error() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let settings = GraphQLSettings {
+            complexity_limit: 10,
+            ..Default::default()
+        };
+
+        let body = send(
+            router_with_settings(fixture.services, settings),
+            fixture.token,
+            json!({ "query": "query Query { products { id }}" }),
+        )
+        .await;
+
+        assert_eq!(body["errors"][0]["extensions"]["code"], "QUERY_TOO_COMPLEX");
+        assert_eq!(body["errors"][0]["extensions"]["limit"], 10);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_introspection_disabled_when_request_schema_then_return_no_schema() {
+        let fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({ "query": "query Query { __schema { queryType { name } } }" }),
+        )
+        .await;
+
+        assert!(body["data"]["__schema"].is_null());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_introspection_enabled_when_request_schema_then_return_200() {
+        let fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+
+        let settings = GraphQLSettings {
+            introspection_enabled: true,
+            ..Default::default()
+        };
+
+        let body = send(
+            router_with_settings(fixture.services, settings),
+            fixture.token,
+            json!({ "query": "query Query { __schema { queryType { name } } }" }),
+        )
+        .await;
+
+        assert_eq!(body["data"]["__schema"]["queryType"]["name"], "QueryRoot");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_permissions_when_query_then_return_forbidden_error() {
+        let fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({ "query": "query Query { products { id }}" }),
+        )
+        .await;
+
+        assert_eq!(body["errors"][0]["extensions"]["code"], "FORBIDDEN");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_valid_input_when_save_product_then_return_saved_product() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductCreate
+            .to_string()
+            .as_str()]);
+
+        let id = backoffice::domain::product::ProductId::default().to_primitive();
+
+        let body = send(
+            router(fixture.services.clone()),
+            fixture.token,
+            json!({
+                "query": SAVE_PRODUCT_MUTATION,
+                "variables": { "input": {
+                    "id": id,
+                    "name": "Guitar",
+                    "price": { "amount": "1500", "currency": "JPY" }
+                } }
+            }),
+        )
+        .await;
+
+        assert_eq!(body["data"]["saveProduct"]["userErrors"], json!([]));
+        assert_eq!(body["data"]["saveProduct"]["product"]["id"], id);
+        assert_eq!(
+            body["data"]["saveProduct"]["product"]["price"],
+            json!({ "amount": "1500", "currency": "JPY" })
+        );
+
+        let id = backoffice::domain::product::ProductId::try_from(id).unwrap();
+        assert!(fixture
+            .services
+            .product_repository
+            .get_by_id(&id)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_invalid_price_when_save_product_then_return_user_error_with_field() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductCreate
+            .to_string()
+            .as_str()]);
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": SAVE_PRODUCT_MUTATION,
+                "variables": { "input": {
+                    "id": backoffice::domain::product::ProductId::default().to_primitive(),
+                    "name": "Guitar",
+                    "price": { "amount": "-1.00", "currency": "EUR" }
+                } }
+            }),
+        )
+        .await;
+
+        assert!(body["data"]["saveProduct"]["product"].is_null());
+        assert_eq!(
+            body["data"]["saveProduct"]["userErrors"],
+            json!([{
+                "field": ["input", "price", "amount"],
+                "code": "INVALID_PRODUCT_PRICE",
+                "message": common::domain::Error::InvalidProductPrice.to_string()
+            }])
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_several_invalid_fields_when_save_product_then_return_one_user_error_per_field() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductCreate
+            .to_string()
+            .as_str()]);
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": SAVE_PRODUCT_MUTATION,
+                "variables": { "input": {
+                    "id": backoffice::domain::product::ProductId::default().to_primitive(),
+                    "name": "",
+                    "price": { "amount": "-1.00", "currency": "EUR" }
+                } }
+            }),
+        )
+        .await;
+
+        let fields: Vec<Value> = body["data"]["saveProduct"]["userErrors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|error| error["field"].clone())
+            .collect();
+
+        assert_eq!(
+            fields,
+            vec![json!(["input", "name"]), json!(["input", "price", "amount"])]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_existing_product_when_save_product_then_return_already_exists_user_error() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductCreate
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": SAVE_PRODUCT_MUTATION,
+                "variables": { "input": {
+                    "id": product.id.to_primitive(),
+                    "name": "Guitar",
+                    "price": { "amount": "1.00", "currency": "EUR" }
+                } }
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            body["data"]["saveProduct"]["userErrors"][0]["code"],
+            "PRODUCT_ALREADY_EXISTS"
+        );
+        assert_eq!(
+            body["data"]["saveProduct"]["userErrors"][0]["field"],
+            json!(["input", "id"])
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_promotion_when_save_product_price_then_return_it_as_current_price_within_window() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[
+            common::domain::Permissions::EcommerceBackofficeProductPriceRead
+                .to_string()
+                .as_str(),
+            common::domain::Permissions::EcommerceBackofficeProductPriceWrite
+                .to_string()
+                .as_str(),
+        ]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let body = send(
+            router(fixture.services.clone()),
+            fixture.token.clone(),
+            json!({
+                "query": SAVE_PRODUCT_PRICE_MUTATION,
+                "variables": { "input": {
+                    "id": backoffice::domain::product_price::ProductPriceId::default().to_primitive(),
+                    "productId": product.id.to_primitive(),
+                    "price": { "amount": "7.99", "currency": "EUR" },
+                    "validFrom": "2024-11-29T00:00:00Z",
+                    "validUntil": "2024-12-02T00:00:00Z"
+                } }
+            }),
+        )
+        .await;
+
+        assert_eq!(body["data"]["saveProductPrice"]["userErrors"], json!([]));
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": r#"query Query($id: UUID!) {
+                    during: currentProductPrice(productId: $id, currency: EUR, at: "2024-11-30T00:00:00Z") { price { amount } }
+                    after: currentProductPrice(productId: $id, currency: EUR, at: "2024-12-02T00:00:00Z") { price { amount } }
+                    prices: productPrices(productId: $id) { validFrom validUntil }
+                }"#,
+                "variables": { "id": product.id.to_primitive() }
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            body["data"],
+            json!({
+                "during": { "price": { "amount": "7.99" } },
+                "after": null,
+                "prices": [{ "validFrom": "2024-11-29T00:00:00+00:00", "validUntil": "2024-12-02T00:00:00+00:00" }]
+            })
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_window_ending_before_it_starts_when_save_product_price_then_return_camel_case_user_error() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductPriceWrite
+            .to_string()
+            .as_str()]);
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": SAVE_PRODUCT_PRICE_MUTATION,
+                "variables": { "input": {
+                    "id": backoffice::domain::product_price::ProductPriceId::default().to_primitive(),
+                    "productId": backoffice::domain::product::ProductId::default().to_primitive(),
+                    "price": { "amount": "7.99", "currency": "EUR" },
+                    "validFrom": "2024-12-02T00:00:00Z",
+                    "validUntil": "2024-11-29T00:00:00Z"
+                } }
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            body["data"]["saveProductPrice"]["userErrors"][0]["field"],
+            json!(["input", "validUntil"])
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_product_create_permission_only_when_save_product_price_then_return_forbidden_error() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductCreate
+            .to_string()
+            .as_str()]);
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": "mutation Mutation($p: UUID!, $id: UUID!) { deleteProductPrice(productId: $p, id: $id) }",
+                "variables": {
+                    "p": backoffice::domain::product::ProductId::default().to_primitive(),
+                    "id": backoffice::domain::product_price::ProductPriceId::default().to_primitive()
+                }
+            }),
+        )
+        .await;
+
+        assert_eq!(body["errors"][0]["extensions"]["code"], "FORBIDDEN");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_currency_when_save_product_then_reject_input() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductCreate
+            .to_string()
+            .as_str()]);
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": SAVE_PRODUCT_MUTATION,
+                "variables": { "input": {
+                    "id": backoffice::domain::product::ProductId::default().to_primitive(),
+                    "name": "Guitar",
+                    "price": { "amount": "1.00", "currency": "XYZ" }
+                } }
+            }),
+        )
+        .await;
+
+        assert!(body["data"].is_null());
+        assert!(body["errors"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains(r#"does not contain the value "XYZ""#));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_updated_product_when_request_price_history_then_return_changes_within_period() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[
+            common::domain::Permissions::EcommerceBackofficeProductRead
+                .to_string()
+                .as_str(),
+            common::domain::Permissions::EcommerceBackofficeProductPriceRead
+                .to_string()
+                .as_str(),
+        ]);
+
+        let builder = backoffice::domain::product::fixture::ProductBuilder {
+            price: common::domain::Money::new(999, common::domain::Currency::EUR),
+            ..Default::default()
+        };
+        builder.save(&fixture.services.product_repository).await;
+
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        let since = chrono::Utc::now();
+
+        let mut product = builder.to_entity();
+        product.price = common::domain::Money::new(1_299, common::domain::Currency::EUR);
+        libs::database::with_caller(
+            Some(String::from("alice")),
+            fixture.services.product_repository.update(&product),
+        )
+        .await
+        .unwrap();
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": r#"query Query($id: UUID!, $from: DateTime!) {
+                    product(id: $id) {
+                        all: priceHistory { newPrice { amount } }
+                        recent: priceHistory(from: $from) { oldPrice { amount } newPrice { amount } changedBy }
+                    }
+                }"#,
+                "variables": {
+                    "id": product.id.to_primitive(),
+                    "from": since.to_rfc3339()
+                }
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            body["data"]["product"],
+            json!({
+                "all": [{ "newPrice": { "amount": "9.99" } }, { "newPrice": { "amount": "12.99" } }],
+                "recent": [{
+                    "oldPrice": { "amount": "9.99" },
+                    "newPrice": { "amount": "12.99" },
+                    "changedBy": "alice"
+                }]
+            })
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_product_read_permission_only_when_request_price_history_then_return_forbidden_error() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": "query Query($id: UUID!) { product(id: $id) { priceHistory { changedAt } } }",
+                "variables": { "id": product.id.to_primitive() }
+            }),
+        )
+        .await;
+
+        assert_eq!(body["errors"][0]["extensions"]["code"], "FORBIDDEN");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_category_tree_when_request_categories_then_return_it_with_subtree_products() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[
+            common::domain::Permissions::EcommerceBackofficeCategoryRead
+                .to_string()
+                .as_str(),
+            common::domain::Permissions::EcommerceBackofficeCategoryWrite
+                .to_string()
+                .as_str(),
+        ]);
+
+        let parent = backoffice::domain::category::CategoryId::default().to_primitive();
+        let child = backoffice::domain::category::CategoryId::default().to_primitive();
+        for (id, parent_id, name) in [(&parent, None, "Shoes"), (&child, Some(&parent), "Running")] {
+            let body = send(
+                router(fixture.services.clone()),
+                fixture.token.clone(),
+                json!({
+                    "query": r#"mutation Mutation($input: CategoryInput!) {
+                        saveCategory(input: $input) { category { depth } userErrors { code } }
+                    }"#,
+                    "variables": { "input": { "id": id, "parentId": parent_id, "name": name } }
+                }),
+            )
+            .await;
+
+            assert_eq!(body["data"]["saveCategory"]["userErrors"], json!([]));
+        }
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let body = send(
+            router(fixture.services.clone()),
+            fixture.token.clone(),
+            json!({
+                "query": r#"mutation Mutation($categoryId: UUID!, $productId: UUID!) {
+                    assignProductToCategory(categoryId: $categoryId, productId: $productId)
+                }"#,
+                "variables": { "categoryId": child, "productId": product.id.to_primitive() }
+            }),
+        )
+        .await;
+        assert_eq!(body["data"]["assignProductToCategory"], true);
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": r#"query Query {
+                    categories {
+                        name depth
+                        products { id }
+                        own: products(includeSubcategories: false) { id }
+                    }
+                }"#
+            }),
+        )
+        .await;
+
+        let product_id = product.id.to_primitive();
+        assert_eq!(
+            body["data"]["categories"],
+            json!([
+                { "name": "Shoes", "depth": 0, "products": [{ "id": product_id }], "own": [] },
+                { "name": "Running", "depth": 1, "products": [{ "id": product_id }], "own": [{ "id": product_id }] }
+            ])
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_own_child_as_parent_when_move_category_then_return_user_error() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeCategoryWrite
+            .to_string()
+            .as_str()]);
+
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let child = backoffice::domain::category::fixture::CategoryBuilder::child_of(&parent);
+        parent.save(&fixture.services.category_repository).await;
+        child.save(&fixture.services.category_repository).await;
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": r#"mutation Mutation($input: MoveCategoryInput!) {
+                    moveCategory(input: $input) { category { id } userErrors { field code } }
+                }"#,
+                "variables": { "input": { "id": parent.id.to_primitive(), "parentId": child.id.to_primitive() } }
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            body["data"]["moveCategory"],
+            json!({
+                "category": null,
+                "userErrors": [{ "field": ["input", "parentId"], "code": "CATEGORY_CYCLE" }]
+            })
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_product_options_when_save_variant_then_return_it_on_the_product() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[
+            common::domain::Permissions::EcommerceBackofficeProductRead
+                .to_string()
+                .as_str(),
+            common::domain::Permissions::EcommerceBackofficeVariantRead
+                .to_string()
+                .as_str(),
+            common::domain::Permissions::EcommerceBackofficeVariantWrite
+                .to_string()
+                .as_str(),
+        ]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+        let product_id = product.id.to_primitive();
+
+        let body = send(
+            router(fixture.services.clone()),
+            fixture.token.clone(),
+            json!({
+                "query": r#"mutation Mutation($input: ProductOptionsInput!) {
+                    setProductOptions(input: $input) { options { name values } userErrors { code } }
+                }"#,
+                "variables": { "input": {
+                    "productId": product_id,
+                    "options": [{ "name": "colour", "values": ["Sunburst", "Black"] }]
+                } }
+            }),
+        )
+        .await;
+        assert_eq!(body["data"]["setProductOptions"]["userErrors"], json!([]));
+
+        let body = send(
+            router(fixture.services.clone()),
+            fixture.token.clone(),
+            json!({
+                "query": r#"mutation Mutation($input: VariantInput!) {
+                    saveVariant(input: $input) { variant { sku } userErrors { field code } }
+                }"#,
+                "variables": { "input": {
+                    "id": backoffice::domain::variant::VariantId::default().to_primitive(),
+                    "productId": product_id,
+                    "sku": "strat-sb",
+                    "options": [{ "name": "colour", "value": "Sunburst" }],
+                    "price": { "amount": "3799.00", "currency": "EUR" },
+                    "barcode": "4006381333931"
+                } }
+            }),
+        )
+        .await;
+        assert_eq!(
+            body["data"]["saveVariant"],
+            json!({ "variant": { "sku": "STRAT-SB" }, "userErrors": [] })
+        );
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": r#"query Query($id: UUID!) {
+                    product(id: $id) {
+                        options { name values }
+                        variants { sku options { name value } price { amount currency } barcode }
+                    }
+                }"#,
+                "variables": { "id": product_id }
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            body["data"]["product"],
+            json!({
+                "options": [{ "name": "colour", "values": ["Sunburst", "Black"] }],
+                "variants": [{
+                    "sku": "STRAT-SB",
+                    "options": [{ "name": "colour", "value": "Sunburst" }],
+                    "price": { "amount": "3799.00", "currency": "EUR" },
+                    "barcode": "4006381333931"
+                }]
+            })
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_taken_sku_when_save_variant_then_return_user_error_on_sku() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeVariantWrite
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+        let taken = backoffice::domain::variant::fixture::VariantBuilder::new(product.id);
+        taken.save(&fixture.services.variant_repository).await;
+
+        let body = send(
+            router(fixture.services),
+            fixture.token,
+            json!({
+                "query": r#"mutation Mutation($input: VariantInput!) {
+                    saveVariant(input: $input) { variant { id } userErrors { field code } }
+                }"#,
+                "variables": { "input": {
+                    "id": backoffice::domain::variant::VariantId::default().to_primitive(),
+                    "productId": product.id.to_primitive(),
+                    "sku": taken.sku.to_primitive(),
+                    "options": []
+                } }
+            }),
+        )
+        .await;
+
+        assert_eq!(
+            body["data"]["saveVariant"],
+            json!({
+                "variant": null,
+                "userErrors": [{ "field": ["input", "sku"], "code": "VARIANT_SKU_ALREADY_EXISTS" }]
+            })
+        );
+    }
 }
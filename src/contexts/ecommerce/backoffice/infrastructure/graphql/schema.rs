@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use async_graphql::{EmptySubscription, Schema};
+
+use crate::contexts::ecommerce::settings::GraphQLSettings;
+use crate::contexts::ecommerce::{backoffice, common};
+
+pub fn build_schema(
+    services: common::infrastructure::DependencyContainer,
+    settings: &GraphQLSettings,
+) -> backoffice::infrastructure::graphql::SchemaRoot {
+    let manifest = match &settings.persisted_queries_manifest {
+        Some(path) => backoffice::infrastructure::graphql::PersistedQueries::load_manifest(path)
+            .expect("GRAPHQL_PERSISTED_QUERIES_MANIFEST"),
+        None if settings.persisted_queries_strict => {
+            panic!("GRAPHQL_PERSISTED_QUERIES_STRICT requires GRAPHQL_PERSISTED_QUERIES_MANIFEST")
+        }
+        None => HashMap::new(),
+    };
+
+    let mut builder = Schema::build(
+        backoffice::infrastructure::graphql::QueryRoot,
+        backoffice::infrastructure::graphql::MutationRoot,
+        EmptySubscription,
+    )
+    .data(services)
+    .extension(backoffice::infrastructure::graphql::QueryLimits::new(
+        settings.depth_limit,
+        settings.complexity_limit,
+        settings.timeout,
+    ))
+    .extension(backoffice::infrastructure::graphql::PersistedQueries::new(
+        settings.persisted_queries_cache_size,
+        manifest,
+        settings.persisted_queries_strict,
+    ))
+    .extension(backoffice::infrastructure::graphql::DataLoaders);
+
+    if !settings.introspection_enabled {
+        builder = builder.disable_introspection();
+    }
+
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_committed_sdl_when_compare_with_generated_then_match() {
+        let schema = Schema::build(
+            backoffice::infrastructure::graphql::QueryRoot,
+            backoffice::infrastructure::graphql::MutationRoot,
+            EmptySubscription,
+        )
+        .finish();
+
+        assert_eq!(
+            schema.sdl(),
+            include_str!("schema.graphql"),
+            "schema.graphql is out of date, regenerate it from the /graphql/schema.graphql endpoint"
+        );
+    }
+}
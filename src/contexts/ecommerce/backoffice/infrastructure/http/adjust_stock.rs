@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Debug, Deserialize)]
+pub struct AdjustStockBody {
+    pub warehouse: String,
+    pub delta: i64,
+    pub reason: String,
+}
+
+/// Adds units to, or takes them out of, a warehouse. The on-hand quantity may not drop below the units
+/// held by pending reservations.
+#[utoipa::path(
+    put,
+    path = "/product/{product_id}/stock/adjustments/{id}",
+    tag = "inventory",
+    security(("Identity" = ["ecommerce.backoffice.inventory:write"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product the stock belongs to"),
+        ("id" = uuid::Uuid, Path, description = "Adjustment id, chosen by the client"),
+    ),
+    request_body = backoffice::infrastructure::http::AdjustStockBody,
+    responses(
+        (status = 200, description = "Stock of the warehouse after the adjustment", body = backoffice::domain::inventory::StockLevel),
+        (status = 400, description = "Malformed JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Adjustment id taken, or not enough units left", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Invalid adjustment fields, listed in `errors`, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn adjust_stock(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::AdjustStock>>,
+    Path((product_id, id)): Path<(String, String)>,
+    common::infrastructure::Json(body): common::infrastructure::Json<AdjustStockBody>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeInventoryWrite)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::AdjustStockInput {
+                id,
+                product_id,
+                warehouse: body.warehouse,
+                delta: body.delta,
+                reason: body.reason,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "AdjustStock")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::AdjustStock> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.adjust_stock_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/stock/adjustments/:id";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(adjust_stock)).with_state(services)
+    }
+
+    async fn put_adjustment(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let id = backoffice::domain::inventory::StockAdjustmentId::default();
+
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/ecommerce/product/{product_id}/stock/adjustments/{id}"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    async fn compose_fixture() -> (
+        common::infrastructure::controller::fixture::HttpContextFixture,
+        backoffice::domain::product::ProductId,
+    ) {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeInventoryWrite
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        (fixture, product.id)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_receipt_when_request_then_return_200_with_stock() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, body) = put_adjustment(
+            &fixture,
+            &product_id,
+            json!({ "warehouse": "mad-1", "delta": 12, "reason": "received" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["warehouse"], "MAD-1");
+        assert_eq!(body["on_hand"], 12);
+        assert_eq!(body["available"], 12);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_reserved_units_when_request_taking_them_out_then_return_409() {
+        let (fixture, product_id) = compose_fixture().await;
+        backoffice::domain::inventory::fixture::StockAdjustmentBuilder::new(product_id, "MAD-1", 5)
+            .save(&fixture.services.inventory_repository)
+            .await;
+        backoffice::domain::inventory::fixture::ReservationBuilder::new(product_id, "MAD-1", 3)
+            .save(&fixture.services.inventory_repository)
+            .await;
+
+        let (status, body) = put_adjustment(
+            &fixture,
+            &product_id,
+            json!({ "warehouse": "MAD-1", "delta": -3, "reason": "damaged" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "INSUFFICIENT_STOCK");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_reason_when_request_then_return_422_with_pointer() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, body) = put_adjustment(
+            &fixture,
+            &product_id,
+            json!({ "warehouse": "MAD-1", "delta": 1, "reason": "found" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["pointer"], "/reason");
+    }
+}
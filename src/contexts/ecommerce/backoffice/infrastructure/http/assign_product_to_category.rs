@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Assigns a product to a category, assigning it again changes nothing.
+#[utoipa::path(
+    put,
+    path = "/category/{category_id}/products/{product_id}",
+    tag = "category",
+    security(("Identity" = ["ecommerce.backoffice.category:write"])),
+    params(
+        ("category_id" = uuid::Uuid, Path, description = "Category to assign the product to"),
+        ("product_id" = uuid::Uuid, Path, description = "Product to assign"),
+    ),
+    responses(
+        (status = 202, description = "Accepted"),
+        (status = 400, description = "Malformed category or product id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Category or product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn assign_product_to_category(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::AssignProductToCategory>>,
+    Path((category_id, product_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeCategoryWrite)?;
+
+    libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::AssignProductToCategoryInput {
+                category_id,
+                product_id,
+            })
+            .instrument(tracing::debug_span!(
+                "Execute use case",
+                name = "AssignProductToCategory"
+            )),
+    )
+    .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::AssignProductToCategory>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.assign_product_to_category_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/category/:category_id/products/:product_id";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new()
+            .route(PATH, put(assign_product_to_category))
+            .with_state(services)
+    }
+
+    async fn request(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        uri: String,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(uri)
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_request_then_return_404() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryWrite);
+        let category = backoffice::domain::category::fixture::CategoryBuilder::default();
+        category.save(&fixture.services.category_repository).await;
+        let product_id = backoffice::domain::product::ProductId::default();
+
+        let (status, body) = request(
+            &fixture,
+            format!("/ecommerce/category/{}/products/{product_id}", category.id),
+            Value::Null,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "PRODUCT_NOT_FOUND");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_existing_product_when_request_twice_then_return_202_and_assign_it_once() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryWrite);
+        let category = backoffice::domain::category::fixture::CategoryBuilder::default();
+        category.save(&fixture.services.category_repository).await;
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        for _ in 0..2 {
+            let (status, _) = request(
+                &fixture,
+                format!("/ecommerce/category/{}/products/{}", category.id, product.id),
+                json!({}),
+            )
+            .await;
+
+            assert_eq!(status, StatusCode::ACCEPTED);
+        }
+
+        let products = fixture
+            .services
+            .category_repository
+            .get_products(&category.id, false)
+            .await
+            .unwrap();
+        assert_eq!(products.len(), 1);
+    }
+}
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+use utoipa::ToSchema;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ChangeProductStatusBody {
+    #[schema(value_type = backoffice::infrastructure::http::ProductStatus, inline)]
+    pub status: String,
+}
+
+/// Moves a product through its lifecycle, only published products are live.
+#[utoipa::path(
+    put,
+    path = "/product/{product_id}/status",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product:update"])),
+    params(("product_id" = uuid::Uuid, Path, description = "Product to move")),
+    request_body = backoffice::infrastructure::http::ChangeProductStatusBody,
+    responses(
+        (status = 200, description = "Product in its new status", body = backoffice::infrastructure::http::Product),
+        (status = 400, description = "Malformed product id, status or JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Transition not allowed from the current status, or the status changed concurrently",
+            body = libs::problem_details::ProblemDetails, content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn change_product_status(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::ChangeProductStatus>>,
+    Path(product_id): Path<String>,
+    common::infrastructure::Json(body): common::infrastructure::Json<ChangeProductStatusBody>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductUpdate)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::ChangeProductStatusInput {
+                id: product_id,
+                status: body.status,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "ChangeProductStatus")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::ChangeProductStatus>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.change_product_status_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/status";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new()
+            .route(PATH, put(change_product_status))
+            .with_state(services)
+    }
+
+    async fn put_status(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+        status: &str,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/ecommerce/product/{product_id}/status"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(json!({ "status": status }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_read_permission_only_when_request_then_return_403() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductRead);
+
+        let (status, _) = put_status(
+            &fixture,
+            &backoffice::domain::product::ProductId::default(),
+            "published",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_draft_when_request_published_then_return_200_with_published_product() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductUpdate);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, body) = put_status(&fixture, &product.id, "published").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "published");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_archived_product_when_request_published_then_return_409() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductUpdate);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Archived);
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, body) = put_status(&fixture, &product.id, "published").await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "INVALID_PRODUCT_STATUS_TRANSITION");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_status_when_request_then_return_400() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductUpdate);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, body) = put_status(&fixture, &product.id, "live").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_PRODUCT_STATUS");
+    }
+}
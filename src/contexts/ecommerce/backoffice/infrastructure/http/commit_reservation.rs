@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Takes the units of a pending reservation out of the on-hand quantity for good.
+#[utoipa::path(
+    post,
+    path = "/product/{product_id}/reservations/{id}/commit",
+    tag = "inventory",
+    security(("Identity" = ["ecommerce.backoffice.inventory:write"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product the reservation belongs to"),
+        ("id" = uuid::Uuid, Path, description = "Reservation id"),
+    ),
+    responses(
+        (status = 200, description = "Reservation after the commit", body = backoffice::domain::inventory::Reservation),
+        (status = 400, description = "Malformed product or reservation id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Reservation not found on that product", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Reservation expired, or already committed or released", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn commit_reservation(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::CommitReservation>>,
+    Path((product_id, id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeInventoryWrite)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::CommitReservationInput { id, product_id })
+            .instrument(tracing::debug_span!("Execute use case", name = "CommitReservation")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::CommitReservation>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.commit_reservation_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::{http, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/reservations/:id/commit";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, post(commit_reservation)).with_state(services)
+    }
+
+    async fn request(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        reservation: &backoffice::domain::inventory::Reservation,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!(
+                        "/ecommerce/product/{}/reservations/{}/commit",
+                        reservation.product_id, reservation.id
+                    ))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    async fn compose_fixture(
+        ttl_seconds: i64,
+    ) -> (
+        common::infrastructure::controller::fixture::HttpContextFixture,
+        backoffice::domain::inventory::Reservation,
+    ) {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeInventoryWrite
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+        backoffice::domain::inventory::fixture::StockAdjustmentBuilder::new(product.id, "MAD-1", 5)
+            .save(&fixture.services.inventory_repository)
+            .await;
+        let reservation = backoffice::domain::inventory::fixture::ReservationBuilder::new(product.id, "MAD-1", 2)
+            .ttl_seconds(ttl_seconds)
+            .save(&fixture.services.inventory_repository)
+            .await;
+
+        (fixture, reservation)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_pending_reservation_when_request_then_return_200_and_take_units_out() {
+        let (fixture, reservation) = compose_fixture(60).await;
+
+        let (status, body) = request(&fixture, &reservation).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "committed");
+
+        let stock = fixture
+            .services
+            .inventory_repository
+            .get_stock(
+                &reservation.product_id,
+                backoffice::domain::product::ProductTimeStamp::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!((stock[0].on_hand, stock[0].reserved), (3, 0));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_expired_reservation_when_request_then_return_409() {
+        let (fixture, reservation) = compose_fixture(-1).await;
+
+        let (status, body) = request(&fixture, &reservation).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "RESERVATION_EXPIRED");
+    }
+}
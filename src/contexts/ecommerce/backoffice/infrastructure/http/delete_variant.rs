@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Removes a variant of a product, freeing its SKU.
+#[utoipa::path(
+    delete,
+    path = "/product/{product_id}/variants/{id}",
+    tag = "variant",
+    security(("Identity" = ["ecommerce.backoffice.variant:write"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product the variant belongs to"),
+        ("id" = uuid::Uuid, Path, description = "Variant id"),
+    ),
+    responses(
+        (status = 204, description = "Deleted"),
+        (status = 400, description = "Malformed product or variant id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Variant not found on that product", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn delete_variant(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::DeleteVariant>>,
+    Path((product_id, id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeVariantWrite)?;
+
+    libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::DeleteVariantInput { id, product_id })
+            .instrument(tracing::debug_span!("Execute use case", name = "DeleteVariant")),
+    )
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::DeleteVariant> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.delete_variant_usecase.clone()
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Returns the whole category tree, every parent followed by its subtree and siblings by position.
+#[utoipa::path(
+    get,
+    path = "/category",
+    tag = "category",
+    security(("Identity" = ["ecommerce.backoffice.category:read"])),
+    responses(
+        (status = 200, description = "Categories, depth first", body = Vec<backoffice::domain::category::Category>),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_categories(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetCategories>>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeCategoryRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(())
+            .instrument(tracing::debug_span!("Execute use case", name = "GetCategories")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::GetCategories> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_categories_usecase.clone()
+    }
+}
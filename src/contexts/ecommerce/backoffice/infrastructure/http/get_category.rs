@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Returns a single category.
+#[utoipa::path(
+    get,
+    path = "/category/{category_id}",
+    tag = "category",
+    security(("Identity" = ["ecommerce.backoffice.category:read"])),
+    params(("category_id" = uuid::Uuid, Path, description = "Category to return")),
+    responses(
+        (status = 200, description = "Category", body = backoffice::domain::category::Category),
+        (status = 400, description = "Malformed category id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Category not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_category(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetCategory>>,
+    Path(category_id): Path<String>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeCategoryRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetCategoryInput { id: category_id })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetCategory")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::GetCategory> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_category_usecase.clone()
+    }
+}
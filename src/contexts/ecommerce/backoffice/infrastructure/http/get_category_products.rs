@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Deserialize)]
+pub struct CategoryProductsParams {
+    #[serde(default = "include_subcategories_by_default")]
+    pub include_subcategories: bool,
+}
+
+fn include_subcategories_by_default() -> bool {
+    true
+}
+
+/// Returns the first page of products assigned to a category or, unless `include_subcategories` is
+/// false, to any category below it, each once and oldest first.
+#[utoipa::path(
+    get,
+    path = "/category/{category_id}/products",
+    tag = "category",
+    security(("Identity" = ["ecommerce.backoffice.category:read"])),
+    params(
+        ("category_id" = uuid::Uuid, Path, description = "Root of the subtree to list the products of"),
+        ("include_subcategories" = Option<bool>, Query, description = "Whether products of the categories below count as well, true by default"),
+    ),
+    responses(
+        (status = 200, description = "Products", body = Vec<backoffice::infrastructure::http::Product>),
+        (status = 400, description = "Malformed category id or query", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Category not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_category_products(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetCategoryProducts>>,
+    Path(category_id): Path<String>,
+    common::infrastructure::Query(params): common::infrastructure::Query<CategoryProductsParams>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeCategoryRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetCategoryProductsInput {
+                category_id,
+                include_subcategories: params.include_subcategories,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetCategoryProducts")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::GetCategoryProducts>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_category_products_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/category/:category_id/products";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new()
+            .route(PATH, get(get_category_products))
+            .with_state(services)
+    }
+
+    async fn request(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        uri: String,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(uri)
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_write_permission_only_when_request_then_return_403() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryWrite);
+        let category_id = backoffice::domain::category::CategoryId::default();
+
+        let (status, _) = request(
+            &fixture,
+            format!("/ecommerce/category/{category_id}/products"),
+            json!({}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_product_in_subcategory_when_request_then_include_it_by_default() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryRead);
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let child = backoffice::domain::category::fixture::CategoryBuilder::child_of(&parent);
+        parent.save(&fixture.services.category_repository).await;
+        child.save(&fixture.services.category_repository).await;
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+        fixture
+            .services
+            .category_repository
+            .assign_product(&child.id, &product.id)
+            .await
+            .unwrap();
+
+        let (status, body) = request(
+            &fixture,
+            format!("/ecommerce/category/{}/products", parent.id),
+            json!({}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 1);
+        assert_eq!(body[0]["id"], product.id.to_primitive());
+
+        let (status, body) = request(
+            &fixture,
+            format!("/ecommerce/category/{}/products?include_subcategories=false", parent.id),
+            json!({}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.as_array().unwrap().is_empty());
+    }
+}
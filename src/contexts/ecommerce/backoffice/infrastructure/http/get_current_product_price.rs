@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Deserialize)]
+pub struct CurrentProductPriceParams {
+    pub currency: String,
+    pub at: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+/// Returns the price of a product in a currency at a point in time, the latest started one where
+/// validity windows overlap.
+#[utoipa::path(
+    get,
+    path = "/product/{product_id}/prices/current",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product_price:read"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product the price belongs to"),
+        ("currency" = String, Query, pattern = "^[A-Z]{3}$", description = "ISO 4217 alphabetic code"),
+        ("at" = Option<chrono::DateTime<chrono::offset::Utc>>, Query, description = "Point in time, now when missing"),
+    ),
+    responses(
+        (status = 200, description = "Price valid at the given time", body = backoffice::domain::product_price::ProductPrice),
+        (status = 400, description = "Malformed product id, currency or time", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "No price valid in that currency at that time", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_current_product_price(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetCurrentProductPrice>>,
+    Path(product_id): Path<String>,
+    common::infrastructure::Query(params): common::infrastructure::Query<CurrentProductPriceParams>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductPriceRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetCurrentProductPriceInput {
+                product_id,
+                currency: params.currency,
+                at: params.at,
+            })
+            .instrument(tracing::debug_span!(
+                "Execute use case",
+                name = "GetCurrentProductPrice"
+            )),
+    )
+    .await?
+    .ok_or(common::domain::Error::ProductPriceNotFound)?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::GetCurrentProductPrice>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_current_product_price_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::{http, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/prices/current";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new()
+            .route(PATH, get(get_current_product_price))
+            .with_state(services)
+    }
+
+    async fn request(
+        fixture: common::infrastructure::controller::fixture::HttpContextFixture,
+        uri: String,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    async fn compose_fixture() -> (
+        common::infrastructure::controller::fixture::HttpContextFixture,
+        backoffice::domain::product::ProductId,
+    ) {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductPriceRead
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        backoffice::domain::product_price::fixture::ProductPriceBuilder::new(product.id, 999, "EUR")
+            .save(&fixture.services.product_price_repository)
+            .await;
+        backoffice::domain::product_price::fixture::ProductPriceBuilder::new(product.id, 799, "EUR")
+            .valid(Some("2024-11-29T00:00:00Z"), Some("2024-12-02T00:00:00Z"))
+            .save(&fixture.services.product_price_repository)
+            .await;
+
+        (fixture, product.id)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_promotion_when_request_within_its_window_then_return_promotion() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, body) = request(
+            fixture,
+            format!("/ecommerce/product/{product_id}/prices/current?currency=EUR&at=2024-11-30T12:00:00Z"),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["price"]["amount"], "7.99");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_promotion_when_request_without_time_then_return_price_valid_now() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, body) = request(
+            fixture,
+            format!("/ecommerce/product/{product_id}/prices/current?currency=EUR"),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["price"]["amount"], "9.99");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_price_in_currency_when_request_then_return_404() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, body) = request(
+            fixture,
+            format!("/ecommerce/product/{product_id}/prices/current?currency=USD"),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "PRODUCT_PRICE_NOT_FOUND");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_malformed_time_when_request_then_return_400() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, _) = request(
+            fixture,
+            format!("/ecommerce/product/{product_id}/prices/current?currency=EUR&at=tomorrow"),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::{FromRef, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::IntoResponse;
+use futures::StreamExt;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+const BATCH_SIZE: i64 = 100;
+
+/// Streams product created/updated/deleted events as Server-Sent Events.
+#[utoipa::path(
+    get,
+    path = "/product/events",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product:read"])),
+    params(
+        ("Last-Event-ID" = Option<i64>, Header, minimum = 0,
+            description = "Sequence of the last received event. Streaming resumes right after it."),
+    ),
+    responses(
+        (status = 200, description = "Endless stream of `created`, `updated` and `deleted` events",
+            content_type = "text/event-stream", body = backoffice::domain::product_event::ProductEvent),
+        (status = 400, description = "Malformed Last-Event-ID", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler(state = common::infrastructure::DependencyContainer)]
+pub async fn get_product_events(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetProductEvents>>,
+    State(watcher): State<backoffice::infrastructure::ProductEventWatcher>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductRead)?;
+
+    let after = headers
+        .get(LAST_EVENT_ID_HEADER)
+        .map(|value| {
+            value
+                .to_str()
+                .map_err(|_| common::domain::Error::InvalidProductEventSequence)
+                .and_then(backoffice::domain::product_event::ProductEventSequence::try_from)
+        })
+        .transpose()?;
+
+    let output = usecase
+        .exec(backoffice::application::usecases::GetProductEventsInput {
+            after,
+            limit: BATCH_SIZE,
+        })
+        .instrument(tracing::debug_span!("Execute use case", name = "GetProductEvents"))
+        .await?;
+
+    let pending = futures::stream::iter(output.events);
+
+    // subscribed before the first read below, so that nothing arriving in between goes unnoticed
+    let last_sequence = watcher.subscribe();
+
+    let followed = futures::stream::unfold(
+        (usecase, watcher, last_sequence, output.last_sequence),
+        |(usecase, watcher, mut last_sequence, after)| async move {
+            loop {
+                let input = backoffice::application::usecases::GetProductEventsInput {
+                    after: Some(after),
+                    limit: BATCH_SIZE,
+                };
+
+                match usecase
+                    .exec(input)
+                    .instrument(tracing::debug_span!("Execute use case", name = "GetProductEvents"))
+                    .await
+                {
+                    Ok(output) if !output.events.is_empty() => {
+                        let after = output.last_sequence;
+                        return Some((
+                            futures::stream::iter(output.events),
+                            (usecase, watcher, last_sequence, after),
+                        ));
+                    }
+                    Ok(_) => {}
+                    Err(err) => tracing::error!("{err}"),
+                }
+
+                // the poller stops with its last subscriber, one racing that gets a closed receiver starts it anew
+                if last_sequence.changed().await.is_err() {
+                    last_sequence = watcher.subscribe();
+                }
+            }
+        },
+    )
+    .flatten();
+
+    let stream = pending.chain(followed).map(|event| {
+        Event::default()
+            .id(event.sequence.to_string())
+            .event(event.kind.to_primitive())
+            .json_data(&event)
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::new().interval(KEEP_ALIVE_INTERVAL).text("keep-alive")))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for backoffice::infrastructure::ProductEventWatcher {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.product_event_watcher.clone()
+    }
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::GetProductEvents> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_product_events_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::get;
+    use axum::{http, Router};
+    use hyper::body::HttpBody;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/events";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, get(get_product_events)).with_state(services)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_token_when_request_then_return_401() {
+        let fixture = common::infrastructure::controller::fixture::HttpContextFixture::new().await;
+
+        let response = router(fixture.services)
+            .oneshot(Request::builder().uri(PATH).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_permissions_when_request_then_return_403() {
+        let fixture = common::infrastructure::controller::fixture::HttpContextFixture::new().await;
+
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(PATH)
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_malformed_last_event_id_when_request_then_return_400() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::new().await;
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(PATH)
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .header(LAST_EVENT_ID_HEADER, "not-a-sequence")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_on_database_when_request_with_last_event_id_then_stream_events() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::new().await;
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(PATH)
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .header(LAST_EVENT_ID_HEADER, "0")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            mime::TEXT_EVENT_STREAM.as_ref()
+        );
+
+        let mut body = response.into_body();
+        let frame = body.data().await.unwrap().unwrap();
+        let frame = String::from_utf8(frame.to_vec()).unwrap();
+
+        assert!(frame.contains("event:created"));
+        assert!(frame.contains(&product.id.to_primitive()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_open_stream_when_product_saved_then_stream_its_event() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .uri(PATH)
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let mut body = response.into_body();
+        let frame = tokio::time::timeout(Duration::from_secs(5), body.data())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let frame = String::from_utf8(frame.to_vec()).unwrap();
+
+        assert!(frame.contains("id:1"));
+        assert!(frame.contains(&product.id.to_primitive()));
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Deserialize)]
+pub struct ProductPriceHistoryParams {
+    pub from: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub until: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+/// Returns every change of a product's price, oldest first, with who made it and when.
+#[utoipa::path(
+    get,
+    path = "/product/{product_id}/price-history",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product_price:read"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product the history belongs to"),
+        ("from" = Option<chrono::DateTime<chrono::offset::Utc>>, Query, description = "Start of the period, inclusive"),
+        ("until" = Option<chrono::DateTime<chrono::offset::Utc>>, Query, description = "End of the period, exclusive"),
+    ),
+    responses(
+        (status = 200, description = "Price changes within the period", body = Vec<backoffice::domain::product_price_history::ProductPriceChange>),
+        (status = 400, description = "Malformed product id or period", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_product_price_history(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetProductPriceHistory>>,
+    Path(product_id): Path<String>,
+    common::infrastructure::Query(params): common::infrastructure::Query<ProductPriceHistoryParams>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductPriceRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetProductPriceHistoryInput {
+                product_id,
+                from: params.from,
+                until: params.until,
+            })
+            .instrument(tracing::debug_span!(
+                "Execute use case",
+                name = "GetProductPriceHistory"
+            )),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::GetProductPriceHistory>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_product_price_history_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/price-history";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new()
+            .route(PATH, get(get_product_price_history))
+            .with_state(services)
+    }
+
+    async fn request(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        uri: String,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_product_read_permission_only_when_request_then_return_403() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductRead);
+        let product_id = backoffice::domain::product::ProductId::default();
+
+        let (status, _) = request(&fixture, format!("/ecommerce/product/{product_id}/price-history")).await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_period_ending_before_it_starts_when_request_then_return_400() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductPriceRead);
+        let product_id = backoffice::domain::product::ProductId::default();
+
+        let (status, body) = request(
+            &fixture,
+            format!(
+                "/ecommerce/product/{product_id}/price-history?from=2024-02-01T00:00:00Z&until=2024-01-01T00:00:00Z"
+            ),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_PRODUCT_PRICE_HISTORY_PERIOD");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_updated_product_when_request_then_return_200_with_changes() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductPriceRead);
+
+        let builder = backoffice::domain::product::fixture::ProductBuilder {
+            price: common::domain::Money::new(999, common::domain::Currency::EUR),
+            ..Default::default()
+        };
+        libs::database::with_caller(
+            Some(String::from("alice")),
+            builder.save(&fixture.services.product_repository),
+        )
+        .await;
+
+        let mut product = builder.to_entity();
+        product.price = common::domain::Money::new(1_299, common::domain::Currency::EUR);
+        libs::database::with_caller(
+            Some(String::from("bob")),
+            fixture.services.product_repository.update(&product),
+        )
+        .await
+        .unwrap();
+
+        let (status, body) = request(&fixture, format!("/ecommerce/product/{}/price-history", product.id)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 2);
+        assert_eq!(body[0]["old_price"], Value::Null);
+        assert_eq!(body[0]["changed_by"], "alice");
+        assert_eq!(body[1]["old_price"], json!({ "amount": "9.99", "currency": "EUR" }));
+        assert_eq!(body[1]["new_price"], json!({ "amount": "12.99", "currency": "EUR" }));
+        assert_eq!(body[1]["changed_by"], "bob");
+    }
+}
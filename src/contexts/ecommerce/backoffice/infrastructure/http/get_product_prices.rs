@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Returns the price list of a product, in every currency and validity window.
+#[utoipa::path(
+    get,
+    path = "/product/{product_id}/prices",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product_price:read"])),
+    params(("product_id" = uuid::Uuid, Path, description = "Product the prices belong to")),
+    responses(
+        (status = 200, description = "Prices by currency and start of validity", body = Vec<backoffice::domain::product_price::ProductPrice>),
+        (status = 400, description = "Malformed product id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_product_prices(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetProductPrices>>,
+    Path(product_id): Path<String>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductPriceRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetProductPricesInput { product_id })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetProductPrices")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::GetProductPrices> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_product_prices_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/prices";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, get(get_product_prices)).with_state(services)
+    }
+
+    async fn request(
+        fixture: common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/ecommerce/product/{product_id}/prices"))
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_product_read_permission_only_when_request_then_return_403() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let (status, _) = request(fixture, &backoffice::domain::product::ProductId::default()).await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_request_then_return_404() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductPriceRead
+            .to_string()
+            .as_str()]);
+
+        let (status, body) = request(fixture, &backoffice::domain::product::ProductId::default()).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "PRODUCT_NOT_FOUND");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_prices_when_request_then_return_200_with_price_list() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductPriceRead
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let price = backoffice::domain::product_price::fixture::ProductPriceBuilder::new(product.id, 799, "EUR")
+            .valid(Some("2024-11-29T00:00:00Z"), None);
+        price.save(&fixture.services.product_price_repository).await;
+
+        let (status, body) = request(fixture, &product.id).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body,
+            json!([{
+                "id": price.id.to_primitive(),
+                "product_id": product.id.to_primitive(),
+                "price": { "amount": "7.99", "currency": "EUR" },
+                "valid_from": "2024-11-29T00:00:00.000+00:00",
+                "valid_until": null,
+                "created_at": price.created_at.to_primitive(),
+            }])
+        );
+    }
+}
@@ -3,23 +3,67 @@ use std::sync::Arc;
 use axum::extract::{FromRef, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
+use serde::Deserialize;
 use tracing::Instrument;
 
 use crate::contexts::ecommerce::common::application::usecase::UseCase;
 use crate::contexts::ecommerce::{backoffice, common};
 use crate::libs;
 
+#[derive(Deserialize)]
+pub struct ProductsParams {
+    pub display_currency: Option<String>,
+    pub status: Option<String>,
+}
+
+/// Returns a list of products, with their prices converted to a display currency when asked for.
+#[utoipa::path(
+    get,
+    path = "/product",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product:read"])),
+    params(
+        ("display_currency" = Option<String>, Query, pattern = "^[A-Z]{3}$",
+            description = "ISO 4217 alphabetic code to also show every price in, at the latest reference rate"),
+        ("status" = Option<String>, Query, pattern = "^(draft|published|archived)$",
+            description = "Lifecycle status to narrow the listing to, every status when left out"),
+    ),
+    responses(
+        (status = 200, description = "Products", body = Vec<backoffice::infrastructure::http::ProductListItem>),
+        (status = 400, description = "Malformed display currency or status", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "No exchange rate to the display currency", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
 #[axum::debug_handler]
 pub async fn get_products(
     identity_claims: common::infrastructure::IdentityClaims,
     State(usecase): State<Arc<backoffice::application::usecases::GetProducts>>,
+    common::infrastructure::Query(params): common::infrastructure::Query<ProductsParams>,
 ) -> Result<impl IntoResponse, common::domain::Error> {
     identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductRead)?;
 
-    let output = usecase
-        .exec(())
-        .instrument(tracing::debug_span!("Execute use case", name = "GetProducts"))
-        .await?;
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetProductsInput {
+                display_currency: params.display_currency,
+                status: params.status,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetProducts")),
+    )
+    .await?;
 
     Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
 }
@@ -141,5 +185,116 @@ mod tests {
         let body = body.as_array().unwrap();
 
         assert_eq!(body.len(), 2);
+        assert!(body[0].get("display_price").is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_display_currency_when_request_then_return_200_with_converted_prices() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder {
+            price: common::domain::Money::new(999, common::domain::Currency::EUR),
+            ..Default::default()
+        };
+        product.save(&fixture.services.product_repository).await;
+
+        fixture
+            .services
+            .exchange_rate_repository
+            .save_many(&[backoffice::domain::exchange_rate::fixture::exchange_rate(
+                "EUR",
+                "JPY",
+                "167.76",
+                "2024-05-10",
+            )])
+            .await
+            .unwrap();
+
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(format!("{PATH}?display_currency=JPY"))
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body[0]["price"], json!({ "amount": "9.99", "currency": "EUR" }));
+        assert_eq!(body[0]["display_price"], json!({ "amount": "1676", "currency": "JPY" }));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_display_currency_without_rate_when_request_then_return_422() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        backoffice::domain::product::fixture::ProductBuilder::default()
+            .save(&fixture.services.product_repository)
+            .await;
+
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(format!("{PATH}?display_currency=CHF"))
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["code"], "EXCHANGE_RATE_NOT_FOUND");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_status_when_request_then_return_200_with_matching_products() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductRead
+            .to_string()
+            .as_str()]);
+
+        backoffice::domain::product::fixture::ProductBuilder::default()
+            .save(&fixture.services.product_repository)
+            .await;
+        let archived = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Archived);
+        archived.save(&fixture.services.product_repository).await;
+
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .uri(format!("{PATH}?status=archived"))
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.as_array().unwrap().len(), 1);
+        assert_eq!(body[0]["id"], archived.id.to_primitive());
+        assert_eq!(body[0]["status"], "archived");
     }
 }
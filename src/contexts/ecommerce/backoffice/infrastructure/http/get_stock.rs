@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Returns the on-hand, reserved and available units of a product in each warehouse holding it.
+#[utoipa::path(
+    get,
+    path = "/product/{product_id}/stock",
+    tag = "inventory",
+    security(("Identity" = ["ecommerce.backoffice.inventory:read"])),
+    params(("product_id" = uuid::Uuid, Path, description = "Product the stock belongs to")),
+    responses(
+        (status = 200, description = "Stock per warehouse, by warehouse code", body = Vec<backoffice::domain::inventory::StockLevel>),
+        (status = 400, description = "Malformed product id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_stock(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetStock>>,
+    Path(product_id): Path<String>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeInventoryRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetStockInput { product_id })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetStock")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::GetStock> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_stock_usecase.clone()
+    }
+}
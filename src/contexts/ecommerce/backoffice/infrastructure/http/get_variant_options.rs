@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Returns the options a product varies along, e.g. colour or size, with the values its variants may pick.
+#[utoipa::path(
+    get,
+    path = "/product/{product_id}/options",
+    tag = "variant",
+    security(("Identity" = ["ecommerce.backoffice.variant:read"])),
+    params(("product_id" = uuid::Uuid, Path, description = "Product the options belong to")),
+    responses(
+        (status = 200, description = "Options in display order", body = Vec<backoffice::domain::variant::VariantOption>),
+        (status = 400, description = "Malformed product id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_variant_options(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetVariantOptions>>,
+    Path(product_id): Path<String>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeVariantRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetVariantOptionsInput { product_id })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetVariantOptions")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::GetVariantOptions>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_variant_options_usecase.clone()
+    }
+}
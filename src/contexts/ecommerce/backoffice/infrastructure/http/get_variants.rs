@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Returns the variants of a product with their SKU, picked options, price override and barcode.
+#[utoipa::path(
+    get,
+    path = "/product/{product_id}/variants",
+    tag = "variant",
+    security(("Identity" = ["ecommerce.backoffice.variant:read"])),
+    params(("product_id" = uuid::Uuid, Path, description = "Product the variants belong to")),
+    responses(
+        (status = 200, description = "Variants, oldest first", body = Vec<backoffice::domain::variant::Variant>),
+        (status = 400, description = "Malformed product id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_variants(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::GetVariants>>,
+    Path(product_id): Path<String>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeVariantRead)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::GetVariantsInput { product_id })
+            .instrument(tracing::debug_span!("Execute use case", name = "GetVariants")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::GetVariants> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.get_variants_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/variants";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, get(get_variants)).with_state(services)
+    }
+
+    async fn request(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/ecommerce/product/{product_id}/variants"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variant_when_request_then_return_200_with_it() {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeVariantRead
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let mut variant = backoffice::domain::variant::fixture::VariantBuilder::new(product.id);
+        variant.price = Some(common::domain::Money::new(379_900, common::domain::Currency::EUR));
+        variant.barcode = Some(backoffice::domain::variant::Barcode::try_from("036000291452").unwrap());
+        variant.save(&fixture.services.variant_repository).await;
+
+        let (status, body) = request(&fixture, &product.id).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 1);
+        assert_eq!(body[0]["sku"], variant.sku.to_primitive());
+        assert_eq!(body[0]["price"], json!({ "amount": "3799.00", "currency": "EUR" }));
+        assert_eq!(body[0]["barcode"], "036000291452");
+    }
+}
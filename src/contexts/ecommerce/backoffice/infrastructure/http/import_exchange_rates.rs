@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::{FromRef, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Room for the full ECB history since 1999, which is far above the default body limit.
+pub const EXCHANGE_RATE_FILE_LIMIT: usize = 32 * 1024 * 1024;
+
+/// Imports an ECB euro reference rate file, daily or historical, as XML or CSV.
+///
+/// Rates already known for the same currency and day are replaced, so the same file can be sent
+/// again. Retired currencies found in historical files are skipped and listed in the response.
+#[utoipa::path(
+    post,
+    path = "/exchange-rates",
+    tag = "exchange_rate",
+    security(("Identity" = ["ecommerce.backoffice.exchange_rate:write"])),
+    request_body(
+        content(
+            (String = "application/xml"),
+            (String = "text/csv"),
+        ),
+        description = "`eurofxref` XML envelope, or CSV with a `Date` column followed by one column per currency",
+    ),
+    responses(
+        (status = 200, description = "Imported", body = backoffice::application::usecases::ImportExchangeRatesOutput),
+        (status = 400, description = "Malformed file", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 413, description = "File too large", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Neither XML nor CSV", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Invalid rates, listed in `errors`", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn import_exchange_rates(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::ImportExchangeRates>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeExchangeRateWrite)?;
+
+    let mime = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<mime::Mime>().ok())
+        .ok_or(common::domain::Error::UnsupportedExchangeRateFileType)?;
+
+    let parse = match (mime.type_(), mime.subtype()) {
+        (mime::APPLICATION | mime::TEXT, mime::XML) => backoffice::infrastructure::ecb::parse_xml,
+        (mime::TEXT, mime::CSV) => backoffice::infrastructure::ecb::parse_csv,
+        _ => return Err(common::domain::Error::UnsupportedExchangeRateFileType),
+    };
+
+    let content = std::str::from_utf8(&body)
+        .map_err(|_| common::domain::Error::InvalidExchangeRateFile(String::from("not UTF-8 text")))?;
+
+    let rates = parse(content)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::ImportExchangeRatesInput { rates })
+            .instrument(tracing::debug_span!("Execute use case", name = "ImportExchangeRates")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::ImportExchangeRates>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.import_exchange_rates_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/exchange-rates";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new()
+            .route(PATH, post(import_exchange_rates))
+            .with_state(services)
+    }
+
+    fn compose_fixture() -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeExchangeRateWrite
+            .to_string()
+            .as_str()]);
+
+        fixture
+    }
+
+    async fn request(
+        fixture: common::infrastructure::controller::fixture::HttpContextFixture,
+        content_type: &str,
+        body: &'static str,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services)
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri(PATH)
+                    .header(http::header::AUTHORIZATION, fixture.token)
+                    .header(http::header::CONTENT_TYPE, content_type)
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_permissions_when_request_then_return_403() {
+        let fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+
+        let (status, _) = request(fixture, "text/csv", "Date,USD\n2024-05-10,1.0772\n").await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_xml_file_when_request_then_return_200_and_store_rates() {
+        let fixture = compose_fixture();
+        let repository = fixture.services.exchange_rate_repository.clone();
+
+        let (status, body) = request(
+            fixture,
+            "application/xml; charset=utf-8",
+            "<Cube><Cube time='2024-05-10'><Cube currency='USD' rate='1.0772'/></Cube></Cube>",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "imported": 1, "skipped_currencies": [] }));
+
+        let rate = repository
+            .get_latest(
+                common::domain::Currency::EUR,
+                common::domain::Currency::try_from("USD").unwrap(),
+                backoffice::domain::exchange_rate::fixture::date("2024-05-10"),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(rate.rate.to_primitive(), "1.0772");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_historical_csv_file_when_request_then_return_200_with_skipped_currencies() {
+        let (status, body) = request(
+            compose_fixture(),
+            "text/csv",
+            "Date,USD,CYP,\n2024-05-10,1.0772,N/A,\n2007-12-31,1.4721,0.585274,\n",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "imported": 2, "skipped_currencies": ["CYP"] }));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_malformed_file_when_request_then_return_400() {
+        let (status, body) = request(compose_fixture(), "text/csv", "Date,USD\nyesterday,1.0772\n").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_EXCHANGE_RATE_FILE");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_json_file_when_request_then_return_415() {
+        let (status, body) = request(compose_fixture(), "application/json", "{}").await;
+
+        assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(body["code"], "UNSUPPORTED_EXCHANGE_RATE_FILE_TYPE");
+    }
+}
@@ -1,5 +1,63 @@
+pub use adjust_stock::*;
+pub use assign_product_to_category::*;
+pub use change_product_status::*;
+pub use commit_reservation::*;
+pub use delete_product_price::*;
+pub use delete_variant::*;
+pub use get_categories::*;
+pub use get_category::*;
+pub use get_category_products::*;
+pub use get_current_product_price::*;
+pub use get_product_events::*;
+pub use get_product_price_history::*;
+pub use get_product_prices::*;
 pub use get_products::*;
+pub use get_stock::*;
+pub use get_stock_adjustments::*;
+pub use get_variant_options::*;
+pub use get_variants::*;
+pub use import_exchange_rates::*;
+pub use move_category::*;
+pub use objects::*;
+pub use release_reservation::*;
+pub use reserve_stock::*;
+pub use save_category::*;
 pub use save_product::*;
+pub use save_product_price::*;
+pub use save_variant::*;
+pub use schedule_product::*;
+pub use set_variant_options::*;
+pub use unassign_product_from_category::*;
+pub use update_product::*;
 
+mod adjust_stock;
+mod assign_product_to_category;
+mod change_product_status;
+mod commit_reservation;
+mod delete_product_price;
+mod delete_variant;
+mod get_categories;
+mod get_category;
+mod get_category_products;
+mod get_current_product_price;
+mod get_product_events;
+mod get_product_price_history;
+mod get_product_prices;
 mod get_products;
+mod get_stock;
+mod get_stock_adjustments;
+mod get_variant_options;
+mod get_variants;
+mod import_exchange_rates;
+mod move_category;
+mod objects;
+mod release_reservation;
+mod reserve_stock;
+mod save_category;
 mod save_product;
+mod save_product_price;
+mod save_variant;
+mod schedule_product;
+mod set_variant_options;
+mod unassign_product_from_category;
+mod update_product;
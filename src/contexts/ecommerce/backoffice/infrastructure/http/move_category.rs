@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Debug, Deserialize)]
+pub struct MoveCategoryBody {
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub position: Option<u32>,
+}
+
+/// Moves a category, with its whole subtree, below another parent or to the roots, and places it among
+/// its new siblings.
+#[utoipa::path(
+    put,
+    path = "/category/{category_id}/position",
+    tag = "category",
+    security(("Identity" = ["ecommerce.backoffice.category:write"])),
+    params(("category_id" = uuid::Uuid, Path, description = "Category to move")),
+    request_body = backoffice::infrastructure::http::MoveCategoryBody,
+    responses(
+        (status = 200, description = "Moved category", body = backoffice::domain::category::Category),
+        (status = 400, description = "Malformed category id, parent id or JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Category or parent not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Parent lies in the moved subtree, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn move_category(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::MoveCategory>>,
+    Path(category_id): Path<String>,
+    common::infrastructure::Json(body): common::infrastructure::Json<MoveCategoryBody>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeCategoryWrite)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::MoveCategoryInput {
+                id: category_id,
+                parent_id: body.parent_id,
+                position: body.position,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "MoveCategory")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::MoveCategory> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.move_category_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/category/:category_id/position";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(move_category)).with_state(services)
+    }
+
+    async fn request(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        uri: String,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(uri)
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_new_parent_when_request_then_return_200_with_moved_category() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryWrite);
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let moved = backoffice::domain::category::fixture::CategoryBuilder::default();
+        parent.save(&fixture.services.category_repository).await;
+        moved.save(&fixture.services.category_repository).await;
+
+        let (status, body) = request(
+            &fixture,
+            format!("/ecommerce/category/{}/position", moved.id),
+            json!({ "parent_id": parent.id.to_primitive(), "position": 3 }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["parent_id"], parent.id.to_primitive());
+        assert_eq!(body["position"], 0);
+        assert_eq!(body["depth"], 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_own_child_as_parent_when_request_then_return_422() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryWrite);
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        let child = backoffice::domain::category::fixture::CategoryBuilder::child_of(&parent);
+        parent.save(&fixture.services.category_repository).await;
+        child.save(&fixture.services.category_repository).await;
+
+        let (status, body) = request(
+            &fixture,
+            format!("/ecommerce/category/{}/position", parent.id),
+            json!({ "parent_id": child.id.to_primitive() }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "CATEGORY_CYCLE");
+    }
+}
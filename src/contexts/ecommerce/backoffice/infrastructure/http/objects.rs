@@ -0,0 +1,104 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+/// Lifecycle stage, only published products are live.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ProductStatus {
+    Draft,
+    Published,
+    Archived,
+}
+
+impl From<backoffice::domain::product::ProductStatus> for ProductStatus {
+    fn from(value: backoffice::domain::product::ProductStatus) -> Self {
+        match value {
+            backoffice::domain::product::ProductStatus::Draft => Self::Draft,
+            backoffice::domain::product::ProductStatus::Published => Self::Published,
+            backoffice::domain::product::ProductStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct Product {
+    #[schema(examples("4548cc0d-2379-427f-93e2-44ac0a0333c6"))]
+    pub id: uuid::Uuid,
+    #[schema(examples("Fender Stratocaster American Standard"))]
+    pub name: String,
+    #[schema(inline)]
+    pub price: common::domain::Money,
+    #[schema(inline)]
+    pub status: ProductStatus,
+    /// A draft gets published once this time is reached.
+    #[schema(value_type = Option<chrono::DateTime<chrono::offset::Utc>>, required = true)]
+    pub publish_at: Option<String>,
+    /// A published product gets archived once this time is reached.
+    #[schema(value_type = Option<chrono::DateTime<chrono::offset::Utc>>, required = true)]
+    pub unpublish_at: Option<String>,
+    #[schema(value_type = chrono::DateTime<chrono::offset::Utc>)]
+    pub updated_at: String,
+    #[schema(value_type = chrono::DateTime<chrono::offset::Utc>)]
+    pub created_at: String,
+}
+
+impl From<&backoffice::domain::product::Product> for Product {
+    fn from(value: &backoffice::domain::product::Product) -> Self {
+        Self {
+            id: value.id.to_uuid(),
+            name: value.name.to_primitive(),
+            price: value.price,
+            status: ProductStatus::from(value.status),
+            publish_at: value.publish_at.map(|publish_at| publish_at.to_primitive()),
+            unpublish_at: value.unpublish_at.map(|unpublish_at| unpublish_at.to_primitive()),
+            updated_at: value.updated_at.to_primitive(),
+            created_at: value.created_at.to_primitive(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ProductListItem {
+    #[schema(examples("4548cc0d-2379-427f-93e2-44ac0a0333c6"))]
+    pub id: uuid::Uuid,
+    #[schema(examples("Fender Stratocaster American Standard"))]
+    pub name: String,
+    #[schema(inline)]
+    pub price: common::domain::Money,
+    /// Price in the requested display currency at the latest reference rate, only present when one was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = common::domain::Money, inline)]
+    pub display_price: Option<common::domain::Money>,
+    #[schema(inline)]
+    pub status: ProductStatus,
+    /// A draft gets published once this time is reached.
+    #[schema(value_type = Option<chrono::DateTime<chrono::offset::Utc>>, required = true)]
+    pub publish_at: Option<String>,
+    /// A published product gets archived once this time is reached.
+    #[schema(value_type = Option<chrono::DateTime<chrono::offset::Utc>>, required = true)]
+    pub unpublish_at: Option<String>,
+    #[schema(value_type = chrono::DateTime<chrono::offset::Utc>)]
+    pub updated_at: String,
+    #[schema(value_type = chrono::DateTime<chrono::offset::Utc>)]
+    pub created_at: String,
+}
+
+impl From<&backoffice::application::usecases::ProductListItem> for ProductListItem {
+    fn from(value: &backoffice::application::usecases::ProductListItem) -> Self {
+        let product = Product::from(&value.product);
+
+        Self {
+            id: product.id,
+            name: product.name,
+            price: product.price,
+            display_price: value.display_price,
+            status: product.status,
+            publish_at: product.publish_at,
+            unpublish_at: product.unpublish_at,
+            updated_at: product.updated_at,
+            created_at: product.created_at,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Gives the units of a pending or expired reservation back to the warehouse.
+#[utoipa::path(
+    post,
+    path = "/product/{product_id}/reservations/{id}/release",
+    tag = "inventory",
+    security(("Identity" = ["ecommerce.backoffice.inventory:write"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product the reservation belongs to"),
+        ("id" = uuid::Uuid, Path, description = "Reservation id"),
+    ),
+    responses(
+        (status = 200, description = "Reservation after the release", body = backoffice::domain::inventory::Reservation),
+        (status = 400, description = "Malformed product or reservation id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Reservation not found on that product", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Reservation already committed or released", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn release_reservation(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::ReleaseReservation>>,
+    Path((product_id, id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeInventoryWrite)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::ReleaseReservationInput { id, product_id })
+            .instrument(tracing::debug_span!("Execute use case", name = "ReleaseReservation")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::ReleaseReservation>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.release_reservation_usecase.clone()
+    }
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Debug, Deserialize)]
+pub struct ReserveStockBody {
+    pub warehouse: String,
+    pub quantity: i64,
+    #[serde(default)]
+    pub ttl_seconds: Option<i64>,
+}
+
+/// Holds units of a warehouse until the reservation is committed, released or expires. Parallel
+/// reservations never hold more units than are on hand.
+#[utoipa::path(
+    put,
+    path = "/product/{product_id}/reservations/{id}",
+    tag = "inventory",
+    security(("Identity" = ["ecommerce.backoffice.inventory:write"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product to reserve"),
+        ("id" = uuid::Uuid, Path, description = "Reservation id, chosen by the client"),
+    ),
+    request_body = backoffice::infrastructure::http::ReserveStockBody,
+    responses(
+        (status = 201, description = "Reserved", body = backoffice::domain::inventory::Reservation),
+        (status = 400, description = "Malformed JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Reservation id taken, or not enough units available", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Invalid reservation fields, listed in `errors`, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn reserve_stock(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::ReserveStock>>,
+    Path((product_id, id)): Path<(String, String)>,
+    common::infrastructure::Json(body): common::infrastructure::Json<ReserveStockBody>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeInventoryWrite)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::ReserveStockInput {
+                id,
+                product_id,
+                warehouse: body.warehouse,
+                quantity: body.quantity,
+                ttl_seconds: body.ttl_seconds,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "ReserveStock")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::CREATED, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::ReserveStock> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.reserve_stock_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/reservations/:id";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(reserve_stock)).with_state(services)
+    }
+
+    async fn put_reservation(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let id = backoffice::domain::inventory::ReservationId::default();
+
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/ecommerce/product/{product_id}/reservations/{id}"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    async fn compose_fixture(
+        on_hand: i64,
+    ) -> (
+        common::infrastructure::controller::fixture::HttpContextFixture,
+        backoffice::domain::product::ProductId,
+    ) {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeInventoryWrite
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+        backoffice::domain::inventory::fixture::StockAdjustmentBuilder::new(product.id, "MAD-1", on_hand)
+            .save(&fixture.services.inventory_repository)
+            .await;
+
+        (fixture, product.id)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_available_units_when_request_then_return_201_with_pending_reservation() {
+        let (fixture, product_id) = compose_fixture(4).await;
+
+        let (status, body) = put_reservation(
+            &fixture,
+            &product_id,
+            json!({ "warehouse": "MAD-1", "quantity": 4, "ttl_seconds": 60 }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["status"], "pending");
+        assert_eq!(body["quantity"], 4);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_parallel_requests_when_request_then_never_reserve_more_than_on_hand() {
+        let (fixture, product_id) = compose_fixture(3).await;
+        let fixture = std::sync::Arc::new(fixture);
+
+        let handles = (0..12)
+            .map(|_| {
+                let fixture = fixture.clone();
+                tokio::spawn(async move {
+                    put_reservation(&fixture, &product_id, json!({ "warehouse": "MAD-1", "quantity": 1 })).await
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut statuses = Vec::new();
+        for handle in handles {
+            statuses.push(handle.await.unwrap().0);
+        }
+
+        assert_eq!(
+            statuses.iter().filter(|status| **status == StatusCode::CREATED).count(),
+            3
+        );
+        assert_eq!(
+            statuses
+                .iter()
+                .filter(|status| **status == StatusCode::CONFLICT)
+                .count(),
+            9
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_zero_quantity_when_request_then_return_422_with_pointer() {
+        let (fixture, product_id) = compose_fixture(3).await;
+
+        let (status, body) =
+            put_reservation(&fixture, &product_id, json!({ "warehouse": "MAD-1", "quantity": 0 })).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["pointer"], "/quantity");
+    }
+}
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Creates a category as the last child of its parent, or as the last root category without one.
+#[utoipa::path(
+    put,
+    path = "/category",
+    tag = "category",
+    security(("Identity" = ["ecommerce.backoffice.category:write"])),
+    request_body = backoffice::application::usecases::SaveCategoryInput,
+    responses(
+        (status = 202, description = "Accepted"),
+        (status = 400, description = "Malformed JSON body or parent id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Parent category not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Category already exists", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Invalid category fields, listed in `errors`, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn save_category(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::SaveCategory>>,
+    common::infrastructure::Json(body): common::infrastructure::Json<
+        backoffice::application::usecases::SaveCategoryInput,
+    >,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeCategoryWrite)?;
+
+    libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(body)
+            .instrument(tracing::debug_span!("Execute use case", name = "SaveCategory")),
+    )
+    .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::SaveCategory> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.save_category_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/category";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(save_category)).with_state(services)
+    }
+
+    async fn request(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        uri: String,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(uri)
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    fn body(
+        parent_id: Option<&backoffice::domain::category::CategoryId>,
+    ) -> (backoffice::domain::category::CategoryId, Value) {
+        let id = backoffice::domain::category::CategoryId::default();
+
+        (
+            id,
+            json!({ "id": id.to_primitive(), "parent_id": parent_id.map(|parent_id| parent_id.to_primitive()), "name": "Shoes" }),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_read_permission_only_when_request_then_return_403() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryRead);
+
+        let (status, _) = request(&fixture, String::from("/ecommerce/category"), body(None).1).await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_existing_parent_when_request_then_return_202_and_save_child() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryWrite);
+        let parent = backoffice::domain::category::fixture::CategoryBuilder::default();
+        parent.save(&fixture.services.category_repository).await;
+
+        let (id, body) = body(Some(&parent.id));
+        let (status, _) = request(&fixture, String::from("/ecommerce/category"), body).await;
+
+        assert_eq!(status, StatusCode::ACCEPTED);
+
+        let category = fixture
+            .services
+            .category_repository
+            .get_by_id(&id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(category.parent_id, Some(parent.id));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_existing_id_when_request_then_return_409() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeCategoryWrite);
+        let (_, body) = body(None);
+
+        request(&fixture, String::from("/ecommerce/category"), body.clone()).await;
+        let (status, body) = request(&fixture, String::from("/ecommerce/category"), body).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "CATEGORY_ALREADY_EXISTS");
+    }
+}
@@ -1,26 +1,74 @@
 use std::sync::Arc;
 
-use axum::extract;
 use axum::extract::{FromRef, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
+use serde::Deserialize;
 use tracing::Instrument;
+use utoipa::ToSchema;
 
 use crate::contexts::ecommerce::common::application::usecase::UseCase;
 use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
 
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SaveProductBody {
+    #[schema(format = Uuid, examples("4548cc0d-2379-427f-93e2-44ac0a0333c6"))]
+    pub id: String,
+    #[schema(examples("Fender Stratocaster American Standard"))]
+    pub name: String,
+    #[schema(inline)]
+    pub price: common::application::inputs::MoneyInput,
+}
+
+/// Creates a new product.
+#[utoipa::path(
+    put,
+    path = "/product",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product:create"])),
+    request_body = backoffice::infrastructure::http::SaveProductBody,
+    responses(
+        (status = 202, description = "Accepted"),
+        (status = 400, description = "Malformed JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Product already exists", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Invalid product fields, listed in `errors`, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
 #[axum::debug_handler]
 pub async fn save_product(
     identity_claims: common::infrastructure::IdentityClaims,
     State(usecase): State<Arc<backoffice::application::usecases::SaveProduct>>,
-    extract::Json(body): extract::Json<backoffice::application::usecases::SaveProductInput>,
+    common::infrastructure::Json(body): common::infrastructure::Json<SaveProductBody>,
 ) -> Result<impl IntoResponse, common::domain::Error> {
     identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductCreate)?;
 
-    usecase
-        .exec(body)
-        .instrument(tracing::debug_span!("Execute use case", name = "SaveProduct"))
-        .await?;
+    libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::SaveProductInput {
+                id: body.id,
+                name: body.name,
+                price: body.price,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "SaveProduct")),
+    )
+    .await?;
 
     Ok(StatusCode::ACCEPTED)
 }
@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Debug, Deserialize)]
+pub struct SaveProductPriceBody {
+    pub price: common::application::inputs::MoneyInput,
+    #[serde(default)]
+    pub valid_from: Option<chrono::DateTime<chrono::offset::Utc>>,
+    #[serde(default)]
+    pub valid_until: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+/// Creates or replaces an entry of a product price list.
+#[utoipa::path(
+    put,
+    path = "/product/{product_id}/prices/{id}",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product_price:write"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product the price belongs to"),
+        ("id" = uuid::Uuid, Path, description = "Price id, chosen by the client"),
+    ),
+    request_body = backoffice::infrastructure::http::SaveProductPriceBody,
+    responses(
+        (status = 202, description = "Accepted"),
+        (status = 400, description = "Malformed JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Price id taken by another product", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Invalid price fields, listed in `errors`, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn save_product_price(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::SaveProductPrice>>,
+    Path((product_id, id)): Path<(String, String)>,
+    common::infrastructure::Json(body): common::infrastructure::Json<SaveProductPriceBody>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductPriceWrite)?;
+
+    libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::SaveProductPriceInput {
+                id,
+                product_id,
+                price: body.price,
+                valid_from: body.valid_from,
+                valid_until: body.valid_until,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "SaveProductPrice")),
+    )
+    .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::SaveProductPrice> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.save_product_price_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/prices/:id";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(save_product_price)).with_state(services)
+    }
+
+    async fn put_price(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let id = backoffice::domain::product_price::ProductPriceId::default();
+
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/ecommerce/product/{product_id}/prices/{id}"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_read_permission_only_when_request_then_return_403() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductPriceRead);
+
+        let (status, _) = put_price(
+            &fixture,
+            &backoffice::domain::product::ProductId::default(),
+            json!({ "price": { "amount": "7.99", "currency": "EUR" } }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_promotion_when_request_then_return_202_and_add_it_to_price_list() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductPriceWrite);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, _) = put_price(
+            &fixture,
+            &product.id,
+            json!({
+                "price": { "amount": "7.99", "currency": "EUR" },
+                "valid_from": "2024-11-29T00:00:00Z",
+                "valid_until": "2024-12-02T00:00:00Z"
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::ACCEPTED);
+
+        let prices = fixture
+            .services
+            .product_price_repository
+            .get_by_product_id(&product.id)
+            .await
+            .unwrap();
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0].price.to_string(), "7.99 EUR");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_window_ending_before_it_starts_when_request_then_return_422_with_pointer() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductPriceWrite);
+
+        let (status, body) = put_price(
+            &fixture,
+            &backoffice::domain::product::ProductId::default(),
+            json!({
+                "price": { "amount": "7.99", "currency": "EUR" },
+                "valid_from": "2024-12-02T00:00:00Z",
+                "valid_until": "2024-11-29T00:00:00Z"
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["pointer"], "/valid_until");
+        assert_eq!(body["errors"][0]["code"], "INVALID_PRODUCT_PRICE_VALIDITY");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_request_then_return_404() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductPriceWrite);
+
+        let (status, body) = put_price(
+            &fixture,
+            &backoffice::domain::product::ProductId::default(),
+            json!({ "price": { "amount": "7.99", "currency": "EUR" } }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "PRODUCT_NOT_FOUND");
+    }
+}
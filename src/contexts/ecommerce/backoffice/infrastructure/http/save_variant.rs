@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Debug, Deserialize)]
+pub struct SaveVariantBody {
+    pub sku: String,
+    #[serde(default)]
+    pub options: BTreeMap<String, String>,
+    #[serde(default)]
+    pub price: Option<common::application::inputs::MoneyInput>,
+    #[serde(default)]
+    pub barcode: Option<String>,
+}
+
+/// Creates or replaces a variant of a product. Its options must pick one allowed value for each option
+/// of the product, and its SKU must not be used by any other variant of any product.
+#[utoipa::path(
+    put,
+    path = "/product/{product_id}/variants/{id}",
+    tag = "variant",
+    security(("Identity" = ["ecommerce.backoffice.variant:write"])),
+    params(
+        ("product_id" = uuid::Uuid, Path, description = "Product the variant belongs to"),
+        ("id" = uuid::Uuid, Path, description = "Variant id, chosen by the client"),
+    ),
+    request_body = backoffice::infrastructure::http::SaveVariantBody,
+    responses(
+        (status = 202, description = "Accepted"),
+        (status = 400, description = "Malformed JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "SKU or variant id taken", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Invalid variant fields, listed in `errors`, options not matching the product, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn save_variant(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::SaveVariant>>,
+    Path((product_id, id)): Path<(String, String)>,
+    common::infrastructure::Json(body): common::infrastructure::Json<SaveVariantBody>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeVariantWrite)?;
+
+    libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::SaveVariantInput {
+                id,
+                product_id,
+                sku: body.sku,
+                options: body.options,
+                price: body.price,
+                barcode: body.barcode,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "SaveVariant")),
+    )
+    .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::SaveVariant> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.save_variant_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/variants/:id";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(save_variant)).with_state(services)
+    }
+
+    async fn put_variant(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let id = backoffice::domain::variant::VariantId::default();
+
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/ecommerce/product/{product_id}/variants/{id}"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    async fn compose_fixture() -> (
+        common::infrastructure::controller::fixture::HttpContextFixture,
+        backoffice::domain::product::ProductId,
+    ) {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeVariantWrite
+            .to_string()
+            .as_str()]);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let colour = backoffice::domain::variant::VariantOption::new(
+            String::from("colour"),
+            vec![String::from("Sunburst"), String::from("Black")],
+        )
+        .unwrap();
+        fixture
+            .services
+            .variant_repository
+            .save_options(&product.id, &[colour])
+            .await
+            .unwrap();
+
+        (fixture, product.id)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_valid_variant_when_request_then_return_202_and_save_it() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, _) = put_variant(
+            &fixture,
+            &product_id,
+            json!({
+                "sku": "strat-sb",
+                "options": { "colour": "Sunburst" },
+                "price": { "amount": "3799.00", "currency": "EUR" },
+                "barcode": "4006381333931"
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::ACCEPTED);
+
+        let variants = fixture
+            .services
+            .variant_repository
+            .get_by_product_id(&product_id)
+            .await
+            .unwrap();
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].sku.to_primitive(), "STRAT-SB");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_wrong_check_digit_when_request_then_return_422_with_pointer() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, body) = put_variant(
+            &fixture,
+            &product_id,
+            json!({ "sku": "strat-sb", "options": { "colour": "Sunburst" }, "barcode": "4006381333932" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["pointer"], "/barcode");
+        assert_eq!(body["errors"][0]["code"], "INVALID_VARIANT_BARCODE");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_sku_of_another_variant_when_request_then_return_409() {
+        let (fixture, product_id) = compose_fixture().await;
+        let body = json!({ "sku": "strat-sb", "options": { "colour": "Sunburst" } });
+
+        put_variant(&fixture, &product_id, body.clone()).await;
+        let (status, body) = put_variant(&fixture, &product_id, body).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "VARIANT_SKU_ALREADY_EXISTS");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_option_the_product_lacks_when_request_then_return_422() {
+        let (fixture, product_id) = compose_fixture().await;
+
+        let (status, body) = put_variant(
+            &fixture,
+            &product_id,
+            json!({ "sku": "strat-sb", "options": { "colour": "Sunburst", "size": "L" } }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "INVALID_VARIANT_OPTIONS");
+    }
+}
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+use utoipa::ToSchema;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// A left out time clears that side of the schedule.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ScheduleProductBody {
+    /// A draft gets published once this time is reached.
+    #[serde(default)]
+    pub publish_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+    /// A published product gets archived once this time is reached.
+    #[serde(default)]
+    pub unpublish_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+/// Replaces the publishing schedule of a product, a background job applies it once each time is reached.
+#[utoipa::path(
+    put,
+    path = "/product/{product_id}/schedule",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product:update"])),
+    params(("product_id" = uuid::Uuid, Path, description = "Product to schedule")),
+    request_body = backoffice::infrastructure::http::ScheduleProductBody,
+    responses(
+        (status = 200, description = "Product with its new schedule", body = backoffice::infrastructure::http::Product),
+        (status = 400, description = "Malformed product id or JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Schedule not possible from the current status, or the status changed concurrently",
+            body = libs::problem_details::ProblemDetails, content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Unpublishing not after publishing, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn schedule_product(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::ScheduleProduct>>,
+    Path(product_id): Path<String>,
+    common::infrastructure::Json(body): common::infrastructure::Json<ScheduleProductBody>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductUpdate)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::ScheduleProductInput {
+                id: product_id,
+                publish_at: body.publish_at,
+                unpublish_at: body.unpublish_at,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "ScheduleProduct")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::ScheduleProduct> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.schedule_product_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/schedule";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(schedule_product)).with_state(services)
+    }
+
+    async fn put_schedule(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/ecommerce/product/{product_id}/schedule"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture() -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[common::domain::Permissions::EcommerceBackofficeProductUpdate
+            .to_string()
+            .as_str()]);
+
+        fixture
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_draft_when_request_then_return_200_with_schedule() {
+        let fixture = compose_fixture();
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, body) = put_schedule(
+            &fixture,
+            &product.id,
+            json!({ "publish_at": "2030-01-01T09:00:00Z", "unpublish_at": "2030-02-01T09:00:00Z" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "draft");
+        assert_eq!(body["publish_at"], "2030-01-01T09:00:00.000+00:00");
+        assert_eq!(body["unpublish_at"], "2030-02-01T09:00:00.000+00:00");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unpublish_before_publish_when_request_then_return_422() {
+        let fixture = compose_fixture();
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, body) = put_schedule(
+            &fixture,
+            &product.id,
+            json!({ "publish_at": "2030-02-01T09:00:00Z", "unpublish_at": "2030-01-01T09:00:00Z" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["code"], "INVALID_PRODUCT_SCHEDULE");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_published_product_when_request_publish_at_then_return_409() {
+        let fixture = compose_fixture();
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default()
+            .status(backoffice::domain::product::ProductStatus::Published);
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, body) = put_schedule(&fixture, &product.id, json!({ "publish_at": "2030-01-01T09:00:00Z" })).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "INVALID_PRODUCT_STATUS_TRANSITION");
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Replaces every option of a product at once. Options or values still picked by a variant cannot go.
+#[utoipa::path(
+    put,
+    path = "/product/{product_id}/options",
+    tag = "variant",
+    security(("Identity" = ["ecommerce.backoffice.variant:write"])),
+    params(("product_id" = uuid::Uuid, Path, description = "Product the options belong to")),
+    request_body = Vec<backoffice::application::usecases::VariantOptionInput>,
+    responses(
+        (status = 200, description = "Options as saved, names and values trimmed", body = Vec<backoffice::domain::variant::VariantOption>),
+        (status = 400, description = "Malformed JSON body or invalid option", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 409, description = "Option or value still picked by a variant", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn set_variant_options(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::SetVariantOptions>>,
+    Path(product_id): Path<String>,
+    common::infrastructure::Json(options): common::infrastructure::Json<
+        Vec<backoffice::application::usecases::VariantOptionInput>,
+    >,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeVariantWrite)?;
+
+    let output = libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::SetVariantOptionsInput { product_id, options })
+            .instrument(tracing::debug_span!("Execute use case", name = "SetVariantOptions")),
+    )
+    .await?;
+
+    Ok(libs::encoding::JsonResponse::with_status(StatusCode::OK, output))
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::SetVariantOptions>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.set_variant_options_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id/options";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(set_variant_options)).with_state(services)
+    }
+
+    async fn put_options(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/ecommerce/product/{product_id}/options"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_read_permission_only_when_request_then_return_403() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeVariantRead);
+
+        let (status, _) = put_options(
+            &fixture,
+            &backoffice::domain::product::ProductId::default(),
+            json!([{ "name": "colour", "values": ["Sunburst"] }]),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_options_when_request_then_return_200_with_them_trimmed() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeVariantWrite);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, body) = put_options(
+            &fixture,
+            &product.id,
+            json!([
+                { "name": " colour ", "values": ["Sunburst", "Olympic White"] },
+                { "name": "neck", "values": ["Maple", "Rosewood"] }
+            ]),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body,
+            json!([
+                { "name": "colour", "values": ["Sunburst", "Olympic White"] },
+                { "name": "neck", "values": ["Maple", "Rosewood"] }
+            ])
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_value_picked_by_a_variant_when_request_without_it_then_return_409() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeVariantWrite);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+        put_options(
+            &fixture,
+            &product.id,
+            json!([{ "name": "colour", "values": ["Sunburst", "Black"] }]),
+        )
+        .await;
+        backoffice::domain::variant::fixture::VariantBuilder::new(product.id)
+            .option("colour", "Black")
+            .save(&fixture.services.variant_repository)
+            .await;
+
+        let (status, body) = put_options(
+            &fixture,
+            &product.id,
+            json!([{ "name": "colour", "values": ["Sunburst"] }]),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "VARIANT_OPTIONS_IN_USE");
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+/// Removes a product from a category, removing one that is not assigned changes nothing.
+#[utoipa::path(
+    delete,
+    path = "/category/{category_id}/products/{product_id}",
+    tag = "category",
+    security(("Identity" = ["ecommerce.backoffice.category:write"])),
+    params(
+        ("category_id" = uuid::Uuid, Path, description = "Category to remove the product from"),
+        ("product_id" = uuid::Uuid, Path, description = "Product to remove"),
+    ),
+    responses(
+        (status = 204, description = "Removed"),
+        (status = 400, description = "Malformed category or product id", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn unassign_product_from_category(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::UnassignProductFromCategory>>,
+    Path((category_id, product_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeCategoryWrite)?;
+
+    libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::UnassignProductFromCategoryInput {
+                category_id,
+                product_id,
+            })
+            .instrument(tracing::debug_span!(
+                "Execute use case",
+                name = "UnassignProductFromCategory"
+            )),
+    )
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+impl FromRef<common::infrastructure::DependencyContainer>
+    for Arc<backoffice::application::usecases::UnassignProductFromCategory>
+{
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.unassign_product_from_category_usecase.clone()
+    }
+}
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRef, Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::Instrument;
+use utoipa::ToSchema;
+
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateProductBody {
+    #[schema(examples("Fender Stratocaster American Standard"))]
+    pub name: String,
+    #[schema(inline)]
+    pub price: common::application::inputs::MoneyInput,
+}
+
+/// Replaces the name and price of an existing product, a price change lands in its price history.
+#[utoipa::path(
+    put,
+    path = "/product/{product_id}",
+    tag = "product",
+    security(("Identity" = ["ecommerce.backoffice.product:update"])),
+    params(("product_id" = uuid::Uuid, Path, description = "Product to update")),
+    request_body = backoffice::infrastructure::http::UpdateProductBody,
+    responses(
+        (status = 202, description = "Accepted"),
+        (status = 400, description = "Malformed product id or JSON body", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 403, description = "Invalid permissions", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 404, description = "Product not found", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 415, description = "Missing JSON content type", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 422, description = "Invalid product fields, listed in `errors`, or wrong JSON types", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable, retryable", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+        (status = 504, description = "Database timeout", body = libs::problem_details::ProblemDetails,
+            content_type = "application/problem+json"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn update_product(
+    identity_claims: common::infrastructure::IdentityClaims,
+    State(usecase): State<Arc<backoffice::application::usecases::UpdateProduct>>,
+    Path(product_id): Path<String>,
+    common::infrastructure::Json(body): common::infrastructure::Json<UpdateProductBody>,
+) -> Result<impl IntoResponse, common::domain::Error> {
+    identity_claims.check_permission(common::domain::Permissions::EcommerceBackofficeProductUpdate)?;
+
+    libs::database::with_caller(
+        identity_claims.sub.clone(),
+        usecase
+            .exec(backoffice::application::usecases::UpdateProductInput {
+                id: product_id,
+                name: body.name,
+                price: body.price,
+            })
+            .instrument(tracing::debug_span!("Execute use case", name = "UpdateProduct")),
+    )
+    .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+impl FromRef<common::infrastructure::DependencyContainer> for Arc<backoffice::application::usecases::UpdateProduct> {
+    fn from_ref(input: &common::infrastructure::DependencyContainer) -> Self {
+        input.update_product_usecase.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::put;
+    use axum::{http, Router};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const PATH: &str = "/ecommerce/product/:product_id";
+
+    fn router(services: common::infrastructure::DependencyContainer) -> Router {
+        Router::new().route(PATH, put(update_product)).with_state(services)
+    }
+
+    async fn put_product(
+        fixture: &common::infrastructure::controller::fixture::HttpContextFixture,
+        product_id: &backoffice::domain::product::ProductId,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let response = router(fixture.services.clone())
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/ecommerce/product/{product_id}"))
+                    .header(http::header::AUTHORIZATION, fixture.token.clone())
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn compose_fixture(
+        permission: common::domain::Permissions,
+    ) -> common::infrastructure::controller::fixture::HttpContextFixture {
+        let mut fixture = common::infrastructure::controller::fixture::HttpContextFixture::in_memory();
+        fixture.with_permissions(&[permission.to_string().as_str()]);
+
+        fixture
+    }
+
+    fn body() -> Value {
+        json!({ "name": "Keyboard", "price": { "amount": "12.99", "currency": "EUR" } })
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_create_permission_only_when_request_then_return_403() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductCreate);
+
+        let (status, _) = put_product(&fixture, &backoffice::domain::product::ProductId::default(), body()).await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_request_then_return_404() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductUpdate);
+
+        let (status, body) = put_product(&fixture, &backoffice::domain::product::ProductId::default(), body()).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "PRODUCT_NOT_FOUND");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_new_price_when_request_then_return_202_and_record_price_change() {
+        let fixture = compose_fixture(common::domain::Permissions::EcommerceBackofficeProductUpdate);
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.services.product_repository).await;
+
+        let (status, _) = put_product(&fixture, &product.id, body()).await;
+
+        assert_eq!(status, StatusCode::ACCEPTED);
+
+        let history = fixture
+            .services
+            .product_price_history_repository
+            .get_by_product_id(&product.id, None, None)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].old_price, Some(product.price));
+        assert_eq!(history[1].new_price.to_string(), "12.99 EUR");
+    }
+}
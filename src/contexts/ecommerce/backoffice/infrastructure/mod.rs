@@ -1,9 +1,19 @@
 pub use controller::*;
-pub use extensions::*;
+pub use openapi::*;
+pub use product_event_watcher::*;
 pub use repositories::*;
+pub use scheduler::*;
 
 mod controller;
+mod ecb;
 mod extensions;
 pub mod graphql;
 pub mod http;
+mod openapi;
+mod product_event_watcher;
 mod repositories;
+mod scheduler;
+
+/// Schema for the embedded sqlite backend, applied on startup since there is no external migration step.
+pub static SQLITE_MIGRATOR: sqlx::migrate::Migrator =
+    sqlx::migrate!("src/contexts/ecommerce/backoffice/infrastructure/schema/sqlite");
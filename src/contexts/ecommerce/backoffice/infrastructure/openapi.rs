@@ -0,0 +1,105 @@
+use utoipa::OpenApi;
+
+use crate::contexts::ecommerce::backoffice;
+use crate::libs;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        backoffice::infrastructure::http::get_products,
+        backoffice::infrastructure::http::save_product,
+        backoffice::infrastructure::http::update_product,
+        backoffice::infrastructure::http::change_product_status,
+        backoffice::infrastructure::http::schedule_product,
+        backoffice::infrastructure::http::get_product_events,
+        backoffice::infrastructure::http::get_product_prices,
+        backoffice::infrastructure::http::get_product_price_history,
+        backoffice::infrastructure::http::get_current_product_price,
+        backoffice::infrastructure::http::save_product_price,
+        backoffice::infrastructure::http::delete_product_price,
+        backoffice::infrastructure::http::import_exchange_rates,
+        backoffice::infrastructure::http::get_categories,
+        backoffice::infrastructure::http::save_category,
+        backoffice::infrastructure::http::get_category,
+        backoffice::infrastructure::http::move_category,
+        backoffice::infrastructure::http::get_category_products,
+        backoffice::infrastructure::http::assign_product_to_category,
+        backoffice::infrastructure::http::unassign_product_from_category,
+        backoffice::infrastructure::http::get_variant_options,
+        backoffice::infrastructure::http::set_variant_options,
+        backoffice::infrastructure::http::get_variants,
+        backoffice::infrastructure::http::save_variant,
+        backoffice::infrastructure::http::delete_variant,
+        backoffice::infrastructure::http::get_stock,
+        backoffice::infrastructure::http::get_stock_adjustments,
+        backoffice::infrastructure::http::adjust_stock,
+        backoffice::infrastructure::http::reserve_stock,
+        backoffice::infrastructure::http::commit_reservation,
+        backoffice::infrastructure::http::release_reservation,
+    ),
+    components(schemas(libs::problem_details::ProblemDetails)),
+    tags(
+        (name = "product", description = "Backoffice product management"),
+        (name = "exchange_rate", description = "Reference rates for converting prices between currencies"),
+        (name = "category", description = "Catalogue category tree and the products assigned to it"),
+        (name = "variant", description = "Product options and the variants, each with its own SKU, picked from them"),
+        (name = "inventory", description = "Stock per warehouse, its adjustments and the reservations holding it"),
+    )
+)]
+pub struct ApiDoc;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::contexts::ecommerce::common;
+
+    use super::*;
+
+    #[test]
+    fn given_generated_spec_when_collect_security_scopes_then_match_permissions() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        let scopes: HashSet<String> = spec["paths"]
+            .as_object()
+            .unwrap()
+            .values()
+            .flat_map(|item| item.as_object().unwrap().values())
+            .flat_map(|operation| operation["security"].as_array().unwrap())
+            .flat_map(|requirement| requirement["Identity"].as_array().unwrap())
+            .map(|scope| scope.as_str().unwrap().to_string())
+            .collect();
+
+        assert_eq!(
+            scopes,
+            HashSet::from([
+                common::domain::Permissions::EcommerceBackofficeProductRead.to_string(),
+                common::domain::Permissions::EcommerceBackofficeProductCreate.to_string(),
+                common::domain::Permissions::EcommerceBackofficeProductUpdate.to_string(),
+                common::domain::Permissions::EcommerceBackofficeProductPriceRead.to_string(),
+                common::domain::Permissions::EcommerceBackofficeProductPriceWrite.to_string(),
+                common::domain::Permissions::EcommerceBackofficeExchangeRateWrite.to_string(),
+                common::domain::Permissions::EcommerceBackofficeCategoryRead.to_string(),
+                common::domain::Permissions::EcommerceBackofficeCategoryWrite.to_string(),
+                common::domain::Permissions::EcommerceBackofficeVariantRead.to_string(),
+                common::domain::Permissions::EcommerceBackofficeVariantWrite.to_string(),
+                common::domain::Permissions::EcommerceBackofficeInventoryRead.to_string(),
+                common::domain::Permissions::EcommerceBackofficeInventoryWrite.to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn given_generated_spec_when_read_product_price_then_describe_money() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        let price = &spec["components"]["schemas"]["Product"]["properties"]["price"];
+
+        assert_eq!(price["properties"]["amount"]["type"], "string");
+        assert_eq!(price["properties"]["currency"]["pattern"], "^[A-Z]{3}$");
+        assert_eq!(
+            spec["components"]["schemas"]["SaveProductBody"]["properties"]["price"],
+            *price
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Polls for new product events on behalf of every stream, which then only read once something arrived.
+///
+/// Polling starts with the first subscriber and stops once the last one is gone.
+#[derive(Clone)]
+pub struct ProductEventWatcher {
+    product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+    last_sequence: Arc<Mutex<Option<watch::Receiver<backoffice::domain::product_event::ProductEventSequence>>>>,
+    poll_interval: Duration,
+}
+
+impl ProductEventWatcher {
+    pub fn new(
+        product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+    ) -> Self {
+        Self {
+            product_event_repository,
+            last_sequence: Arc::new(Mutex::new(None)),
+            poll_interval: POLL_INTERVAL,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Receiver of the last readable sequence, it changes whenever new events can be read.
+    pub fn subscribe(&self) -> watch::Receiver<backoffice::domain::product_event::ProductEventSequence> {
+        let mut last_sequence = self.last_sequence.lock().unwrap();
+
+        if let Some(receiver) = last_sequence.as_ref().filter(|receiver| receiver.has_changed().is_ok()) {
+            return receiver.clone();
+        }
+
+        let (sender, receiver) = watch::channel(backoffice::domain::product_event::ProductEventSequence::default());
+        tokio::spawn(Self::poll(
+            self.product_event_repository.clone(),
+            sender,
+            self.poll_interval,
+        ));
+        *last_sequence = Some(receiver.clone());
+
+        receiver
+    }
+
+    async fn poll(
+        product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+        sender: watch::Sender<backoffice::domain::product_event::ProductEventSequence>,
+        poll_interval: Duration,
+    ) {
+        let mut ticker = tokio::time::interval(poll_interval);
+
+        // the watcher keeps one receiver around for later subscribers, that one alone is nobody listening
+        while sender.receiver_count() > 1 {
+            ticker.tick().await;
+
+            match product_event_repository
+                .get_last_sequence()
+                .instrument(tracing::info_span!("Invoke ProductEventRepository.get_last_sequence"))
+                .await
+            {
+                Ok(sequence) => {
+                    sender.send_if_modified(|last_sequence| {
+                        let modified = *last_sequence != sequence;
+                        *last_sequence = sequence;
+                        modified
+                    });
+                }
+                Err(err) => tracing::error!("could not poll product events: {err}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose_fixture() -> (
+        ProductEventWatcher,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let watcher = ProductEventWatcher::new(Arc::new(product_repository.events()))
+            .with_poll_interval(Duration::from_millis(10));
+
+        (watcher, Arc::new(product_repository))
+    }
+
+    #[tokio::test]
+    async fn given_subscribers_when_event_recorded_then_notify_every_one() {
+        let (watcher, repository) = compose_fixture();
+        let mut first = watcher.subscribe();
+        let mut second = watcher.subscribe();
+
+        backoffice::domain::product::fixture::ProductBuilder::default()
+            .save(&repository)
+            .await;
+
+        first.changed().await.unwrap();
+        second.changed().await.unwrap();
+        assert_eq!(first.borrow().to_primitive(), 1);
+        assert_eq!(second.borrow().to_primitive(), 1);
+    }
+
+    #[tokio::test]
+    async fn given_last_subscriber_gone_when_subscribe_again_then_poll_anew() {
+        let (watcher, repository) = compose_fixture();
+        let first = watcher.subscribe();
+        drop(first);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let mut second = watcher.subscribe();
+        backoffice::domain::product::fixture::ProductBuilder::default()
+            .save(&repository)
+            .await;
+
+        second.changed().await.unwrap();
+        assert_eq!(second.borrow().to_primitive(), 1);
+    }
+}
@@ -0,0 +1,496 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::Connection;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct PostgresCategoryRepository {
+    db: libs::postgres::Executor,
+    retry_policy: libs::postgres::retry::RetryPolicy,
+}
+
+impl PostgresCategoryRepository {
+    pub fn new(db: libs::postgres::ConnectionPool) -> Self {
+        Self {
+            db: db.into(),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Serves reads from the replica behind `router`, writes still go to its primary.
+    pub fn routed(router: Arc<libs::postgres::ReplicaRouter>) -> Self {
+        Self {
+            db: libs::postgres::Executor::Routed(router),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: libs::postgres::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
+
+static SELECT_BY_ID_SQL: &str = r#"
+    SELECT id, parent_id, name, position, path, created_at, updated_at
+    FROM category
+    WHERE id = $1
+"#;
+
+// tree writes shift sibling positions and rewrite subtree paths, taking them one at a time keeps
+// the tree consistent while reads carry on
+static LOCK_TREE_SQL: &str = "LOCK TABLE category IN SHARE ROW EXCLUSIVE MODE";
+
+#[async_trait]
+impl backoffice::domain::category::CategoryRepository for PostgresCategoryRepository {
+    type Error = common::domain::Error;
+
+    async fn get(&self) -> Result<Vec<backoffice::domain::category::Category>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, parent_id, name, position, path, created_at, updated_at
+            FROM category
+            ORDER BY created_at, id
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_categories",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .fetch_all(&mut *self.db.acquire_read("get_categories").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+    ) -> Result<Option<backoffice::domain::category::Category>, Self::Error> {
+        self.retry_policy
+            .run(
+                "get_category_by_id",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SELECT_BY_ID_SQL)
+                        .bind(id.to_uuid())
+                        .fetch_optional(&mut *self.db.acquire_read("get_category_by_id").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn save(&self, category: &backoffice::domain::category::Category) -> Result<(), Self::Error> {
+        // nothing is inserted when the parent does not exist
+        static SQL: &str = r#"
+            INSERT INTO category (id, parent_id, name, position, path)
+            SELECT $1::UUID,
+                   $2::UUID,
+                   $3,
+                   (SELECT COUNT(*) FROM category WHERE parent_id IS NOT DISTINCT FROM $2::UUID),
+                   COALESCE((SELECT path FROM category WHERE id = $2::UUID), '/') || CAST($1::UUID AS TEXT) || '/'
+            WHERE $2::UUID IS NULL OR EXISTS (SELECT 1 FROM category WHERE id = $2::UUID)
+        "#;
+
+        // a dropped connection may hide a committed insert, so repeating it could report a false conflict
+        let result = self
+            .retry_policy
+            .run(
+                "save_category",
+                libs::postgres::retry::Idempotency::NonIdempotent,
+                || async {
+                    let mut connection = self.db.acquire("save_category").await?;
+                    let mut transaction = connection.begin().await?;
+
+                    sqlx::query(LOCK_TREE_SQL).execute(&mut transaction).await?;
+                    let result = sqlx::query(SQL)
+                        .bind(category.id.to_uuid())
+                        .bind(category.parent_id.map(|parent_id| parent_id.to_uuid()))
+                        .bind(category.name.to_primitive())
+                        .execute(&mut transaction)
+                        .await?;
+
+                    transaction.commit().await?;
+
+                    Ok(result)
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::postgres::errcodes::Codes::from) {
+                    Some(libs::postgres::errcodes::Codes::UniqueViolation) => {
+                        common::domain::Error::CategoryAlreadyExists
+                    }
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        self.db.record_write();
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::CategoryNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn move_to(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        parent_id: Option<&backoffice::domain::category::CategoryId>,
+        position: i32,
+    ) -> Result<(), Self::Error> {
+        static CLOSE_GAP_SQL: &str = r#"
+            UPDATE category
+            SET position = position - 1
+            WHERE parent_id IS NOT DISTINCT FROM $1 AND position > $2
+        "#;
+        static COUNT_SIBLINGS_SQL: &str = r#"
+            SELECT COUNT(*)
+            FROM category
+            WHERE parent_id IS NOT DISTINCT FROM $1 AND id <> $2
+        "#;
+        static OPEN_GAP_SQL: &str = r#"
+            UPDATE category
+            SET position = position + 1
+            WHERE parent_id IS NOT DISTINCT FROM $1 AND id <> $2 AND position >= $3
+        "#;
+        static MOVE_SQL: &str = r#"
+            UPDATE category
+            SET parent_id = $2,
+                position  = $3
+            WHERE id = $1
+        "#;
+        static REWRITE_PATHS_SQL: &str = r#"
+            UPDATE category
+            SET path = $2 || substr(path, length($1) + 1)
+            WHERE path LIKE $1 || '%'
+        "#;
+
+        // the checks run against the locked tree, their rejections are kept apart from the database
+        // errors that may be retried
+        let outcome = self
+            .retry_policy
+            .run(
+                "move_category",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    let mut connection = self.db.acquire("move_category").await?;
+                    let mut transaction = connection.begin().await?;
+
+                    sqlx::query(LOCK_TREE_SQL).execute(&mut transaction).await?;
+
+                    let Some(category): Option<backoffice::domain::category::Category> =
+                        sqlx::query_as(SELECT_BY_ID_SQL)
+                            .bind(id.to_uuid())
+                            .fetch_optional(&mut transaction)
+                            .await?
+                    else {
+                        return Ok(Err(common::domain::Error::CategoryNotFound));
+                    };
+
+                    let parent: Option<backoffice::domain::category::Category> = match parent_id {
+                        Some(parent_id) => {
+                            match sqlx::query_as(SELECT_BY_ID_SQL)
+                                .bind(parent_id.to_uuid())
+                                .fetch_optional(&mut transaction)
+                                .await?
+                            {
+                                Some(parent) => Some(parent),
+                                None => return Ok(Err(common::domain::Error::CategoryNotFound)),
+                            }
+                        }
+                        None => None,
+                    };
+
+                    if let Err(error) = category.validate_parent(parent.as_ref()) {
+                        return Ok(Err(error));
+                    }
+
+                    let path = parent.as_ref().map_or_else(
+                        || backoffice::domain::category::CategoryPath::root(category.id),
+                        |parent| parent.path.child(category.id),
+                    );
+                    let parent_id = parent.map(|parent| parent.id.to_uuid());
+
+                    sqlx::query(CLOSE_GAP_SQL)
+                        .bind(category.parent_id.map(|parent_id| parent_id.to_uuid()))
+                        .bind(category.position)
+                        .execute(&mut transaction)
+                        .await?;
+
+                    let siblings: i64 = sqlx::query_scalar(COUNT_SIBLINGS_SQL)
+                        .bind(parent_id)
+                        .bind(category.id.to_uuid())
+                        .fetch_one(&mut transaction)
+                        .await?;
+                    let position = position.clamp(0, i32::try_from(siblings).unwrap_or(i32::MAX));
+
+                    sqlx::query(OPEN_GAP_SQL)
+                        .bind(parent_id)
+                        .bind(category.id.to_uuid())
+                        .bind(position)
+                        .execute(&mut transaction)
+                        .await?;
+
+                    sqlx::query(MOVE_SQL)
+                        .bind(category.id.to_uuid())
+                        .bind(parent_id)
+                        .bind(position)
+                        .execute(&mut transaction)
+                        .await?;
+
+                    sqlx::query(REWRITE_PATHS_SQL)
+                        .bind(category.path.to_primitive())
+                        .bind(path.to_primitive())
+                        .execute(&mut transaction)
+                        .await?;
+
+                    transaction.commit().await?;
+
+                    Ok(Ok(()))
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        self.db.record_write();
+
+        outcome
+    }
+
+    async fn assign_product(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            INSERT INTO product_category (category_id, product_id)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+        "#;
+
+        self.retry_policy
+            .run(
+                "assign_product_to_category",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query(SQL)
+                        .bind(id.to_uuid())
+                        .bind(product_id.to_uuid())
+                        .execute(&mut *self.db.acquire("assign_product_to_category").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|error| {
+                let Some(database_error) = error.as_database_error() else {
+                    return common::domain::Error::from(error);
+                };
+
+                match (
+                    libs::postgres::errcodes::Codes::from(database_error),
+                    database_error.constraint(),
+                ) {
+                    (
+                        libs::postgres::errcodes::Codes::ForeignKeyViolation,
+                        Some("product_category_category_id_fkey"),
+                    ) => common::domain::Error::CategoryNotFound,
+                    (libs::postgres::errcodes::Codes::ForeignKeyViolation, _) => common::domain::Error::ProductNotFound,
+                    _ => common::domain::Error::from(error),
+                }
+            })?;
+
+        self.db.record_write();
+
+        Ok(())
+    }
+
+    async fn unassign_product(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            DELETE FROM product_category
+            WHERE category_id = $1 AND product_id = $2
+        "#;
+
+        self.retry_policy
+            .run(
+                "unassign_product_from_category",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query(SQL)
+                        .bind(id.to_uuid())
+                        .bind(product_id.to_uuid())
+                        .execute(&mut *self.db.acquire("unassign_product_from_category").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        self.db.record_write();
+
+        Ok(())
+    }
+
+    async fn get_products(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        include_subcategories: bool,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
+            FROM product
+            WHERE EXISTS (
+                SELECT 1
+                FROM product_category
+                JOIN category ON category.id = product_category.category_id
+                WHERE product_category.product_id = product.id
+                  AND (category.id = $1
+                    OR ($2 AND category.path LIKE (SELECT path FROM category WHERE id = $1) || '%'))
+            )
+            ORDER BY created_at, id
+            LIMIT $3
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_category_products",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(id.to_uuid())
+                        .bind(include_subcategories)
+                        .bind(backoffice::domain::product::PRODUCT_PAGE_SIZE)
+                        .fetch_all(&mut *self.db.acquire_read("get_category_products").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contexts::ecommerce::backoffice;
+    use crate::libs;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+
+        (
+            Arc::new(PostgresCategoryRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::PostgresProductRepository::new(
+                database.pool,
+            )),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_categories_when_get_by_id_then_return_same_category() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_saved_categories_when_get_by_id_then_return_same_category(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_existing_id_when_save_then_return_already_exists() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_existing_id_when_save_then_return_already_exists(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_parent_when_save_then_return_category_not_found() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_unknown_parent_when_save_then_return_category_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_siblings_when_save_then_append_them_in_order() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_siblings_when_save_then_append_them_in_order(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_siblings_when_move_to_within_parent_then_reorder_them() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_siblings_when_move_to_within_parent_then_reorder_them(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_subtree_when_move_to_other_parent_then_rewrite_paths_and_positions() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_subtree_when_move_to_other_parent_then_rewrite_paths_and_positions(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_own_descendant_as_parent_when_move_to_then_return_cycle_and_keep_tree() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_own_descendant_as_parent_when_move_to_then_return_cycle_and_keep_tree(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_category_or_parent_when_move_to_then_return_category_not_found() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_unknown_category_or_parent_when_move_to_then_return_category_not_found(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_in_subtree_when_get_products_then_return_each_once_in_creation_order() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_products_in_subtree_when_get_products_then_return_each_once_in_creation_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_category_or_product_when_assign_product_then_return_not_found() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_unknown_category_or_product_when_assign_product_then_return_not_found(repository, products).await;
+    }
+}
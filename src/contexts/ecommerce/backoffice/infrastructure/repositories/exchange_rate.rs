@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct PostgresExchangeRateRepository {
+    db: libs::postgres::Executor,
+    retry_policy: libs::postgres::retry::RetryPolicy,
+}
+
+impl PostgresExchangeRateRepository {
+    pub fn new(db: libs::postgres::ConnectionPool) -> Self {
+        Self {
+            db: db.into(),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Serves reads from the replica behind `router`, writes still go to its primary.
+    pub fn routed(router: Arc<libs::postgres::ReplicaRouter>) -> Self {
+        Self {
+            db: libs::postgres::Executor::Routed(router),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: libs::postgres::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
+
+#[async_trait]
+impl backoffice::domain::exchange_rate::ExchangeRateRepository for PostgresExchangeRateRepository {
+    type Error = common::domain::Error;
+
+    async fn get_latest(
+        &self,
+        base: common::domain::Currency,
+        quote: common::domain::Currency,
+        on: chrono::NaiveDate,
+    ) -> Result<Option<backoffice::domain::exchange_rate::ExchangeRate>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT base, quote, rate::TEXT, valid_on
+            FROM exchange_rate
+            WHERE base = $1 AND quote = $2 AND valid_on <= $3
+            ORDER BY valid_on DESC
+            LIMIT 1
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_latest_exchange_rate",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(base.to_primitive())
+                        .bind(quote.to_primitive())
+                        .bind(on)
+                        .fetch_optional(&mut *self.db.acquire_read("get_latest_exchange_rate").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn save_many(&self, rates: &[backoffice::domain::exchange_rate::ExchangeRate]) -> Result<(), Self::Error> {
+        // one statement for the whole file, the rate travels as text and becomes an exact numeric here
+        static SQL: &str = r#"
+            INSERT INTO exchange_rate (base, quote, rate, valid_on)
+            SELECT base, quote, rate::NUMERIC, valid_on
+            FROM UNNEST($1::TEXT[], $2::TEXT[], $3::TEXT[], $4::DATE[]) AS imported (base, quote, rate, valid_on)
+            ON CONFLICT (base, quote, valid_on) DO UPDATE
+            SET rate = EXCLUDED.rate
+        "#;
+
+        // a row may only be touched once per statement, the last rate of a pair and day wins as in a loop
+        let mut latest: Vec<&backoffice::domain::exchange_rate::ExchangeRate> = Vec::with_capacity(rates.len());
+        for rate in rates {
+            latest.retain(|existing| {
+                (existing.base, existing.quote, existing.valid_on) != (rate.base, rate.quote, rate.valid_on)
+            });
+            latest.push(rate);
+        }
+
+        let bases: Vec<String> = latest.iter().map(|rate| rate.base.to_primitive()).collect();
+        let quotes: Vec<String> = latest.iter().map(|rate| rate.quote.to_primitive()).collect();
+        let values: Vec<String> = latest.iter().map(|rate| rate.rate.to_primitive()).collect();
+        let valid_ons: Vec<chrono::NaiveDate> = latest.iter().map(|rate| rate.valid_on).collect();
+
+        self.retry_policy
+            .run(
+                "save_exchange_rates",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query(SQL)
+                        .bind(&bases)
+                        .bind(&quotes)
+                        .bind(&values)
+                        .bind(&valid_ons)
+                        .execute(&mut *self.db.acquire("save_exchange_rates").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        self.db.record_write();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contexts::ecommerce::backoffice;
+    use crate::libs;
+
+    use super::*;
+
+    async fn compose_repository_fixture(
+    ) -> backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error> {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+
+        Arc::new(PostgresExchangeRateRepository::new(database.pool))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_rates_when_get_latest_then_return_none() {
+        backoffice::domain::exchange_rate::conformance::given_no_rates_when_get_latest_then_return_none(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_daily_rates_when_get_latest_then_return_last_published_on_or_before_date() {
+        backoffice::domain::exchange_rate::conformance::given_daily_rates_when_get_latest_then_return_last_published_on_or_before_date(compose_repository_fixture().await).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_rate_when_get_latest_then_return_same_rate() {
+        backoffice::domain::exchange_rate::conformance::given_saved_rate_when_get_latest_then_return_same_rate(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_rates_when_save_many_same_day_then_replace_them() {
+        backoffice::domain::exchange_rate::conformance::given_saved_rates_when_save_many_same_day_then_replace_them(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_same_pair_and_day_twice_when_save_many_then_keep_last() {
+        backoffice::domain::exchange_rate::conformance::given_same_pair_and_day_twice_when_save_many_then_keep_last(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_rates_when_save_many_nothing_then_succeed() {
+        backoffice::domain::exchange_rate::conformance::given_no_rates_when_save_many_nothing_then_succeed(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
+}
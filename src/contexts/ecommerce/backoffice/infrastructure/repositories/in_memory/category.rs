@@ -0,0 +1,285 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::Store;
+
+#[derive(Clone)]
+pub struct InMemoryCategoryRepository {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+#[async_trait]
+impl backoffice::domain::category::CategoryRepository for InMemoryCategoryRepository {
+    type Error = common::domain::Error;
+
+    async fn get(&self) -> Result<Vec<backoffice::domain::category::Category>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store.categories.clone())
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+    ) -> Result<Option<backoffice::domain::category::Category>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store.categories.iter().find(|category| &category.id == id).cloned())
+    }
+
+    async fn save(&self, category: &backoffice::domain::category::Category) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        if store.categories.iter().any(|existing| existing.id == category.id) {
+            return Err(common::domain::Error::CategoryAlreadyExists);
+        }
+
+        let path = match category.parent_id {
+            Some(parent_id) => store
+                .categories
+                .iter()
+                .find(|existing| existing.id == parent_id)
+                .ok_or(common::domain::Error::CategoryNotFound)?
+                .path
+                .child(category.id),
+            None => backoffice::domain::category::CategoryPath::root(category.id),
+        };
+        let siblings = store
+            .categories
+            .iter()
+            .filter(|existing| existing.parent_id == category.parent_id)
+            .count();
+
+        store.categories.push(backoffice::domain::category::Category {
+            position: i32::try_from(siblings).unwrap(),
+            path,
+            ..category.clone()
+        });
+
+        Ok(())
+    }
+
+    async fn move_to(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        parent_id: Option<&backoffice::domain::category::CategoryId>,
+        position: i32,
+    ) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        let find = |id: &backoffice::domain::category::CategoryId| {
+            store
+                .categories
+                .iter()
+                .find(|category| &category.id == id)
+                .cloned()
+                .ok_or(common::domain::Error::CategoryNotFound)
+        };
+
+        let category = find(id)?;
+        let parent = parent_id.map(find).transpose()?;
+
+        category.validate_parent(parent.as_ref())?;
+
+        let path = parent.as_ref().map_or_else(
+            || backoffice::domain::category::CategoryPath::root(category.id),
+            |parent| parent.path.child(category.id),
+        );
+        let parent_id = parent.map(|parent| parent.id);
+
+        for sibling in store.categories.iter_mut() {
+            if sibling.parent_id == category.parent_id && sibling.position > category.position {
+                sibling.position -= 1;
+            }
+        }
+
+        let siblings = store
+            .categories
+            .iter()
+            .filter(|sibling| sibling.parent_id == parent_id && sibling.id != category.id)
+            .count();
+        let position = position.clamp(0, i32::try_from(siblings).unwrap());
+
+        for existing in store.categories.iter_mut() {
+            if existing.id == category.id {
+                existing.parent_id = parent_id;
+                existing.position = position;
+            } else if existing.parent_id == parent_id && existing.position >= position {
+                existing.position += 1;
+            }
+
+            if existing.path.starts_with(&category.path) {
+                existing.path = existing.path.rebase(&category.path, &path);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn assign_product(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        if !store.categories.iter().any(|category| &category.id == id) {
+            return Err(common::domain::Error::CategoryNotFound);
+        }
+        if !store.products.iter().any(|product| &product.id == product_id) {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+        if !store.product_categories.contains(&(*id, *product_id)) {
+            store.product_categories.push((*id, *product_id));
+        }
+
+        Ok(())
+    }
+
+    async fn unassign_product(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        store
+            .product_categories
+            .retain(|assignment| assignment != &(*id, *product_id));
+
+        Ok(())
+    }
+
+    async fn get_products(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        include_subcategories: bool,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        let Some(root) = store.categories.iter().find(|category| &category.id == id) else {
+            return Ok(Vec::new());
+        };
+
+        let categories: Vec<_> = store
+            .categories
+            .iter()
+            .filter(|category| &category.id == id || (include_subcategories && category.path.starts_with(&root.path)))
+            .map(|category| category.id)
+            .collect();
+
+        Ok(store
+            .products_in_creation_order()
+            .filter(|product| {
+                store
+                    .product_categories
+                    .iter()
+                    .any(|(category_id, product_id)| product_id == &product.id && categories.contains(category_id))
+            })
+            .take(backoffice::domain::product::PRODUCT_PAGE_SIZE as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose_category_repository_fixture() -> (
+        backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+
+        (Arc::new(repository.categories()), Arc::new(repository))
+    }
+
+    #[tokio::test]
+    async fn given_saved_categories_when_get_by_id_then_return_same_category() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_saved_categories_when_get_by_id_then_return_same_category(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_existing_id_when_save_then_return_already_exists() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_existing_id_when_save_then_return_already_exists(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_unknown_parent_when_save_then_return_category_not_found() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_unknown_parent_when_save_then_return_category_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_siblings_when_save_then_append_them_in_order() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_siblings_when_save_then_append_them_in_order(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_siblings_when_move_to_within_parent_then_reorder_them() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_siblings_when_move_to_within_parent_then_reorder_them(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_subtree_when_move_to_other_parent_then_rewrite_paths_and_positions() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_subtree_when_move_to_other_parent_then_rewrite_paths_and_positions(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_own_descendant_as_parent_when_move_to_then_return_cycle_and_keep_tree() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_own_descendant_as_parent_when_move_to_then_return_cycle_and_keep_tree(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_unknown_category_or_parent_when_move_to_then_return_category_not_found() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_unknown_category_or_parent_when_move_to_then_return_category_not_found(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_products_in_subtree_when_get_products_then_return_each_once_in_creation_order() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_products_in_subtree_when_get_products_then_return_each_once_in_creation_order(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_unknown_category_or_product_when_assign_product_then_return_not_found() {
+        let (repository, products) = compose_category_repository_fixture();
+
+        backoffice::domain::category::conformance::given_unknown_category_or_product_when_assign_product_then_return_not_found(repository, products).await;
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::Store;
+
+#[derive(Clone)]
+pub struct InMemoryExchangeRateRepository {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+#[async_trait]
+impl backoffice::domain::exchange_rate::ExchangeRateRepository for InMemoryExchangeRateRepository {
+    type Error = common::domain::Error;
+
+    async fn get_latest(
+        &self,
+        base: common::domain::Currency,
+        quote: common::domain::Currency,
+        on: chrono::NaiveDate,
+    ) -> Result<Option<backoffice::domain::exchange_rate::ExchangeRate>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .exchange_rates
+            .iter()
+            .filter(|rate| rate.base == base && rate.quote == quote && rate.valid_on <= on)
+            .max_by_key(|rate| rate.valid_on)
+            .cloned())
+    }
+
+    async fn save_many(&self, rates: &[backoffice::domain::exchange_rate::ExchangeRate]) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        for rate in rates {
+            store.exchange_rates.retain(|existing| {
+                (existing.base, existing.quote, existing.valid_on) != (rate.base, rate.quote, rate.valid_on)
+            });
+            store.exchange_rates.push(rate.clone());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose_exchange_rate_repository_fixture(
+    ) -> backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error> {
+        Arc::new(backoffice::infrastructure::InMemoryProductRepository::new().exchange_rates())
+    }
+
+    #[tokio::test]
+    async fn given_no_rates_when_get_latest_then_return_none() {
+        backoffice::domain::exchange_rate::conformance::given_no_rates_when_get_latest_then_return_none(
+            compose_exchange_rate_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_daily_rates_when_get_latest_then_return_last_published_on_or_before_date() {
+        backoffice::domain::exchange_rate::conformance::given_daily_rates_when_get_latest_then_return_last_published_on_or_before_date(compose_exchange_rate_repository_fixture()).await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_rate_when_get_latest_then_return_same_rate() {
+        backoffice::domain::exchange_rate::conformance::given_saved_rate_when_get_latest_then_return_same_rate(
+            compose_exchange_rate_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_rates_when_save_many_same_day_then_replace_them() {
+        backoffice::domain::exchange_rate::conformance::given_saved_rates_when_save_many_same_day_then_replace_them(
+            compose_exchange_rate_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_no_rates_when_save_many_nothing_then_succeed() {
+        backoffice::domain::exchange_rate::conformance::given_no_rates_when_save_many_nothing_then_succeed(
+            compose_exchange_rate_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_same_pair_and_day_twice_when_save_many_then_keep_last() {
+        backoffice::domain::exchange_rate::conformance::given_same_pair_and_day_twice_when_save_many_then_keep_last(
+            compose_exchange_rate_repository_fixture(),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,331 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::Store;
+
+/// Checks and writes under the store lock, so concurrent reservations apply one after the other as
+/// they do under the database locks.
+pub struct InMemoryInventoryRepository {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+#[async_trait]
+impl backoffice::domain::inventory::InventoryRepository for InMemoryInventoryRepository {
+    type Error = common::domain::Error;
+
+    async fn get_stock(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Vec<backoffice::domain::inventory::StockLevel>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        let mut stock: Vec<_> = store
+            .stock
+            .iter()
+            .filter(|(id, _, _)| id == product_id)
+            .map(|(_, warehouse, on_hand)| backoffice::domain::inventory::StockLevel {
+                product_id: *product_id,
+                warehouse: warehouse.clone(),
+                on_hand: *on_hand,
+                reserved: store.reserved(product_id, warehouse, at),
+            })
+            .collect();
+        stock.sort_by(|a, b| a.warehouse.cmp(&b.warehouse));
+
+        Ok(stock)
+    }
+
+    async fn get_adjustments(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::inventory::StockAdjustment>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .stock_adjustments
+            .iter()
+            .filter(|adjustment| &adjustment.product_id == product_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn adjust(
+        &self,
+        adjustment: &backoffice::domain::inventory::StockAdjustment,
+    ) -> Result<backoffice::domain::inventory::StockLevel, Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        if !store.products.iter().any(|product| product.id == adjustment.product_id) {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+        if store
+            .stock_adjustments
+            .iter()
+            .any(|existing| existing.id == adjustment.id)
+        {
+            return Err(common::domain::Error::StockAdjustmentAlreadyExists);
+        }
+
+        let on_hand = store
+            .on_hand(&adjustment.product_id, &adjustment.warehouse)
+            .unwrap_or_default()
+            + adjustment.delta;
+        let reserved = store.reserved(&adjustment.product_id, &adjustment.warehouse, adjustment.created_at);
+        if on_hand < reserved {
+            return Err(common::domain::Error::InsufficientStock);
+        }
+
+        store.set_on_hand(&adjustment.product_id, &adjustment.warehouse, on_hand);
+        store.stock_adjustments.push(adjustment.clone());
+
+        Ok(backoffice::domain::inventory::StockLevel {
+            product_id: adjustment.product_id,
+            warehouse: adjustment.warehouse.clone(),
+            on_hand,
+            reserved,
+        })
+    }
+
+    async fn get_reservation(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+    ) -> Result<Option<backoffice::domain::inventory::Reservation>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .reservations
+            .iter()
+            .find(|reservation| &reservation.id == id && &reservation.product_id == product_id)
+            .cloned())
+    }
+
+    async fn reserve(&self, reservation: &backoffice::domain::inventory::Reservation) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        if store.reservations.iter().any(|existing| existing.id == reservation.id) {
+            return Err(common::domain::Error::ReservationAlreadyExists);
+        }
+
+        let on_hand = store
+            .on_hand(&reservation.product_id, &reservation.warehouse)
+            .unwrap_or_default();
+        let reserved = store.reserved(&reservation.product_id, &reservation.warehouse, reservation.created_at);
+        if on_hand - reserved < reservation.quantity {
+            return Err(common::domain::Error::InsufficientStock);
+        }
+
+        store.reservations.push(reservation.clone());
+
+        Ok(())
+    }
+
+    async fn commit(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<backoffice::domain::inventory::Reservation, Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        store.transition(product_id, id, at, backoffice::domain::inventory::Reservation::commit)
+    }
+
+    async fn release(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<backoffice::domain::inventory::Reservation, Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        store.transition(product_id, id, at, backoffice::domain::inventory::Reservation::release)
+    }
+}
+
+impl Store {
+    fn on_hand(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        warehouse: &backoffice::domain::inventory::WarehouseCode,
+    ) -> Option<i64> {
+        self.stock
+            .iter()
+            .find(|(id, code, _)| id == product_id && code == warehouse)
+            .map(|(_, _, on_hand)| *on_hand)
+    }
+
+    fn reserved(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        warehouse: &backoffice::domain::inventory::WarehouseCode,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> i64 {
+        self.reservations
+            .iter()
+            .filter(|reservation| &reservation.product_id == product_id && &reservation.warehouse == warehouse)
+            .filter(|reservation| {
+                reservation.status_at(at) == backoffice::domain::inventory::ReservationStatus::Pending
+            })
+            .map(|reservation| reservation.quantity)
+            .sum()
+    }
+
+    fn set_on_hand(
+        &mut self,
+        product_id: &backoffice::domain::product::ProductId,
+        warehouse: &backoffice::domain::inventory::WarehouseCode,
+        on_hand: i64,
+    ) {
+        match self
+            .stock
+            .iter_mut()
+            .find(|(id, code, _)| id == product_id && code == warehouse)
+        {
+            Some(stock) => stock.2 = on_hand,
+            None => self.stock.push((*product_id, warehouse.clone(), on_hand)),
+        }
+    }
+
+    fn transition(
+        &mut self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+        transition: fn(
+            &backoffice::domain::inventory::Reservation,
+            backoffice::domain::product::ProductTimeStamp,
+        ) -> Result<backoffice::domain::inventory::Reservation, common::domain::Error>,
+    ) -> Result<backoffice::domain::inventory::Reservation, common::domain::Error> {
+        let Some(index) = self
+            .reservations
+            .iter()
+            .position(|reservation| &reservation.id == id && &reservation.product_id == product_id)
+        else {
+            return Err(common::domain::Error::ReservationNotFound);
+        };
+
+        let next = transition(&self.reservations[index], at)?;
+        if next.status == backoffice::domain::inventory::ReservationStatus::Committed {
+            let on_hand = self.on_hand(product_id, &next.warehouse).unwrap_or_default();
+            self.set_on_hand(product_id, &next.warehouse, on_hand - next.quantity);
+        }
+        self.reservations[index] = next.clone();
+
+        Ok(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose_inventory_repository_fixture() -> (
+        backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+
+        (Arc::new(repository.inventory()), Arc::new(repository))
+    }
+
+    #[tokio::test]
+    async fn given_no_stock_when_get_then_return_empty_vecs() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_no_stock_when_get_then_return_empty_vecs(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_adjustments_when_get_stock_then_sum_them_per_warehouse_in_code_order() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_adjustments_when_get_stock_then_sum_them_per_warehouse_in_code_order(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_reserved_stock_when_adjust_below_it_then_return_insufficient_stock_and_keep_stock() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_reserved_stock_when_adjust_below_it_then_return_insufficient_stock_and_keep_stock(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_adjustment_when_adjust_with_same_id_then_return_already_exists() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_saved_adjustment_when_adjust_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_adjust_then_return_product_not_found() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_unknown_product_when_adjust_then_return_product_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_stock_when_reserve_then_hold_it_until_released() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_stock_when_reserve_then_hold_it_until_released(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_pending_reservation_when_commit_then_take_it_out_of_on_hand_once() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_pending_reservation_when_commit_then_take_it_out_of_on_hand_once(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_expired_reservation_when_reserve_then_ignore_it_and_refuse_its_commit() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_expired_reservation_when_reserve_then_ignore_it_and_refuse_its_commit(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_reservation_when_reserve_with_same_id_then_return_already_exists() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_saved_reservation_when_reserve_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_reservation_of_other_product_when_commit_or_release_then_return_not_found() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_reservation_of_other_product_when_commit_or_release_then_return_not_found(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_stock_when_reserve_concurrently_then_never_oversell() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_stock_when_reserve_concurrently_then_never_oversell(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_pending_reservation_when_commit_and_release_concurrently_then_apply_only_one() {
+        let (repository, products) = compose_inventory_repository_fixture();
+
+        backoffice::domain::inventory::conformance::given_pending_reservation_when_commit_and_release_concurrently_then_apply_only_one(repository, products).await;
+    }
+}
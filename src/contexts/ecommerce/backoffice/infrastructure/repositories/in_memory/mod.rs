@@ -0,0 +1,48 @@
+use crate::contexts::ecommerce::backoffice;
+
+pub use category::*;
+pub use exchange_rate::*;
+pub use inventory::*;
+pub use product::*;
+pub use product_event::*;
+pub use product_price::*;
+pub use product_price_history::*;
+pub use unit_of_work::*;
+pub use variant::*;
+
+mod category;
+mod exchange_rate;
+mod inventory;
+mod product;
+mod product_event;
+mod product_price;
+mod product_price_history;
+mod unit_of_work;
+mod variant;
+
+/// State behind every in-memory repository, shared so that writes through one show up in the others.
+#[derive(Clone, Default)]
+struct Store {
+    products: Vec<backoffice::domain::product::Product>,
+    events: Vec<backoffice::domain::product_event::ProductEvent>,
+    prices: Vec<backoffice::domain::product_price::ProductPrice>,
+    price_changes: Vec<backoffice::domain::product_price_history::ProductPriceChange>,
+    exchange_rates: Vec<backoffice::domain::exchange_rate::ExchangeRate>,
+    categories: Vec<backoffice::domain::category::Category>,
+    product_categories: Vec<(
+        backoffice::domain::category::CategoryId,
+        backoffice::domain::product::ProductId,
+    )>,
+    variant_options: Vec<(
+        backoffice::domain::product::ProductId,
+        Vec<backoffice::domain::variant::VariantOption>,
+    )>,
+    variants: Vec<backoffice::domain::variant::Variant>,
+    stock: Vec<(
+        backoffice::domain::product::ProductId,
+        backoffice::domain::inventory::WarehouseCode,
+        i64,
+    )>,
+    stock_adjustments: Vec<backoffice::domain::inventory::StockAdjustment>,
+    reservations: Vec<backoffice::domain::inventory::Reservation>,
+}
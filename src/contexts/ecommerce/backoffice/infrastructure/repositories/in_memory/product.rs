@@ -0,0 +1,340 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::Store;
+
+/// Lists products by creation time then id, like the database, and records events and price changes on every
+/// write as the database triggers do.
+#[derive(Clone, Default)]
+pub struct InMemoryProductRepository {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+impl InMemoryProductRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Event log fed by this repository.
+    pub fn events(&self) -> backoffice::infrastructure::InMemoryProductEventRepository {
+        backoffice::infrastructure::InMemoryProductEventRepository {
+            store: self.store.clone(),
+        }
+    }
+
+    /// Price lists of the products kept by this repository.
+    pub fn prices(&self) -> backoffice::infrastructure::InMemoryProductPriceRepository {
+        backoffice::infrastructure::InMemoryProductPriceRepository {
+            store: self.store.clone(),
+        }
+    }
+
+    /// Price history fed by this repository.
+    pub fn price_history(&self) -> backoffice::infrastructure::InMemoryProductPriceHistoryRepository {
+        backoffice::infrastructure::InMemoryProductPriceHistoryRepository {
+            store: self.store.clone(),
+        }
+    }
+
+    /// Exchange rates kept next to the products, so that one fixture holds a whole backoffice.
+    pub fn exchange_rates(&self) -> backoffice::infrastructure::InMemoryExchangeRateRepository {
+        backoffice::infrastructure::InMemoryExchangeRateRepository {
+            store: self.store.clone(),
+        }
+    }
+
+    /// Category tree the products of this repository get assigned to.
+    pub fn categories(&self) -> backoffice::infrastructure::InMemoryCategoryRepository {
+        backoffice::infrastructure::InMemoryCategoryRepository {
+            store: self.store.clone(),
+        }
+    }
+
+    /// Options and variants of the products kept by this repository.
+    pub fn variants(&self) -> backoffice::infrastructure::InMemoryVariantRepository {
+        backoffice::infrastructure::InMemoryVariantRepository {
+            store: self.store.clone(),
+        }
+    }
+
+    /// Stock of the products kept by this repository.
+    pub fn inventory(&self) -> backoffice::infrastructure::InMemoryInventoryRepository {
+        backoffice::infrastructure::InMemoryInventoryRepository {
+            store: self.store.clone(),
+        }
+    }
+
+    /// Units of work writing into this repository.
+    pub fn unit_of_work_factory(&self) -> backoffice::infrastructure::InMemoryUnitOfWorkFactory {
+        backoffice::infrastructure::InMemoryUnitOfWorkFactory {
+            store: self.store.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl backoffice::domain::product::ProductRepository for InMemoryProductRepository {
+    type Error = common::domain::Error;
+
+    async fn get(
+        &self,
+        status: Option<backoffice::domain::product::ProductStatus>,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .products_in_creation_order()
+            .filter(|product| status.map_or(true, |status| product.status == status))
+            .take(backoffice::domain::product::PRODUCT_PAGE_SIZE as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &backoffice::domain::product::ProductId,
+    ) -> Result<Option<backoffice::domain::product::Product>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store.products.iter().find(|product| &product.id == id).cloned())
+    }
+
+    async fn get_many_by_ids(
+        &self,
+        ids: &[backoffice::domain::product::ProductId],
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .products
+            .iter()
+            .filter(|product| ids.contains(&product.id))
+            .cloned()
+            .collect())
+    }
+
+    async fn save(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+        self.store.lock().unwrap().save_product(product)
+    }
+
+    async fn update(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+        self.store.lock().unwrap().update_product(product)
+    }
+
+    async fn update_status(
+        &self,
+        product: &backoffice::domain::product::Product,
+        from: backoffice::domain::product::ProductStatus,
+    ) -> Result<(), Self::Error> {
+        self.store.lock().unwrap().update_product_status(product, from)
+    }
+
+    async fn get_scheduled(
+        &self,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .products_in_creation_order()
+            .filter(|product| product.apply_schedule(at).is_some())
+            .take(backoffice::domain::product::PRODUCT_PAGE_SIZE as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+impl Store {
+    pub(super) fn products_in_creation_order(&self) -> impl Iterator<Item = &backoffice::domain::product::Product> {
+        let mut products: Vec<_> = self.products.iter().collect();
+        products.sort_by_key(|product| (product.created_at.to_datetime(), product.id.to_uuid()));
+
+        products.into_iter()
+    }
+
+    pub(super) fn save_product(&mut self, product: &backoffice::domain::product::Product) -> Result<(), common::domain::Error> {
+        if self.products.iter().any(|existing| existing.id == product.id) {
+            return Err(common::domain::Error::ProductAlreadyExists);
+        }
+
+        self.record_event(backoffice::domain::product_event::ProductEventKind::Created, product)?;
+        self.record_price_change(None, product);
+        self.products.push(product.clone());
+
+        Ok(())
+    }
+
+    pub(super) fn update_product(&mut self, product: &backoffice::domain::product::Product) -> Result<(), common::domain::Error> {
+        let Some(index) = self.products.iter().position(|existing| existing.id == product.id) else {
+            return Err(common::domain::Error::ProductNotFound);
+        };
+
+        let existing = &self.products[index];
+        // status and schedule only change through update_status, as in the database
+        let updated = backoffice::domain::product::Product {
+            status: existing.status,
+            publish_at: existing.publish_at,
+            unpublish_at: existing.unpublish_at,
+            created_at: existing.created_at,
+            updated_at: backoffice::domain::product::ProductTimeStamp::default(),
+            ..product.clone()
+        };
+        let old_price = existing.price;
+
+        self.record_event(backoffice::domain::product_event::ProductEventKind::Updated, &updated)?;
+        if old_price != updated.price {
+            self.record_price_change(Some(old_price), &updated);
+        }
+        self.products[index] = updated;
+
+        Ok(())
+    }
+
+    pub(super) fn update_product_status(
+        &mut self,
+        product: &backoffice::domain::product::Product,
+        from: backoffice::domain::product::ProductStatus,
+    ) -> Result<(), common::domain::Error> {
+        let Some(index) = self.products.iter().position(|existing| existing.id == product.id) else {
+            return Err(common::domain::Error::ProductNotFound);
+        };
+
+        let existing = &self.products[index];
+        if existing.status != from {
+            return Err(common::domain::Error::ProductStatusConflict);
+        }
+
+        let updated = backoffice::domain::product::Product {
+            status: product.status,
+            publish_at: product.publish_at,
+            unpublish_at: product.unpublish_at,
+            updated_at: backoffice::domain::product::ProductTimeStamp::default(),
+            ..existing.clone()
+        };
+
+        self.record_event(backoffice::domain::product_event::ProductEventKind::Updated, &updated)?;
+        self.products[index] = updated;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose_repository_fixture() -> backoffice::domain::product::DynProductRepository<common::domain::Error> {
+        Arc::new(InMemoryProductRepository::new())
+    }
+
+    #[tokio::test]
+    async fn given_empty_store_when_get_then_return_empty_vec() {
+        backoffice::domain::product::conformance::given_empty_store_when_get_then_return_empty_vec(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_products_when_get_then_return_them_in_creation_order() {
+        backoffice::domain::product::conformance::given_products_when_get_then_return_them_in_creation_order(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_products_created_at_same_time_when_get_then_order_them_by_id() {
+        backoffice::domain::product::conformance::given_products_created_at_same_time_when_get_then_order_them_by_id(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_more_products_than_page_size_when_get_then_return_one_page() {
+        backoffice::domain::product::conformance::given_more_products_than_page_size_when_get_then_return_one_page(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_empty_store_when_get_by_id_then_return_none() {
+        backoffice::domain::product::conformance::given_empty_store_when_get_by_id_then_return_none(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_product_when_get_by_id_then_return_same_product() {
+        backoffice::domain::product::conformance::given_saved_product_when_get_by_id_then_return_same_product(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_price_beyond_i32_in_three_decimal_currency_when_save_then_keep_exact_amount() {
+        backoffice::domain::product::conformance::given_price_beyond_i32_in_three_decimal_currency_when_save_then_keep_exact_amount(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_products_when_get_many_by_ids_then_return_only_matching() {
+        backoffice::domain::product::conformance::given_products_when_get_many_by_ids_then_return_only_matching(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_product_when_save_with_same_id_then_return_already_exists() {
+        backoffice::domain::product::conformance::given_saved_product_when_save_with_same_id_then_return_already_exists(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_product_when_update_then_return_new_name_and_price() {
+        backoffice::domain::product::conformance::given_saved_product_when_update_then_return_new_name_and_price(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_update_then_return_not_found() {
+        backoffice::domain::product::conformance::given_unknown_product_when_update_then_return_not_found(
+            compose_repository_fixture(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_products_in_every_status_when_get_with_status_then_return_only_matching() {
+        backoffice::domain::product::conformance::given_products_in_every_status_when_get_with_status_then_return_only_matching(compose_repository_fixture()).await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_product_when_update_status_then_return_new_status_and_schedule() {
+        backoffice::domain::product::conformance::given_saved_product_when_update_status_then_return_new_status_and_schedule(compose_repository_fixture()).await;
+    }
+
+    #[tokio::test]
+    async fn given_status_changed_meanwhile_when_update_status_then_return_conflict() {
+        backoffice::domain::product::conformance::given_status_changed_meanwhile_when_update_status_then_return_conflict(compose_repository_fixture()).await;
+    }
+
+    #[tokio::test]
+    async fn given_scheduled_products_when_get_scheduled_then_return_only_due_ones() {
+        backoffice::domain::product::conformance::given_scheduled_products_when_get_scheduled_then_return_only_due_ones(compose_repository_fixture()).await;
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::Store;
+
+#[derive(Clone)]
+pub struct InMemoryProductEventRepository {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+#[async_trait]
+impl backoffice::domain::product_event::ProductEventRepository for InMemoryProductEventRepository {
+    type Error = common::domain::Error;
+
+    async fn get_after(
+        &self,
+        sequence: &backoffice::domain::product_event::ProductEventSequence,
+        limit: i64,
+    ) -> Result<Vec<backoffice::domain::product_event::ProductEvent>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .events
+            .iter()
+            .filter(|event| &event.sequence > sequence)
+            .take(limit.max(0) as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_last_sequence(&self) -> Result<backoffice::domain::product_event::ProductEventSequence, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store.events.last().map(|event| event.sequence).unwrap_or_default())
+    }
+}
+
+impl Store {
+    pub(super) fn record_event(
+        &mut self,
+        kind: backoffice::domain::product_event::ProductEventKind,
+        product: &backoffice::domain::product::Product,
+    ) -> Result<(), common::domain::Error> {
+        let sequence = backoffice::domain::product_event::ProductEventSequence::try_from(self.events.len() as i64 + 1)?;
+
+        self.events.push(backoffice::domain::product_event::ProductEvent {
+            sequence,
+            kind,
+            product_id: product.id,
+            // same shape as the row the database trigger records
+            payload: serde_json::json!({
+                "id": product.id.to_primitive(),
+                "name": product.name.to_primitive(),
+                "price": product.price.minor_units(),
+                "currency": product.price.currency().to_primitive(),
+                "status": product.status.to_primitive(),
+                "publish_at": product.publish_at.map(|publish_at| publish_at.to_primitive()),
+                "unpublish_at": product.unpublish_at.map(|unpublish_at| unpublish_at.to_primitive()),
+                "created_at": product.created_at.to_primitive(),
+                "updated_at": product.updated_at.to_primitive(),
+            }),
+            occurred_at: backoffice::domain::product::ProductTimeStamp::default(),
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn given_saved_products_when_get_events_after_then_return_created_events_in_order() {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let events = repository.events();
+
+        let p1 = backoffice::domain::product::fixture::ProductBuilder::default();
+        let p2 = backoffice::domain::product::fixture::ProductBuilder::default();
+        backoffice::domain::product::ProductRepository::save(&repository, &p1.to_entity())
+            .await
+            .unwrap();
+        backoffice::domain::product::ProductRepository::save(&repository, &p2.to_entity())
+            .await
+            .unwrap();
+
+        let after = backoffice::domain::product_event::ProductEventSequence::default();
+        let events = backoffice::domain::product_event::ProductEventRepository::get_after(&events, &after, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].product_id, p1.id);
+        assert_eq!(events[1].product_id, p2.id);
+        assert_eq!(
+            events[1].kind,
+            backoffice::domain::product_event::ProductEventKind::Created
+        );
+    }
+
+    #[tokio::test]
+    async fn given_saved_product_when_get_after_then_return_payload_of_public_columns() {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+
+        backoffice::domain::product_event::conformance::given_saved_product_when_get_after_then_return_payload_of_public_columns(
+            Arc::new(repository.events()),
+            Arc::new(repository),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_status_transition_when_get_after_then_return_payload_with_new_status() {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+
+        backoffice::domain::product_event::conformance::given_status_transition_when_get_after_then_return_payload_with_new_status(
+            Arc::new(repository.events()),
+            Arc::new(repository),
+        )
+        .await;
+    }
+}
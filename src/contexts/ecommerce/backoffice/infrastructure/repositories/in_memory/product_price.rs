@@ -0,0 +1,207 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::Store;
+
+#[derive(Clone)]
+pub struct InMemoryProductPriceRepository {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+#[async_trait]
+impl backoffice::domain::product_price::ProductPriceRepository for InMemoryProductPriceRepository {
+    type Error = common::domain::Error;
+
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::product_price::ProductPrice>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        let mut prices: Vec<_> = store
+            .prices
+            .iter()
+            .filter(|price| &price.product_id == product_id)
+            .cloned()
+            .collect();
+
+        // stable, so prices starting at the same time stay in insertion order
+        prices.sort_by(|a, b| {
+            (a.price.currency().code(), a.valid_from)
+                .partial_cmp(&(b.price.currency().code(), b.valid_from))
+                .unwrap()
+        });
+
+        Ok(prices)
+    }
+
+    async fn get_current(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        currency: common::domain::Currency,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Option<backoffice::domain::product_price::ProductPrice>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        // the last of equally ranked prices was saved last
+        Ok(store
+            .prices
+            .iter()
+            .filter(|price| &price.product_id == product_id && price.price.currency() == currency)
+            .filter(|price| price.is_valid_at(at))
+            .fold(
+                None,
+                |current: Option<&backoffice::domain::product_price::ProductPrice>, price| match current {
+                    Some(current) if current.valid_from > price.valid_from => Some(current),
+                    _ => Some(price),
+                },
+            )
+            .cloned())
+    }
+
+    async fn save(&self, price: &backoffice::domain::product_price::ProductPrice) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        if !store.products.iter().any(|product| product.id == price.product_id) {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+
+        match store.prices.iter_mut().find(|existing| existing.id == price.id) {
+            Some(existing) if existing.product_id != price.product_id => {
+                Err(common::domain::Error::ProductPriceAlreadyExists)
+            }
+            Some(existing) => {
+                *existing = backoffice::domain::product_price::ProductPrice {
+                    created_at: existing.created_at,
+                    ..price.clone()
+                };
+                Ok(())
+            }
+            None => {
+                store.prices.push(price.clone());
+                Ok(())
+            }
+        }
+    }
+
+    async fn delete(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::product_price::ProductPriceId,
+    ) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        let count = store.prices.len();
+        store
+            .prices
+            .retain(|price| !(&price.id == id && &price.product_id == product_id));
+
+        if store.prices.len() == count {
+            return Err(common::domain::Error::ProductPriceNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose_price_repository_fixture() -> (
+        backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+
+        (Arc::new(repository.prices()), Arc::new(repository))
+    }
+
+    #[tokio::test]
+    async fn given_no_prices_when_get_by_product_id_then_return_empty_vec() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_no_prices_when_get_by_product_id_then_return_empty_vec(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_prices_of_several_products_when_get_by_product_id_then_return_only_its_list_in_order() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_prices_of_several_products_when_get_by_product_id_then_return_only_its_list_in_order(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_price_when_get_by_product_id_then_return_same_price() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_saved_price_when_get_by_product_id_then_return_same_price(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_base_price_and_promotion_when_get_current_then_return_promotion_only_within_its_window() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_base_price_and_promotion_when_get_current_then_return_promotion_only_within_its_window(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_price_in_other_currency_or_outside_window_when_get_current_then_return_none() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_price_in_other_currency_or_outside_window_when_get_current_then_return_none(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_price_when_save_with_same_id_then_replace_it() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_saved_price_when_save_with_same_id_then_replace_it(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_price_of_other_product_when_save_with_same_id_then_return_already_exists() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_price_of_other_product_when_save_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_save_then_return_product_not_found() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_unknown_product_when_save_then_return_product_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_price_when_delete_then_remove_only_it() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_saved_price_when_delete_then_remove_only_it(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_price_of_other_product_when_delete_then_return_not_found() {
+        let (repository, products) = compose_price_repository_fixture();
+
+        backoffice::domain::product_price::conformance::given_price_of_other_product_when_delete_then_return_not_found(
+            repository, products,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+use super::Store;
+
+#[derive(Clone)]
+pub struct InMemoryProductPriceHistoryRepository {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+#[async_trait]
+impl backoffice::domain::product_price_history::ProductPriceHistoryRepository
+    for InMemoryProductPriceHistoryRepository
+{
+    type Error = common::domain::Error;
+
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        from: Option<backoffice::domain::product::ProductTimeStamp>,
+        until: Option<backoffice::domain::product::ProductTimeStamp>,
+    ) -> Result<Vec<backoffice::domain::product_price_history::ProductPriceChange>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .price_changes
+            .iter()
+            .filter(|change| &change.product_id == product_id && change.is_within(from, until))
+            .cloned()
+            .collect())
+    }
+}
+
+impl Store {
+    pub(super) fn record_price_change(
+        &mut self,
+        old_price: Option<common::domain::Money>,
+        product: &backoffice::domain::product::Product,
+    ) {
+        self.price_changes
+            .push(backoffice::domain::product_price_history::ProductPriceChange {
+                product_id: product.id,
+                old_price,
+                new_price: product.price,
+                changed_by: libs::database::current_caller(),
+                changed_at: backoffice::domain::product::ProductTimeStamp::default(),
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose_price_history_repository_fixture() -> (
+        backoffice::domain::product_price_history::DynProductPriceHistoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+
+        (Arc::new(repository.price_history()), Arc::new(repository))
+    }
+
+    #[tokio::test]
+    async fn given_new_product_when_get_by_product_id_then_return_initial_price() {
+        let (repository, products) = compose_price_history_repository_fixture();
+
+        backoffice::domain::product_price_history::conformance::given_new_product_when_get_by_product_id_then_return_initial_price(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_price_and_currency_updates_when_get_by_product_id_then_return_changes_in_order() {
+        let (repository, products) = compose_price_history_repository_fixture();
+
+        backoffice::domain::product_price_history::conformance::given_price_and_currency_updates_when_get_by_product_id_then_return_changes_in_order(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_update_keeping_price_when_get_by_product_id_then_record_nothing() {
+        let (repository, products) = compose_price_history_repository_fixture();
+
+        backoffice::domain::product_price_history::conformance::given_update_keeping_price_when_get_by_product_id_then_record_nothing(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_changes_of_several_products_when_get_by_product_id_then_return_only_its_history() {
+        let (repository, products) = compose_price_history_repository_fixture();
+
+        backoffice::domain::product_price_history::conformance::given_changes_of_several_products_when_get_by_product_id_then_return_only_its_history(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_period_when_get_by_product_id_then_return_changes_from_start_until_before_end() {
+        let (repository, products) = compose_price_history_repository_fixture();
+
+        backoffice::domain::product_price_history::conformance::given_period_when_get_by_product_id_then_return_changes_from_start_until_before_end(repository, products).await;
+    }
+}
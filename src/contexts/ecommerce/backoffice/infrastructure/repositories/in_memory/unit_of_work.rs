@@ -0,0 +1,277 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::Store;
+
+/// Product write made within a unit of work, replayed onto the store on commit.
+enum StagedWrite {
+    Save(backoffice::domain::product::Product),
+    Update(backoffice::domain::product::Product),
+    UpdateStatus(
+        backoffice::domain::product::Product,
+        backoffice::domain::product::ProductStatus,
+    ),
+}
+
+impl Store {
+    fn apply(&mut self, write: &StagedWrite) -> Result<(), common::domain::Error> {
+        match write {
+            StagedWrite::Save(product) => self.save_product(product),
+            StagedWrite::Update(product) => self.update_product(product),
+            StagedWrite::UpdateStatus(product, from) => self.update_product_status(product, *from),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct InMemoryUnitOfWorkFactory {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+#[async_trait]
+impl common::application::unit_of_work::UnitOfWorkFactory for InMemoryUnitOfWorkFactory {
+    type Error = common::domain::Error;
+
+    async fn begin(&self) -> Result<common::application::unit_of_work::DynUnitOfWork<Self::Error>, Self::Error> {
+        let snapshot = self.store.lock().unwrap().clone();
+
+        Ok(Box::new(InMemoryUnitOfWork {
+            store: self.store.clone(),
+            product_repository: Arc::new(StagedProductRepository {
+                snapshot: backoffice::infrastructure::InMemoryProductRepository {
+                    store: Arc::new(Mutex::new(snapshot)),
+                },
+                writes: Mutex::new(vec![]),
+            }),
+        }))
+    }
+}
+
+/// Works on a snapshot of the store, so that its own writes and their events read back right away, and replays
+/// them onto a copy of the store on commit, which only replaces the store once every write applied.
+pub struct InMemoryUnitOfWork {
+    pub(super) store: Arc<Mutex<Store>>,
+    product_repository: Arc<StagedProductRepository>,
+}
+
+#[async_trait]
+impl common::application::unit_of_work::UnitOfWork for InMemoryUnitOfWork {
+    type Error = common::domain::Error;
+
+    fn product_repository(&self) -> backoffice::domain::product::DynProductRepository<Self::Error> {
+        self.product_repository.clone()
+    }
+
+    fn product_event_repository(&self) -> backoffice::domain::product_event::DynProductEventRepository<Self::Error> {
+        Arc::new(self.product_repository.snapshot.events())
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), Self::Error> {
+        let writes = std::mem::take(&mut *self.product_repository.writes.lock().unwrap());
+
+        let mut store = self.store.lock().unwrap();
+        let mut committed = store.clone();
+        for write in &writes {
+            committed.apply(write)?;
+        }
+        *store = committed;
+
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), Self::Error> {
+        self.product_repository.writes.lock().unwrap().clear();
+
+        Ok(())
+    }
+}
+
+struct StagedProductRepository {
+    snapshot: backoffice::infrastructure::InMemoryProductRepository,
+    writes: Mutex<Vec<StagedWrite>>,
+}
+
+#[async_trait]
+impl backoffice::domain::product::ProductRepository for StagedProductRepository {
+    type Error = common::domain::Error;
+
+    async fn get(
+        &self,
+        status: Option<backoffice::domain::product::ProductStatus>,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        backoffice::domain::product::ProductRepository::get(&self.snapshot, status).await
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &backoffice::domain::product::ProductId,
+    ) -> Result<Option<backoffice::domain::product::Product>, Self::Error> {
+        backoffice::domain::product::ProductRepository::get_by_id(&self.snapshot, id).await
+    }
+
+    async fn get_many_by_ids(
+        &self,
+        ids: &[backoffice::domain::product::ProductId],
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        backoffice::domain::product::ProductRepository::get_many_by_ids(&self.snapshot, ids).await
+    }
+
+    async fn save(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+        backoffice::domain::product::ProductRepository::save(&self.snapshot, product).await?;
+        self.writes.lock().unwrap().push(StagedWrite::Save(product.clone()));
+
+        Ok(())
+    }
+
+    async fn update(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+        backoffice::domain::product::ProductRepository::update(&self.snapshot, product).await?;
+        self.writes.lock().unwrap().push(StagedWrite::Update(product.clone()));
+
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        product: &backoffice::domain::product::Product,
+        from: backoffice::domain::product::ProductStatus,
+    ) -> Result<(), Self::Error> {
+        backoffice::domain::product::ProductRepository::update_status(&self.snapshot, product, from).await?;
+        self.writes
+            .lock()
+            .unwrap()
+            .push(StagedWrite::UpdateStatus(product.clone(), from));
+
+        Ok(())
+    }
+
+    async fn get_scheduled(
+        &self,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        backoffice::domain::product::ProductRepository::get_scheduled(&self.snapshot, at).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn given_saved_product_when_commit_unit_of_work_then_persist_product_and_event() {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let events = repository.events();
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        let unit_of_work =
+            common::application::unit_of_work::UnitOfWorkFactory::begin(&repository.unit_of_work_factory())
+                .await
+                .unwrap();
+        unit_of_work.product_repository().save(&product).await.unwrap();
+
+        let sequence = unit_of_work
+            .product_event_repository()
+            .get_last_sequence()
+            .await
+            .unwrap();
+        assert_eq!(sequence.to_primitive(), 1);
+        assert!(
+            backoffice::domain::product::ProductRepository::get_by_id(&repository, &product.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        unit_of_work.commit().await.unwrap();
+
+        assert!(
+            backoffice::domain::product::ProductRepository::get_by_id(&repository, &product.id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        let sequence = backoffice::domain::product_event::ProductEventRepository::get_last_sequence(&events)
+            .await
+            .unwrap();
+        assert_eq!(sequence.to_primitive(), 1);
+    }
+
+    #[tokio::test]
+    async fn given_failing_second_save_when_commit_unit_of_work_then_leave_store_untouched() {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let events = repository.events();
+        let p1 = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+        let p2 = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        let unit_of_work =
+            common::application::unit_of_work::UnitOfWorkFactory::begin(&repository.unit_of_work_factory())
+                .await
+                .unwrap();
+        unit_of_work.product_repository().save(&p1).await.unwrap();
+        unit_of_work.product_repository().save(&p2).await.unwrap();
+        backoffice::domain::product::ProductRepository::save(&repository, &p2)
+            .await
+            .unwrap();
+
+        let result = unit_of_work.commit().await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductAlreadyExists)));
+        assert!(
+            backoffice::domain::product::ProductRepository::get_by_id(&repository, &p1.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let sequence = backoffice::domain::product_event::ProductEventRepository::get_last_sequence(&events)
+            .await
+            .unwrap();
+        assert_eq!(sequence.to_primitive(), 1);
+    }
+
+    #[tokio::test]
+    async fn given_failing_status_change_after_update_when_commit_unit_of_work_then_leave_store_untouched() {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+        backoffice::domain::product::ProductRepository::save(&repository, &product)
+            .await
+            .unwrap();
+
+        let unit_of_work =
+            common::application::unit_of_work::UnitOfWorkFactory::begin(&repository.unit_of_work_factory())
+                .await
+                .unwrap();
+        let renamed = backoffice::domain::product::Product {
+            name: backoffice::domain::product::ProductName::try_from(String::from("Renamed")).unwrap(),
+            ..product.clone()
+        };
+        unit_of_work.product_repository().update(&renamed).await.unwrap();
+        let published = backoffice::domain::product::Product {
+            status: backoffice::domain::product::ProductStatus::Published,
+            ..product.clone()
+        };
+        unit_of_work
+            .product_repository()
+            .update_status(&published, product.status)
+            .await
+            .unwrap();
+        let archived = backoffice::domain::product::Product {
+            status: backoffice::domain::product::ProductStatus::Archived,
+            ..product.clone()
+        };
+        backoffice::domain::product::ProductRepository::update_status(&repository, &archived, product.status)
+            .await
+            .unwrap();
+
+        let result = unit_of_work.commit().await;
+
+        assert!(matches!(result, Err(common::domain::Error::ProductStatusConflict)));
+        let stored = backoffice::domain::product::ProductRepository::get_by_id(&repository, &product.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.name.to_primitive(), product.name.to_primitive());
+        assert_eq!(stored.status, backoffice::domain::product::ProductStatus::Archived);
+    }
+}
@@ -0,0 +1,226 @@
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+
+use super::Store;
+
+#[derive(Clone)]
+pub struct InMemoryVariantRepository {
+    pub(super) store: Arc<Mutex<Store>>,
+}
+
+#[async_trait]
+impl backoffice::domain::variant::VariantRepository for InMemoryVariantRepository {
+    type Error = common::domain::Error;
+
+    async fn get_options(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::variant::VariantOption>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .variant_options
+            .iter()
+            .find(|(id, _)| id == product_id)
+            .map(|(_, options)| options.clone())
+            .unwrap_or_default())
+    }
+
+    async fn save_options(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        options: &[backoffice::domain::variant::VariantOption],
+    ) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        if !store.products.iter().any(|product| &product.id == product_id) {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+
+        store.variant_options.retain(|(id, _)| id != product_id);
+        store.variant_options.push((*product_id, options.to_vec()));
+
+        Ok(())
+    }
+
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::variant::Variant>, Self::Error> {
+        let store = self.store.lock().unwrap();
+
+        Ok(store
+            .variants
+            .iter()
+            .filter(|variant| &variant.product_id == product_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save(&self, variant: &backoffice::domain::variant::Variant) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        if !store.products.iter().any(|product| product.id == variant.product_id) {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+
+        if store
+            .variants
+            .iter()
+            .any(|existing| existing.sku == variant.sku && existing.id != variant.id)
+        {
+            return Err(common::domain::Error::VariantSkuAlreadyExists);
+        }
+
+        match store.variants.iter_mut().find(|existing| existing.id == variant.id) {
+            Some(existing) if existing.product_id != variant.product_id => {
+                Err(common::domain::Error::VariantAlreadyExists)
+            }
+            Some(existing) => {
+                *existing = backoffice::domain::variant::Variant {
+                    created_at: existing.created_at,
+                    ..variant.clone()
+                };
+                Ok(())
+            }
+            None => {
+                store.variants.push(variant.clone());
+                Ok(())
+            }
+        }
+    }
+
+    async fn delete(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::variant::VariantId,
+    ) -> Result<(), Self::Error> {
+        let mut store = self.store.lock().unwrap();
+
+        let count = store.variants.len();
+        store
+            .variants
+            .retain(|variant| !(&variant.id == id && &variant.product_id == product_id));
+
+        if store.variants.len() == count {
+            return Err(common::domain::Error::VariantNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose_variant_repository_fixture() -> (
+        backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let repository = backoffice::infrastructure::InMemoryProductRepository::new();
+
+        (Arc::new(repository.variants()), Arc::new(repository))
+    }
+
+    #[tokio::test]
+    async fn given_no_variants_when_get_then_return_empty_vecs() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_no_variants_when_get_then_return_empty_vecs(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_options_when_save_options_then_replace_them_in_order() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_saved_options_when_save_options_then_replace_them_in_order(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_save_options_then_return_product_not_found() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_unknown_product_when_save_options_then_return_product_not_found(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_variants_when_get_by_product_id_then_return_only_its_variants_in_order() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_saved_variants_when_get_by_product_id_then_return_only_its_variants_in_order(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_variant_when_get_by_product_id_then_return_same_variant() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_get_by_product_id_then_return_same_variant(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_variant_when_save_with_same_id_then_replace_it() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_save_with_same_id_then_replace_it(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_sku_taken_by_any_product_when_save_then_return_sku_already_exists() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_sku_taken_by_any_product_when_save_then_return_sku_already_exists(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_variant_of_other_product_when_save_with_same_id_then_return_already_exists() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_variant_of_other_product_when_save_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test]
+    async fn given_unknown_product_when_save_variant_then_return_product_not_found() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_unknown_product_when_save_variant_then_return_product_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_saved_variant_when_delete_then_remove_only_it_and_free_its_sku() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_delete_then_remove_only_it_and_free_its_sku(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn given_variant_of_other_product_when_delete_then_return_not_found() {
+        let (repository, products) = compose_variant_repository_fixture();
+
+        backoffice::domain::variant::conformance::given_variant_of_other_product_when_delete_then_return_not_found(
+            repository, products,
+        )
+        .await;
+    }
+}
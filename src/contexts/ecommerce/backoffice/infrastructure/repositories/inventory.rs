@@ -0,0 +1,533 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::Connection;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+// pending reservations still holding their units at $3, summed up under the lock of their stock row
+static RESERVED_SQL: &str = r#"
+    SELECT COALESCE(SUM(quantity), 0)::BIGINT
+    FROM stock_reservation
+    WHERE product_id = $1 AND warehouse = $2 AND status = 'pending' AND expires_at > $3
+"#;
+// every write to the stock of a warehouse goes through this lock, so checks and writes can't interleave
+static LOCK_STOCK_SQL: &str = r#"
+    SELECT on_hand FROM stock WHERE product_id = $1 AND warehouse = $2 FOR UPDATE
+"#;
+
+pub struct PostgresInventoryRepository {
+    db: libs::postgres::Executor,
+    retry_policy: libs::postgres::retry::RetryPolicy,
+}
+
+impl PostgresInventoryRepository {
+    pub fn new(db: libs::postgres::ConnectionPool) -> Self {
+        Self {
+            db: db.into(),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Serves reads from the replica behind `router`, writes still go to its primary.
+    pub fn routed(router: Arc<libs::postgres::ReplicaRouter>) -> Self {
+        Self {
+            db: libs::postgres::Executor::Routed(router),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: libs::postgres::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Moves a reservation on with `transition` while holding its row, taking committed units out of the
+    /// on-hand quantity in the same transaction.
+    async fn transition(
+        &self,
+        operation: &'static str,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+        transition: fn(
+            &backoffice::domain::inventory::Reservation,
+            backoffice::domain::product::ProductTimeStamp,
+        ) -> Result<backoffice::domain::inventory::Reservation, common::domain::Error>,
+    ) -> Result<backoffice::domain::inventory::Reservation, common::domain::Error> {
+        static LOCK_RESERVATION_SQL: &str = r#"
+            SELECT id, product_id, warehouse, quantity, status, expires_at, created_at
+            FROM stock_reservation
+            WHERE id = $1 AND product_id = $2
+            FOR UPDATE
+        "#;
+        static TAKE_OUT_SQL: &str = r#"
+            UPDATE stock SET on_hand = on_hand - $3 WHERE product_id = $1 AND warehouse = $2
+        "#;
+        static UPDATE_STATUS_SQL: &str = r#"
+            UPDATE stock_reservation SET status = $2 WHERE id = $1
+        "#;
+
+        // a dropped connection may hide a committed transition, so repeating it could report it as not pending
+        let result = self
+            .retry_policy
+            .run(operation, libs::postgres::retry::Idempotency::NonIdempotent, || async {
+                let mut connection = self.db.acquire(operation).await?;
+                let mut transaction = connection.begin().await?;
+
+                let reservation: Option<backoffice::domain::inventory::Reservation> =
+                    sqlx::query_as(LOCK_RESERVATION_SQL)
+                        .bind(id.to_uuid())
+                        .bind(product_id.to_uuid())
+                        .fetch_optional(&mut transaction)
+                        .await?;
+                let Some(reservation) = reservation else {
+                    return Ok(Err(common::domain::Error::ReservationNotFound));
+                };
+                let next = match transition(&reservation, at) {
+                    Ok(next) => next,
+                    Err(err) => return Ok(Err(err)),
+                };
+
+                if next.status == backoffice::domain::inventory::ReservationStatus::Committed {
+                    sqlx::query(TAKE_OUT_SQL)
+                        .bind(product_id.to_uuid())
+                        .bind(next.warehouse.to_primitive())
+                        .bind(next.quantity)
+                        .execute(&mut transaction)
+                        .await?;
+                }
+                sqlx::query(UPDATE_STATUS_SQL)
+                    .bind(id.to_uuid())
+                    .bind(next.status.to_primitive())
+                    .execute(&mut transaction)
+                    .await?;
+
+                transaction.commit().await?;
+
+                Ok(Ok(next))
+            })
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        self.db.record_write();
+
+        result
+    }
+}
+
+#[async_trait]
+impl backoffice::domain::inventory::InventoryRepository for PostgresInventoryRepository {
+    type Error = common::domain::Error;
+
+    async fn get_stock(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Vec<backoffice::domain::inventory::StockLevel>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT stock.product_id, stock.warehouse, stock.on_hand,
+                   COALESCE(SUM(stock_reservation.quantity), 0)::BIGINT AS reserved
+            FROM stock
+            LEFT JOIN stock_reservation
+                   ON stock_reservation.product_id = stock.product_id
+                  AND stock_reservation.warehouse = stock.warehouse
+                  AND stock_reservation.status = 'pending'
+                  AND stock_reservation.expires_at > $2
+            WHERE stock.product_id = $1
+            GROUP BY stock.product_id, stock.warehouse, stock.on_hand
+            ORDER BY stock.warehouse
+        "#;
+
+        self.retry_policy
+            .run("get_stock", libs::postgres::retry::Idempotency::Idempotent, || async {
+                sqlx::query_as(SQL)
+                    .bind(product_id.to_uuid())
+                    .bind(at.to_datetime())
+                    .fetch_all(&mut *self.db.acquire_read("get_stock").await?)
+                    .await
+            })
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_adjustments(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::inventory::StockAdjustment>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, product_id, warehouse, delta, reason, created_at
+            FROM stock_adjustment
+            WHERE product_id = $1
+            ORDER BY created_at, id
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_stock_adjustments",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(product_id.to_uuid())
+                        .fetch_all(&mut *self.db.acquire_read("get_stock_adjustments").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn adjust(
+        &self,
+        adjustment: &backoffice::domain::inventory::StockAdjustment,
+    ) -> Result<backoffice::domain::inventory::StockLevel, Self::Error> {
+        static CREATE_STOCK_SQL: &str = r#"
+            INSERT INTO stock (product_id, warehouse) VALUES ($1, $2)
+            ON CONFLICT (product_id, warehouse) DO NOTHING
+        "#;
+        static UPDATE_STOCK_SQL: &str = r#"
+            UPDATE stock SET on_hand = on_hand + $3 WHERE product_id = $1 AND warehouse = $2
+        "#;
+        static INSERT_SQL: &str = r#"
+            INSERT INTO stock_adjustment (id, product_id, warehouse, delta, reason)
+            VALUES ($1, $2, $3, $4, $5)
+        "#;
+
+        let product_id = adjustment.product_id.to_uuid();
+        let warehouse = adjustment.warehouse.to_primitive();
+
+        // a dropped connection may hide a committed adjustment, so repeating it could report it as existing
+        let level = self
+            .retry_policy
+            .run(
+                "adjust_stock",
+                libs::postgres::retry::Idempotency::NonIdempotent,
+                || async {
+                    let mut connection = self.db.acquire("adjust_stock").await?;
+                    let mut transaction = connection.begin().await?;
+
+                    sqlx::query(CREATE_STOCK_SQL)
+                        .bind(product_id)
+                        .bind(&warehouse)
+                        .execute(&mut transaction)
+                        .await?;
+                    let (on_hand,): (i64,) = sqlx::query_as(LOCK_STOCK_SQL)
+                        .bind(product_id)
+                        .bind(&warehouse)
+                        .fetch_one(&mut transaction)
+                        .await?;
+                    let (reserved,): (i64,) = sqlx::query_as(RESERVED_SQL)
+                        .bind(product_id)
+                        .bind(&warehouse)
+                        .bind(adjustment.created_at.to_datetime())
+                        .fetch_one(&mut transaction)
+                        .await?;
+
+                    if on_hand + adjustment.delta < reserved {
+                        return Ok(None);
+                    }
+
+                    sqlx::query(UPDATE_STOCK_SQL)
+                        .bind(product_id)
+                        .bind(&warehouse)
+                        .bind(adjustment.delta)
+                        .execute(&mut transaction)
+                        .await?;
+                    sqlx::query(INSERT_SQL)
+                        .bind(adjustment.id.to_uuid())
+                        .bind(product_id)
+                        .bind(&warehouse)
+                        .bind(adjustment.delta)
+                        .bind(adjustment.reason.to_primitive())
+                        .execute(&mut transaction)
+                        .await?;
+
+                    transaction.commit().await?;
+
+                    Ok(Some(backoffice::domain::inventory::StockLevel {
+                        product_id: adjustment.product_id,
+                        warehouse: adjustment.warehouse.clone(),
+                        on_hand: on_hand + adjustment.delta,
+                        reserved,
+                    }))
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|error| {
+                let Some(database_error) = error.as_database_error() else {
+                    return common::domain::Error::from(error);
+                };
+
+                match libs::postgres::errcodes::Codes::from(database_error) {
+                    libs::postgres::errcodes::Codes::UniqueViolation => {
+                        common::domain::Error::StockAdjustmentAlreadyExists
+                    }
+                    libs::postgres::errcodes::Codes::ForeignKeyViolation => common::domain::Error::ProductNotFound,
+                    _ => common::domain::Error::from(error),
+                }
+            })?;
+
+        self.db.record_write();
+
+        level.ok_or(common::domain::Error::InsufficientStock)
+    }
+
+    async fn get_reservation(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+    ) -> Result<Option<backoffice::domain::inventory::Reservation>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, product_id, warehouse, quantity, status, expires_at, created_at
+            FROM stock_reservation
+            WHERE id = $1 AND product_id = $2
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_stock_reservation",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(id.to_uuid())
+                        .bind(product_id.to_uuid())
+                        .fetch_optional(&mut *self.db.acquire_read("get_stock_reservation").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn reserve(&self, reservation: &backoffice::domain::inventory::Reservation) -> Result<(), Self::Error> {
+        static INSERT_SQL: &str = r#"
+            INSERT INTO stock_reservation (id, product_id, warehouse, quantity, status, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#;
+
+        let product_id = reservation.product_id.to_uuid();
+        let warehouse = reservation.warehouse.to_primitive();
+
+        // a dropped connection may hide a committed reservation, so repeating it could report it as existing
+        let reserved = self
+            .retry_policy
+            .run(
+                "reserve_stock",
+                libs::postgres::retry::Idempotency::NonIdempotent,
+                || async {
+                    let mut connection = self.db.acquire("reserve_stock").await?;
+                    let mut transaction = connection.begin().await?;
+
+                    let stock: Option<(i64,)> = sqlx::query_as(LOCK_STOCK_SQL)
+                        .bind(product_id)
+                        .bind(&warehouse)
+                        .fetch_optional(&mut transaction)
+                        .await?;
+                    let Some((on_hand,)) = stock else {
+                        return Ok(false);
+                    };
+                    let (reserved,): (i64,) = sqlx::query_as(RESERVED_SQL)
+                        .bind(product_id)
+                        .bind(&warehouse)
+                        .bind(reservation.created_at.to_datetime())
+                        .fetch_one(&mut transaction)
+                        .await?;
+
+                    if on_hand - reserved < reservation.quantity {
+                        return Ok(false);
+                    }
+
+                    sqlx::query(INSERT_SQL)
+                        .bind(reservation.id.to_uuid())
+                        .bind(product_id)
+                        .bind(&warehouse)
+                        .bind(reservation.quantity)
+                        .bind(reservation.status.to_primitive())
+                        .bind(reservation.expires_at.to_datetime())
+                        .bind(reservation.created_at.to_datetime())
+                        .execute(&mut transaction)
+                        .await?;
+
+                    transaction.commit().await?;
+
+                    Ok(true)
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|error| {
+                let Some(database_error) = error.as_database_error() else {
+                    return common::domain::Error::from(error);
+                };
+
+                match libs::postgres::errcodes::Codes::from(database_error) {
+                    libs::postgres::errcodes::Codes::UniqueViolation => common::domain::Error::ReservationAlreadyExists,
+                    _ => common::domain::Error::from(error),
+                }
+            })?;
+
+        self.db.record_write();
+
+        if !reserved {
+            return Err(common::domain::Error::InsufficientStock);
+        }
+
+        Ok(())
+    }
+
+    async fn commit(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<backoffice::domain::inventory::Reservation, Self::Error> {
+        self.transition(
+            "commit_stock_reservation",
+            product_id,
+            id,
+            at,
+            backoffice::domain::inventory::Reservation::commit,
+        )
+        .await
+    }
+
+    async fn release(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<backoffice::domain::inventory::Reservation, Self::Error> {
+        self.transition(
+            "release_stock_reservation",
+            product_id,
+            id,
+            at,
+            backoffice::domain::inventory::Reservation::release,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contexts::ecommerce::backoffice;
+    use crate::libs;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+
+        (
+            Arc::new(PostgresInventoryRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::PostgresProductRepository::new(
+                database.pool,
+            )),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_stock_when_get_then_return_empty_vecs() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_no_stock_when_get_then_return_empty_vecs(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_adjustments_when_get_stock_then_sum_them_per_warehouse_in_code_order() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_adjustments_when_get_stock_then_sum_them_per_warehouse_in_code_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_reserved_stock_when_adjust_below_it_then_return_insufficient_stock_and_keep_stock() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_reserved_stock_when_adjust_below_it_then_return_insufficient_stock_and_keep_stock(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_adjustment_when_adjust_with_same_id_then_return_already_exists() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_saved_adjustment_when_adjust_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_adjust_then_return_product_not_found() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_unknown_product_when_adjust_then_return_product_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_stock_when_reserve_then_hold_it_until_released() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_stock_when_reserve_then_hold_it_until_released(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_pending_reservation_when_commit_then_take_it_out_of_on_hand_once() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_pending_reservation_when_commit_then_take_it_out_of_on_hand_once(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_expired_reservation_when_reserve_then_ignore_it_and_refuse_its_commit() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_expired_reservation_when_reserve_then_ignore_it_and_refuse_its_commit(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_reservation_when_reserve_with_same_id_then_return_already_exists() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_saved_reservation_when_reserve_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_reservation_of_other_product_when_commit_or_release_then_return_not_found() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_reservation_of_other_product_when_commit_or_release_then_return_not_found(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_stock_when_reserve_concurrently_then_never_oversell() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_stock_when_reserve_concurrently_then_never_oversell(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_pending_reservation_when_commit_and_release_concurrently_then_apply_only_one() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_pending_reservation_when_commit_and_release_concurrently_then_apply_only_one(repository, products).await;
+    }
+}
@@ -1,3 +1,37 @@
+pub use category::*;
+pub use exchange_rate::*;
+#[cfg(test)]
+pub use in_memory::*;
+pub use inventory::*;
 pub use product::*;
+pub use product_event::*;
+pub use product_price::*;
+pub use product_price_history::*;
+pub use sqlite_category::*;
+pub use sqlite_exchange_rate::*;
+pub use sqlite_inventory::*;
+pub use sqlite_product::*;
+pub use sqlite_product_event::*;
+pub use sqlite_product_price::*;
+pub use sqlite_product_price_history::*;
+pub use sqlite_variant::*;
+pub use variant::*;
 
+mod category;
+mod exchange_rate;
+#[cfg(test)]
+mod in_memory;
+mod inventory;
 mod product;
+mod product_event;
+mod product_price;
+mod product_price_history;
+mod sqlite_category;
+mod sqlite_exchange_rate;
+mod sqlite_inventory;
+mod sqlite_product;
+mod sqlite_product_event;
+mod sqlite_product_price;
+mod sqlite_product_price_history;
+mod sqlite_variant;
+mod variant;
@@ -1,15 +1,42 @@
+use std::sync::Arc;
+
 use axum::async_trait;
 
 use crate::contexts::ecommerce::{backoffice, common};
 use crate::libs;
 
 pub struct PostgresProductRepository {
-    db: libs::postgres::ConnectionPool,
+    db: libs::postgres::Executor,
+    retry_policy: libs::postgres::retry::RetryPolicy,
 }
 
 impl PostgresProductRepository {
     pub fn new(db: libs::postgres::ConnectionPool) -> Self {
-        Self { db }
+        Self {
+            db: db.into(),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Serves reads from the replica behind `router`, writes still go to its primary.
+    pub fn routed(router: Arc<libs::postgres::ReplicaRouter>) -> Self {
+        Self {
+            db: libs::postgres::Executor::Routed(router),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Binds the repository to an open transaction, its statements commit or roll back together.
+    pub fn in_transaction(transaction: libs::postgres::SharedTransaction) -> Self {
+        Self {
+            db: libs::postgres::Executor::Transaction(transaction),
+            retry_policy: libs::postgres::retry::RetryPolicy::disabled(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: libs::postgres::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 }
 
@@ -17,18 +44,35 @@ impl PostgresProductRepository {
 impl backoffice::domain::product::ProductRepository for PostgresProductRepository {
     type Error = common::domain::Error;
 
-    async fn get(&self) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+    async fn get(
+        &self,
+        status: Option<backoffice::domain::product::ProductStatus>,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
         static SQL: &str = r#"
-                SELECT *
+                SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
                 FROM product
-                LIMIT 50
+                WHERE $2::TEXT IS NULL OR status = $2
+                ORDER BY created_at, id
+                LIMIT $1
             "#;
 
-        sqlx::query_as(SQL)
-            .fetch_all(&self.db)
+        let status = status.map(|status| status.to_primitive());
+
+        self.retry_policy
+            .run(
+                "get_products",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(backoffice::domain::product::PRODUCT_PAGE_SIZE)
+                        .bind(status.clone())
+                        .fetch_all(&mut *self.db.acquire_read("get_products").await?)
+                        .await
+                },
+            )
             .await
             .inspect_err(|err| tracing::error!("{err}"))
-            .map_err(|err| common::domain::Error::Persistence(err.to_string()))
+            .map_err(common::domain::Error::from)
     }
 
     async fn get_by_id(
@@ -36,53 +80,226 @@ impl backoffice::domain::product::ProductRepository for PostgresProductRepositor
         id: &backoffice::domain::product::ProductId,
     ) -> Result<Option<backoffice::domain::product::Product>, Self::Error> {
         static SQL: &str = r#"
-            SELECT *
+            SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
             FROM product
             WHERE id = $1
         "#;
 
-        sqlx::query_as(SQL)
-            .bind(id.to_uuid())
-            .fetch_optional(&self.db)
+        self.retry_policy
+            .run(
+                "get_product_by_id",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(id.to_uuid())
+                        .fetch_optional(&mut *self.db.acquire_read("get_product_by_id").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_many_by_ids(
+        &self,
+        ids: &[backoffice::domain::product::ProductId],
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
+            FROM product
+            WHERE id = ANY($1)
+        "#;
+
+        let ids: Vec<uuid::Uuid> = ids.iter().map(|id| id.to_uuid()).collect();
+
+        self.retry_policy
+            .run(
+                "get_products_by_ids",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(ids.clone())
+                        .fetch_all(&mut *self.db.acquire_read("get_products_by_ids").await?)
+                        .await
+                },
+            )
             .await
             .inspect_err(|err| tracing::error!("{err}"))
-            .map_err(|err| common::domain::Error::Persistence(err.to_string()))
+            .map_err(common::domain::Error::from)
     }
 
     async fn save(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
         static SQL: &str = r#"
-            INSERT INTO product (id, name, price, currency)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO product (id, name, price, currency, updated_by, status, publish_at, unpublish_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
         "#;
 
-        sqlx::query(SQL)
-            .bind(product.id.to_uuid())
-            .bind(product.name.to_primitive())
-            .bind(product.price.to_primitive())
-            .bind(product.currency.to_primitive())
-            .execute(&self.db)
+        let caller = libs::database::current_caller();
+
+        // a dropped connection may hide a committed insert, so repeating it could report a false conflict
+        self.retry_policy
+            .run(
+                "save_product",
+                libs::postgres::retry::Idempotency::NonIdempotent,
+                || async {
+                    sqlx::query(SQL)
+                        .bind(product.id.to_uuid())
+                        .bind(product.name.to_primitive())
+                        .bind(product.price.minor_units())
+                        .bind(product.price.currency().to_primitive())
+                        .bind(caller.clone())
+                        .bind(product.status.to_primitive())
+                        .bind(product.publish_at.map(|publish_at| publish_at.to_datetime()))
+                        .bind(product.unpublish_at.map(|unpublish_at| unpublish_at.to_datetime()))
+                        .execute(&mut *self.db.acquire("save_product").await?)
+                        .await
+                },
+            )
             .await
             .inspect_err(|err| tracing::error!("{err}"))
-            .map_err(|error| {
-                if let Some(error) = error.as_database_error() {
-                    if let Some(code) = error.code() {
-                        if code == libs::postgres::errcodes::Codes::UniqueViolation.to_string() {
-                            return common::domain::Error::ProductAlreadyExists;
-                        }
+            .map_err(
+                |error| match error.as_database_error().map(libs::postgres::errcodes::Codes::from) {
+                    Some(libs::postgres::errcodes::Codes::UniqueViolation) => {
+                        common::domain::Error::ProductAlreadyExists
                     }
-                }
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        self.db.record_write();
 
-                common::domain::Error::Persistence(error.to_string())
-            })?;
+        Ok(())
+    }
+
+    async fn update(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            UPDATE product
+            SET name       = $2,
+                price      = $3,
+                currency   = $4,
+                updated_by = $5
+            WHERE id = $1
+        "#;
+
+        let caller = libs::database::current_caller();
+
+        // writing the same values again changes nothing, not even the price history
+        let result = self
+            .retry_policy
+            .run(
+                "update_product",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query(SQL)
+                        .bind(product.id.to_uuid())
+                        .bind(product.name.to_primitive())
+                        .bind(product.price.minor_units())
+                        .bind(product.price.currency().to_primitive())
+                        .bind(caller.clone())
+                        .execute(&mut *self.db.acquire("update_product").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        self.db.record_write();
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::ProductNotFound);
+        }
 
         Ok(())
     }
+
+    async fn update_status(
+        &self,
+        product: &backoffice::domain::product::Product,
+        from: backoffice::domain::product::ProductStatus,
+    ) -> Result<(), Self::Error> {
+        // the outer query reads the snapshot from before the update, so a product that moved on still exists
+        static SQL: &str = r#"
+            WITH updated AS (
+                UPDATE product
+                SET status       = $2,
+                    publish_at   = $3,
+                    unpublish_at = $4,
+                    updated_by   = $5
+                WHERE id = $1
+                  AND status = $6
+                RETURNING id
+            )
+            SELECT EXISTS (SELECT 1 FROM updated), EXISTS (SELECT 1 FROM product WHERE id = $1)
+        "#;
+
+        let caller = libs::database::current_caller();
+
+        // a dropped connection may hide a committed update, repeating it would report a false conflict
+        let (updated, exists): (bool, bool) = self
+            .retry_policy
+            .run(
+                "update_product_status",
+                libs::postgres::retry::Idempotency::NonIdempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(product.id.to_uuid())
+                        .bind(product.status.to_primitive())
+                        .bind(product.publish_at.map(|publish_at| publish_at.to_datetime()))
+                        .bind(product.unpublish_at.map(|unpublish_at| unpublish_at.to_datetime()))
+                        .bind(caller.clone())
+                        .bind(from.to_primitive())
+                        .fetch_one(&mut *self.db.acquire("update_product_status").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        self.db.record_write();
+
+        match (updated, exists) {
+            (true, _) => Ok(()),
+            (false, true) => Err(common::domain::Error::ProductStatusConflict),
+            (false, false) => Err(common::domain::Error::ProductNotFound),
+        }
+    }
+
+    async fn get_scheduled(
+        &self,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
+            FROM product
+            WHERE (publish_at IS NOT NULL OR unpublish_at IS NOT NULL)
+              AND ((status = 'draft' AND publish_at <= $1) OR (status = 'published' AND unpublish_at <= $1))
+            ORDER BY created_at, id
+            LIMIT $2
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_scheduled_products",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(at.to_datetime())
+                        .bind(backoffice::domain::product::PRODUCT_PAGE_SIZE)
+                        .fetch_all(&mut *self.db.acquire_read("get_scheduled_products").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
     use crate::contexts::ecommerce::backoffice;
     use crate::libs;
 
@@ -95,72 +312,112 @@ mod tests {
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn given_empty_database_when_get_then_return_empty_vec() {
-        let repository = compose_repository_fixture().await;
-
-        assert!(repository.get().await.unwrap().is_empty());
+    async fn given_empty_store_when_get_then_return_empty_vec() {
+        backoffice::domain::product::conformance::given_empty_store_when_get_then_return_empty_vec(
+            compose_repository_fixture().await,
+        )
+        .await;
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn given_products_on_database_when_get_then_return_fulfilled_vec() {
-        let repository = compose_repository_fixture().await;
-
-        let p1 = backoffice::domain::product::fixture::ProductBuilder::default();
-        let p2 = backoffice::domain::product::fixture::ProductBuilder::default();
-        let p3 = backoffice::domain::product::fixture::ProductBuilder::default();
-        let p4 = backoffice::domain::product::fixture::ProductBuilder::default();
-        let p5 = backoffice::domain::product::fixture::ProductBuilder::default();
-
-        tokio::join!(
-            p1.save(&repository),
-            p2.save(&repository),
-            p3.save(&repository),
-            p4.save(&repository),
-            p5.save(&repository)
-        );
-
-        assert_eq!(repository.get().await.unwrap().len(), 5);
+    async fn given_products_when_get_then_return_them_in_creation_order() {
+        backoffice::domain::product::conformance::given_products_when_get_then_return_them_in_creation_order(
+            compose_repository_fixture().await,
+        )
+        .await;
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn given_empty_database_when_get_by_id_then_return_none() {
-        let repository = compose_repository_fixture().await;
+    async fn given_products_created_at_same_time_when_get_then_order_them_by_id() {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+        // now() stays put within a transaction, so every product saved in it gets the same creation time
+        let transaction = database.pool.begin().await.unwrap();
+        let transaction = libs::postgres::SharedTransaction::new(transaction, &database.pool);
+
+        backoffice::domain::product::conformance::given_products_created_at_same_time_when_get_then_order_them_by_id(
+            Arc::new(PostgresProductRepository::in_transaction(transaction)),
+        )
+        .await;
+    }
 
-        let id = backoffice::domain::product::ProductId::default();
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_more_products_than_page_size_when_get_then_return_one_page() {
+        backoffice::domain::product::conformance::given_more_products_than_page_size_when_get_then_return_one_page(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
 
-        assert!(repository.get_by_id(&id).await.unwrap().is_none());
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_empty_store_when_get_by_id_then_return_none() {
+        backoffice::domain::product::conformance::given_empty_store_when_get_by_id_then_return_none(
+            compose_repository_fixture().await,
+        )
+        .await;
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn given_products_on_database_when_get_by_id_then_return_some() {
-        let repository = compose_repository_fixture().await;
+    async fn given_saved_product_when_get_by_id_then_return_same_product() {
+        backoffice::domain::product::conformance::given_saved_product_when_get_by_id_then_return_same_product(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
 
-        let product = backoffice::domain::product::fixture::ProductBuilder::default();
-        product.save(&repository).await;
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_price_beyond_i32_in_three_decimal_currency_when_save_then_keep_exact_amount() {
+        backoffice::domain::product::conformance::given_price_beyond_i32_in_three_decimal_currency_when_save_then_keep_exact_amount(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
 
-        assert!(repository.get_by_id(&product.id).await.unwrap().is_some());
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_when_get_many_by_ids_then_return_only_matching() {
+        backoffice::domain::product::conformance::given_products_when_get_many_by_ids_then_return_only_matching(
+            compose_repository_fixture().await,
+        )
+        .await;
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn given_empty_database_when_save_then_return_ok() {
-        let repository = compose_repository_fixture().await;
+    async fn given_saved_product_when_save_with_same_id_then_return_already_exists() {
+        backoffice::domain::product::conformance::given_saved_product_when_save_with_same_id_then_return_already_exists(compose_repository_fixture().await).await;
+    }
 
-        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_update_then_return_new_name_and_price() {
+        backoffice::domain::product::conformance::given_saved_product_when_update_then_return_new_name_and_price(
+            compose_repository_fixture().await,
+        )
+        .await;
+    }
 
-        assert!(repository.save(&product.to_entity()).await.is_ok());
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_update_then_return_not_found() {
+        backoffice::domain::product::conformance::given_unknown_product_when_update_then_return_not_found(
+            compose_repository_fixture().await,
+        )
+        .await;
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn given_products_on_database_when_save_with_same_id_then_return_err() {
-        let repository = compose_repository_fixture().await;
+    async fn given_products_in_every_status_when_get_with_status_then_return_only_matching() {
+        backoffice::domain::product::conformance::given_products_in_every_status_when_get_with_status_then_return_only_matching(compose_repository_fixture().await).await;
+    }
 
-        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_update_status_then_return_new_status_and_schedule() {
+        backoffice::domain::product::conformance::given_saved_product_when_update_status_then_return_new_status_and_schedule(compose_repository_fixture().await).await;
+    }
 
-        assert!(repository.save(&product.to_entity()).await.is_ok());
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_status_changed_meanwhile_when_update_status_then_return_conflict() {
+        backoffice::domain::product::conformance::given_status_changed_meanwhile_when_update_status_then_return_conflict(compose_repository_fixture().await).await;
+    }
 
-        matches!(
-            repository.save(&product.to_entity()).await.err().unwrap(),
-            common::domain::Error::ProductAlreadyExists
-        );
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_scheduled_products_when_get_scheduled_then_return_only_due_ones() {
+        backoffice::domain::product::conformance::given_scheduled_products_when_get_scheduled_then_return_only_due_ones(compose_repository_fixture().await).await;
     }
 }
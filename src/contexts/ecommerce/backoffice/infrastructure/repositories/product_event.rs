@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct PostgresProductEventRepository {
+    db: libs::postgres::Executor,
+    retry_policy: libs::postgres::retry::RetryPolicy,
+}
+
+impl PostgresProductEventRepository {
+    pub fn new(db: libs::postgres::ConnectionPool) -> Self {
+        Self {
+            db: db.into(),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Serves reads from the replica behind `router`.
+    pub fn routed(router: Arc<libs::postgres::ReplicaRouter>) -> Self {
+        Self {
+            db: libs::postgres::Executor::Routed(router),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Reads through an open transaction, so events of its uncommitted writes are visible.
+    pub fn in_transaction(transaction: libs::postgres::SharedTransaction) -> Self {
+        Self {
+            db: libs::postgres::Executor::Transaction(transaction),
+            retry_policy: libs::postgres::retry::RetryPolicy::disabled(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: libs::postgres::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
+
+#[async_trait]
+impl backoffice::domain::product_event::ProductEventRepository for PostgresProductEventRepository {
+    type Error = common::domain::Error;
+
+    async fn get_after(
+        &self,
+        sequence: &backoffice::domain::product_event::ProductEventSequence,
+        limit: i64,
+    ) -> Result<Vec<backoffice::domain::product_event::ProductEvent>, Self::Error> {
+        // events come in commit order, a transaction still running may yet add events below the
+        // sequences already streamed, so everything from the oldest running one on is held back
+        static SQL: &str = r#"
+            WITH after AS (
+                SELECT xid, sequence
+                FROM product_event
+                WHERE sequence = $1
+            )
+            SELECT sequence, product_id, kind, payload, occurred_at
+            FROM product_event
+            WHERE (xid < pg_snapshot_xmin(pg_current_snapshot()) OR xid = pg_current_xact_id_if_assigned())
+              AND ((xid, sequence) > (SELECT xid, sequence FROM after)
+                OR (NOT EXISTS (SELECT 1 FROM after) AND sequence > $1))
+            ORDER BY xid, sequence
+            LIMIT $2
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_product_events",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(sequence.to_primitive())
+                        .bind(limit)
+                        .fetch_all(&mut *self.db.acquire_read("get_product_events").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_last_sequence(&self) -> Result<backoffice::domain::product_event::ProductEventSequence, Self::Error> {
+        static SQL: &str = r#"
+            SELECT sequence
+            FROM product_event
+            WHERE xid < pg_snapshot_xmin(pg_current_snapshot()) OR xid = pg_current_xact_id_if_assigned()
+            ORDER BY xid DESC, sequence DESC
+            LIMIT 1
+        "#;
+
+        let sequence: Option<i64> = self
+            .retry_policy
+            .run(
+                "get_last_product_event_sequence",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_scalar(SQL)
+                        .fetch_optional(&mut *self.db.acquire_read("get_last_product_event_sequence").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        backoffice::domain::product_event::ProductEventSequence::try_from(sequence.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contexts::ecommerce::backoffice;
+    use crate::libs;
+
+    use super::*;
+
+    struct RepositoryFixture {
+        pool: libs::postgres::ConnectionPool,
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+    }
+
+    async fn compose_repository_fixture() -> RepositoryFixture {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+
+        RepositoryFixture {
+            pool: database.pool.clone(),
+            product_repository: Arc::new(backoffice::infrastructure::PostgresProductRepository::new(
+                database.pool.clone(),
+            )),
+            product_event_repository: Arc::new(PostgresProductEventRepository::new(database.pool)),
+        }
+    }
+
+    /// Waits for `count` events after `sequence`, transactions of concurrent tests hold them back for a moment.
+    async fn get_settled_after(
+        fixture: &RepositoryFixture,
+        sequence: &backoffice::domain::product_event::ProductEventSequence,
+        count: usize,
+    ) -> Vec<backoffice::domain::product_event::ProductEvent> {
+        for _ in 0..500 {
+            let events = fixture.product_event_repository.get_after(sequence, 10).await.unwrap();
+            if events.len() >= count {
+                return events;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        panic!("events did not settle");
+    }
+
+    async fn rename_product(
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        product: &backoffice::domain::product::fixture::ProductBuilder,
+    ) {
+        sqlx::query("UPDATE product SET name = 'renamed' WHERE id = $1")
+            .bind(product.id.to_uuid())
+            .execute(&mut **transaction)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_empty_database_when_get_last_sequence_then_return_zero() {
+        let fixture = compose_repository_fixture().await;
+
+        let sequence = fixture.product_event_repository.get_last_sequence().await.unwrap();
+
+        assert_eq!(sequence.to_primitive(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_empty_database_when_get_after_then_return_empty_vec() {
+        let fixture = compose_repository_fixture().await;
+
+        let events = fixture
+            .product_event_repository
+            .get_after(&backoffice::domain::product_event::ProductEventSequence::default(), 10)
+            .await
+            .unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_products_when_get_after_then_return_created_events_in_order() {
+        let fixture = compose_repository_fixture().await;
+
+        let p1 = backoffice::domain::product::fixture::ProductBuilder::default();
+        p1.save(&fixture.product_repository).await;
+
+        let p2 = backoffice::domain::product::fixture::ProductBuilder::default();
+        p2.save(&fixture.product_repository).await;
+
+        let events = get_settled_after(
+            &fixture,
+            &backoffice::domain::product_event::ProductEventSequence::default(),
+            2,
+        )
+        .await;
+
+        assert_eq!(events.len(), 2);
+        assert!(events[0].sequence < events[1].sequence);
+        assert!(events[0].product_id == p1.id);
+        assert!(events[1].product_id == p2.id);
+        assert_eq!(
+            events[0].kind,
+            backoffice::domain::product_event::ProductEventKind::Created
+        );
+        assert_eq!(events[0].payload["name"], p1.name.to_primitive());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_products_when_get_after_last_sequence_then_return_empty_vec() {
+        let fixture = compose_repository_fixture().await;
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default();
+        product.save(&fixture.product_repository).await;
+        get_settled_after(
+            &fixture,
+            &backoffice::domain::product_event::ProductEventSequence::default(),
+            1,
+        )
+        .await;
+
+        let last_sequence = fixture.product_event_repository.get_last_sequence().await.unwrap();
+
+        let events = fixture
+            .product_event_repository
+            .get_after(&last_sequence, 10)
+            .await
+            .unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_transactions_committing_out_of_order_when_get_after_then_stream_every_event_once() {
+        let fixture = compose_repository_fixture().await;
+
+        let p1 = backoffice::domain::product::fixture::ProductBuilder::default();
+        p1.save(&fixture.product_repository).await;
+        let p2 = backoffice::domain::product::fixture::ProductBuilder::default();
+        p2.save(&fixture.product_repository).await;
+        let created = get_settled_after(
+            &fixture,
+            &backoffice::domain::product_event::ProductEventSequence::default(),
+            2,
+        )
+        .await;
+        let cursor = created[1].sequence;
+
+        // the first transaction takes the lower sequence but commits last
+        let mut first = fixture.pool.begin().await.unwrap();
+        rename_product(&mut first, &p1).await;
+        let mut second = fixture.pool.begin().await.unwrap();
+        rename_product(&mut second, &p2).await;
+        second.commit().await.unwrap();
+
+        let events = fixture.product_event_repository.get_after(&cursor, 10).await.unwrap();
+        assert!(events.is_empty());
+
+        first.commit().await.unwrap();
+
+        let events = get_settled_after(&fixture, &cursor, 2).await;
+        let product_ids: Vec<_> = events.iter().map(|event| event.product_id).collect();
+        assert_eq!(product_ids, vec![p1.id, p2.id]);
+        assert!(events[0].sequence < events[1].sequence);
+
+        let events = fixture
+            .product_event_repository
+            .get_after(&events[1].sequence, 10)
+            .await
+            .unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_get_after_then_return_payload_of_public_columns() {
+        let fixture = compose_repository_fixture().await;
+
+        backoffice::domain::product_event::conformance::given_saved_product_when_get_after_then_return_payload_of_public_columns(
+            fixture.product_event_repository,
+            fixture.product_repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_status_transition_when_get_after_then_return_payload_with_new_status() {
+        let fixture = compose_repository_fixture().await;
+
+        backoffice::domain::product_event::conformance::given_status_transition_when_get_after_then_return_payload_with_new_status(
+            fixture.product_event_repository,
+            fixture.product_repository,
+        )
+        .await;
+    }
+}
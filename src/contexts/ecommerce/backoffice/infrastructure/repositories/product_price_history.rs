@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct PostgresProductPriceHistoryRepository {
+    db: libs::postgres::Executor,
+    retry_policy: libs::postgres::retry::RetryPolicy,
+}
+
+impl PostgresProductPriceHistoryRepository {
+    pub fn new(db: libs::postgres::ConnectionPool) -> Self {
+        Self {
+            db: db.into(),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Serves reads from the replica behind `router`.
+    pub fn routed(router: Arc<libs::postgres::ReplicaRouter>) -> Self {
+        Self {
+            db: libs::postgres::Executor::Routed(router),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: libs::postgres::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
+
+#[async_trait]
+impl backoffice::domain::product_price_history::ProductPriceHistoryRepository
+    for PostgresProductPriceHistoryRepository
+{
+    type Error = common::domain::Error;
+
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        from: Option<backoffice::domain::product::ProductTimeStamp>,
+        until: Option<backoffice::domain::product::ProductTimeStamp>,
+    ) -> Result<Vec<backoffice::domain::product_price_history::ProductPriceChange>, Self::Error> {
+        // id breaks ties between changes made in the same transaction, which share their timestamp
+        static SQL: &str = r#"
+            SELECT product_id, old_price, old_currency, new_price, new_currency, changed_by, changed_at
+            FROM product_price_history
+            WHERE product_id = $1
+              AND ($2::TIMESTAMPTZ IS NULL OR changed_at >= $2)
+              AND ($3::TIMESTAMPTZ IS NULL OR changed_at < $3)
+            ORDER BY changed_at, id
+        "#;
+
+        let from = from.map(|from| from.to_datetime());
+        let until = until.map(|until| until.to_datetime());
+
+        self.retry_policy
+            .run(
+                "get_product_price_history",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(product_id.to_uuid())
+                        .bind(from)
+                        .bind(until)
+                        .fetch_all(&mut *self.db.acquire_read("get_product_price_history").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::product_price_history::DynProductPriceHistoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+
+        (
+            Arc::new(PostgresProductPriceHistoryRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::PostgresProductRepository::new(
+                database.pool,
+            )),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_new_product_when_get_by_product_id_then_return_initial_price() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_new_product_when_get_by_product_id_then_return_initial_price(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_price_and_currency_updates_when_get_by_product_id_then_return_changes_in_order() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_price_and_currency_updates_when_get_by_product_id_then_return_changes_in_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_update_keeping_price_when_get_by_product_id_then_record_nothing() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_update_keeping_price_when_get_by_product_id_then_record_nothing(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_changes_of_several_products_when_get_by_product_id_then_return_only_its_history() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_changes_of_several_products_when_get_by_product_id_then_return_only_its_history(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_period_when_get_by_product_id_then_return_changes_from_start_until_before_end() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_period_when_get_by_product_id_then_return_changes_from_start_until_before_end(repository, products).await;
+    }
+}
@@ -0,0 +1,411 @@
+use axum::async_trait;
+use sqlx::Connection;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct SqliteCategoryRepository {
+    db: libs::sqlite::Executor,
+}
+
+impl SqliteCategoryRepository {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+static SELECT_BY_ID_SQL: &str = r#"
+    SELECT id, parent_id, name, position, path, created_at, updated_at
+    FROM category
+    WHERE id = ?
+"#;
+
+fn log_error(error: sqlx::Error) -> common::domain::Error {
+    tracing::error!("{error}");
+
+    common::domain::Error::from(error)
+}
+
+#[async_trait]
+impl backoffice::domain::category::CategoryRepository for SqliteCategoryRepository {
+    type Error = common::domain::Error;
+
+    async fn get(&self) -> Result<Vec<backoffice::domain::category::Category>, Self::Error> {
+        // rowid breaks ties between rows created within the same millisecond
+        static SQL: &str = r#"
+            SELECT id, parent_id, name, position, path, created_at, updated_at
+            FROM category
+            ORDER BY created_at, rowid
+        "#;
+
+        sqlx::query_as(SQL)
+            .fetch_all(&mut *self.db.acquire("get_categories").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+    ) -> Result<Option<backoffice::domain::category::Category>, Self::Error> {
+        sqlx::query_as(SELECT_BY_ID_SQL)
+            .bind(id.to_primitive())
+            .fetch_optional(&mut *self.db.acquire("get_category_by_id").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn save(&self, category: &backoffice::domain::category::Category) -> Result<(), Self::Error> {
+        // a single statement, so the position cannot race with another insert; nothing is inserted
+        // when the parent does not exist
+        static SQL: &str = r#"
+            INSERT INTO category (id, parent_id, name, position, path)
+            SELECT ?1,
+                   ?2,
+                   ?3,
+                   (SELECT COUNT(*) FROM category WHERE parent_id IS ?2),
+                   COALESCE((SELECT path FROM category WHERE id = ?2), '/') || ?1 || '/'
+            WHERE ?2 IS NULL OR EXISTS (SELECT 1 FROM category WHERE id = ?2)
+        "#;
+
+        let result = sqlx::query(SQL)
+            .bind(category.id.to_primitive())
+            .bind(category.parent_id.map(|parent_id| parent_id.to_primitive()))
+            .bind(category.name.to_primitive())
+            .execute(&mut *self.db.acquire("save_category").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::sqlite::errcodes::Codes::from) {
+                    Some(libs::sqlite::errcodes::Codes::ConstraintPrimaryKey)
+                    | Some(libs::sqlite::errcodes::Codes::ConstraintUnique) => {
+                        common::domain::Error::CategoryAlreadyExists
+                    }
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::CategoryNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn move_to(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        parent_id: Option<&backoffice::domain::category::CategoryId>,
+        position: i32,
+    ) -> Result<(), Self::Error> {
+        static CLOSE_GAP_SQL: &str = r#"
+            UPDATE category
+            SET position = position - 1
+            WHERE parent_id IS ?1 AND position > ?2
+        "#;
+        static COUNT_SIBLINGS_SQL: &str = r#"
+            SELECT COUNT(*)
+            FROM category
+            WHERE parent_id IS ?1 AND id <> ?2
+        "#;
+        static OPEN_GAP_SQL: &str = r#"
+            UPDATE category
+            SET position = position + 1
+            WHERE parent_id IS ?1 AND id <> ?2 AND position >= ?3
+        "#;
+        static MOVE_SQL: &str = r#"
+            UPDATE category
+            SET parent_id = ?2,
+                position  = ?3
+            WHERE id = ?1
+        "#;
+        static REWRITE_PATHS_SQL: &str = r#"
+            UPDATE category
+            SET path = ?2 || substr(path, length(?1) + 1)
+            WHERE path LIKE ?1 || '%'
+        "#;
+
+        let mut connection = self.db.acquire("move_category").await?;
+        // rejections return before the commit, dropping the transaction rolls back whatever ran
+        let mut transaction = connection.begin().await.map_err(log_error)?;
+
+        let category: backoffice::domain::category::Category = sqlx::query_as(SELECT_BY_ID_SQL)
+            .bind(id.to_primitive())
+            .fetch_optional(&mut transaction)
+            .await
+            .map_err(log_error)?
+            .ok_or(common::domain::Error::CategoryNotFound)?;
+
+        let parent: Option<backoffice::domain::category::Category> = match parent_id {
+            Some(parent_id) => Some(
+                sqlx::query_as(SELECT_BY_ID_SQL)
+                    .bind(parent_id.to_primitive())
+                    .fetch_optional(&mut transaction)
+                    .await
+                    .map_err(log_error)?
+                    .ok_or(common::domain::Error::CategoryNotFound)?,
+            ),
+            None => None,
+        };
+
+        category.validate_parent(parent.as_ref())?;
+
+        let path = parent.as_ref().map_or_else(
+            || backoffice::domain::category::CategoryPath::root(category.id),
+            |parent| parent.path.child(category.id),
+        );
+        let parent_id = parent.map(|parent| parent.id.to_primitive());
+
+        sqlx::query(CLOSE_GAP_SQL)
+            .bind(category.parent_id.map(|parent_id| parent_id.to_primitive()))
+            .bind(category.position)
+            .execute(&mut transaction)
+            .await
+            .map_err(log_error)?;
+
+        let siblings: i64 = sqlx::query_scalar(COUNT_SIBLINGS_SQL)
+            .bind(&parent_id)
+            .bind(category.id.to_primitive())
+            .fetch_one(&mut transaction)
+            .await
+            .map_err(log_error)?;
+        let position = position.clamp(0, i32::try_from(siblings).unwrap_or(i32::MAX));
+
+        sqlx::query(OPEN_GAP_SQL)
+            .bind(&parent_id)
+            .bind(category.id.to_primitive())
+            .bind(position)
+            .execute(&mut transaction)
+            .await
+            .map_err(log_error)?;
+
+        sqlx::query(MOVE_SQL)
+            .bind(category.id.to_primitive())
+            .bind(&parent_id)
+            .bind(position)
+            .execute(&mut transaction)
+            .await
+            .map_err(log_error)?;
+
+        sqlx::query(REWRITE_PATHS_SQL)
+            .bind(category.path.to_primitive())
+            .bind(path.to_primitive())
+            .execute(&mut transaction)
+            .await
+            .map_err(log_error)?;
+
+        transaction.commit().await.map_err(log_error)
+    }
+
+    async fn assign_product(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<(), Self::Error> {
+        // sqlite does not name the failing foreign key, so each side is checked up front
+        static SQL: &str = r#"
+            SELECT EXISTS (SELECT 1 FROM category WHERE id = ?1),
+                   EXISTS (SELECT 1 FROM product WHERE id = ?2)
+        "#;
+        static INSERT_SQL: &str = r#"
+            INSERT OR IGNORE INTO product_category (category_id, product_id)
+            VALUES (?, ?)
+        "#;
+
+        let mut connection = self.db.acquire("assign_product_to_category").await?;
+
+        let (category_exists, product_exists): (bool, bool) = sqlx::query_as(SQL)
+            .bind(id.to_primitive())
+            .bind(product_id.to_primitive())
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(log_error)?;
+
+        if !category_exists {
+            return Err(common::domain::Error::CategoryNotFound);
+        }
+        if !product_exists {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+
+        sqlx::query(INSERT_SQL)
+            .bind(id.to_primitive())
+            .bind(product_id.to_primitive())
+            .execute(&mut *connection)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::sqlite::errcodes::Codes::from) {
+                    // either side was deleted in between
+                    Some(libs::sqlite::errcodes::Codes::ConstraintForeignKey) => {
+                        common::domain::Error::CategoryNotFound
+                    }
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        Ok(())
+    }
+
+    async fn unassign_product(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            DELETE FROM product_category
+            WHERE category_id = ? AND product_id = ?
+        "#;
+
+        sqlx::query(SQL)
+            .bind(id.to_primitive())
+            .bind(product_id.to_primitive())
+            .execute(&mut *self.db.acquire("unassign_product_from_category").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        Ok(())
+    }
+
+    async fn get_products(
+        &self,
+        id: &backoffice::domain::category::CategoryId,
+        include_subcategories: bool,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
+            FROM product
+            WHERE EXISTS (
+                SELECT 1
+                FROM product_category
+                JOIN category ON category.id = product_category.category_id
+                WHERE product_category.product_id = product.id
+                  AND (category.id = ?1
+                    OR (?2 AND category.path LIKE (SELECT path FROM category WHERE id = ?1) || '%'))
+            )
+            ORDER BY created_at, id
+            LIMIT ?3
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(id.to_primitive())
+            .bind(include_subcategories)
+            .bind(backoffice::domain::product::PRODUCT_PAGE_SIZE)
+            .fetch_all(&mut *self.db.acquire("get_category_products").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        libs::sqlite::fixture::SqliteDatabaseFixture,
+    ) {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        (
+            Arc::new(SqliteCategoryRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::SqliteProductRepository::new(
+                database.pool.clone(),
+            )),
+            database,
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_categories_when_get_by_id_then_return_same_category() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_saved_categories_when_get_by_id_then_return_same_category(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_existing_id_when_save_then_return_already_exists() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_existing_id_when_save_then_return_already_exists(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_parent_when_save_then_return_category_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_unknown_parent_when_save_then_return_category_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_siblings_when_save_then_append_them_in_order() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_siblings_when_save_then_append_them_in_order(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_siblings_when_move_to_within_parent_then_reorder_them() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_siblings_when_move_to_within_parent_then_reorder_them(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_subtree_when_move_to_other_parent_then_rewrite_paths_and_positions() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_subtree_when_move_to_other_parent_then_rewrite_paths_and_positions(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_own_descendant_as_parent_when_move_to_then_return_cycle_and_keep_tree() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_own_descendant_as_parent_when_move_to_then_return_cycle_and_keep_tree(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_category_or_parent_when_move_to_then_return_category_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_unknown_category_or_parent_when_move_to_then_return_category_not_found(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_in_subtree_when_get_products_then_return_each_once_in_creation_order() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_products_in_subtree_when_get_products_then_return_each_once_in_creation_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_category_or_product_when_assign_product_then_return_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::category::conformance::given_unknown_category_or_product_when_assign_product_then_return_not_found(repository, products).await;
+    }
+}
@@ -0,0 +1,149 @@
+use axum::async_trait;
+use sqlx::Connection;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct SqliteExchangeRateRepository {
+    db: libs::sqlite::Executor,
+}
+
+impl SqliteExchangeRateRepository {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl backoffice::domain::exchange_rate::ExchangeRateRepository for SqliteExchangeRateRepository {
+    type Error = common::domain::Error;
+
+    async fn get_latest(
+        &self,
+        base: common::domain::Currency,
+        quote: common::domain::Currency,
+        on: chrono::NaiveDate,
+    ) -> Result<Option<backoffice::domain::exchange_rate::ExchangeRate>, Self::Error> {
+        // ISO dates compare in time order as text
+        static SQL: &str = r#"
+            SELECT base, quote, rate, valid_on
+            FROM exchange_rate
+            WHERE base = ? AND quote = ? AND valid_on <= ?
+            ORDER BY valid_on DESC
+            LIMIT 1
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(base.to_primitive())
+            .bind(quote.to_primitive())
+            .bind(on)
+            .fetch_optional(&mut *self.db.acquire("get_latest_exchange_rate").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn save_many(&self, rates: &[backoffice::domain::exchange_rate::ExchangeRate]) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            INSERT INTO exchange_rate (base, quote, rate, valid_on)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT (base, quote, valid_on) DO UPDATE
+            SET rate = excluded.rate
+        "#;
+
+        let mut connection = self.db.acquire("save_exchange_rates").await?;
+
+        // a whole file or nothing, and far fewer syncs than one transaction per row
+        let mut transaction = connection.begin().await.inspect_err(|err| tracing::error!("{err}"))?;
+
+        for rate in rates {
+            sqlx::query(SQL)
+                .bind(rate.base.to_primitive())
+                .bind(rate.quote.to_primitive())
+                .bind(rate.rate.to_primitive())
+                .bind(rate.valid_on)
+                .execute(&mut *transaction)
+                .await
+                .inspect_err(|err| tracing::error!("{err}"))?;
+        }
+
+        transaction
+            .commit()
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error>,
+        libs::sqlite::fixture::SqliteDatabaseFixture,
+    ) {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        (
+            Arc::new(SqliteExchangeRateRepository::new(database.pool.clone())),
+            database,
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_rates_when_get_latest_then_return_none() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::exchange_rate::conformance::given_no_rates_when_get_latest_then_return_none(repository)
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_daily_rates_when_get_latest_then_return_last_published_on_or_before_date() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::exchange_rate::conformance::given_daily_rates_when_get_latest_then_return_last_published_on_or_before_date(repository).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_rate_when_get_latest_then_return_same_rate() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::exchange_rate::conformance::given_saved_rate_when_get_latest_then_return_same_rate(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_rates_when_save_many_same_day_then_replace_them() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::exchange_rate::conformance::given_saved_rates_when_save_many_same_day_then_replace_them(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_same_pair_and_day_twice_when_save_many_then_keep_last() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::exchange_rate::conformance::given_same_pair_and_day_twice_when_save_many_then_keep_last(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_rates_when_save_many_nothing_then_succeed() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::exchange_rate::conformance::given_no_rates_when_save_many_nothing_then_succeed(repository)
+            .await;
+    }
+}
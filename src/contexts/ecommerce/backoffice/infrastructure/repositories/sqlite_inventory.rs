@@ -0,0 +1,456 @@
+use axum::async_trait;
+use sqlx::Connection;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+// pending reservations still holding their units at ?3
+static RESERVED_SQL: &str = r#"
+    SELECT COALESCE(SUM(quantity), 0)
+    FROM stock_reservation
+    WHERE product_id = ?1 AND warehouse = ?2 AND status = 'pending' AND expires_at > ?3
+"#;
+
+pub struct SqliteInventoryRepository {
+    db: libs::sqlite::Executor,
+}
+
+impl SqliteInventoryRepository {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db: db.into() }
+    }
+
+    /// Moves a reservation on with `transition`, taking committed units out of the on-hand quantity in the
+    /// same transaction.
+    async fn transition(
+        &self,
+        operation: &'static str,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+        transition: fn(
+            &backoffice::domain::inventory::Reservation,
+            backoffice::domain::product::ProductTimeStamp,
+        ) -> Result<backoffice::domain::inventory::Reservation, common::domain::Error>,
+    ) -> Result<backoffice::domain::inventory::Reservation, common::domain::Error> {
+        // sqlite has no FOR UPDATE, writing first takes the database lock before anything is read
+        static LOCK_SQL: &str = r#"
+            UPDATE stock_reservation SET status = status WHERE id = ? AND product_id = ?
+        "#;
+        static SELECT_SQL: &str = r#"
+            SELECT id, product_id, warehouse, quantity, status, expires_at, created_at
+            FROM stock_reservation
+            WHERE id = ? AND product_id = ?
+        "#;
+        static TAKE_OUT_SQL: &str = r#"
+            UPDATE stock SET on_hand = on_hand - ? WHERE product_id = ? AND warehouse = ?
+        "#;
+        static UPDATE_STATUS_SQL: &str = r#"
+            UPDATE stock_reservation SET status = ? WHERE id = ?
+        "#;
+
+        let mut connection = self.db.acquire(operation).await?;
+        // rejections return before the commit, dropping the transaction rolls back whatever ran
+        let mut transaction = connection.begin().await.map_err(log_error)?;
+
+        sqlx::query(LOCK_SQL)
+            .bind(id.to_primitive())
+            .bind(product_id.to_primitive())
+            .execute(&mut transaction)
+            .await
+            .map_err(log_error)?;
+        let reservation: Option<backoffice::domain::inventory::Reservation> = sqlx::query_as(SELECT_SQL)
+            .bind(id.to_primitive())
+            .bind(product_id.to_primitive())
+            .fetch_optional(&mut transaction)
+            .await
+            .map_err(log_error)?;
+        let next = transition(&reservation.ok_or(common::domain::Error::ReservationNotFound)?, at)?;
+
+        if next.status == backoffice::domain::inventory::ReservationStatus::Committed {
+            sqlx::query(TAKE_OUT_SQL)
+                .bind(next.quantity)
+                .bind(product_id.to_primitive())
+                .bind(next.warehouse.to_primitive())
+                .execute(&mut transaction)
+                .await
+                .map_err(log_error)?;
+        }
+        sqlx::query(UPDATE_STATUS_SQL)
+            .bind(next.status.to_primitive())
+            .bind(id.to_primitive())
+            .execute(&mut transaction)
+            .await
+            .map_err(log_error)?;
+
+        transaction.commit().await.map_err(log_error)?;
+
+        Ok(next)
+    }
+}
+
+fn log_error(error: sqlx::Error) -> common::domain::Error {
+    tracing::error!("{error}");
+
+    common::domain::Error::from(error)
+}
+
+fn to_sqlite_timestamp(timestamp: backoffice::domain::product::ProductTimeStamp) -> String {
+    timestamp.to_datetime().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+#[async_trait]
+impl backoffice::domain::inventory::InventoryRepository for SqliteInventoryRepository {
+    type Error = common::domain::Error;
+
+    async fn get_stock(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Vec<backoffice::domain::inventory::StockLevel>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT stock.product_id, stock.warehouse, stock.on_hand,
+                   COALESCE(SUM(stock_reservation.quantity), 0) AS reserved
+            FROM stock
+            LEFT JOIN stock_reservation
+                   ON stock_reservation.product_id = stock.product_id
+                  AND stock_reservation.warehouse = stock.warehouse
+                  AND stock_reservation.status = 'pending'
+                  AND stock_reservation.expires_at > ?2
+            WHERE stock.product_id = ?1
+            GROUP BY stock.product_id, stock.warehouse, stock.on_hand
+            ORDER BY stock.warehouse
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(product_id.to_primitive())
+            .bind(to_sqlite_timestamp(at))
+            .fetch_all(&mut *self.db.acquire("get_stock").await?)
+            .await
+            .map_err(log_error)
+    }
+
+    async fn get_adjustments(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::inventory::StockAdjustment>, Self::Error> {
+        // rowid breaks ties between rows created within the same millisecond
+        static SQL: &str = r#"
+            SELECT id, product_id, warehouse, delta, reason, created_at
+            FROM stock_adjustment
+            WHERE product_id = ?
+            ORDER BY created_at, rowid
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(product_id.to_primitive())
+            .fetch_all(&mut *self.db.acquire("get_stock_adjustments").await?)
+            .await
+            .map_err(log_error)
+    }
+
+    async fn adjust(
+        &self,
+        adjustment: &backoffice::domain::inventory::StockAdjustment,
+    ) -> Result<backoffice::domain::inventory::StockLevel, Self::Error> {
+        // written first, so that the database lock is taken before the stock is read
+        static CREATE_STOCK_SQL: &str = r#"
+            INSERT INTO stock (product_id, warehouse) VALUES (?, ?)
+            ON CONFLICT (product_id, warehouse) DO NOTHING
+        "#;
+        static SELECT_STOCK_SQL: &str = r#"
+            SELECT on_hand FROM stock WHERE product_id = ? AND warehouse = ?
+        "#;
+        static UPDATE_STOCK_SQL: &str = r#"
+            UPDATE stock SET on_hand = on_hand + ? WHERE product_id = ? AND warehouse = ?
+        "#;
+        static INSERT_SQL: &str = r#"
+            INSERT INTO stock_adjustment (id, product_id, warehouse, delta, reason)
+            VALUES (?, ?, ?, ?, ?)
+        "#;
+
+        let product_id = adjustment.product_id.to_primitive();
+        let warehouse = adjustment.warehouse.to_primitive();
+
+        let mut connection = self.db.acquire("adjust_stock").await?;
+        // rejections return before the commit, dropping the transaction rolls back whatever ran
+        let mut transaction = connection.begin().await.map_err(log_error)?;
+
+        sqlx::query(CREATE_STOCK_SQL)
+            .bind(&product_id)
+            .bind(&warehouse)
+            .execute(&mut transaction)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::sqlite::errcodes::Codes::from) {
+                    Some(libs::sqlite::errcodes::Codes::ConstraintForeignKey) => common::domain::Error::ProductNotFound,
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+        let on_hand: i64 = sqlx::query_scalar(SELECT_STOCK_SQL)
+            .bind(&product_id)
+            .bind(&warehouse)
+            .fetch_one(&mut transaction)
+            .await
+            .map_err(log_error)?;
+        let reserved: i64 = sqlx::query_scalar(RESERVED_SQL)
+            .bind(&product_id)
+            .bind(&warehouse)
+            .bind(to_sqlite_timestamp(adjustment.created_at))
+            .fetch_one(&mut transaction)
+            .await
+            .map_err(log_error)?;
+
+        if on_hand + adjustment.delta < reserved {
+            return Err(common::domain::Error::InsufficientStock);
+        }
+
+        sqlx::query(UPDATE_STOCK_SQL)
+            .bind(adjustment.delta)
+            .bind(&product_id)
+            .bind(&warehouse)
+            .execute(&mut transaction)
+            .await
+            .map_err(log_error)?;
+        sqlx::query(INSERT_SQL)
+            .bind(adjustment.id.to_primitive())
+            .bind(&product_id)
+            .bind(&warehouse)
+            .bind(adjustment.delta)
+            .bind(adjustment.reason.to_primitive())
+            .execute(&mut transaction)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::sqlite::errcodes::Codes::from) {
+                    Some(libs::sqlite::errcodes::Codes::ConstraintPrimaryKey) => {
+                        common::domain::Error::StockAdjustmentAlreadyExists
+                    }
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        transaction.commit().await.map_err(log_error)?;
+
+        Ok(backoffice::domain::inventory::StockLevel {
+            product_id: adjustment.product_id,
+            warehouse: adjustment.warehouse.clone(),
+            on_hand: on_hand + adjustment.delta,
+            reserved,
+        })
+    }
+
+    async fn get_reservation(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+    ) -> Result<Option<backoffice::domain::inventory::Reservation>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, product_id, warehouse, quantity, status, expires_at, created_at
+            FROM stock_reservation
+            WHERE id = ? AND product_id = ?
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(id.to_primitive())
+            .bind(product_id.to_primitive())
+            .fetch_optional(&mut *self.db.acquire("get_stock_reservation").await?)
+            .await
+            .map_err(log_error)
+    }
+
+    async fn reserve(&self, reservation: &backoffice::domain::inventory::Reservation) -> Result<(), Self::Error> {
+        // one statement checks and inserts under the database lock, nothing can slip in between
+        static SQL: &str = r#"
+            INSERT INTO stock_reservation (id, product_id, warehouse, quantity, status, expires_at, created_at)
+            SELECT ?4, ?1, ?2, ?5, ?6, ?7, ?3
+            FROM stock
+            WHERE stock.product_id = ?1
+              AND stock.warehouse = ?2
+              AND stock.on_hand - (
+                      SELECT COALESCE(SUM(quantity), 0)
+                      FROM stock_reservation
+                      WHERE product_id = ?1 AND warehouse = ?2 AND status = 'pending' AND expires_at > ?3
+                  ) >= ?5
+        "#;
+
+        let result = sqlx::query(SQL)
+            .bind(reservation.product_id.to_primitive())
+            .bind(reservation.warehouse.to_primitive())
+            .bind(to_sqlite_timestamp(reservation.created_at))
+            .bind(reservation.id.to_primitive())
+            .bind(reservation.quantity)
+            .bind(reservation.status.to_primitive())
+            .bind(to_sqlite_timestamp(reservation.expires_at))
+            .execute(&mut *self.db.acquire("reserve_stock").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::sqlite::errcodes::Codes::from) {
+                    Some(libs::sqlite::errcodes::Codes::ConstraintPrimaryKey) => {
+                        common::domain::Error::ReservationAlreadyExists
+                    }
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::InsufficientStock);
+        }
+
+        Ok(())
+    }
+
+    async fn commit(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<backoffice::domain::inventory::Reservation, Self::Error> {
+        self.transition(
+            "commit_stock_reservation",
+            product_id,
+            id,
+            at,
+            backoffice::domain::inventory::Reservation::commit,
+        )
+        .await
+    }
+
+    async fn release(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::inventory::ReservationId,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<backoffice::domain::inventory::Reservation, Self::Error> {
+        self.transition(
+            "release_stock_reservation",
+            product_id,
+            id,
+            at,
+            backoffice::domain::inventory::Reservation::release,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        libs::sqlite::fixture::SqliteDatabaseFixture,
+    ) {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        (
+            Arc::new(SqliteInventoryRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::SqliteProductRepository::new(
+                database.pool.clone(),
+            )),
+            database,
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_stock_when_get_then_return_empty_vecs() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_no_stock_when_get_then_return_empty_vecs(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_adjustments_when_get_stock_then_sum_them_per_warehouse_in_code_order() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_adjustments_when_get_stock_then_sum_them_per_warehouse_in_code_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_reserved_stock_when_adjust_below_it_then_return_insufficient_stock_and_keep_stock() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_reserved_stock_when_adjust_below_it_then_return_insufficient_stock_and_keep_stock(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_adjustment_when_adjust_with_same_id_then_return_already_exists() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_saved_adjustment_when_adjust_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_adjust_then_return_product_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_unknown_product_when_adjust_then_return_product_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_stock_when_reserve_then_hold_it_until_released() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_stock_when_reserve_then_hold_it_until_released(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_pending_reservation_when_commit_then_take_it_out_of_on_hand_once() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_pending_reservation_when_commit_then_take_it_out_of_on_hand_once(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_expired_reservation_when_reserve_then_ignore_it_and_refuse_its_commit() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_expired_reservation_when_reserve_then_ignore_it_and_refuse_its_commit(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_reservation_when_reserve_with_same_id_then_return_already_exists() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_saved_reservation_when_reserve_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_reservation_of_other_product_when_commit_or_release_then_return_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_reservation_of_other_product_when_commit_or_release_then_return_not_found(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_stock_when_reserve_concurrently_then_never_oversell() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_stock_when_reserve_concurrently_then_never_oversell(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_pending_reservation_when_commit_and_release_concurrently_then_apply_only_one() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::inventory::conformance::given_pending_reservation_when_commit_and_release_concurrently_then_apply_only_one(repository, products).await;
+    }
+}
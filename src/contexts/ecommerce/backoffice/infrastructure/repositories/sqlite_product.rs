@@ -0,0 +1,394 @@
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct SqliteProductRepository {
+    db: libs::sqlite::Executor,
+}
+
+impl SqliteProductRepository {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db: db.into() }
+    }
+
+    /// Binds the repository to an open transaction, its statements commit or roll back together.
+    pub fn in_transaction(transaction: libs::sqlite::SharedTransaction) -> Self {
+        Self {
+            db: libs::sqlite::Executor::Transaction(transaction),
+        }
+    }
+}
+
+/// Same text format as the column defaults, so that timestamps compare in time order.
+fn to_sqlite_timestamp(timestamp: backoffice::domain::product::ProductTimeStamp) -> String {
+    timestamp.to_datetime().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+#[async_trait]
+impl backoffice::domain::product::ProductRepository for SqliteProductRepository {
+    type Error = common::domain::Error;
+
+    async fn get(
+        &self,
+        status: Option<backoffice::domain::product::ProductStatus>,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        // id breaks ties between rows created within the same millisecond, in the order postgres uses
+        static SQL: &str = r#"
+                SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
+                FROM product
+                WHERE ?2 IS NULL OR status = ?2
+                ORDER BY created_at, id
+                LIMIT ?1
+            "#;
+
+        sqlx::query_as(SQL)
+            .bind(backoffice::domain::product::PRODUCT_PAGE_SIZE)
+            .bind(status.map(|status| status.to_primitive()))
+            .fetch_all(&mut *self.db.acquire("get_products").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &backoffice::domain::product::ProductId,
+    ) -> Result<Option<backoffice::domain::product::Product>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
+            FROM product
+            WHERE id = ?
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(id.to_primitive())
+            .fetch_optional(&mut *self.db.acquire("get_product_by_id").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_many_by_ids(
+        &self,
+        ids: &[backoffice::domain::product::ProductId],
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        // sqlite has no array binds, the ids travel as a json array instead
+        static SQL: &str = r#"
+            SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
+            FROM product
+            WHERE id IN (SELECT value FROM json_each(?))
+        "#;
+
+        let ids: Vec<String> = ids.iter().map(|id| id.to_primitive()).collect();
+
+        sqlx::query_as(SQL)
+            .bind(sqlx::types::Json(ids))
+            .fetch_all(&mut *self.db.acquire("get_products_by_ids").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn save(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            INSERT INTO product (id, name, price, currency, updated_by, status, publish_at, unpublish_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#;
+
+        sqlx::query(SQL)
+            .bind(product.id.to_primitive())
+            .bind(product.name.to_primitive())
+            .bind(product.price.minor_units())
+            .bind(product.price.currency().to_primitive())
+            .bind(libs::database::current_caller())
+            .bind(product.status.to_primitive())
+            .bind(product.publish_at.map(to_sqlite_timestamp))
+            .bind(product.unpublish_at.map(to_sqlite_timestamp))
+            .execute(&mut *self.db.acquire("save_product").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::sqlite::errcodes::Codes::from) {
+                    Some(libs::sqlite::errcodes::Codes::ConstraintPrimaryKey)
+                    | Some(libs::sqlite::errcodes::Codes::ConstraintUnique) => {
+                        common::domain::Error::ProductAlreadyExists
+                    }
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        Ok(())
+    }
+
+    async fn update(&self, product: &backoffice::domain::product::Product) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            UPDATE product
+            SET name       = ?2,
+                price      = ?3,
+                currency   = ?4,
+                updated_by = ?5
+            WHERE id = ?1
+        "#;
+
+        let result = sqlx::query(SQL)
+            .bind(product.id.to_primitive())
+            .bind(product.name.to_primitive())
+            .bind(product.price.minor_units())
+            .bind(product.price.currency().to_primitive())
+            .bind(libs::database::current_caller())
+            .execute(&mut *self.db.acquire("update_product").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        product: &backoffice::domain::product::Product,
+        from: backoffice::domain::product::ProductStatus,
+    ) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            UPDATE product
+            SET status       = ?2,
+                publish_at   = ?3,
+                unpublish_at = ?4,
+                updated_by   = ?5
+            WHERE id = ?1
+              AND status = ?6
+        "#;
+
+        static EXISTS_SQL: &str = r#"
+            SELECT EXISTS (SELECT 1 FROM product WHERE id = ?)
+        "#;
+
+        let result = sqlx::query(SQL)
+            .bind(product.id.to_primitive())
+            .bind(product.status.to_primitive())
+            .bind(product.publish_at.map(to_sqlite_timestamp))
+            .bind(product.unpublish_at.map(to_sqlite_timestamp))
+            .bind(libs::database::current_caller())
+            .bind(from.to_primitive())
+            .execute(&mut *self.db.acquire("update_product_status").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        if result.rows_affected() > 0 {
+            return Ok(());
+        }
+
+        let exists: bool = sqlx::query_scalar(EXISTS_SQL)
+            .bind(product.id.to_primitive())
+            .fetch_one(&mut *self.db.acquire("update_product_status").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        match exists {
+            true => Err(common::domain::Error::ProductStatusConflict),
+            false => Err(common::domain::Error::ProductNotFound),
+        }
+    }
+
+    async fn get_scheduled(
+        &self,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Vec<backoffice::domain::product::Product>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, name, price, currency, created_at, updated_at, status, publish_at, unpublish_at
+            FROM product
+            WHERE (publish_at IS NOT NULL OR unpublish_at IS NOT NULL)
+              AND ((status = 'draft' AND publish_at <= ?1) OR (status = 'published' AND unpublish_at <= ?1))
+            ORDER BY created_at, id
+            LIMIT ?2
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(to_sqlite_timestamp(at))
+            .bind(backoffice::domain::product::PRODUCT_PAGE_SIZE)
+            .fetch_all(&mut *self.db.acquire("get_scheduled_products").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        libs::sqlite::fixture::SqliteDatabaseFixture,
+    ) {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        (Arc::new(SqliteProductRepository::new(database.pool.clone())), database)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_empty_store_when_get_then_return_empty_vec() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_empty_store_when_get_then_return_empty_vec(repository).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_when_get_then_return_them_in_creation_order() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_products_when_get_then_return_them_in_creation_order(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_created_at_same_time_when_get_then_order_them_by_id() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_products_created_at_same_time_when_get_then_order_them_by_id(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_more_products_than_page_size_when_get_then_return_one_page() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_more_products_than_page_size_when_get_then_return_one_page(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_empty_store_when_get_by_id_then_return_none() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_empty_store_when_get_by_id_then_return_none(repository).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_get_by_id_then_return_same_product() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_saved_product_when_get_by_id_then_return_same_product(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_price_beyond_i32_in_three_decimal_currency_when_save_then_keep_exact_amount() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_price_beyond_i32_in_three_decimal_currency_when_save_then_keep_exact_amount(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_when_get_many_by_ids_then_return_only_matching() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_products_when_get_many_by_ids_then_return_only_matching(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_save_with_same_id_then_return_already_exists() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_saved_product_when_save_with_same_id_then_return_already_exists(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_update_then_return_new_name_and_price() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_saved_product_when_update_then_return_new_name_and_price(
+            repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_update_then_return_not_found() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_unknown_product_when_update_then_return_not_found(repository)
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_products_in_every_status_when_get_with_status_then_return_only_matching() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_products_in_every_status_when_get_with_status_then_return_only_matching(repository).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_update_status_then_return_new_status_and_schedule() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_saved_product_when_update_status_then_return_new_status_and_schedule(repository).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_status_changed_meanwhile_when_update_status_then_return_conflict() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_status_changed_meanwhile_when_update_status_then_return_conflict(repository).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_scheduled_products_when_get_scheduled_then_return_only_due_ones() {
+        let (repository, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product::conformance::given_scheduled_products_when_get_scheduled_then_return_only_due_ones(repository).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_update_then_refresh_updated_at() {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+        let repository = SqliteProductRepository::new(database.pool.clone());
+
+        let builder = backoffice::domain::product::fixture::ProductBuilder::default();
+        backoffice::domain::product::ProductRepository::save(&repository, &builder.to_entity())
+            .await
+            .unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        sqlx::query("UPDATE product SET name = 'renamed' WHERE id = ?")
+            .bind(builder.id.to_primitive())
+            .execute(&*database.pool)
+            .await
+            .unwrap();
+
+        let product = backoffice::domain::product::ProductRepository::get_by_id(&repository, &builder.id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(product.updated_at > product.created_at);
+    }
+}
@@ -0,0 +1,147 @@
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct SqliteProductEventRepository {
+    db: libs::sqlite::Executor,
+}
+
+impl SqliteProductEventRepository {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db: db.into() }
+    }
+
+    /// Reads through an open transaction, so events of its uncommitted writes are visible.
+    pub fn in_transaction(transaction: libs::sqlite::SharedTransaction) -> Self {
+        Self {
+            db: libs::sqlite::Executor::Transaction(transaction),
+        }
+    }
+}
+
+#[async_trait]
+impl backoffice::domain::product_event::ProductEventRepository for SqliteProductEventRepository {
+    type Error = common::domain::Error;
+
+    async fn get_after(
+        &self,
+        sequence: &backoffice::domain::product_event::ProductEventSequence,
+        limit: i64,
+    ) -> Result<Vec<backoffice::domain::product_event::ProductEvent>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT sequence, product_id, kind, payload, occurred_at
+            FROM product_event
+            WHERE sequence > ?
+            ORDER BY sequence
+            LIMIT ?
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(sequence.to_primitive())
+            .bind(limit)
+            .fetch_all(&mut *self.db.acquire("get_product_events").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_last_sequence(&self) -> Result<backoffice::domain::product_event::ProductEventSequence, Self::Error> {
+        static SQL: &str = r#"
+            SELECT COALESCE(MAX(sequence), 0)
+            FROM product_event
+        "#;
+
+        let sequence: i64 = sqlx::query_scalar(SQL)
+            .fetch_one(&mut *self.db.acquire("get_last_product_event_sequence").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        backoffice::domain::product_event::ProductEventSequence::try_from(sequence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    struct RepositoryFixture {
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+        _database: libs::sqlite::fixture::SqliteDatabaseFixture,
+    }
+
+    async fn compose_repository_fixture() -> RepositoryFixture {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        RepositoryFixture {
+            product_repository: Arc::new(backoffice::infrastructure::SqliteProductRepository::new(
+                database.pool.clone(),
+            )),
+            product_event_repository: Arc::new(SqliteProductEventRepository::new(database.pool.clone())),
+            _database: database,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_empty_database_when_get_last_sequence_then_return_zero() {
+        let fixture = compose_repository_fixture().await;
+
+        let sequence = fixture.product_event_repository.get_last_sequence().await.unwrap();
+
+        assert_eq!(sequence.to_primitive(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_products_when_get_after_then_return_created_events_in_order() {
+        let fixture = compose_repository_fixture().await;
+
+        let p1 = backoffice::domain::product::fixture::ProductBuilder::default();
+        p1.save(&fixture.product_repository).await;
+
+        let p2 = backoffice::domain::product::fixture::ProductBuilder::default();
+        p2.save(&fixture.product_repository).await;
+
+        let events = fixture
+            .product_event_repository
+            .get_after(&backoffice::domain::product_event::ProductEventSequence::default(), 10)
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(events[0].sequence < events[1].sequence);
+        assert!(events[0].product_id == p1.id);
+        assert!(events[1].product_id == p2.id);
+        assert_eq!(
+            events[0].kind,
+            backoffice::domain::product_event::ProductEventKind::Created
+        );
+        assert_eq!(events[0].payload["name"], p1.name.to_primitive());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_get_after_then_return_payload_of_public_columns() {
+        let fixture = compose_repository_fixture().await;
+
+        backoffice::domain::product_event::conformance::given_saved_product_when_get_after_then_return_payload_of_public_columns(
+            fixture.product_event_repository,
+            fixture.product_repository,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_status_transition_when_get_after_then_return_payload_with_new_status() {
+        let fixture = compose_repository_fixture().await;
+
+        backoffice::domain::product_event::conformance::given_status_transition_when_get_after_then_return_payload_with_new_status(
+            fixture.product_event_repository,
+            fixture.product_repository,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,242 @@
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct SqliteProductPriceRepository {
+    db: libs::sqlite::Executor,
+}
+
+impl SqliteProductPriceRepository {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+/// Same text format as the column defaults, so that timestamps compare in time order.
+fn to_sqlite_timestamp(timestamp: backoffice::domain::product::ProductTimeStamp) -> String {
+    timestamp.to_datetime().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+#[async_trait]
+impl backoffice::domain::product_price::ProductPriceRepository for SqliteProductPriceRepository {
+    type Error = common::domain::Error;
+
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::product_price::ProductPrice>, Self::Error> {
+        // nulls sort first in sqlite, rowid breaks ties between rows created within the same millisecond
+        static SQL: &str = r#"
+            SELECT id, product_id, price, currency, valid_from, valid_until, created_at
+            FROM product_price
+            WHERE product_id = ?
+            ORDER BY currency, valid_from, created_at, rowid
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(product_id.to_primitive())
+            .fetch_all(&mut *self.db.acquire("get_product_prices_by_product_id").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn get_current(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        currency: common::domain::Currency,
+        at: backoffice::domain::product::ProductTimeStamp,
+    ) -> Result<Option<backoffice::domain::product_price::ProductPrice>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, product_id, price, currency, valid_from, valid_until, created_at
+            FROM product_price
+            WHERE product_id = ?1
+              AND currency = ?2
+              AND (valid_from IS NULL OR valid_from <= ?3)
+              AND (valid_until IS NULL OR ?3 < valid_until)
+            ORDER BY valid_from DESC, created_at DESC, rowid DESC
+            LIMIT 1
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(product_id.to_primitive())
+            .bind(currency.to_primitive())
+            .bind(to_sqlite_timestamp(at))
+            .fetch_optional(&mut *self.db.acquire("get_current_product_price").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn save(&self, price: &backoffice::domain::product_price::ProductPrice) -> Result<(), Self::Error> {
+        // the update is skipped when the id belongs to another product's price
+        static SQL: &str = r#"
+            INSERT INTO product_price (id, product_id, price, currency, valid_from, valid_until)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT (id) DO UPDATE
+            SET price       = excluded.price,
+                currency    = excluded.currency,
+                valid_from  = excluded.valid_from,
+                valid_until = excluded.valid_until
+            WHERE product_price.product_id = excluded.product_id
+        "#;
+
+        let result = sqlx::query(SQL)
+            .bind(price.id.to_primitive())
+            .bind(price.product_id.to_primitive())
+            .bind(price.price.minor_units())
+            .bind(price.price.currency().to_primitive())
+            .bind(price.valid_from.map(to_sqlite_timestamp))
+            .bind(price.valid_until.map(to_sqlite_timestamp))
+            .execute(&mut *self.db.acquire("save_product_price").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::sqlite::errcodes::Codes::from) {
+                    Some(libs::sqlite::errcodes::Codes::ConstraintForeignKey) => common::domain::Error::ProductNotFound,
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::ProductPriceAlreadyExists);
+        }
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::product_price::ProductPriceId,
+    ) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            DELETE FROM product_price
+            WHERE id = ? AND product_id = ?
+        "#;
+
+        let result = sqlx::query(SQL)
+            .bind(id.to_primitive())
+            .bind(product_id.to_primitive())
+            .execute(&mut *self.db.acquire("delete_product_price").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::ProductPriceNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        libs::sqlite::fixture::SqliteDatabaseFixture,
+    ) {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        (
+            Arc::new(SqliteProductPriceRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::SqliteProductRepository::new(
+                database.pool.clone(),
+            )),
+            database,
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_prices_when_get_by_product_id_then_return_empty_vec() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_no_prices_when_get_by_product_id_then_return_empty_vec(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_prices_of_several_products_when_get_by_product_id_then_return_only_its_list_in_order() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_prices_of_several_products_when_get_by_product_id_then_return_only_its_list_in_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_price_when_get_by_product_id_then_return_same_price() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_saved_price_when_get_by_product_id_then_return_same_price(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_base_price_and_promotion_when_get_current_then_return_promotion_only_within_its_window() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_base_price_and_promotion_when_get_current_then_return_promotion_only_within_its_window(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_price_in_other_currency_or_outside_window_when_get_current_then_return_none() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_price_in_other_currency_or_outside_window_when_get_current_then_return_none(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_price_when_save_with_same_id_then_replace_it() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_saved_price_when_save_with_same_id_then_replace_it(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_price_of_other_product_when_save_with_same_id_then_return_already_exists() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_price_of_other_product_when_save_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_save_then_return_product_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_unknown_product_when_save_then_return_product_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_price_when_delete_then_remove_only_it() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_saved_price_when_delete_then_remove_only_it(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_price_of_other_product_when_delete_then_return_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price::conformance::given_price_of_other_product_when_delete_then_return_not_found(
+            repository, products,
+        )
+        .await;
+    }
+}
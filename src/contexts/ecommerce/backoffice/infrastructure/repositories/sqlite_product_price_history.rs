@@ -0,0 +1,108 @@
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct SqliteProductPriceHistoryRepository {
+    db: libs::sqlite::Executor,
+}
+
+impl SqliteProductPriceHistoryRepository {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+/// Same text format as the column default, so that timestamps compare in time order.
+fn to_sqlite_timestamp(timestamp: backoffice::domain::product::ProductTimeStamp) -> String {
+    timestamp.to_datetime().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+#[async_trait]
+impl backoffice::domain::product_price_history::ProductPriceHistoryRepository for SqliteProductPriceHistoryRepository {
+    type Error = common::domain::Error;
+
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        from: Option<backoffice::domain::product::ProductTimeStamp>,
+        until: Option<backoffice::domain::product::ProductTimeStamp>,
+    ) -> Result<Vec<backoffice::domain::product_price_history::ProductPriceChange>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT product_id, old_price, old_currency, new_price, new_currency, changed_by, changed_at
+            FROM product_price_history
+            WHERE product_id = ?1
+              AND (?2 IS NULL OR changed_at >= ?2)
+              AND (?3 IS NULL OR changed_at < ?3)
+            ORDER BY changed_at, id
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(product_id.to_primitive())
+            .bind(from.map(to_sqlite_timestamp))
+            .bind(until.map(to_sqlite_timestamp))
+            .fetch_all(&mut *self.db.acquire("get_product_price_history").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::product_price_history::DynProductPriceHistoryRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        libs::sqlite::fixture::SqliteDatabaseFixture,
+    ) {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        (
+            Arc::new(SqliteProductPriceHistoryRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::SqliteProductRepository::new(
+                database.pool.clone(),
+            )),
+            database,
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_new_product_when_get_by_product_id_then_return_initial_price() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_new_product_when_get_by_product_id_then_return_initial_price(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_price_and_currency_updates_when_get_by_product_id_then_return_changes_in_order() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_price_and_currency_updates_when_get_by_product_id_then_return_changes_in_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_update_keeping_price_when_get_by_product_id_then_record_nothing() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_update_keeping_price_when_get_by_product_id_then_record_nothing(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_changes_of_several_products_when_get_by_product_id_then_return_only_its_history() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_changes_of_several_products_when_get_by_product_id_then_return_only_its_history(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_period_when_get_by_product_id_then_return_changes_from_start_until_before_end() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::product_price_history::conformance::given_period_when_get_by_product_id_then_return_changes_from_start_until_before_end(repository, products).await;
+    }
+}
@@ -0,0 +1,300 @@
+use axum::async_trait;
+use sqlx::Connection;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct SqliteVariantRepository {
+    db: libs::sqlite::Executor,
+}
+
+impl SqliteVariantRepository {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+fn log_error(error: sqlx::Error) -> common::domain::Error {
+    tracing::error!("{error}");
+
+    common::domain::Error::from(error)
+}
+
+#[async_trait]
+impl backoffice::domain::variant::VariantRepository for SqliteVariantRepository {
+    type Error = common::domain::Error;
+
+    async fn get_options(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::variant::VariantOption>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT name, allowed_values
+            FROM variant_option
+            WHERE product_id = ?
+            ORDER BY position
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(product_id.to_primitive())
+            .fetch_all(&mut *self.db.acquire("get_variant_options").await?)
+            .await
+            .map_err(log_error)
+    }
+
+    async fn save_options(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        options: &[backoffice::domain::variant::VariantOption],
+    ) -> Result<(), Self::Error> {
+        static PRODUCT_EXISTS_SQL: &str = r#"
+            SELECT EXISTS (SELECT 1 FROM product WHERE id = ?)
+        "#;
+        static DELETE_SQL: &str = r#"
+            DELETE FROM variant_option WHERE product_id = ?
+        "#;
+        static INSERT_SQL: &str = r#"
+            INSERT INTO variant_option (product_id, name, position, allowed_values)
+            VALUES (?, ?, ?, ?)
+        "#;
+
+        let mut connection = self.db.acquire("save_variant_options").await?;
+        // rejections return before the commit, dropping the transaction rolls back whatever ran
+        let mut transaction = connection.begin().await.map_err(log_error)?;
+
+        let exists: bool = sqlx::query_scalar(PRODUCT_EXISTS_SQL)
+            .bind(product_id.to_primitive())
+            .fetch_one(&mut transaction)
+            .await
+            .map_err(log_error)?;
+        if !exists {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+
+        sqlx::query(DELETE_SQL)
+            .bind(product_id.to_primitive())
+            .execute(&mut transaction)
+            .await
+            .map_err(log_error)?;
+        for (position, option) in options.iter().enumerate() {
+            sqlx::query(INSERT_SQL)
+                .bind(product_id.to_primitive())
+                .bind(&option.name)
+                .bind(position as i32)
+                .bind(sqlx::types::Json(&option.values))
+                .execute(&mut transaction)
+                .await
+                .map_err(log_error)?;
+        }
+
+        transaction.commit().await.map_err(log_error)
+    }
+
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::variant::Variant>, Self::Error> {
+        // rowid breaks ties between rows created within the same millisecond
+        static SQL: &str = r#"
+            SELECT id, product_id, sku, options, price, currency, barcode, created_at, updated_at
+            FROM variant
+            WHERE product_id = ?
+            ORDER BY created_at, rowid
+        "#;
+
+        sqlx::query_as(SQL)
+            .bind(product_id.to_primitive())
+            .fetch_all(&mut *self.db.acquire("get_variants_by_product_id").await?)
+            .await
+            .map_err(log_error)
+    }
+
+    async fn save(&self, variant: &backoffice::domain::variant::Variant) -> Result<(), Self::Error> {
+        // the update is skipped when the id belongs to another product's variant
+        static SQL: &str = r#"
+            INSERT INTO variant (id, product_id, sku, options, price, currency, barcode)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT (id) DO UPDATE
+            SET sku      = excluded.sku,
+                options  = excluded.options,
+                price    = excluded.price,
+                currency = excluded.currency,
+                barcode  = excluded.barcode
+            WHERE variant.product_id = excluded.product_id
+        "#;
+
+        let result = sqlx::query(SQL)
+            .bind(variant.id.to_primitive())
+            .bind(variant.product_id.to_primitive())
+            .bind(variant.sku.to_primitive())
+            .bind(sqlx::types::Json(&variant.options))
+            .bind(variant.price.map(|price| price.minor_units()))
+            .bind(variant.price.map(|price| price.currency().to_primitive()))
+            .bind(variant.barcode.as_ref().map(|barcode| barcode.to_primitive()))
+            .execute(&mut *self.db.acquire("save_variant").await?)
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(
+                |error| match error.as_database_error().map(libs::sqlite::errcodes::Codes::from) {
+                    // the primary key conflict is handled by the upsert, only the sku index is left
+                    Some(libs::sqlite::errcodes::Codes::ConstraintUnique) => {
+                        common::domain::Error::VariantSkuAlreadyExists
+                    }
+                    Some(libs::sqlite::errcodes::Codes::ConstraintForeignKey) => common::domain::Error::ProductNotFound,
+                    _ => common::domain::Error::from(error),
+                },
+            )?;
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::VariantAlreadyExists);
+        }
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::variant::VariantId,
+    ) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            DELETE FROM variant
+            WHERE id = ? AND product_id = ?
+        "#;
+
+        let result = sqlx::query(SQL)
+            .bind(id.to_primitive())
+            .bind(product_id.to_primitive())
+            .execute(&mut *self.db.acquire("delete_variant").await?)
+            .await
+            .map_err(log_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::VariantNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        libs::sqlite::fixture::SqliteDatabaseFixture,
+    ) {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        (
+            Arc::new(SqliteVariantRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::SqliteProductRepository::new(
+                database.pool.clone(),
+            )),
+            database,
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_variants_when_get_then_return_empty_vecs() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_no_variants_when_get_then_return_empty_vecs(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_options_when_save_options_then_replace_them_in_order() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_options_when_save_options_then_replace_them_in_order(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_save_options_then_return_product_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_unknown_product_when_save_options_then_return_product_not_found(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variants_when_get_by_product_id_then_return_only_its_variants_in_order() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_variants_when_get_by_product_id_then_return_only_its_variants_in_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variant_when_get_by_product_id_then_return_same_variant() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_get_by_product_id_then_return_same_variant(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variant_when_save_with_same_id_then_replace_it() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_save_with_same_id_then_replace_it(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_sku_taken_by_any_product_when_save_then_return_sku_already_exists() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_sku_taken_by_any_product_when_save_then_return_sku_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_variant_of_other_product_when_save_with_same_id_then_return_already_exists() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_variant_of_other_product_when_save_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_save_variant_then_return_product_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_unknown_product_when_save_variant_then_return_product_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variant_when_delete_then_remove_only_it_and_free_its_sku() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_delete_then_remove_only_it_and_free_its_sku(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_variant_of_other_product_when_delete_then_return_not_found() {
+        let (repository, products, _database) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_variant_of_other_product_when_delete_then_return_not_found(
+            repository, products,
+        )
+        .await;
+    }
+}
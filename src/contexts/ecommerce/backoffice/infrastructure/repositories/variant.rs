@@ -0,0 +1,378 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::Connection;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+const SKU_CONSTRAINT: &str = "variants_by_sku";
+
+pub struct PostgresVariantRepository {
+    db: libs::postgres::Executor,
+    retry_policy: libs::postgres::retry::RetryPolicy,
+}
+
+impl PostgresVariantRepository {
+    pub fn new(db: libs::postgres::ConnectionPool) -> Self {
+        Self {
+            db: db.into(),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    /// Serves reads from the replica behind `router`, writes still go to its primary.
+    pub fn routed(router: Arc<libs::postgres::ReplicaRouter>) -> Self {
+        Self {
+            db: libs::postgres::Executor::Routed(router),
+            retry_policy: libs::postgres::retry::RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: libs::postgres::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
+
+#[async_trait]
+impl backoffice::domain::variant::VariantRepository for PostgresVariantRepository {
+    type Error = common::domain::Error;
+
+    async fn get_options(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::variant::VariantOption>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT name, allowed_values
+            FROM variant_option
+            WHERE product_id = $1
+            ORDER BY position
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_variant_options",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(product_id.to_uuid())
+                        .fetch_all(&mut *self.db.acquire_read("get_variant_options").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn save_options(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        options: &[backoffice::domain::variant::VariantOption],
+    ) -> Result<(), Self::Error> {
+        // locks the product row, so concurrent replacements of the same definitions apply one after the other
+        static LOCK_PRODUCT_SQL: &str = r#"
+            SELECT id FROM product WHERE id = $1 FOR UPDATE
+        "#;
+        static DELETE_SQL: &str = r#"
+            DELETE FROM variant_option WHERE product_id = $1
+        "#;
+        static INSERT_SQL: &str = r#"
+            INSERT INTO variant_option (product_id, name, position, allowed_values)
+            VALUES ($1, $2, $3, $4)
+        "#;
+
+        let found = self
+            .retry_policy
+            .run(
+                "save_variant_options",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    let mut connection = self.db.acquire("save_variant_options").await?;
+                    let mut transaction = connection.begin().await?;
+
+                    let product: Option<(uuid::Uuid,)> = sqlx::query_as(LOCK_PRODUCT_SQL)
+                        .bind(product_id.to_uuid())
+                        .fetch_optional(&mut transaction)
+                        .await?;
+                    if product.is_none() {
+                        return Ok(false);
+                    }
+
+                    sqlx::query(DELETE_SQL)
+                        .bind(product_id.to_uuid())
+                        .execute(&mut transaction)
+                        .await?;
+                    for (position, option) in options.iter().enumerate() {
+                        sqlx::query(INSERT_SQL)
+                            .bind(product_id.to_uuid())
+                            .bind(&option.name)
+                            .bind(position as i32)
+                            .bind(sqlx::types::Json(&option.values))
+                            .execute(&mut transaction)
+                            .await?;
+                    }
+
+                    transaction.commit().await?;
+
+                    Ok(true)
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        self.db.record_write();
+
+        if !found {
+            return Err(common::domain::Error::ProductNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn get_by_product_id(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+    ) -> Result<Vec<backoffice::domain::variant::Variant>, Self::Error> {
+        static SQL: &str = r#"
+            SELECT id, product_id, sku, options, price, currency, barcode, created_at, updated_at
+            FROM variant
+            WHERE product_id = $1
+            ORDER BY created_at, id
+        "#;
+
+        self.retry_policy
+            .run(
+                "get_variants_by_product_id",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query_as(SQL)
+                        .bind(product_id.to_uuid())
+                        .fetch_all(&mut *self.db.acquire_read("get_variants_by_product_id").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn save(&self, variant: &backoffice::domain::variant::Variant) -> Result<(), Self::Error> {
+        // the update is skipped when the id belongs to another product's variant
+        static SQL: &str = r#"
+            INSERT INTO variant (id, product_id, sku, options, price, currency, barcode)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id) DO UPDATE
+            SET sku      = EXCLUDED.sku,
+                options  = EXCLUDED.options,
+                price    = EXCLUDED.price,
+                currency = EXCLUDED.currency,
+                barcode  = EXCLUDED.barcode
+            WHERE variant.product_id = EXCLUDED.product_id
+        "#;
+
+        let result = self
+            .retry_policy
+            .run(
+                "save_variant",
+                libs::postgres::retry::Idempotency::Idempotent,
+                || async {
+                    sqlx::query(SQL)
+                        .bind(variant.id.to_uuid())
+                        .bind(variant.product_id.to_uuid())
+                        .bind(variant.sku.to_primitive())
+                        .bind(sqlx::types::Json(&variant.options))
+                        .bind(variant.price.map(|price| price.minor_units()))
+                        .bind(variant.price.map(|price| price.currency().to_primitive()))
+                        .bind(variant.barcode.as_ref().map(|barcode| barcode.to_primitive()))
+                        .execute(&mut *self.db.acquire("save_variant").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(|error| {
+                let Some(database_error) = error.as_database_error() else {
+                    return common::domain::Error::from(error);
+                };
+
+                match (
+                    libs::postgres::errcodes::Codes::from(database_error),
+                    database_error.constraint(),
+                ) {
+                    (libs::postgres::errcodes::Codes::UniqueViolation, Some(SKU_CONSTRAINT)) => {
+                        common::domain::Error::VariantSkuAlreadyExists
+                    }
+                    (libs::postgres::errcodes::Codes::ForeignKeyViolation, _) => common::domain::Error::ProductNotFound,
+                    _ => common::domain::Error::from(error),
+                }
+            })?;
+
+        self.db.record_write();
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::VariantAlreadyExists);
+        }
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        product_id: &backoffice::domain::product::ProductId,
+        id: &backoffice::domain::variant::VariantId,
+    ) -> Result<(), Self::Error> {
+        static SQL: &str = r#"
+            DELETE FROM variant
+            WHERE id = $1 AND product_id = $2
+        "#;
+
+        // a dropped connection may hide a committed delete, so repeating it could report a false not found
+        let result = self
+            .retry_policy
+            .run(
+                "delete_variant",
+                libs::postgres::retry::Idempotency::NonIdempotent,
+                || async {
+                    sqlx::query(SQL)
+                        .bind(id.to_uuid())
+                        .bind(product_id.to_uuid())
+                        .execute(&mut *self.db.acquire("delete_variant").await?)
+                        .await
+                },
+            )
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        self.db.record_write();
+
+        if result.rows_affected() == 0 {
+            return Err(common::domain::Error::VariantNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contexts::ecommerce::backoffice;
+    use crate::libs;
+
+    use super::*;
+
+    async fn compose_repository_fixture() -> (
+        backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+
+        (
+            Arc::new(PostgresVariantRepository::new(database.pool.clone())),
+            Arc::new(backoffice::infrastructure::PostgresProductRepository::new(
+                database.pool,
+            )),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_no_variants_when_get_then_return_empty_vecs() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_no_variants_when_get_then_return_empty_vecs(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_options_when_save_options_then_replace_them_in_order() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_options_when_save_options_then_replace_them_in_order(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_save_options_then_return_product_not_found() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_unknown_product_when_save_options_then_return_product_not_found(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variants_when_get_by_product_id_then_return_only_its_variants_in_order() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_variants_when_get_by_product_id_then_return_only_its_variants_in_order(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variant_when_get_by_product_id_then_return_same_variant() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_get_by_product_id_then_return_same_variant(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variant_when_save_with_same_id_then_replace_it() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_save_with_same_id_then_replace_it(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_sku_taken_by_any_product_when_save_then_return_sku_already_exists() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_sku_taken_by_any_product_when_save_then_return_sku_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_variant_of_other_product_when_save_with_same_id_then_return_already_exists() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_variant_of_other_product_when_save_with_same_id_then_return_already_exists(repository, products).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unknown_product_when_save_variant_then_return_product_not_found() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_unknown_product_when_save_variant_then_return_product_not_found(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_variant_when_delete_then_remove_only_it_and_free_its_sku() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_saved_variant_when_delete_then_remove_only_it_and_free_its_sku(
+            repository, products,
+        )
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_variant_of_other_product_when_delete_then_return_not_found() {
+        let (repository, products) = compose_repository_fixture().await;
+
+        backoffice::domain::variant::conformance::given_variant_of_other_product_when_delete_then_return_not_found(
+            repository, products,
+        )
+        .await;
+    }
+}
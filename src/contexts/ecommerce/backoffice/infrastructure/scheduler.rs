@@ -0,0 +1,75 @@
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use tracing::Instrument;
+
+use crate::contexts::ecommerce::backoffice;
+use crate::contexts::ecommerce::common::application::usecase::UseCase;
+use crate::libs;
+
+pub const DEFAULT_PRODUCT_SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Recorded as the author of the status changes the schedules make.
+const PRODUCT_SCHEDULER_CALLER: &str = "product-scheduler";
+
+/// Applies due product schedules every `interval` until the use case is dropped.
+pub fn spawn_product_scheduler(
+    usecase: &Arc<backoffice::application::usecases::ApplyProductSchedules>,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    let usecase: Weak<backoffice::application::usecases::ApplyProductSchedules> = Arc::downgrade(usecase);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+
+            let Some(usecase) = usecase.upgrade() else {
+                return;
+            };
+
+            // a failed run is retried on the next tick, the due products are still there
+            match libs::database::with_caller(
+                Some(String::from(PRODUCT_SCHEDULER_CALLER)),
+                usecase
+                    .exec(backoffice::application::usecases::ApplyProductSchedulesInput)
+                    .instrument(tracing::debug_span!("Execute use case", name = "ApplyProductSchedules")),
+            )
+            .await
+            {
+                Ok(applied) if !applied.is_empty() => tracing::info!("applied {} product schedules", applied.len()),
+                Ok(_) => {}
+                Err(err) => tracing::error!("could not apply product schedules: {err}"),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contexts::ecommerce::common;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn given_due_schedule_when_spawn_then_publish_product_on_first_tick() {
+        let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+            Arc::new(backoffice::infrastructure::InMemoryProductRepository::new());
+        let usecase = Arc::new(backoffice::application::usecases::ApplyProductSchedules::new(
+            product_repository.clone(),
+        ));
+
+        let product = backoffice::domain::product::fixture::ProductBuilder::default()
+            .scheduled(Some(chrono::Duration::minutes(-1)), None);
+        product.save(&product_repository).await;
+
+        let handle = spawn_product_scheduler(&usecase, Duration::from_secs(3600));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        drop(usecase);
+
+        let stored = product_repository.get_by_id(&product.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, backoffice::domain::product::ProductStatus::Published);
+        handle.abort();
+    }
+}
@@ -0,0 +1,36 @@
+CREATE TABLE category
+(
+    id        UUID DEFAULT uuid_generate_v4(),
+    parent_id UUID    NULL REFERENCES category (id),
+    name      TEXT    NOT NULL,
+    -- place among the children of the same parent, counted from 0
+    position  INTEGER NOT NULL CHECK (position >= 0),
+    -- ids from the root down to the category, '/<root id>/.../<id>/', a subtree shares its prefix
+    path      TEXT    NOT NULL,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX categories_by_parent_id ON category (parent_id, position);
+CREATE INDEX categories_by_path ON category (path text_pattern_ops);
+
+CREATE TRIGGER update_category_timestamp_trigger
+    BEFORE UPDATE
+    ON category
+    FOR EACH ROW
+    EXECUTE FUNCTION update_timestamp();
+
+CREATE TABLE product_category
+(
+    category_id UUID NOT NULL REFERENCES category (id) ON DELETE CASCADE,
+    product_id  UUID NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (category_id, product_id)
+);
+
+CREATE INDEX product_categories_by_product_id ON product_category (product_id);
\ No newline at end of file
@@ -0,0 +1,13 @@
+CREATE TABLE exchange_rate
+(
+    base       TEXT    NOT NULL CHECK (base ~ '^[A-Z]{3}$'),
+    quote      TEXT    NOT NULL CHECK (quote ~ '^[A-Z]{3}$' AND quote <> base),
+    -- units of quote per unit of base, kept with the exact digits it was published with
+    rate       NUMERIC NOT NULL CHECK (rate > 0),
+    valid_on   DATE    NOT NULL,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- also serves the lookup of the latest rate of a pair
+    PRIMARY KEY (base, quote, valid_on)
+);
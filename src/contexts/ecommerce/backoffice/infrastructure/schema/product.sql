@@ -2,17 +2,29 @@ CREATE TABLE product
 (
     id       UUID DEFAULT uuid_generate_v4(),
     name     TEXT    NOT NULL,
-    price    INTEGER NOT NULL,
-    currency TEXT    NOT NULL,
+    -- amount in minor units of the ISO 4217 currency, e.g. cents for EUR and yen for JPY
+    price    BIGINT  NOT NULL CHECK (price >= 0),
+    currency TEXT    NOT NULL CHECK (currency ~ '^[A-Z]{3}$'),
+    -- lifecycle stage, see ProductStatus for the allowed transitions
+    status   TEXT    NOT NULL DEFAULT 'draft' CHECK (status IN ('draft', 'published', 'archived')),
+
+    -- applied by the product schedule job once reached
+    publish_at   TIMESTAMPTZ NULL,
+    unpublish_at TIMESTAMPTZ NULL CHECK (unpublish_at > publish_at),
 
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- caller behind the last write, recorded with every price change
+    updated_by TEXT        NULL,
 
     PRIMARY KEY (id)
 );
 
 CREATE INDEX products_by_name ON product (name);
 CREATE INDEX products_by_currency ON product (currency);
+CREATE INDEX products_by_status ON product (status, created_at);
+CREATE INDEX scheduled_products ON product (created_at)
+    WHERE publish_at IS NOT NULL OR unpublish_at IS NOT NULL;
 
 CREATE TRIGGER update_product_timestamp_trigger
     BEFORE UPDATE
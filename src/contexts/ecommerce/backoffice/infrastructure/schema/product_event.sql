@@ -0,0 +1,50 @@
+CREATE TABLE product_event
+(
+    sequence   BIGSERIAL,
+    product_id UUID  NOT NULL,
+    kind       TEXT  NOT NULL,
+    payload    JSONB NOT NULL,
+
+    -- sequences are handed out on insert but show up on commit, readers order by the writing
+    -- transaction first and only read past transactions that can no longer commit
+    xid XID8 NOT NULL DEFAULT pg_current_xact_id(),
+
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (sequence)
+);
+
+CREATE INDEX product_events_by_product_id ON product_event (product_id);
+CREATE INDEX product_events_by_position ON product_event (xid, sequence);
+
+-- columns streamed to clients, spelled out so that bookkeeping such as updated_by stays private
+CREATE FUNCTION product_event_payload(product product)
+    RETURNS JSONB AS
+    $$
+SELECT jsonb_build_object('id', product.id, 'name', product.name, 'price', product.price,
+    'currency', product.currency, 'status', product.status, 'publish_at', product.publish_at,
+    'unpublish_at', product.unpublish_at, 'created_at', product.created_at, 'updated_at', product.updated_at);
+$$
+LANGUAGE sql;
+
+CREATE FUNCTION record_product_event()
+    RETURNS TRIGGER AS
+    $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO product_event (product_id, kind, payload) VALUES (OLD.id, 'deleted', product_event_payload(OLD));
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO product_event (product_id, kind, payload)
+    VALUES (NEW.id, CASE TG_OP WHEN 'INSERT' THEN 'created' ELSE 'updated' END, product_event_payload(NEW));
+    RETURN NEW;
+END;
+$$
+LANGUAGE plpgsql;
+
+CREATE TRIGGER record_product_event_trigger
+    AFTER INSERT OR UPDATE OR DELETE
+    ON product
+    FOR EACH ROW
+    EXECUTE FUNCTION record_product_event();
\ No newline at end of file
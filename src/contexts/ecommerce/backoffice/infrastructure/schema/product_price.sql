@@ -0,0 +1,17 @@
+CREATE TABLE product_price
+(
+    id          UUID DEFAULT uuid_generate_v4(),
+    product_id  UUID        NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+    -- amount in minor units of the ISO 4217 currency, as product.price
+    price       BIGINT      NOT NULL CHECK (price >= 0),
+    currency    TEXT        NOT NULL CHECK (currency ~ '^[A-Z]{3}$'),
+    -- NULL leaves the window open on that side, valid_until is exclusive
+    valid_from  TIMESTAMPTZ NULL,
+    valid_until TIMESTAMPTZ NULL CHECK (valid_until > valid_from),
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX product_prices_by_product_id_and_currency ON product_price (product_id, currency, valid_from);
@@ -0,0 +1,41 @@
+CREATE TABLE product_price_history
+(
+    id           BIGSERIAL,
+    product_id   UUID   NOT NULL,
+    -- NULL for the price the product was created with
+    old_price    BIGINT NULL,
+    old_currency TEXT   NULL,
+    new_price    BIGINT NOT NULL,
+    new_currency TEXT   NOT NULL,
+    changed_by   TEXT   NULL,
+
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX product_price_history_by_product_id ON product_price_history (product_id, changed_at);
+
+CREATE FUNCTION record_product_price_change()
+    RETURNS TRIGGER AS
+    $$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        INSERT INTO product_price_history (product_id, new_price, new_currency, changed_by)
+        VALUES (NEW.id, NEW.price, NEW.currency, NEW.updated_by);
+    ELSIF OLD.price IS DISTINCT FROM NEW.price OR OLD.currency IS DISTINCT FROM NEW.currency THEN
+        INSERT INTO product_price_history (product_id, old_price, old_currency, new_price, new_currency, changed_by)
+        VALUES (NEW.id, OLD.price, OLD.currency, NEW.price, NEW.currency, NEW.updated_by);
+    END IF;
+
+    RETURN NEW;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- the history outlives deleted products, as the event log does
+CREATE TRIGGER record_product_price_change_trigger
+    AFTER INSERT OR UPDATE
+    ON product
+    FOR EACH ROW
+    EXECUTE FUNCTION record_product_price_change();
\ No newline at end of file
@@ -1,5 +1,5 @@
-INSERT INTO product (id, name, price, currency)
-VALUES ('710ba3ee-29bd-4a22-98b4-cb0da764de1a', 'Fender Stratocaster American Standard', 360000, 'EUR');
+INSERT INTO product (id, name, price, currency, status)
+VALUES ('710ba3ee-29bd-4a22-98b4-cb0da764de1a', 'Fender Stratocaster American Standard', 360000, 'EUR', 'published');
 
-INSERT INTO product (id, name, price, currency)
-VALUES ('1b607dda-8b86-4229-b5b8-57a936b90803', 'Ford Mustang 5.0 V8 GT', 5758062, 'EUR');
\ No newline at end of file
+INSERT INTO product (id, name, price, currency, status)
+VALUES ('1b607dda-8b86-4229-b5b8-57a936b90803', 'Ford Mustang 5.0 V8 GT', 5758062, 'EUR', 'published');
\ No newline at end of file
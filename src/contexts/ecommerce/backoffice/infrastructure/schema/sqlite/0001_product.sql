@@ -0,0 +1,23 @@
+CREATE TABLE product
+(
+    id       TEXT    NOT NULL CHECK (length(id) = 36),
+    name     TEXT    NOT NULL,
+    price    INTEGER NOT NULL,
+    currency TEXT    NOT NULL,
+
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX products_by_name ON product (name);
+CREATE INDEX products_by_currency ON product (currency);
+
+CREATE TRIGGER update_product_timestamp_trigger
+    AFTER UPDATE OF id, name, price, currency
+    ON product
+    FOR EACH ROW
+BEGIN
+    UPDATE product SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now') WHERE id = NEW.id;
+END;
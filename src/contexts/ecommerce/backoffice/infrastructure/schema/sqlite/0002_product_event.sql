@@ -0,0 +1,42 @@
+CREATE TABLE product_event
+(
+    sequence   INTEGER PRIMARY KEY AUTOINCREMENT,
+    product_id TEXT NOT NULL,
+    kind       TEXT NOT NULL,
+    payload    TEXT NOT NULL,
+
+    occurred_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE INDEX product_events_by_product_id ON product_event (product_id);
+
+CREATE TRIGGER record_product_created_event_trigger
+    AFTER INSERT
+    ON product
+    FOR EACH ROW
+BEGIN
+    INSERT INTO product_event (product_id, kind, payload)
+    VALUES (NEW.id, 'created', json_object('id', NEW.id, 'name', NEW.name, 'price', NEW.price,
+        'currency', NEW.currency, 'created_at', NEW.created_at, 'updated_at', NEW.updated_at));
+END;
+
+-- fires once the timestamp trigger has refreshed updated_at, so the payload carries the final row
+CREATE TRIGGER record_product_updated_event_trigger
+    AFTER UPDATE OF updated_at
+    ON product
+    FOR EACH ROW
+BEGIN
+    INSERT INTO product_event (product_id, kind, payload)
+    VALUES (NEW.id, 'updated', json_object('id', NEW.id, 'name', NEW.name, 'price', NEW.price,
+        'currency', NEW.currency, 'created_at', NEW.created_at, 'updated_at', NEW.updated_at));
+END;
+
+CREATE TRIGGER record_product_deleted_event_trigger
+    AFTER DELETE
+    ON product
+    FOR EACH ROW
+BEGIN
+    INSERT INTO product_event (product_id, kind, payload)
+    VALUES (OLD.id, 'deleted', json_object('id', OLD.id, 'name', OLD.name, 'price', OLD.price,
+        'currency', OLD.currency, 'created_at', OLD.created_at, 'updated_at', OLD.updated_at));
+END;
@@ -0,0 +1,18 @@
+-- price holds minor units of the ISO 4217 currency as a 64 bit INTEGER, these mirror the postgres checks
+CREATE TRIGGER check_product_price_insert_trigger
+    BEFORE INSERT
+    ON product
+    FOR EACH ROW
+    WHEN NEW.price < 0 OR NEW.currency NOT GLOB '[A-Z][A-Z][A-Z]'
+BEGIN
+    SELECT RAISE(ABORT, 'invalid product price');
+END;
+
+CREATE TRIGGER check_product_price_update_trigger
+    BEFORE UPDATE OF price, currency
+    ON product
+    FOR EACH ROW
+    WHEN NEW.price < 0 OR NEW.currency NOT GLOB '[A-Z][A-Z][A-Z]'
+BEGIN
+    SELECT RAISE(ABORT, 'invalid product price');
+END;
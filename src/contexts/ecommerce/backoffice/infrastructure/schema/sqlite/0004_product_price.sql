@@ -0,0 +1,16 @@
+CREATE TABLE product_price
+(
+    id          TEXT    NOT NULL CHECK (length(id) = 36),
+    product_id  TEXT    NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+    price       INTEGER NOT NULL CHECK (price >= 0),
+    currency    TEXT    NOT NULL CHECK (currency GLOB '[A-Z][A-Z][A-Z]'),
+    -- same text format as the default timestamps, so they compare in time order
+    valid_from  TEXT    NULL,
+    valid_until TEXT    NULL CHECK (valid_until > valid_from),
+
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX product_prices_by_product_id_and_currency ON product_price (product_id, currency, valid_from);
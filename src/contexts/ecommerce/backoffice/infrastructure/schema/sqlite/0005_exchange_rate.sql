@@ -0,0 +1,12 @@
+CREATE TABLE exchange_rate
+(
+    base       TEXT NOT NULL CHECK (base GLOB '[A-Z][A-Z][A-Z]'),
+    quote      TEXT NOT NULL CHECK (quote GLOB '[A-Z][A-Z][A-Z]' AND quote <> base),
+    -- decimal text, sqlite has no exact numeric type
+    rate       TEXT NOT NULL,
+    valid_on   TEXT NOT NULL CHECK (valid_on GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]'),
+
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (base, quote, valid_on)
+);
@@ -0,0 +1,35 @@
+ALTER TABLE product ADD COLUMN updated_by TEXT NULL;
+
+CREATE TABLE product_price_history
+(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    product_id   TEXT    NOT NULL,
+    old_price    INTEGER NULL,
+    old_currency TEXT    NULL,
+    new_price    INTEGER NOT NULL,
+    new_currency TEXT    NOT NULL,
+    changed_by   TEXT    NULL,
+
+    changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE INDEX product_price_history_by_product_id ON product_price_history (product_id, changed_at);
+
+CREATE TRIGGER record_product_price_created_trigger
+    AFTER INSERT
+    ON product
+    FOR EACH ROW
+BEGIN
+    INSERT INTO product_price_history (product_id, new_price, new_currency, changed_by)
+    VALUES (NEW.id, NEW.price, NEW.currency, NEW.updated_by);
+END;
+
+CREATE TRIGGER record_product_price_changed_trigger
+    AFTER UPDATE OF price, currency
+    ON product
+    FOR EACH ROW
+    WHEN OLD.price IS NOT NEW.price OR OLD.currency IS NOT NEW.currency
+BEGIN
+    INSERT INTO product_price_history (product_id, old_price, old_currency, new_price, new_currency, changed_by)
+    VALUES (NEW.id, OLD.price, OLD.currency, NEW.price, NEW.currency, NEW.updated_by);
+END;
@@ -0,0 +1,38 @@
+CREATE TABLE category
+(
+    id        TEXT    NOT NULL CHECK (length(id) = 36),
+    parent_id TEXT    NULL REFERENCES category (id),
+    name      TEXT    NOT NULL,
+    -- place among the children of the same parent, counted from 0
+    position  INTEGER NOT NULL CHECK (position >= 0),
+    -- ids from the root down to the category, '/<root id>/.../<id>/', a subtree shares its prefix
+    path      TEXT    NOT NULL,
+
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX categories_by_parent_id ON category (parent_id, position);
+CREATE INDEX categories_by_path ON category (path);
+
+CREATE TRIGGER update_category_timestamp_trigger
+    AFTER UPDATE OF parent_id, name, position, path
+    ON category
+    FOR EACH ROW
+BEGIN
+    UPDATE category SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now') WHERE id = NEW.id;
+END;
+
+CREATE TABLE product_category
+(
+    category_id TEXT NOT NULL REFERENCES category (id) ON DELETE CASCADE,
+    product_id  TEXT NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (category_id, product_id)
+);
+
+CREATE INDEX product_categories_by_product_id ON product_category (product_id);
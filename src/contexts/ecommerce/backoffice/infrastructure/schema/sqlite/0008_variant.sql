@@ -0,0 +1,41 @@
+CREATE TABLE variant_option
+(
+    product_id     TEXT    NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+    name           TEXT    NOT NULL,
+    -- display order among the options of the product, counted from 0
+    position       INTEGER NOT NULL CHECK (position >= 0),
+    -- JSON array of the values a variant may pick, in display order
+    allowed_values TEXT    NOT NULL,
+
+    PRIMARY KEY (product_id, name)
+);
+
+CREATE TABLE variant
+(
+    id         TEXT    NOT NULL CHECK (length(id) = 36),
+    product_id TEXT    NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+    -- unique across all products, whatever product a variant belongs to
+    sku        TEXT    NOT NULL,
+    -- JSON object of option name to the value picked for it
+    options    TEXT    NOT NULL,
+    price      INTEGER NULL CHECK (price >= 0),
+    currency   TEXT    NULL CHECK (currency GLOB '[A-Z][A-Z][A-Z]'),
+    barcode    TEXT    NULL,
+
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (id),
+    CHECK ((price IS NULL) = (currency IS NULL))
+);
+
+CREATE UNIQUE INDEX variants_by_sku ON variant (sku);
+CREATE INDEX variants_by_product_id ON variant (product_id);
+
+CREATE TRIGGER update_variant_timestamp_trigger
+    AFTER UPDATE OF sku, options, price, currency, barcode
+    ON variant
+    FOR EACH ROW
+BEGIN
+    UPDATE variant SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now') WHERE id = NEW.id;
+END;
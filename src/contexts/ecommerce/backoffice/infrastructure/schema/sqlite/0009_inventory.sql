@@ -0,0 +1,44 @@
+CREATE TABLE stock
+(
+    product_id TEXT    NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+    warehouse  TEXT    NOT NULL,
+    -- committed reservations are already taken out, pending ones are summed up from stock_reservation
+    on_hand    INTEGER NOT NULL DEFAULT 0 CHECK (on_hand >= 0),
+
+    PRIMARY KEY (product_id, warehouse)
+);
+
+CREATE TABLE stock_adjustment
+(
+    id         TEXT    NOT NULL CHECK (length(id) = 36),
+    product_id TEXT    NOT NULL,
+    warehouse  TEXT    NOT NULL,
+    delta      INTEGER NOT NULL CHECK (delta <> 0),
+    reason     TEXT    NOT NULL CHECK (reason IN ('received', 'returned', 'damaged', 'lost', 'correction')),
+
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (id),
+    FOREIGN KEY (product_id, warehouse) REFERENCES stock (product_id, warehouse) ON DELETE CASCADE
+);
+
+CREATE INDEX stock_adjustments_by_product_id ON stock_adjustment (product_id);
+
+CREATE TABLE stock_reservation
+(
+    id         TEXT    NOT NULL CHECK (length(id) = 36),
+    product_id TEXT    NOT NULL,
+    warehouse  TEXT    NOT NULL,
+    quantity   INTEGER NOT NULL CHECK (quantity > 0),
+    -- a pending reservation past expires_at no longer holds its units, it is never rewritten as expired
+    status     TEXT    NOT NULL CHECK (status IN ('pending', 'committed', 'released')),
+    expires_at TEXT    NOT NULL,
+
+    created_at TEXT NOT NULL,
+
+    PRIMARY KEY (id),
+    FOREIGN KEY (product_id, warehouse) REFERENCES stock (product_id, warehouse) ON DELETE CASCADE
+);
+
+CREATE INDEX pending_stock_reservations ON stock_reservation (product_id, warehouse, expires_at)
+    WHERE status = 'pending';
@@ -0,0 +1,60 @@
+ALTER TABLE product ADD COLUMN status TEXT NOT NULL DEFAULT 'draft'
+    CHECK (status IN ('draft', 'published', 'archived'));
+ALTER TABLE product ADD COLUMN publish_at TEXT NULL;
+ALTER TABLE product ADD COLUMN unpublish_at TEXT NULL CHECK (unpublish_at > publish_at);
+
+-- products saved before the lifecycle existed were live, so they stay published; the triggers below
+-- are not in place yet, so this neither refreshes updated_at nor records events
+UPDATE product SET status = 'published';
+
+CREATE INDEX products_by_status ON product (status, created_at);
+CREATE INDEX scheduled_products ON product (created_at)
+    WHERE publish_at IS NOT NULL OR unpublish_at IS NOT NULL;
+
+-- status changes are updates too, they refresh updated_at and get recorded as events
+DROP TRIGGER update_product_timestamp_trigger;
+
+CREATE TRIGGER update_product_timestamp_trigger
+    AFTER UPDATE OF id, name, price, currency, status, publish_at, unpublish_at
+    ON product
+    FOR EACH ROW
+BEGIN
+    UPDATE product SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now') WHERE id = NEW.id;
+END;
+
+DROP TRIGGER record_product_created_event_trigger;
+DROP TRIGGER record_product_updated_event_trigger;
+DROP TRIGGER record_product_deleted_event_trigger;
+
+CREATE TRIGGER record_product_created_event_trigger
+    AFTER INSERT
+    ON product
+    FOR EACH ROW
+BEGIN
+    INSERT INTO product_event (product_id, kind, payload)
+    VALUES (NEW.id, 'created', json_object('id', NEW.id, 'name', NEW.name, 'price', NEW.price,
+        'currency', NEW.currency, 'status', NEW.status, 'publish_at', NEW.publish_at,
+        'unpublish_at', NEW.unpublish_at, 'created_at', NEW.created_at, 'updated_at', NEW.updated_at));
+END;
+
+CREATE TRIGGER record_product_updated_event_trigger
+    AFTER UPDATE OF updated_at
+    ON product
+    FOR EACH ROW
+BEGIN
+    INSERT INTO product_event (product_id, kind, payload)
+    VALUES (NEW.id, 'updated', json_object('id', NEW.id, 'name', NEW.name, 'price', NEW.price,
+        'currency', NEW.currency, 'status', NEW.status, 'publish_at', NEW.publish_at,
+        'unpublish_at', NEW.unpublish_at, 'created_at', NEW.created_at, 'updated_at', NEW.updated_at));
+END;
+
+CREATE TRIGGER record_product_deleted_event_trigger
+    AFTER DELETE
+    ON product
+    FOR EACH ROW
+BEGIN
+    INSERT INTO product_event (product_id, kind, payload)
+    VALUES (OLD.id, 'deleted', json_object('id', OLD.id, 'name', OLD.name, 'price', OLD.price,
+        'currency', OLD.currency, 'status', OLD.status, 'publish_at', OLD.publish_at,
+        'unpublish_at', OLD.unpublish_at, 'created_at', OLD.created_at, 'updated_at', OLD.updated_at));
+END;
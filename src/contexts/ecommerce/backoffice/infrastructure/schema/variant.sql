@@ -0,0 +1,41 @@
+CREATE TABLE variant_option
+(
+    product_id     UUID    NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+    name           TEXT    NOT NULL,
+    -- display order among the options of the product, counted from 0
+    position       INTEGER NOT NULL CHECK (position >= 0),
+    -- values a variant may pick, in display order
+    allowed_values JSONB   NOT NULL,
+
+    PRIMARY KEY (product_id, name)
+);
+
+CREATE TABLE variant
+(
+    id         UUID DEFAULT uuid_generate_v4(),
+    product_id UUID   NOT NULL REFERENCES product (id) ON DELETE CASCADE,
+    -- unique across all products, whatever product a variant belongs to
+    sku        TEXT   NOT NULL,
+    -- option name to the value picked for it
+    options    JSONB  NOT NULL,
+    -- override of the product price in minor units, NULL to sell at the product price
+    price      BIGINT NULL CHECK (price >= 0),
+    currency   TEXT   NULL CHECK (currency ~ '^[A-Z]{3}$'),
+    -- EAN-8, UPC-A or EAN-13
+    barcode    TEXT   NULL,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id),
+    CHECK ((price IS NULL) = (currency IS NULL))
+);
+
+CREATE UNIQUE INDEX variants_by_sku ON variant (sku);
+CREATE INDEX variants_by_product_id ON variant (product_id, created_at);
+
+CREATE TRIGGER update_variant_timestamp_trigger
+    BEFORE UPDATE
+    ON variant
+    FOR EACH ROW
+    EXECUTE FUNCTION update_timestamp();
\ No newline at end of file
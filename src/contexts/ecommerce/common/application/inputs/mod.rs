@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::contexts::ecommerce::common;
+
+/// Money as callers send it, a decimal amount and an ISO 4217 currency code.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MoneyInput {
+    pub amount: String,
+    pub currency: String,
+}
+
+impl TryFrom<MoneyInput> for common::domain::Money {
+    type Error = common::domain::Error;
+
+    fn try_from(value: MoneyInput) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast Money from MoneyInput").entered();
+
+        let currency = common::domain::Currency::try_from(value.currency)?;
+
+        common::domain::Money::parse(&value.amount, currency)
+    }
+}
@@ -1 +1,3 @@
+pub mod inputs;
+pub mod unit_of_work;
 pub mod usecase;
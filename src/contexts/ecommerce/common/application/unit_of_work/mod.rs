@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::backoffice;
+
+pub type DynUnitOfWork<E> = Box<dyn UnitOfWork<Error = E> + Send + Sync + 'static>;
+pub type DynUnitOfWorkFactory<E> = Arc<dyn UnitOfWorkFactory<Error = E> + Send + Sync + 'static>;
+
+#[async_trait]
+pub trait UnitOfWorkFactory {
+    type Error;
+
+    async fn begin(&self) -> Result<DynUnitOfWork<Self::Error>, Self::Error>;
+}
+
+/// Groups writes across repositories so they apply all together on commit or not at all.
+///
+/// Dropping a unit of work without committing rolls it back.
+#[async_trait]
+pub trait UnitOfWork {
+    type Error;
+
+    fn product_repository(&self) -> backoffice::domain::product::DynProductRepository<Self::Error>;
+    fn product_event_repository(&self) -> backoffice::domain::product_event::DynProductEventRepository<Self::Error>;
+
+    async fn commit(self: Box<Self>) -> Result<(), Self::Error>;
+    async fn rollback(self: Box<Self>) -> Result<(), Self::Error>;
+}
@@ -4,9 +4,56 @@ use derive_more::Display;
 pub enum Error {
     #[display(fmt = "db error: {}", _0)]
     Persistence(String),
+    #[display(fmt = "db unavailable: {}", _0)]
+    Unavailable(String),
+    #[display(fmt = "db timeout: {}", _0)]
+    Timeout(String),
+    #[display(fmt = "conflicting data")]
+    Conflict(String),
+    #[display(fmt = "invalid data")]
+    InvalidData(String),
 
     #[display(fmt = "product already exists")]
     ProductAlreadyExists,
+    #[display(fmt = "product not found")]
+    ProductNotFound,
+    #[display(fmt = "product status changed concurrently")]
+    ProductStatusConflict,
+    #[display(fmt = "product price already exists")]
+    ProductPriceAlreadyExists,
+    #[display(fmt = "product price not found")]
+    ProductPriceNotFound,
+    #[display(fmt = "exchange rate not found")]
+    ExchangeRateNotFound,
+    #[display(fmt = "category already exists")]
+    CategoryAlreadyExists,
+    #[display(fmt = "category not found")]
+    CategoryNotFound,
+    #[display(fmt = "category cannot move below itself")]
+    CategoryCycle,
+    #[display(fmt = "variant already exists")]
+    VariantAlreadyExists,
+    #[display(fmt = "variant not found")]
+    VariantNotFound,
+    #[display(fmt = "variant sku already exists")]
+    VariantSkuAlreadyExists,
+    #[display(fmt = "variant options in use by existing variants")]
+    VariantOptionsInUse,
+    #[display(fmt = "stock adjustment already exists")]
+    StockAdjustmentAlreadyExists,
+    #[display(fmt = "insufficient stock")]
+    InsufficientStock,
+    #[display(fmt = "reservation already exists")]
+    ReservationAlreadyExists,
+    #[display(fmt = "reservation not found")]
+    ReservationNotFound,
+    #[display(fmt = "reservation expired")]
+    ReservationExpired,
+    #[display(fmt = "reservation no longer pending")]
+    ReservationNotPending,
+
+    #[display(fmt = "validation failed")]
+    Validation(Vec<Error>),
 
     #[display(fmt = "invalid product timestamp relation")]
     InvalidProductTimeStampRelation,
@@ -19,7 +66,177 @@ pub enum Error {
     InvalidProductPrice,
     #[display(fmt = "invalid product currency")]
     InvalidProductCurrency,
+    #[display(fmt = "invalid product status")]
+    InvalidProductStatus,
+    #[display(fmt = "product status transition not allowed")]
+    InvalidProductStatusTransition,
+    #[display(fmt = "invalid product schedule")]
+    InvalidProductSchedule,
+
+    #[display(fmt = "invalid product price id")]
+    InvalidProductPriceId,
+    #[display(fmt = "invalid product price validity")]
+    InvalidProductPriceValidity,
+    #[display(fmt = "invalid product price history period")]
+    InvalidProductPriceHistoryPeriod,
+
+    #[display(fmt = "invalid category id")]
+    InvalidCategoryId,
+    #[display(fmt = "invalid category parent id")]
+    InvalidCategoryParentId,
+    #[display(fmt = "invalid category name")]
+    InvalidCategoryName,
+
+    #[display(fmt = "invalid variant id")]
+    InvalidVariantId,
+    #[display(fmt = "invalid variant sku")]
+    InvalidVariantSku,
+    #[display(fmt = "invalid variant barcode")]
+    InvalidVariantBarcode,
+    #[display(fmt = "invalid variant option")]
+    InvalidVariantOption,
+    #[display(fmt = "variant options do not match the product options")]
+    InvalidVariantOptions,
+
+    #[display(fmt = "invalid warehouse code")]
+    InvalidWarehouseCode,
+    #[display(fmt = "invalid stock adjustment id")]
+    InvalidStockAdjustmentId,
+    #[display(fmt = "invalid stock adjustment delta")]
+    InvalidStockAdjustmentDelta,
+    #[display(fmt = "invalid stock adjustment reason")]
+    InvalidStockAdjustmentReason,
+    #[display(fmt = "invalid reservation id")]
+    InvalidReservationId,
+    #[display(fmt = "invalid reservation quantity")]
+    InvalidReservationQuantity,
+    #[display(fmt = "invalid reservation ttl")]
+    InvalidReservationTtl,
+    #[display(fmt = "invalid reservation status")]
+    InvalidReservationStatus,
+
+    #[display(fmt = "invalid money amount")]
+    InvalidMoneyAmount,
+    #[display(fmt = "invalid currency")]
+    InvalidCurrency,
+    #[display(fmt = "money amount out of range")]
+    MoneyOverflow,
+
+    #[display(fmt = "invalid exchange rate")]
+    InvalidExchangeRate,
+    #[display(fmt = "invalid exchange rate file: {}", _0)]
+    InvalidExchangeRateFile(String),
+    #[display(fmt = "unsupported exchange rate file type")]
+    UnsupportedExchangeRateFileType,
+
+    #[display(fmt = "invalid product event kind")]
+    InvalidProductEventKind,
+    #[display(fmt = "invalid product event sequence")]
+    InvalidProductEventSequence,
 
     #[display(fmt = "invalid permission")]
     InvalidPermission,
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Persistence(_) => "INTERNAL",
+            Self::Unavailable(_) => "UNAVAILABLE",
+            Self::Timeout(_) => "TIMEOUT",
+            Self::Conflict(_) => "CONFLICT",
+            Self::InvalidData(_) => "INVALID_DATA",
+            Self::ProductAlreadyExists => "PRODUCT_ALREADY_EXISTS",
+            Self::ProductNotFound => "PRODUCT_NOT_FOUND",
+            Self::ProductStatusConflict => "PRODUCT_STATUS_CONFLICT",
+            Self::ProductPriceAlreadyExists => "PRODUCT_PRICE_ALREADY_EXISTS",
+            Self::ProductPriceNotFound => "PRODUCT_PRICE_NOT_FOUND",
+            Self::ExchangeRateNotFound => "EXCHANGE_RATE_NOT_FOUND",
+            Self::CategoryAlreadyExists => "CATEGORY_ALREADY_EXISTS",
+            Self::CategoryNotFound => "CATEGORY_NOT_FOUND",
+            Self::CategoryCycle => "CATEGORY_CYCLE",
+            Self::VariantAlreadyExists => "VARIANT_ALREADY_EXISTS",
+            Self::VariantNotFound => "VARIANT_NOT_FOUND",
+            Self::VariantSkuAlreadyExists => "VARIANT_SKU_ALREADY_EXISTS",
+            Self::VariantOptionsInUse => "VARIANT_OPTIONS_IN_USE",
+            Self::StockAdjustmentAlreadyExists => "STOCK_ADJUSTMENT_ALREADY_EXISTS",
+            Self::InsufficientStock => "INSUFFICIENT_STOCK",
+            Self::ReservationAlreadyExists => "RESERVATION_ALREADY_EXISTS",
+            Self::ReservationNotFound => "RESERVATION_NOT_FOUND",
+            Self::ReservationExpired => "RESERVATION_EXPIRED",
+            Self::ReservationNotPending => "RESERVATION_NOT_PENDING",
+            Self::Validation(_) => "VALIDATION_FAILED",
+            Self::InvalidProductTimeStampRelation => "INVALID_PRODUCT_TIMESTAMP_RELATION",
+            Self::InvalidProductId => "INVALID_PRODUCT_ID",
+            Self::InvalidProductName => "INVALID_PRODUCT_NAME",
+            Self::InvalidProductPrice => "INVALID_PRODUCT_PRICE",
+            Self::InvalidProductCurrency => "INVALID_PRODUCT_CURRENCY",
+            Self::InvalidProductStatus => "INVALID_PRODUCT_STATUS",
+            Self::InvalidProductStatusTransition => "INVALID_PRODUCT_STATUS_TRANSITION",
+            Self::InvalidProductSchedule => "INVALID_PRODUCT_SCHEDULE",
+            Self::InvalidProductPriceId => "INVALID_PRODUCT_PRICE_ID",
+            Self::InvalidProductPriceValidity => "INVALID_PRODUCT_PRICE_VALIDITY",
+            Self::InvalidProductPriceHistoryPeriod => "INVALID_PRODUCT_PRICE_HISTORY_PERIOD",
+            Self::InvalidCategoryId => "INVALID_CATEGORY_ID",
+            Self::InvalidCategoryParentId => "INVALID_CATEGORY_PARENT_ID",
+            Self::InvalidCategoryName => "INVALID_CATEGORY_NAME",
+            Self::InvalidVariantId => "INVALID_VARIANT_ID",
+            Self::InvalidVariantSku => "INVALID_VARIANT_SKU",
+            Self::InvalidVariantBarcode => "INVALID_VARIANT_BARCODE",
+            Self::InvalidVariantOption => "INVALID_VARIANT_OPTION",
+            Self::InvalidVariantOptions => "INVALID_VARIANT_OPTIONS",
+            Self::InvalidWarehouseCode => "INVALID_WAREHOUSE_CODE",
+            Self::InvalidStockAdjustmentId => "INVALID_STOCK_ADJUSTMENT_ID",
+            Self::InvalidStockAdjustmentDelta => "INVALID_STOCK_ADJUSTMENT_DELTA",
+            Self::InvalidStockAdjustmentReason => "INVALID_STOCK_ADJUSTMENT_REASON",
+            Self::InvalidReservationId => "INVALID_RESERVATION_ID",
+            Self::InvalidReservationQuantity => "INVALID_RESERVATION_QUANTITY",
+            Self::InvalidReservationTtl => "INVALID_RESERVATION_TTL",
+            Self::InvalidReservationStatus => "INVALID_RESERVATION_STATUS",
+            Self::InvalidMoneyAmount => "INVALID_MONEY_AMOUNT",
+            Self::InvalidCurrency => "INVALID_CURRENCY",
+            Self::MoneyOverflow => "MONEY_OVERFLOW",
+            Self::InvalidExchangeRate => "INVALID_EXCHANGE_RATE",
+            Self::InvalidExchangeRateFile(_) => "INVALID_EXCHANGE_RATE_FILE",
+            Self::UnsupportedExchangeRateFileType => "UNSUPPORTED_EXCHANGE_RATE_FILE_TYPE",
+            Self::InvalidProductEventKind => "INVALID_PRODUCT_EVENT_KIND",
+            Self::InvalidProductEventSequence => "INVALID_PRODUCT_EVENT_SEQUENCE",
+            Self::InvalidPermission => "FORBIDDEN",
+        }
+    }
+
+    /// Whether the same operation may succeed if repeated without any change from the caller.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Unavailable(_))
+    }
+
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            Self::InvalidProductId => Some("id"),
+            Self::InvalidProductName => Some("name"),
+            Self::InvalidProductPrice => Some("price/amount"),
+            Self::InvalidProductCurrency => Some("price/currency"),
+            Self::InvalidProductStatus => Some("status"),
+            Self::InvalidProductSchedule => Some("unpublish_at"),
+            Self::InvalidProductPriceId => Some("id"),
+            Self::InvalidProductPriceValidity => Some("valid_until"),
+            Self::InvalidProductPriceHistoryPeriod => Some("until"),
+            Self::InvalidCategoryId => Some("id"),
+            Self::InvalidCategoryParentId => Some("parent_id"),
+            Self::InvalidCategoryName => Some("name"),
+            Self::CategoryCycle => Some("parent_id"),
+            Self::InvalidVariantId => Some("id"),
+            Self::InvalidVariantSku | Self::VariantSkuAlreadyExists => Some("sku"),
+            Self::InvalidVariantBarcode => Some("barcode"),
+            Self::InvalidVariantOption | Self::InvalidVariantOptions | Self::VariantOptionsInUse => Some("options"),
+            Self::InvalidWarehouseCode => Some("warehouse"),
+            Self::InvalidStockAdjustmentId | Self::InvalidReservationId => Some("id"),
+            Self::InvalidStockAdjustmentDelta => Some("delta"),
+            Self::InvalidStockAdjustmentReason => Some("reason"),
+            Self::InvalidReservationQuantity => Some("quantity"),
+            Self::InvalidReservationTtl => Some("ttl_seconds"),
+            Self::InvalidExchangeRate => Some("rate"),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub use errors::*;
+pub use money::*;
 pub use permissions::*;
 
 mod errors;
+mod money;
 mod permissions;
@@ -0,0 +1,247 @@
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+/// ISO 4217 currency with the number of decimal digits of its minor unit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: &'static str,
+    numeric: u16,
+    exponent: u8,
+}
+
+impl Currency {
+    /// Reference currency of the ECB rates, which every other currency is quoted against.
+    pub const EUR: Self = Self::new("EUR", 978, 2);
+
+    const fn new(code: &'static str, numeric: u16, exponent: u8) -> Self {
+        Self {
+            code,
+            numeric,
+            exponent,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        self.code
+    }
+
+    pub fn numeric(self) -> u16 {
+        self.numeric
+    }
+
+    /// Decimal digits of the minor unit, 2 for EUR cents, 0 for JPY, 3 for KWD fils.
+    pub fn exponent(self) -> u8 {
+        self.exponent
+    }
+
+    pub fn all() -> &'static [Currency] {
+        CURRENCIES
+    }
+
+    pub fn to_primitive(self) -> String {
+        let _e = tracing::debug_span!("Transform Currency to primitive").entered();
+
+        self.code.to_string()
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+impl TryFrom<&str> for Currency {
+    type Error = common::domain::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let _e = tracing::debug_span!("Try cast Currency from &str").entered();
+
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.code == value)
+            .copied()
+            .ok_or(common::domain::Error::InvalidCurrency)
+            .inspect_err(|err| tracing::error!("{err}"))
+    }
+}
+
+impl TryFrom<String> for Currency {
+    type Error = common::domain::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+// ISO 4217 list one, currencies without a minor unit (precious metals, SDR, testing codes) are left out
+static CURRENCIES: &[Currency] = &[
+    Currency::new("AED", 784, 2),
+    Currency::new("AFN", 971, 2),
+    Currency::new("ALL", 8, 2),
+    Currency::new("AMD", 51, 2),
+    Currency::new("ANG", 532, 2),
+    Currency::new("AOA", 973, 2),
+    Currency::new("ARS", 32, 2),
+    Currency::new("AUD", 36, 2),
+    Currency::new("AWG", 533, 2),
+    Currency::new("AZN", 944, 2),
+    Currency::new("BAM", 977, 2),
+    Currency::new("BBD", 52, 2),
+    Currency::new("BDT", 50, 2),
+    Currency::new("BGN", 975, 2),
+    Currency::new("BHD", 48, 3),
+    Currency::new("BIF", 108, 0),
+    Currency::new("BMD", 60, 2),
+    Currency::new("BND", 96, 2),
+    Currency::new("BOB", 68, 2),
+    Currency::new("BOV", 984, 2),
+    Currency::new("BRL", 986, 2),
+    Currency::new("BSD", 44, 2),
+    Currency::new("BTN", 64, 2),
+    Currency::new("BWP", 72, 2),
+    Currency::new("BYN", 933, 2),
+    Currency::new("BZD", 84, 2),
+    Currency::new("CAD", 124, 2),
+    Currency::new("CDF", 976, 2),
+    Currency::new("CHE", 947, 2),
+    Currency::new("CHF", 756, 2),
+    Currency::new("CHW", 948, 2),
+    Currency::new("CLF", 990, 4),
+    Currency::new("CLP", 152, 0),
+    Currency::new("CNY", 156, 2),
+    Currency::new("COP", 170, 2),
+    Currency::new("COU", 970, 2),
+    Currency::new("CRC", 188, 2),
+    Currency::new("CUC", 931, 2),
+    Currency::new("CUP", 192, 2),
+    Currency::new("CVE", 132, 2),
+    Currency::new("CZK", 203, 2),
+    Currency::new("DJF", 262, 0),
+    Currency::new("DKK", 208, 2),
+    Currency::new("DOP", 214, 2),
+    Currency::new("DZD", 12, 2),
+    Currency::new("EGP", 818, 2),
+    Currency::new("ERN", 232, 2),
+    Currency::new("ETB", 230, 2),
+    Currency::EUR,
+    Currency::new("FJD", 242, 2),
+    Currency::new("FKP", 238, 2),
+    Currency::new("GBP", 826, 2),
+    Currency::new("GEL", 981, 2),
+    Currency::new("GHS", 936, 2),
+    Currency::new("GIP", 292, 2),
+    Currency::new("GMD", 270, 2),
+    Currency::new("GNF", 324, 0),
+    Currency::new("GTQ", 320, 2),
+    Currency::new("GYD", 328, 2),
+    Currency::new("HKD", 344, 2),
+    Currency::new("HNL", 340, 2),
+    Currency::new("HTG", 332, 2),
+    Currency::new("HUF", 348, 2),
+    Currency::new("IDR", 360, 2),
+    Currency::new("ILS", 376, 2),
+    Currency::new("INR", 356, 2),
+    Currency::new("IQD", 368, 3),
+    Currency::new("IRR", 364, 2),
+    Currency::new("ISK", 352, 0),
+    Currency::new("JMD", 388, 2),
+    Currency::new("JOD", 400, 3),
+    Currency::new("JPY", 392, 0),
+    Currency::new("KES", 404, 2),
+    Currency::new("KGS", 417, 2),
+    Currency::new("KHR", 116, 2),
+    Currency::new("KMF", 174, 0),
+    Currency::new("KPW", 408, 2),
+    Currency::new("KRW", 410, 0),
+    Currency::new("KWD", 414, 3),
+    Currency::new("KYD", 136, 2),
+    Currency::new("KZT", 398, 2),
+    Currency::new("LAK", 418, 2),
+    Currency::new("LBP", 422, 2),
+    Currency::new("LKR", 144, 2),
+    Currency::new("LRD", 430, 2),
+    Currency::new("LSL", 426, 2),
+    Currency::new("LYD", 434, 3),
+    Currency::new("MAD", 504, 2),
+    Currency::new("MDL", 498, 2),
+    Currency::new("MGA", 969, 2),
+    Currency::new("MKD", 807, 2),
+    Currency::new("MMK", 104, 2),
+    Currency::new("MNT", 496, 2),
+    Currency::new("MOP", 446, 2),
+    Currency::new("MRU", 929, 2),
+    Currency::new("MUR", 480, 2),
+    Currency::new("MVR", 462, 2),
+    Currency::new("MWK", 454, 2),
+    Currency::new("MXN", 484, 2),
+    Currency::new("MXV", 979, 2),
+    Currency::new("MYR", 458, 2),
+    Currency::new("MZN", 943, 2),
+    Currency::new("NAD", 516, 2),
+    Currency::new("NGN", 566, 2),
+    Currency::new("NIO", 558, 2),
+    Currency::new("NOK", 578, 2),
+    Currency::new("NPR", 524, 2),
+    Currency::new("NZD", 554, 2),
+    Currency::new("OMR", 512, 3),
+    Currency::new("PAB", 590, 2),
+    Currency::new("PEN", 604, 2),
+    Currency::new("PGK", 598, 2),
+    Currency::new("PHP", 608, 2),
+    Currency::new("PKR", 586, 2),
+    Currency::new("PLN", 985, 2),
+    Currency::new("PYG", 600, 0),
+    Currency::new("QAR", 634, 2),
+    Currency::new("RON", 946, 2),
+    Currency::new("RSD", 941, 2),
+    Currency::new("RUB", 643, 2),
+    Currency::new("RWF", 646, 0),
+    Currency::new("SAR", 682, 2),
+    Currency::new("SBD", 90, 2),
+    Currency::new("SCR", 690, 2),
+    Currency::new("SDG", 938, 2),
+    Currency::new("SEK", 752, 2),
+    Currency::new("SGD", 702, 2),
+    Currency::new("SHP", 654, 2),
+    Currency::new("SLE", 925, 2),
+    Currency::new("SOS", 706, 2),
+    Currency::new("SRD", 968, 2),
+    Currency::new("SSP", 728, 2),
+    Currency::new("STN", 930, 2),
+    Currency::new("SVC", 222, 2),
+    Currency::new("SYP", 760, 2),
+    Currency::new("SZL", 748, 2),
+    Currency::new("THB", 764, 2),
+    Currency::new("TJS", 972, 2),
+    Currency::new("TMT", 934, 2),
+    Currency::new("TND", 788, 3),
+    Currency::new("TOP", 776, 2),
+    Currency::new("TRY", 949, 2),
+    Currency::new("TTD", 780, 2),
+    Currency::new("TWD", 901, 2),
+    Currency::new("TZS", 834, 2),
+    Currency::new("UAH", 980, 2),
+    Currency::new("UGX", 800, 0),
+    Currency::new("USD", 840, 2),
+    Currency::new("USN", 997, 2),
+    Currency::new("UYI", 940, 0),
+    Currency::new("UYU", 858, 2),
+    Currency::new("UYW", 927, 4),
+    Currency::new("UZS", 860, 2),
+    Currency::new("VED", 926, 2),
+    Currency::new("VES", 928, 2),
+    Currency::new("VND", 704, 0),
+    Currency::new("VUV", 548, 0),
+    Currency::new("WST", 882, 2),
+    Currency::new("XAF", 950, 0),
+    Currency::new("XCD", 951, 2),
+    Currency::new("XCG", 532, 2),
+    Currency::new("XOF", 952, 0),
+    Currency::new("XPF", 953, 0),
+    Currency::new("YER", 886, 2),
+    Currency::new("ZAR", 710, 2),
+    Currency::new("ZMW", 967, 2),
+    Currency::new("ZWG", 924, 2),
+];
@@ -0,0 +1,231 @@
+pub use currency::*;
+pub use rational::*;
+pub use rounding::*;
+
+use std::fmt::{Display, Formatter};
+
+use crate::contexts::ecommerce::common;
+
+mod currency;
+mod rational;
+mod rounding;
+
+/// Amount of a currency, counted in its minor unit so that no rounding ever happens.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Money {
+    minor_units: i64,
+    currency: Currency,
+}
+
+impl Money {
+    pub fn new(minor_units: i64, currency: Currency) -> Self {
+        Self { minor_units, currency }
+    }
+
+    /// Reads a plain decimal amount like `"1234.50"`, with no more significant fraction digits than
+    /// the currency has.
+    pub fn parse(amount: &str, currency: Currency) -> Result<Self, common::domain::Error> {
+        let _e = tracing::debug_span!("Parse Money").entered();
+
+        Self::parse_minor_units(amount, currency.exponent())
+            .map(|minor_units| Self::new(minor_units, currency))
+            .ok_or(common::domain::Error::InvalidMoneyAmount)
+            .inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    fn parse_minor_units(amount: &str, exponent: u8) -> Option<i64> {
+        let (negative, digits) = match amount.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, amount),
+        };
+
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let exponent = exponent as usize;
+
+        let is_digits = |value: &str| value.bytes().all(|byte| byte.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) || digits.ends_with('.') {
+            return None;
+        }
+
+        // trailing zeros beyond the minor unit do not change the amount
+        let (fraction, excess) = fraction.split_at(fraction.len().min(exponent));
+        if excess.bytes().any(|byte| byte != b'0') {
+            return None;
+        }
+
+        let scale = 10_i64.checked_pow(exponent as u32)?;
+        let fraction_scale = 10_i64.checked_pow((exponent - fraction.len()) as u32)?;
+
+        let integer: i64 = integer.parse().ok()?;
+        let fraction: i64 = if fraction.is_empty() { 0 } else { fraction.parse().ok()? };
+
+        let minor_units = integer
+            .checked_mul(scale)?
+            .checked_add(fraction.checked_mul(fraction_scale)?)?;
+
+        Some(if negative { -minor_units } else { minor_units })
+    }
+
+    pub fn minor_units(self) -> i64 {
+        self.minor_units
+    }
+
+    pub fn currency(self) -> Currency {
+        self.currency
+    }
+
+    /// Amount in `currency` at `rate` units of it per unit of this one, rounded to its minor unit.
+    pub fn convert(
+        self,
+        rate: Rational,
+        currency: Currency,
+        rounding: RoundingMode,
+    ) -> Result<Self, common::domain::Error> {
+        let _e = tracing::debug_span!("Convert Money").entered();
+
+        // minor units differ between currencies, a cent is a hundredth of a euro but a fils a thousandth of a dinar
+        let minor_unit_ratio =
+            Rational::power_of_ten(i32::from(currency.exponent()) - i32::from(self.currency.exponent()));
+
+        minor_unit_ratio
+            .and_then(|ratio| Rational::from_integer(self.minor_units.into()).checked_mul(ratio))
+            .and_then(|amount| amount.checked_mul(rate))
+            .and_then(|amount| i64::try_from(amount.round(rounding)).ok())
+            .map(|minor_units| Self::new(minor_units, currency))
+            .ok_or(common::domain::Error::MoneyOverflow)
+            .inspect_err(|err| tracing::error!("{err}"))
+    }
+
+    /// Amount as a decimal string with exactly as many fraction digits as the currency has.
+    pub fn to_decimal_string(self) -> String {
+        let _e = tracing::debug_span!("Transform Money to decimal string").entered();
+
+        let exponent = self.currency.exponent() as usize;
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let digits = format!("{:0>width$}", self.minor_units.unsigned_abs(), width = exponent + 1);
+
+        if exponent == 0 {
+            return format!("{sign}{digits}");
+        }
+
+        let (integer, fraction) = digits.split_at(digits.len() - exponent);
+
+        format!("{sign}{integer}.{fraction}")
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn currency(code: &str) -> Currency {
+        Currency::try_from(code).unwrap()
+    }
+
+    #[test]
+    fn given_currencies_with_different_exponents_when_format_then_use_their_minor_unit() {
+        assert_eq!(Money::new(123_456, currency("EUR")).to_decimal_string(), "1234.56");
+        assert_eq!(Money::new(123_456, currency("JPY")).to_decimal_string(), "123456");
+        assert_eq!(Money::new(123_456, currency("KWD")).to_decimal_string(), "123.456");
+        assert_eq!(Money::new(5, currency("EUR")).to_decimal_string(), "0.05");
+        assert_eq!(Money::new(-5, currency("KWD")).to_decimal_string(), "-0.005");
+        assert_eq!(Money::new(1, currency("CLF")).to_string(), "0.0001 CLF");
+    }
+
+    #[test]
+    fn given_decimal_strings_when_parse_then_return_minor_units() {
+        let parse = |amount: &str, code: &str| Money::parse(amount, currency(code)).map(Money::minor_units);
+
+        assert_eq!(parse("1234.56", "EUR").unwrap(), 123_456);
+        assert_eq!(parse("1234.5", "EUR").unwrap(), 123_450);
+        assert_eq!(parse("1234", "EUR").unwrap(), 123_400);
+        assert_eq!(parse("1234.500", "EUR").unwrap(), 123_450);
+        assert_eq!(parse("500", "JPY").unwrap(), 500);
+        assert_eq!(parse("1.234", "KWD").unwrap(), 1_234);
+        assert_eq!(parse("-0.01", "USD").unwrap(), -1);
+    }
+
+    #[test]
+    fn given_malformed_or_too_precise_amounts_when_parse_then_return_err() {
+        for (amount, code) in [
+            ("", "EUR"),
+            ("1.", "EUR"),
+            (".5", "EUR"),
+            ("1,50", "EUR"),
+            ("+1", "EUR"),
+            ("1e3", "EUR"),
+            ("1.005", "EUR"),
+            ("1.5", "JPY"),
+            ("99999999999999999999", "EUR"),
+        ] {
+            assert!(Money::parse(amount, currency(code)).is_err(), "{amount} {code}");
+        }
+    }
+
+    #[test]
+    fn given_amount_when_format_and_parse_then_round_trip() {
+        for code in ["EUR", "JPY", "KWD", "CLF"] {
+            let money = Money::new(-9_876_543, currency(code));
+
+            assert_eq!(
+                Money::parse(&money.to_decimal_string(), money.currency()).unwrap(),
+                money
+            );
+        }
+    }
+
+    #[test]
+    fn given_rate_when_convert_then_scale_between_minor_units_and_round() {
+        let rate = Rational::new(10_772, 10_000).unwrap();
+        let eur = Money::new(999, currency("EUR"));
+
+        assert_eq!(
+            eur.convert(rate, currency("USD"), RoundingMode::HalfEven)
+                .unwrap()
+                .minor_units(),
+            1_076
+        );
+        assert_eq!(
+            eur.convert(rate, currency("USD"), RoundingMode::Up)
+                .unwrap()
+                .minor_units(),
+            1_077
+        );
+        assert_eq!(
+            eur.convert(rate, currency("JPY"), RoundingMode::HalfEven)
+                .unwrap()
+                .minor_units(),
+            11
+        );
+        assert_eq!(
+            eur.convert(rate, currency("KWD"), RoundingMode::Down)
+                .unwrap()
+                .minor_units(),
+            10_761
+        );
+        assert!(matches!(
+            Money::new(i64::MAX, currency("JPY")).convert(
+                Rational::from_integer(2),
+                currency("KWD"),
+                RoundingMode::Down
+            ),
+            Err(common::domain::Error::MoneyOverflow)
+        ));
+    }
+
+    #[test]
+    fn given_iso_codes_when_try_from_then_know_full_table() {
+        assert_eq!(currency("JPY").exponent(), 0);
+        assert_eq!(currency("KWD").exponent(), 3);
+        assert_eq!(currency("EUR").numeric(), 978);
+        assert!(Currency::all().len() > 150);
+        assert!(Currency::try_from("eur").is_err());
+        assert!(Currency::try_from("XXX").is_err());
+    }
+}
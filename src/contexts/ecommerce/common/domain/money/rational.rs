@@ -0,0 +1,184 @@
+use std::fmt::{Display, Formatter};
+
+use super::RoundingMode;
+
+/// Exact fraction for money arithmetic, kept in lowest terms with a positive denominator.
+///
+/// Every operation is checked and returns `None` instead of wrapping once an intermediate value no
+/// longer fits in `i128`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// `None` for a zero denominator or when the sign cannot be moved to the numerator.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+
+        if denominator < 0 {
+            return Some(Self {
+                numerator: numerator.checked_neg()?,
+                denominator: denominator.checked_neg()?,
+            });
+        }
+
+        Some(Self { numerator, denominator })
+    }
+
+    pub fn from_integer(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    /// `10^exponent`, or its inverse for negative exponents.
+    pub fn power_of_ten(exponent: i32) -> Option<Self> {
+        let power = 10_i128.checked_pow(exponent.unsigned_abs())?;
+
+        if exponent < 0 {
+            Self::new(1, power)
+        } else {
+            Some(Self::from_integer(power))
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // cross reducing first keeps the intermediate products as small as possible
+        let left = gcd(self.numerator, other.denominator);
+        let right = gcd(other.numerator, self.denominator);
+
+        Self::new(
+            (self.numerator / left).checked_mul(other.numerator / right)?,
+            (self.denominator / right).checked_mul(other.denominator / left)?,
+        )
+    }
+
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(other.checked_recip()?)
+    }
+
+    /// `None` for zero, which has no inverse.
+    pub fn checked_recip(self) -> Option<Self> {
+        Self::new(self.denominator, self.numerator)
+    }
+
+    /// Nearest integer according to `mode`, exact whenever the fraction already is one.
+    pub fn round(self, mode: RoundingMode) -> i128 {
+        let quotient = self.numerator / self.denominator;
+        let remainder = (self.numerator % self.denominator).abs();
+
+        if remainder == 0 {
+            return quotient;
+        }
+
+        // away from zero, on the side of the fraction's sign
+        let step = self.numerator.signum();
+        // comparing against the rest of the denominator avoids doubling the remainder
+        let above_half = remainder > self.denominator - remainder;
+        let at_half = remainder == self.denominator - remainder;
+
+        let away = match mode {
+            RoundingMode::Down => false,
+            RoundingMode::Up => true,
+            RoundingMode::HalfUp => above_half || at_half,
+            RoundingMode::HalfEven => above_half || (at_half && quotient % 2 != 0),
+        };
+
+        if away {
+            quotient + step
+        } else {
+            quotient
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+fn gcd(left: i128, right: i128) -> i128 {
+    let (mut left, mut right) = (left.unsigned_abs(), right.unsigned_abs());
+
+    while right != 0 {
+        (left, right) = (right, left % right);
+    }
+
+    // only i128::MIN against 0 or itself has a gcd beyond i128::MAX, 1 leaves such fractions as they are
+    i128::try_from(left).unwrap_or(1).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn given_fractions_when_new_then_keep_lowest_terms_with_positive_denominator() {
+        assert_eq!(rational(6, -4), rational(-3, 2));
+        assert_eq!(rational(-6, -4).denominator(), 2);
+        assert_eq!(rational(0, 7), Rational::from_integer(0));
+        assert!(Rational::new(1, 0).is_none());
+    }
+
+    #[test]
+    fn given_fractions_when_mul_and_div_then_return_exact_result() {
+        let third = rational(1, 3);
+
+        assert_eq!(third.checked_mul(rational(3, 1)).unwrap(), Rational::ONE);
+        assert_eq!(third.checked_div(rational(2, 9)).unwrap(), rational(3, 2));
+        assert!(Rational::from_integer(0).checked_recip().is_none());
+        assert!(Rational::from_integer(i128::MAX)
+            .checked_mul(Rational::from_integer(2))
+            .is_none());
+    }
+
+    #[test]
+    fn given_halves_when_round_then_follow_mode() {
+        let cases = [
+            (rational(5, 2), [2, 3, 2, 3]),
+            (rational(7, 2), [4, 4, 3, 4]),
+            (rational(-5, 2), [-2, -3, -2, -3]),
+            (rational(21, 10), [2, 2, 2, 3]),
+            (rational(-29, 10), [-3, -3, -2, -3]),
+            (rational(4, 1), [4, 4, 4, 4]),
+        ];
+
+        for (value, expected) in cases {
+            let rounded = [
+                RoundingMode::HalfEven,
+                RoundingMode::HalfUp,
+                RoundingMode::Down,
+                RoundingMode::Up,
+            ]
+            .map(|mode| value.round(mode));
+
+            assert_eq!(rounded, expected, "{value}");
+        }
+    }
+}
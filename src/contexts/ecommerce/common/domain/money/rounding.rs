@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+use derive_more::Display;
+
+/// How an exact amount that falls between two minor units is brought back to one of them.
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Nearest unit, halves to the even one, so that rounding errors cancel out over many amounts.
+    #[default]
+    #[display(fmt = "half_even")]
+    HalfEven,
+    /// Nearest unit, halves away from zero, as taught in school.
+    #[display(fmt = "half_up")]
+    HalfUp,
+    /// Towards zero, never showing more than the exact amount.
+    #[display(fmt = "down")]
+    Down,
+    /// Away from zero, never showing less than the exact amount.
+    #[display(fmt = "up")]
+    Up,
+}
+
+impl FromStr for RoundingMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "half_even" => Ok(Self::HalfEven),
+            "half_up" => Ok(Self::HalfUp),
+            "down" => Ok(Self::Down),
+            "up" => Ok(Self::Up),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use derive_more::Display;
 
+// variants spell out the full scope they stand for
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Display)]
 pub enum Permissions {
     #[display(fmt = "ecommerce.backoffice.product:read")]
@@ -7,4 +9,34 @@ pub enum Permissions {
 
     #[display(fmt = "ecommerce.backoffice.product:create")]
     EcommerceBackofficeProductCreate,
+
+    #[display(fmt = "ecommerce.backoffice.product:update")]
+    EcommerceBackofficeProductUpdate,
+
+    #[display(fmt = "ecommerce.backoffice.product_price:read")]
+    EcommerceBackofficeProductPriceRead,
+
+    #[display(fmt = "ecommerce.backoffice.product_price:write")]
+    EcommerceBackofficeProductPriceWrite,
+
+    #[display(fmt = "ecommerce.backoffice.category:read")]
+    EcommerceBackofficeCategoryRead,
+
+    #[display(fmt = "ecommerce.backoffice.category:write")]
+    EcommerceBackofficeCategoryWrite,
+
+    #[display(fmt = "ecommerce.backoffice.variant:read")]
+    EcommerceBackofficeVariantRead,
+
+    #[display(fmt = "ecommerce.backoffice.variant:write")]
+    EcommerceBackofficeVariantWrite,
+
+    #[display(fmt = "ecommerce.backoffice.inventory:read")]
+    EcommerceBackofficeInventoryRead,
+
+    #[display(fmt = "ecommerce.backoffice.inventory:write")]
+    EcommerceBackofficeInventoryWrite,
+
+    #[display(fmt = "ecommerce.backoffice.exchange_rate:write")]
+    EcommerceBackofficeExchangeRateWrite,
 }
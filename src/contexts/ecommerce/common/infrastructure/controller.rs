@@ -1,6 +1,8 @@
 #[cfg(test)]
 pub mod fixture {
-    use crate::contexts::ecommerce::common;
+    use std::sync::Arc;
+
+    use crate::contexts::ecommerce::{backoffice, common};
     use crate::libs;
 
     #[derive(Clone)]
@@ -15,7 +17,42 @@ pub mod fixture {
 
             Self {
                 token: common::infrastructure::extractors::fixture::encode_jwt(&[]),
-                services: common::infrastructure::DependencyContainer::new(database.pool),
+                services: common::infrastructure::DependencyContainer::new(
+                    database.pool,
+                    libs::postgres::retry::RetryPolicy::default(),
+                    common::domain::RoundingMode::default(),
+                ),
+            }
+        }
+
+        /// Same services backed by in-memory repositories, for tests that don't need Postgres.
+        pub fn in_memory() -> Self {
+            let product_repository = backoffice::infrastructure::InMemoryProductRepository::new();
+            let product_event_repository = Arc::new(product_repository.events());
+            let product_price_repository = Arc::new(product_repository.prices());
+            let product_price_history_repository = Arc::new(product_repository.price_history());
+            let exchange_rate_repository = Arc::new(product_repository.exchange_rates());
+            let category_repository = Arc::new(product_repository.categories());
+            let variant_repository = Arc::new(product_repository.variants());
+            let inventory_repository = Arc::new(product_repository.inventory());
+            let unit_of_work_factory = Arc::new(product_repository.unit_of_work_factory());
+            let product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error> =
+                Arc::new(product_repository);
+
+            Self {
+                token: common::infrastructure::extractors::fixture::encode_jwt(&[]),
+                services: common::infrastructure::DependencyContainer::with_repositories(
+                    product_repository.clone(),
+                    product_event_repository,
+                    product_price_repository,
+                    product_price_history_repository,
+                    exchange_rate_repository,
+                    category_repository,
+                    variant_repository,
+                    inventory_repository,
+                    unit_of_work_factory,
+                    common::domain::RoundingMode::default(),
+                ),
             }
         }
 
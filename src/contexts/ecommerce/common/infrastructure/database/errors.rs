@@ -0,0 +1,46 @@
+use crate::contexts::ecommerce::common;
+use crate::libs;
+
+/// Hands database errors to the mapping of the backend that raised them.
+impl From<sqlx::Error> for common::domain::Error {
+    fn from(error: sqlx::Error) -> Self {
+        let Some(database_error) = error.as_database_error() else {
+            let reason = error.to_string();
+
+            if libs::database::is_connection_failure(&error) {
+                return Self::Unavailable(reason);
+            }
+
+            return Self::Persistence(reason);
+        };
+
+        if let Some(error) = database_error.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+            return common::infrastructure::from_postgres_error(error);
+        }
+
+        if let Some(error) = database_error.try_downcast_ref::<sqlx::sqlite::SqliteError>() {
+            return common::infrastructure::from_sqlite_error(error);
+        }
+
+        Self::Persistence(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_pool_timeout_when_convert_then_return_unavailable() {
+        let error = common::domain::Error::from(sqlx::Error::PoolTimedOut);
+
+        assert!(error.is_retryable());
+    }
+
+    #[test]
+    fn given_decode_failure_when_convert_then_return_persistence() {
+        let error = common::domain::Error::from(sqlx::Error::ColumnNotFound(String::from("missing")));
+
+        assert!(matches!(error, common::domain::Error::Persistence(_)));
+    }
+}
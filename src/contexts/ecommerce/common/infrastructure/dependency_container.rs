@@ -6,22 +6,331 @@ use crate::libs;
 #[derive(Clone)]
 pub struct DependencyContainer {
     pub product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    pub product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+    pub product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+    pub product_price_history_repository:
+        backoffice::domain::product_price_history::DynProductPriceHistoryRepository<common::domain::Error>,
+    pub exchange_rate_repository: backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error>,
+    pub category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+    pub variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+    pub inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+    pub unit_of_work_factory: common::application::unit_of_work::DynUnitOfWorkFactory<common::domain::Error>,
+    pub product_event_watcher: backoffice::infrastructure::ProductEventWatcher,
 
     pub get_products_usecase: Arc<backoffice::application::usecases::GetProducts>,
     pub save_product_usecase: Arc<backoffice::application::usecases::SaveProduct>,
+    pub update_product_usecase: Arc<backoffice::application::usecases::UpdateProduct>,
+    pub change_product_status_usecase: Arc<backoffice::application::usecases::ChangeProductStatus>,
+    pub schedule_product_usecase: Arc<backoffice::application::usecases::ScheduleProduct>,
+    pub apply_product_schedules_usecase: Arc<backoffice::application::usecases::ApplyProductSchedules>,
+    pub get_product_events_usecase: Arc<backoffice::application::usecases::GetProductEvents>,
+    pub get_product_prices_usecase: Arc<backoffice::application::usecases::GetProductPrices>,
+    pub get_product_price_history_usecase: Arc<backoffice::application::usecases::GetProductPriceHistory>,
+    pub get_current_product_price_usecase: Arc<backoffice::application::usecases::GetCurrentProductPrice>,
+    pub save_product_price_usecase: Arc<backoffice::application::usecases::SaveProductPrice>,
+    pub delete_product_price_usecase: Arc<backoffice::application::usecases::DeleteProductPrice>,
+    pub import_exchange_rates_usecase: Arc<backoffice::application::usecases::ImportExchangeRates>,
+    pub get_categories_usecase: Arc<backoffice::application::usecases::GetCategories>,
+    pub get_category_usecase: Arc<backoffice::application::usecases::GetCategory>,
+    pub save_category_usecase: Arc<backoffice::application::usecases::SaveCategory>,
+    pub move_category_usecase: Arc<backoffice::application::usecases::MoveCategory>,
+    pub assign_product_to_category_usecase: Arc<backoffice::application::usecases::AssignProductToCategory>,
+    pub unassign_product_from_category_usecase: Arc<backoffice::application::usecases::UnassignProductFromCategory>,
+    pub get_category_products_usecase: Arc<backoffice::application::usecases::GetCategoryProducts>,
+    pub get_variant_options_usecase: Arc<backoffice::application::usecases::GetVariantOptions>,
+    pub set_variant_options_usecase: Arc<backoffice::application::usecases::SetVariantOptions>,
+    pub get_variants_usecase: Arc<backoffice::application::usecases::GetVariants>,
+    pub save_variant_usecase: Arc<backoffice::application::usecases::SaveVariant>,
+    pub delete_variant_usecase: Arc<backoffice::application::usecases::DeleteVariant>,
+    pub get_stock_usecase: Arc<backoffice::application::usecases::GetStock>,
+    pub get_stock_adjustments_usecase: Arc<backoffice::application::usecases::GetStockAdjustments>,
+    pub adjust_stock_usecase: Arc<backoffice::application::usecases::AdjustStock>,
+    pub reserve_stock_usecase: Arc<backoffice::application::usecases::ReserveStock>,
+    pub commit_reservation_usecase: Arc<backoffice::application::usecases::CommitReservation>,
+    pub release_reservation_usecase: Arc<backoffice::application::usecases::ReleaseReservation>,
 }
 
 impl DependencyContainer {
-    pub fn new(db: libs::postgres::ConnectionPool) -> Self {
-        let product_repository = Arc::new(backoffice::infrastructure::PostgresProductRepository::new(db));
+    pub fn new(
+        db: libs::postgres::ConnectionPool,
+        retry_policy: libs::postgres::retry::RetryPolicy,
+        currency_rounding: common::domain::RoundingMode,
+    ) -> Self {
+        let product_repository = Arc::new(
+            backoffice::infrastructure::PostgresProductRepository::new(db.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let product_event_repository = Arc::new(
+            backoffice::infrastructure::PostgresProductEventRepository::new(db.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let product_price_repository = Arc::new(
+            backoffice::infrastructure::PostgresProductPriceRepository::new(db.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let product_price_history_repository = Arc::new(
+            backoffice::infrastructure::PostgresProductPriceHistoryRepository::new(db.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let exchange_rate_repository = Arc::new(
+            backoffice::infrastructure::PostgresExchangeRateRepository::new(db.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let category_repository = Arc::new(
+            backoffice::infrastructure::PostgresCategoryRepository::new(db.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let variant_repository = Arc::new(
+            backoffice::infrastructure::PostgresVariantRepository::new(db.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let inventory_repository = Arc::new(
+            backoffice::infrastructure::PostgresInventoryRepository::new(db.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let unit_of_work_factory = Arc::new(common::infrastructure::PostgresUnitOfWorkFactory::new(db, retry_policy));
 
+        Self::with_repositories(
+            product_repository,
+            product_event_repository,
+            product_price_repository,
+            product_price_history_repository,
+            exchange_rate_repository,
+            category_repository,
+            variant_repository,
+            inventory_repository,
+            unit_of_work_factory,
+            currency_rounding,
+        )
+    }
+
+    /// Like `new`, with read-only repository methods served by the replica behind `replica_router`.
+    pub fn new_with_replica(
+        replica_router: Arc<libs::postgres::ReplicaRouter>,
+        retry_policy: libs::postgres::retry::RetryPolicy,
+        currency_rounding: common::domain::RoundingMode,
+    ) -> Self {
+        let product_repository = Arc::new(
+            backoffice::infrastructure::PostgresProductRepository::routed(replica_router.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let product_event_repository = Arc::new(
+            backoffice::infrastructure::PostgresProductEventRepository::routed(replica_router.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let product_price_repository = Arc::new(
+            backoffice::infrastructure::PostgresProductPriceRepository::routed(replica_router.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let product_price_history_repository = Arc::new(
+            backoffice::infrastructure::PostgresProductPriceHistoryRepository::routed(replica_router.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let exchange_rate_repository = Arc::new(
+            backoffice::infrastructure::PostgresExchangeRateRepository::routed(replica_router.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let category_repository = Arc::new(
+            backoffice::infrastructure::PostgresCategoryRepository::routed(replica_router.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let variant_repository = Arc::new(
+            backoffice::infrastructure::PostgresVariantRepository::routed(replica_router.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let inventory_repository = Arc::new(
+            backoffice::infrastructure::PostgresInventoryRepository::routed(replica_router.clone())
+                .with_retry_policy(retry_policy.clone()),
+        );
+        let unit_of_work_factory = Arc::new(
+            common::infrastructure::PostgresUnitOfWorkFactory::new(replica_router.primary().clone(), retry_policy)
+                .with_replica_router(replica_router),
+        );
+
+        Self::with_repositories(
+            product_repository,
+            product_event_repository,
+            product_price_repository,
+            product_price_history_repository,
+            exchange_rate_repository,
+            category_repository,
+            variant_repository,
+            inventory_repository,
+            unit_of_work_factory,
+            currency_rounding,
+        )
+    }
+
+    pub fn new_sqlite(db: libs::sqlite::ConnectionPool, currency_rounding: common::domain::RoundingMode) -> Self {
+        let product_repository = Arc::new(backoffice::infrastructure::SqliteProductRepository::new(db.clone()));
+        let product_event_repository = Arc::new(backoffice::infrastructure::SqliteProductEventRepository::new(
+            db.clone(),
+        ));
+        let product_price_repository = Arc::new(backoffice::infrastructure::SqliteProductPriceRepository::new(
+            db.clone(),
+        ));
+        let product_price_history_repository = Arc::new(
+            backoffice::infrastructure::SqliteProductPriceHistoryRepository::new(db.clone()),
+        );
+        let exchange_rate_repository = Arc::new(backoffice::infrastructure::SqliteExchangeRateRepository::new(
+            db.clone(),
+        ));
+        let category_repository = Arc::new(backoffice::infrastructure::SqliteCategoryRepository::new(db.clone()));
+        let variant_repository = Arc::new(backoffice::infrastructure::SqliteVariantRepository::new(db.clone()));
+        let inventory_repository = Arc::new(backoffice::infrastructure::SqliteInventoryRepository::new(db.clone()));
+        let unit_of_work_factory = Arc::new(common::infrastructure::SqliteUnitOfWorkFactory::new(db));
+
+        Self::with_repositories(
+            product_repository,
+            product_event_repository,
+            product_price_repository,
+            product_price_history_repository,
+            exchange_rate_repository,
+            category_repository,
+            variant_repository,
+            inventory_repository,
+            unit_of_work_factory,
+            currency_rounding,
+        )
+    }
+
+    /// Wires the use cases on top of any repository implementation, e.g. in-memory ones in tests, rounding
+    /// converted prices with `currency_rounding`.
+    // one argument per repository, grouping them would only move the list elsewhere
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_repositories(
+        product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+        product_price_repository: backoffice::domain::product_price::DynProductPriceRepository<common::domain::Error>,
+        product_price_history_repository: backoffice::domain::product_price_history::DynProductPriceHistoryRepository<
+            common::domain::Error,
+        >,
+        exchange_rate_repository: backoffice::domain::exchange_rate::DynExchangeRateRepository<common::domain::Error>,
+        category_repository: backoffice::domain::category::DynCategoryRepository<common::domain::Error>,
+        variant_repository: backoffice::domain::variant::DynVariantRepository<common::domain::Error>,
+        inventory_repository: backoffice::domain::inventory::DynInventoryRepository<common::domain::Error>,
+        unit_of_work_factory: common::application::unit_of_work::DynUnitOfWorkFactory<common::domain::Error>,
+        currency_rounding: common::domain::RoundingMode,
+    ) -> Self {
         Self {
             product_repository: product_repository.clone(),
+            product_event_repository: product_event_repository.clone(),
+            product_price_repository: product_price_repository.clone(),
+            product_price_history_repository: product_price_history_repository.clone(),
+            exchange_rate_repository: exchange_rate_repository.clone(),
+            category_repository: category_repository.clone(),
+            variant_repository: variant_repository.clone(),
+            inventory_repository: inventory_repository.clone(),
+            unit_of_work_factory: unit_of_work_factory.clone(),
+            product_event_watcher: backoffice::infrastructure::ProductEventWatcher::new(
+                product_event_repository.clone(),
+            ),
 
             get_products_usecase: Arc::new(backoffice::application::usecases::GetProducts::new(
                 product_repository.clone(),
+                backoffice::domain::exchange_rate::CurrencyConverter::new(exchange_rate_repository.clone())
+                    .with_rounding_mode(currency_rounding),
+            )),
+            save_product_usecase: Arc::new(backoffice::application::usecases::SaveProduct::new(
+                unit_of_work_factory.clone(),
+            )),
+            update_product_usecase: Arc::new(backoffice::application::usecases::UpdateProduct::new(
+                unit_of_work_factory,
+            )),
+            change_product_status_usecase: Arc::new(backoffice::application::usecases::ChangeProductStatus::new(
+                product_repository.clone(),
+            )),
+            schedule_product_usecase: Arc::new(backoffice::application::usecases::ScheduleProduct::new(
+                product_repository.clone(),
+            )),
+            apply_product_schedules_usecase: Arc::new(backoffice::application::usecases::ApplyProductSchedules::new(
+                product_repository.clone(),
+            )),
+            get_product_events_usecase: Arc::new(backoffice::application::usecases::GetProductEvents::new(
+                product_event_repository,
+            )),
+            get_product_prices_usecase: Arc::new(backoffice::application::usecases::GetProductPrices::new(
+                product_repository.clone(),
+                product_price_repository.clone(),
+            )),
+            get_product_price_history_usecase: Arc::new(
+                backoffice::application::usecases::GetProductPriceHistory::new(
+                    product_repository.clone(),
+                    product_price_history_repository,
+                ),
+            ),
+            get_current_product_price_usecase: Arc::new(
+                backoffice::application::usecases::GetCurrentProductPrice::new(product_price_repository.clone()),
+            ),
+            save_product_price_usecase: Arc::new(backoffice::application::usecases::SaveProductPrice::new(
+                product_price_repository.clone(),
+            )),
+            delete_product_price_usecase: Arc::new(backoffice::application::usecases::DeleteProductPrice::new(
+                product_price_repository,
+            )),
+            import_exchange_rates_usecase: Arc::new(backoffice::application::usecases::ImportExchangeRates::new(
+                exchange_rate_repository,
+            )),
+            get_categories_usecase: Arc::new(backoffice::application::usecases::GetCategories::new(
+                category_repository.clone(),
+            )),
+            get_category_usecase: Arc::new(backoffice::application::usecases::GetCategory::new(
+                category_repository.clone(),
+            )),
+            save_category_usecase: Arc::new(backoffice::application::usecases::SaveCategory::new(
+                category_repository.clone(),
+            )),
+            move_category_usecase: Arc::new(backoffice::application::usecases::MoveCategory::new(
+                category_repository.clone(),
+            )),
+            assign_product_to_category_usecase: Arc::new(
+                backoffice::application::usecases::AssignProductToCategory::new(category_repository.clone()),
+            ),
+            unassign_product_from_category_usecase: Arc::new(
+                backoffice::application::usecases::UnassignProductFromCategory::new(category_repository.clone()),
+            ),
+            get_category_products_usecase: Arc::new(backoffice::application::usecases::GetCategoryProducts::new(
+                category_repository,
+            )),
+            get_variant_options_usecase: Arc::new(backoffice::application::usecases::GetVariantOptions::new(
+                product_repository.clone(),
+                variant_repository.clone(),
+            )),
+            set_variant_options_usecase: Arc::new(backoffice::application::usecases::SetVariantOptions::new(
+                variant_repository.clone(),
+            )),
+            get_variants_usecase: Arc::new(backoffice::application::usecases::GetVariants::new(
+                product_repository.clone(),
+                variant_repository.clone(),
+            )),
+            save_variant_usecase: Arc::new(backoffice::application::usecases::SaveVariant::new(
+                product_repository.clone(),
+                variant_repository.clone(),
+            )),
+            delete_variant_usecase: Arc::new(backoffice::application::usecases::DeleteVariant::new(
+                variant_repository,
+            )),
+            get_stock_usecase: Arc::new(backoffice::application::usecases::GetStock::new(
+                product_repository.clone(),
+                inventory_repository.clone(),
+            )),
+            get_stock_adjustments_usecase: Arc::new(backoffice::application::usecases::GetStockAdjustments::new(
+                product_repository.clone(),
+                inventory_repository.clone(),
+            )),
+            adjust_stock_usecase: Arc::new(backoffice::application::usecases::AdjustStock::new(
+                inventory_repository.clone(),
+            )),
+            reserve_stock_usecase: Arc::new(backoffice::application::usecases::ReserveStock::new(
+                product_repository,
+                inventory_repository.clone(),
+            )),
+            commit_reservation_usecase: Arc::new(backoffice::application::usecases::CommitReservation::new(
+                inventory_repository.clone(),
+            )),
+            release_reservation_usecase: Arc::new(backoffice::application::usecases::ReleaseReservation::new(
+                inventory_repository,
             )),
-            save_product_usecase: Arc::new(backoffice::application::usecases::SaveProduct::new(product_repository)),
         }
     }
 }
@@ -0,0 +1,3 @@
+pub use money::*;
+
+mod money;
@@ -0,0 +1,64 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::contexts::ecommerce::common;
+
+impl Serialize for common::domain::Money {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _e = tracing::debug_span!("Serialize Money").entered();
+
+        let mut state = serializer.serialize_struct("Money", 2)?;
+
+        state.serialize_field("amount", &self.to_decimal_string())?;
+        state.serialize_field("currency", &self.currency().to_primitive())?;
+
+        state.end()
+    }
+}
+
+/// Shared by the money a client sends and the one it receives, both have the same shape.
+pub fn money_schema() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .property(
+            "amount",
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .pattern(Some(r"^-?\d+(\.\d+)?$"))
+                .description(Some(
+                    "Decimal amount, with at most as many fraction digits as the currency minor unit.",
+                ))
+                .examples(["3600.00"]),
+        )
+        .required("amount")
+        .property(
+            "currency",
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .pattern(Some("^[A-Z]{3}$"))
+                .description(Some("ISO 4217 alphabetic code."))
+                .examples(["EUR"]),
+        )
+        .required("currency")
+}
+
+impl PartialSchema for common::domain::Money {
+    fn schema() -> RefOr<Schema> {
+        money_schema().into()
+    }
+}
+
+impl ToSchema for common::domain::Money {}
+
+impl PartialSchema for common::application::inputs::MoneyInput {
+    fn schema() -> RefOr<Schema> {
+        money_schema().into()
+    }
+}
+
+impl ToSchema for common::application::inputs::MoneyInput {}
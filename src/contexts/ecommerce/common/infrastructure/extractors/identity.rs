@@ -4,7 +4,6 @@ use std::sync::OnceLock;
 use axum::async_trait;
 use axum::extract::FromRequestParts;
 use axum::http::request::Parts;
-use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
 
@@ -50,14 +49,14 @@ where
             tracing::error!("not found token");
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token not found in header");
-            return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+            return Err(problem_details.into_response());
         };
 
         let Ok(header_as_str) = header.to_str() else {
             tracing::error!("malformed token {:?}", header);
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token is malformed");
-            return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+            return Err(problem_details.into_response());
         };
 
         let header_parts: Vec<&str> = header_as_str.split(' ').collect();
@@ -66,18 +65,14 @@ where
             tracing::error!("malformed token {:?}", header);
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token is malformed");
-            return Err(
-                libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response(),
-            );
+            return Err(problem_details.into_response());
         }
 
         if header_parts.first().copied() != Some("Bearer") {
             tracing::error!("malformed token {:?}", header);
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token is malformed");
-            return Err(
-                libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response(),
-            );
+            return Err(problem_details.into_response());
         }
 
         tracing::debug!("header_parts={:?}", header_parts);
@@ -86,7 +81,7 @@ where
             tracing::error!("impossible to read token {:?}", header);
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token is malformed");
-            return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+            return Err(problem_details.into_response());
         };
 
         tracing::debug!("header_token={:?}", header_token);
@@ -95,7 +90,7 @@ where
             tracing::error!("impossible to decode token {:?}", header);
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token can't be decoded");
-            return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+            return Err(problem_details.into_response());
         };
 
         tracing::debug!("decoded_header_token={:?}", decoded_header_token);
@@ -104,7 +99,7 @@ where
             tracing::error!("impossible to extract KID {:?}", header);
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token kid not present");
-            return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+            return Err(problem_details.into_response());
         };
 
         tracing::debug!("decoded_header_token_kid={:?}", decoded_header_token_kid);
@@ -115,7 +110,7 @@ where
             tracing::error!("impossible to extract well-known {:?}", header);
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token is malformed");
-            return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+            return Err(problem_details.into_response());
         };
 
         tracing::debug!("jwks_content={:?}", jwks_content);
@@ -124,7 +119,7 @@ where
             tracing::error!("impossible to find KID {:?}", header);
             let mut problem_details = libs::problem_details::ProblemDetails::from_401();
             problem_details.set_detail("Authorization Token kid not present");
-            return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+            return Err(problem_details.into_response());
         };
 
         tracing::debug!("jwk={:?}", jwk);
@@ -137,7 +132,7 @@ where
                     tracing::error!("not found identity provider domain");
                     let mut problem_details = libs::problem_details::ProblemDetails::from_401();
                     problem_details.set_detail("Host not prepared");
-                    return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+                    return Err(problem_details.into_response());
                 };
 
                 tracing::debug!("identity_provider_domain={:?}", identity_provider_domain);
@@ -146,7 +141,7 @@ where
                     tracing::error!("not found identity provider audience");
                     let mut problem_details = libs::problem_details::ProblemDetails::from_401();
                     problem_details.set_detail("Host not prepared");
-                    return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+                    return Err(problem_details.into_response());
                 };
 
                 tracing::debug!("identity_provider_audience={:?}", identity_provider_audience);
@@ -163,7 +158,7 @@ where
                     tracing::error!("impossible to decode rsa key {:?}", header);
                     let mut problem_details = libs::problem_details::ProblemDetails::from_401();
                     problem_details.set_detail("Impossible to decode RSA");
-                    return Err(libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details).into_response());
+                    return Err(problem_details.into_response());
                 };
 
                 return match jsonwebtoken::decode::<IdentityClaims>(header_token, &decoding_key, &rs256_validation) {
@@ -177,10 +172,7 @@ where
                         let mut problem_details = libs::problem_details::ProblemDetails::from_401();
                         problem_details.set_detail(format!("Impossible to decode token data: {}", error));
 
-                        Err(
-                            libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details)
-                                .into_response(),
-                        )
+                        Err(problem_details.into_response())
                     }
                 };
             }
@@ -188,10 +180,7 @@ where
                 tracing::error!("invalid algorithm {:?}", header);
                 let mut problem_details = libs::problem_details::ProblemDetails::from_401();
                 problem_details.set_detail("Invalid algorithm");
-                Err(
-                    libs::encoding::JsonResponse::with_status(StatusCode::UNAUTHORIZED, problem_details)
-                        .into_response(),
-                )
+                Err(problem_details.into_response())
             }
         };
     }
@@ -0,0 +1,87 @@
+use axum::extract::rejection::JsonRejection;
+use axum::extract::FromRequest;
+
+use crate::libs;
+
+/// `axum::Json` whose rejections are reported as Problem Details.
+#[derive(FromRequest)]
+#[from_request(via(axum::Json), rejection(libs::problem_details::ProblemDetails))]
+pub struct Json<T>(pub T);
+
+impl From<JsonRejection> for libs::problem_details::ProblemDetails {
+    fn from(rejection: JsonRejection) -> Self {
+        tracing::error!("{rejection}");
+
+        let mut problem_details = Self::from_status(rejection.status());
+        problem_details.set_detail(rejection.body_text());
+        problem_details
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{header, Request, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use serde::Deserialize;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Input {
+        #[allow(dead_code)]
+        price: i32,
+    }
+
+    async fn send(content_type: &str, body: &'static str) -> (StatusCode, Value) {
+        let response = Router::new()
+            .route("/", post(|Json(_): Json<Input>| async {}))
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header(header::CONTENT_TYPE, content_type)
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            libs::problem_details::PROBLEM_DETAILS_CONTENT_TYPE
+        );
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn given_malformed_json_when_extract_then_return_400_problem_details() {
+        let (status, body) = send(mime::APPLICATION_JSON.as_ref(), "{").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["status"], 400);
+    }
+
+    #[tokio::test]
+    async fn given_wrong_field_type_when_extract_then_return_422_problem_details() {
+        let (status, body) = send(mime::APPLICATION_JSON.as_ref(), r#"{"price": "free"}"#).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["detail"].as_str().unwrap().contains("price"));
+    }
+
+    #[tokio::test]
+    async fn given_missing_content_type_when_extract_then_return_415_problem_details() {
+        let (status, body) = send(mime::TEXT_PLAIN.as_ref(), r#"{"price": 1}"#).await;
+
+        assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(body["title"], "Unsupported Media Type");
+    }
+}
@@ -1,3 +1,7 @@
 pub use identity::*;
+pub use json::*;
+pub use query::*;
 
 mod identity;
+mod json;
+mod query;
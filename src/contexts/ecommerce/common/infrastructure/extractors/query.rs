@@ -0,0 +1,58 @@
+use axum::extract::rejection::QueryRejection;
+use axum::extract::FromRequestParts;
+
+use crate::libs;
+
+/// `axum::extract::Query` whose rejections are reported as Problem Details.
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Query), rejection(libs::problem_details::ProblemDetails))]
+pub struct Query<T>(pub T);
+
+impl From<QueryRejection> for libs::problem_details::ProblemDetails {
+    fn from(rejection: QueryRejection) -> Self {
+        tracing::error!("{rejection}");
+
+        let mut problem_details = Self::from_status(rejection.status());
+        problem_details.set_detail(rejection.body_text());
+        problem_details
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{header, Request, StatusCode};
+    use axum::routing::get;
+    use axum::Router;
+    use serde::Deserialize;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Params {
+        #[allow(dead_code)]
+        at: chrono::DateTime<chrono::offset::Utc>,
+    }
+
+    #[tokio::test]
+    async fn given_malformed_query_when_extract_then_return_400_problem_details() {
+        let response = Router::new()
+            .route("/", get(|Query(_): Query<Params>| async {}))
+            .oneshot(Request::builder().uri("/?at=yesterday").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            libs::problem_details::PROBLEM_DETAILS_CONTENT_TYPE
+        );
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert!(body["detail"].as_str().unwrap().contains("query string"));
+    }
+}
@@ -0,0 +1,107 @@
+use async_graphql::ErrorExtensions;
+
+use crate::contexts::ecommerce::common;
+
+/// Splits a domain error field into the path of the GraphQL input field, whose names are camelCase.
+pub fn graphql_field_path(field: &str) -> Vec<String> {
+    field
+        .split('/')
+        .map(|segment| {
+            let mut words = segment.split('_');
+            let first = words.next().unwrap_or_default().to_string();
+
+            words.fold(first, |mut path, word| {
+                let mut chars = word.chars();
+                path.extend(chars.next().map(|char| char.to_ascii_uppercase()));
+                path.push_str(chars.as_str());
+                path
+            })
+        })
+        .collect()
+}
+
+impl ErrorExtensions for common::domain::Error {
+    fn extend(&self) -> async_graphql::Error {
+        let message = match self {
+            Self::Persistence(_) => String::from("internal error"),
+            Self::Unavailable(_) => String::from("service unavailable"),
+            Self::Timeout(_) => String::from("timeout"),
+            _ => self.to_string(),
+        };
+
+        async_graphql::Error::new(message).extend_with(|_, extensions| {
+            extensions.set("code", self.code());
+
+            if let Some(field) = self.field() {
+                extensions.set("field", graphql_field_path(field));
+            }
+
+            if self.is_retryable() {
+                extensions.set("retryable", true);
+            }
+
+            if let Self::Persistence(reason) | Self::Unavailable(reason) | Self::Timeout(reason) = self {
+                let correlation_id = uuid::Uuid::new_v4().to_string();
+                tracing::error!(correlation_id, "{reason}");
+
+                extensions.set("correlationId", correlation_id);
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_validation_error_when_extend_then_set_code_and_field() {
+        let error = common::domain::Error::InvalidProductPrice.extend();
+
+        let extensions = serde_json::to_value(error.extensions.unwrap()).unwrap();
+
+        assert_eq!(extensions["code"], "INVALID_PRODUCT_PRICE");
+        assert_eq!(extensions["field"], serde_json::json!(["price", "amount"]));
+    }
+
+    #[test]
+    fn given_snake_case_field_when_extend_then_set_camel_case_field() {
+        let error = common::domain::Error::InvalidProductPriceValidity.extend();
+
+        let extensions = serde_json::to_value(error.extensions.unwrap()).unwrap();
+
+        assert_eq!(extensions["field"], serde_json::json!(["validUntil"]));
+    }
+
+    #[test]
+    fn given_permission_error_when_extend_then_set_forbidden_code() {
+        let error = common::domain::Error::InvalidPermission.extend();
+
+        let extensions = serde_json::to_value(error.extensions.unwrap()).unwrap();
+
+        assert_eq!(extensions["code"], "FORBIDDEN");
+        assert!(extensions.get("field").is_none());
+    }
+
+    #[test]
+    fn given_persistence_error_when_extend_then_hide_reason_behind_correlation_id() {
+        let error = common::domain::Error::Persistence(String::from("relation \"product\" does not exist")).extend();
+
+        assert!(!error.message.contains("product"));
+
+        let extensions = serde_json::to_value(error.extensions.unwrap()).unwrap();
+
+        assert_eq!(extensions["code"], "INTERNAL");
+        assert!(uuid::Uuid::parse_str(extensions["correlationId"].as_str().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn given_unavailable_error_when_extend_then_flag_as_retryable() {
+        let error = common::domain::Error::Unavailable(String::from("could not serialize access")).extend();
+
+        let extensions = serde_json::to_value(error.extensions.unwrap()).unwrap();
+
+        assert_eq!(extensions["code"], "UNAVAILABLE");
+        assert_eq!(extensions["retryable"], true);
+    }
+}
@@ -0,0 +1,3 @@
+pub use errors::*;
+
+mod errors;
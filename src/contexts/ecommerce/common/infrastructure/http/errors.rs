@@ -1,9 +1,12 @@
-use axum::http::StatusCode;
+use axum::http::header;
 use axum::response::{IntoResponse, Response};
 
 use crate::contexts::ecommerce::common;
 use crate::libs;
 
+// hint for clients on transient failures, the database usually recovers from them within a second
+const RETRY_AFTER_SECONDS: u64 = 1;
+
 impl IntoResponse for common::domain::Error {
     fn into_response(self) -> Response {
         let mut problem_details: libs::problem_details::ProblemDetails;
@@ -13,25 +16,216 @@ impl IntoResponse for common::domain::Error {
             | Self::InvalidProductName
             | Self::InvalidProductPrice
             | Self::InvalidProductCurrency
-            | Self::ProductAlreadyExists
+            | Self::InvalidProductStatus
+            | Self::InvalidProductSchedule
+            | Self::InvalidProductPriceId
+            | Self::InvalidProductPriceValidity
+            | Self::InvalidProductPriceHistoryPeriod
+            | Self::InvalidCategoryId
+            | Self::InvalidCategoryParentId
+            | Self::InvalidCategoryName
+            | Self::InvalidVariantId
+            | Self::InvalidVariantSku
+            | Self::InvalidVariantBarcode
+            | Self::InvalidVariantOption
+            | Self::InvalidWarehouseCode
+            | Self::InvalidStockAdjustmentId
+            | Self::InvalidStockAdjustmentDelta
+            | Self::InvalidStockAdjustmentReason
+            | Self::InvalidReservationId
+            | Self::InvalidReservationQuantity
+            | Self::InvalidReservationTtl
+            | Self::InvalidReservationStatus
+            | Self::InvalidMoneyAmount
+            | Self::InvalidCurrency
+            | Self::InvalidExchangeRate
+            | Self::InvalidExchangeRateFile(_)
+            | Self::InvalidProductEventKind
+            | Self::InvalidProductEventSequence
             | Self::InvalidProductTimeStampRelation => {
                 problem_details = libs::problem_details::ProblemDetails::from_400();
-                problem_details.set_detail(self);
+                problem_details.set_detail(&self);
+                problem_details.set_extension("code", self.code());
+            }
+            Self::UnsupportedExchangeRateFileType => {
+                problem_details = libs::problem_details::ProblemDetails::from_415();
+                problem_details.set_detail(&self);
+                problem_details.set_extension("code", self.code());
+            }
+            Self::InvalidData(_)
+            | Self::MoneyOverflow
+            | Self::ExchangeRateNotFound
+            | Self::CategoryCycle
+            | Self::InvalidVariantOptions => {
+                problem_details = libs::problem_details::ProblemDetails::from_422();
+                problem_details.set_detail(&self);
+                problem_details.set_extension("code", self.code());
+            }
+            Self::Validation(ref errors) => {
+                problem_details = libs::problem_details::ProblemDetails::from_422();
+                problem_details.set_detail(&self);
+                problem_details.set_extension("code", self.code());
+
+                for error in errors {
+                    let pointer = error.field().map(|field| format!("/{field}")).unwrap_or_default();
+                    problem_details.push_error(pointer, error.code(), error);
+                }
+            }
+            Self::ProductNotFound
+            | Self::ProductPriceNotFound
+            | Self::CategoryNotFound
+            | Self::VariantNotFound
+            | Self::ReservationNotFound => {
+                problem_details = libs::problem_details::ProblemDetails::from_404();
+                problem_details.set_detail(&self);
+                problem_details.set_extension("code", self.code());
+            }
+            Self::ProductAlreadyExists
+            | Self::ProductStatusConflict
+            | Self::InvalidProductStatusTransition
+            | Self::ProductPriceAlreadyExists
+            | Self::CategoryAlreadyExists
+            | Self::VariantAlreadyExists
+            | Self::VariantSkuAlreadyExists
+            | Self::VariantOptionsInUse
+            | Self::StockAdjustmentAlreadyExists
+            | Self::InsufficientStock
+            | Self::ReservationAlreadyExists
+            | Self::ReservationExpired
+            | Self::ReservationNotPending
+            | Self::Conflict(_) => {
+                problem_details = libs::problem_details::ProblemDetails::from_409();
+                problem_details.set_detail(&self);
+                problem_details.set_extension("code", self.code());
             }
             Self::InvalidPermission => {
                 problem_details = libs::problem_details::ProblemDetails::from_403();
-                problem_details.set_detail(self);
+                problem_details.set_detail(&self);
+                problem_details.set_extension("code", self.code());
             }
-            Self::Persistence(reason) => {
+            Self::Unavailable(ref reason) => {
+                tracing::warn!("{reason}");
+
                 problem_details = libs::problem_details::ProblemDetails::from_503();
-                problem_details.set_detail(reason);
+                problem_details.set_extension("code", self.code());
+                problem_details.set_extension("retryable", true);
+
+                return (
+                    [(header::RETRY_AFTER, RETRY_AFTER_SECONDS.to_string())],
+                    problem_details,
+                )
+                    .into_response();
+            }
+            Self::Timeout(ref reason) => {
+                tracing::warn!("{reason}");
+
+                problem_details = libs::problem_details::ProblemDetails::from_504();
+                problem_details.set_extension("code", self.code());
+            }
+            Self::Persistence(ref reason) => {
+                let correlation_id = uuid::Uuid::new_v4().to_string();
+                tracing::error!(correlation_id, "{reason}");
+
+                problem_details = libs::problem_details::ProblemDetails::from_500();
+                problem_details.set_extension("code", self.code());
+                problem_details.set_extension("correlationId", correlation_id);
             }
         }
 
-        libs::encoding::JsonResponse::with_status(
-            StatusCode::from_u16(problem_details.status).unwrap_or(StatusCode::IM_A_TEAPOT),
-            problem_details.clone(),
-        )
-        .into_response()
+        problem_details.into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    async fn body(response: Response) -> Value {
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn given_validation_error_when_into_response_then_return_422_with_errors() {
+        let error = common::domain::Error::Validation(vec![
+            common::domain::Error::InvalidProductName,
+            common::domain::Error::InvalidProductPrice,
+        ]);
+
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = body(response).await;
+        assert_eq!(body["code"], "VALIDATION_FAILED");
+        assert_eq!(
+            body["errors"],
+            json!([
+                { "pointer": "/name", "code": "INVALID_PRODUCT_NAME", "message": "invalid product name" },
+                { "pointer": "/price/amount", "code": "INVALID_PRODUCT_PRICE", "message": "invalid product price" }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn given_already_exists_error_when_into_response_then_return_409() {
+        let response = common::domain::Error::ProductAlreadyExists.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let body = body(response).await;
+        assert_eq!(body["code"], "PRODUCT_ALREADY_EXISTS");
+    }
+
+    #[tokio::test]
+    async fn given_not_found_error_when_into_response_then_return_404() {
+        let response = common::domain::Error::ProductPriceNotFound.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = body(response).await;
+        assert_eq!(body["code"], "PRODUCT_PRICE_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn given_missing_exchange_rate_error_when_into_response_then_return_422() {
+        let response = common::domain::Error::ExchangeRateNotFound.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = body(response).await;
+        assert_eq!(body["code"], "EXCHANGE_RATE_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn given_unavailable_error_when_into_response_then_return_503_with_retry_after() {
+        let response = common::domain::Error::Unavailable(String::from("deadlock detected")).into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+
+        let body = body(response).await;
+        assert_eq!(body["code"], "UNAVAILABLE");
+        assert_eq!(body["retryable"], true);
+        assert!(!body["detail"].as_str().unwrap().contains("deadlock"));
+    }
+
+    #[tokio::test]
+    async fn given_client_caused_db_errors_when_into_response_then_return_4xx() {
+        let conflict = common::domain::Error::Conflict(String::from("product_fkey")).into_response();
+        assert_eq!(conflict.status(), StatusCode::CONFLICT);
+
+        let invalid = common::domain::Error::InvalidData(String::from("product_price_check")).into_response();
+        assert_eq!(invalid.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn given_persistence_error_when_into_response_then_return_500_without_reason() {
+        let response =
+            common::domain::Error::Persistence(String::from("relation \"product\" does not exist")).into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body = body(response).await;
+        assert_eq!(body["code"], "INTERNAL");
+        assert!(!body["detail"].as_str().unwrap().contains("product"));
+        assert!(body["correlationId"].is_string());
     }
 }
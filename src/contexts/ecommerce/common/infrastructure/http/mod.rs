@@ -1,3 +1 @@
-pub use errors::*;
-
 mod errors;
@@ -1,8 +1,16 @@
 pub use dependency_container::*;
+pub use extensions::*;
 pub use extractors::*;
-pub use http::*;
+pub use graphql::*;
+pub use postgres::*;
+pub use sqlite::*;
 
 pub mod controller;
+mod database;
 mod dependency_container;
+mod extensions;
 mod extractors;
+mod graphql;
 mod http;
+mod postgres;
+mod sqlite;
@@ -0,0 +1,77 @@
+use crate::contexts::ecommerce::common;
+use crate::libs::postgres::errcodes::{Class, Codes};
+
+/// Maps postgres SQLSTATE codes onto domain errors.
+pub fn from_postgres_error(error: &sqlx::postgres::PgDatabaseError) -> common::domain::Error {
+    let database_error: &dyn sqlx::error::DatabaseError = error;
+    let reason = database_error.message().to_string();
+    let code = Codes::from(database_error);
+    let constraint = database_error.constraint().map(String::from).unwrap_or(reason.clone());
+
+    if code.is_retryable() {
+        return common::domain::Error::Unavailable(reason);
+    }
+
+    match code {
+        Codes::QueryCanceled => common::domain::Error::Timeout(reason),
+        Codes::UniqueViolation | Codes::ForeignKeyViolation | Codes::RestrictViolation | Codes::ExclusionViolation => {
+            common::domain::Error::Conflict(constraint)
+        }
+        _ => match code.class() {
+            Class::IntegrityConstraintViolation | Class::DataException => {
+                common::domain::Error::InvalidData(constraint)
+            }
+            _ => common::domain::Error::Persistence(reason),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::Executor;
+
+    use crate::libs;
+
+    use super::*;
+
+    async fn execute(sql: &str) -> common::domain::Error {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+
+        database.pool.execute(sql).await.unwrap_err().into()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_data_exception_when_convert_then_return_invalid_data() {
+        let error = execute("SELECT CAST('abc' AS INTEGER)").await;
+
+        assert!(matches!(error, common::domain::Error::InvalidData(_)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_not_null_violation_when_convert_then_return_invalid_data() {
+        let error = execute("INSERT INTO product (id) VALUES (uuid_generate_v4())").await;
+
+        assert!(matches!(error, common::domain::Error::InvalidData(_)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_canceled_query_when_convert_then_return_timeout() {
+        let error = execute("SET statement_timeout = 1; SELECT pg_sleep(1)").await;
+
+        assert!(matches!(error, common::domain::Error::Timeout(_)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_serialization_failure_when_convert_then_return_retryable_unavailable() {
+        let error = execute("DO $$ BEGIN RAISE EXCEPTION 'conflict' USING ERRCODE = '40001'; END $$").await;
+
+        assert!(error.is_retryable());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_undefined_table_when_convert_then_return_persistence() {
+        let error = execute("SELECT * FROM missing").await;
+
+        assert!(matches!(error, common::domain::Error::Persistence(_)));
+    }
+}
@@ -0,0 +1,5 @@
+pub use errors::*;
+pub use unit_of_work::*;
+
+mod errors;
+mod unit_of_work;
@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct PostgresUnitOfWorkFactory {
+    db: libs::postgres::ConnectionPool,
+    retry_policy: libs::postgres::retry::RetryPolicy,
+    replica_router: Option<Arc<libs::postgres::ReplicaRouter>>,
+}
+
+impl PostgresUnitOfWorkFactory {
+    pub fn new(db: libs::postgres::ConnectionPool, retry_policy: libs::postgres::retry::RetryPolicy) -> Self {
+        Self {
+            db,
+            retry_policy,
+            replica_router: None,
+        }
+    }
+
+    /// Reports committed writes to `replica_router`, so the caller reads them back from the primary.
+    pub fn with_replica_router(mut self, replica_router: Arc<libs::postgres::ReplicaRouter>) -> Self {
+        self.replica_router = Some(replica_router);
+        self
+    }
+}
+
+#[async_trait]
+impl common::application::unit_of_work::UnitOfWorkFactory for PostgresUnitOfWorkFactory {
+    type Error = common::domain::Error;
+
+    async fn begin(&self) -> Result<common::application::unit_of_work::DynUnitOfWork<Self::Error>, Self::Error> {
+        let transaction = self
+            .retry_policy
+            .run("begin", libs::postgres::retry::Idempotency::Idempotent, || {
+                self.db.begin()
+            })
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        let transaction = libs::postgres::SharedTransaction::new(transaction, &self.db);
+
+        Ok(Box::new(PostgresUnitOfWork {
+            product_repository: Arc::new(backoffice::infrastructure::PostgresProductRepository::in_transaction(
+                transaction.clone(),
+            )),
+            product_event_repository: Arc::new(
+                backoffice::infrastructure::PostgresProductEventRepository::in_transaction(transaction.clone()),
+            ),
+            transaction,
+            replica_router: self.replica_router.clone(),
+        }))
+    }
+}
+
+pub struct PostgresUnitOfWork {
+    transaction: libs::postgres::SharedTransaction,
+    replica_router: Option<Arc<libs::postgres::ReplicaRouter>>,
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+}
+
+#[async_trait]
+impl common::application::unit_of_work::UnitOfWork for PostgresUnitOfWork {
+    type Error = common::domain::Error;
+
+    fn product_repository(&self) -> backoffice::domain::product::DynProductRepository<Self::Error> {
+        self.product_repository.clone()
+    }
+
+    fn product_event_repository(&self) -> backoffice::domain::product_event::DynProductEventRepository<Self::Error> {
+        self.product_event_repository.clone()
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), Self::Error> {
+        let Some(transaction) = self.transaction.lock().await.take() else {
+            return Ok(());
+        };
+
+        transaction
+            .commit()
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        if let Some(replica_router) = &self.replica_router {
+            replica_router.record_write();
+        }
+
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), Self::Error> {
+        let Some(transaction) = self.transaction.lock().await.take() else {
+            return Ok(());
+        };
+
+        transaction
+            .rollback()
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contexts::ecommerce::common::application::unit_of_work::UnitOfWorkFactory;
+
+    use super::*;
+
+    async fn compose_fixture() -> (
+        PostgresUnitOfWorkFactory,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    ) {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+
+        (
+            PostgresUnitOfWorkFactory::new(database.pool.clone(), libs::postgres::retry::RetryPolicy::default()),
+            Arc::new(backoffice::infrastructure::PostgresProductRepository::new(
+                database.pool,
+            )),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_commit_then_persist_product_and_event() {
+        let (factory, repository) = compose_fixture().await;
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        let unit_of_work = factory.begin().await.unwrap();
+        unit_of_work.product_repository().save(&product).await.unwrap();
+
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_none());
+
+        let events = unit_of_work
+            .product_event_repository()
+            .get_last_sequence()
+            .await
+            .unwrap();
+        assert_eq!(events.to_primitive(), 1);
+
+        unit_of_work.commit().await.unwrap();
+
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_some());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_rollback_then_discard_product() {
+        let (factory, repository) = compose_fixture().await;
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        let unit_of_work = factory.begin().await.unwrap();
+        unit_of_work.product_repository().save(&product).await.unwrap();
+        unit_of_work.rollback().await.unwrap();
+
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unfinished_unit_of_work_when_drop_then_discard_product() {
+        let (factory, repository) = compose_fixture().await;
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        let unit_of_work = factory.begin().await.unwrap();
+        unit_of_work.product_repository().save(&product).await.unwrap();
+        drop(unit_of_work);
+
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_finished_unit_of_work_when_use_repository_then_return_err() {
+        let (factory, _) = compose_fixture().await;
+
+        let unit_of_work = factory.begin().await.unwrap();
+        let repository = unit_of_work.product_repository();
+        unit_of_work.commit().await.unwrap();
+
+        assert!(repository.get(None).await.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_replica_router_when_commit_then_caller_reads_own_write_from_primary() {
+        let primary = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+        let replica = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+        let router = Arc::new(libs::postgres::ReplicaRouter::new(
+            primary.pool.clone(),
+            replica.pool,
+            std::time::Duration::from_secs(60),
+        ));
+
+        let factory = PostgresUnitOfWorkFactory::new(primary.pool, libs::postgres::retry::RetryPolicy::default())
+            .with_replica_router(router.clone());
+        let repository = backoffice::infrastructure::PostgresProductRepository::routed(router);
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        libs::database::with_caller(Some(String::from("writer")), async {
+            let unit_of_work = factory.begin().await.unwrap();
+            unit_of_work.product_repository().save(&product).await.unwrap();
+            unit_of_work.commit().await.unwrap();
+        })
+        .await;
+
+        let read_by = |caller: &str| {
+            libs::database::with_caller(
+                Some(String::from(caller)),
+                backoffice::domain::product::ProductRepository::get_by_id(&repository, &product.id),
+            )
+        };
+
+        assert!(read_by("writer").await.unwrap().is_some());
+        assert!(read_by("other").await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::contexts::ecommerce::common;
+use crate::libs::sqlite::errcodes::Codes;
+
+/// Maps sqlite result codes onto the same domain errors as their postgres counterparts.
+pub fn from_sqlite_error(error: &sqlx::sqlite::SqliteError) -> common::domain::Error {
+    let database_error: &dyn sqlx::error::DatabaseError = error;
+    let reason = database_error.message().to_string();
+    let code = Codes::from(database_error);
+
+    if code.is_retryable() {
+        return common::domain::Error::Unavailable(reason);
+    }
+
+    match code {
+        Codes::Interrupt => common::domain::Error::Timeout(reason),
+        Codes::ConstraintPrimaryKey | Codes::ConstraintUnique | Codes::ConstraintForeignKey => {
+            common::domain::Error::Conflict(reason)
+        }
+        Codes::ConstraintNotNull | Codes::ConstraintCheck | Codes::Mismatch | Codes::TooBig => {
+            common::domain::Error::InvalidData(reason)
+        }
+        // any other SQLITE_CONSTRAINT flavour, e.g. raised from a trigger
+        _ if code.primary() == 19 => common::domain::Error::InvalidData(reason),
+        _ => common::domain::Error::Persistence(reason),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::Executor;
+
+    use crate::contexts::ecommerce::backoffice;
+    use crate::libs;
+
+    use super::*;
+
+    async fn execute(sql: &str) -> common::domain::Error {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        database.pool.execute(sql).await.unwrap_err().into()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_not_null_violation_when_convert_then_return_invalid_data() {
+        let error = execute("INSERT INTO product (id) VALUES ('00000000-0000-0000-0000-000000000000')").await;
+
+        assert!(matches!(error, common::domain::Error::InvalidData(_)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_check_violation_when_convert_then_return_invalid_data() {
+        let error = execute("INSERT INTO product (id, name, price, currency) VALUES ('short', 'a', 1, 'EUR')").await;
+
+        assert!(matches!(error, common::domain::Error::InvalidData(_)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_undefined_table_when_convert_then_return_persistence() {
+        let error = execute("SELECT * FROM missing").await;
+
+        assert!(matches!(error, common::domain::Error::Persistence(_)));
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
+pub struct SqliteUnitOfWorkFactory {
+    db: libs::sqlite::ConnectionPool,
+}
+
+impl SqliteUnitOfWorkFactory {
+    pub fn new(db: libs::sqlite::ConnectionPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl common::application::unit_of_work::UnitOfWorkFactory for SqliteUnitOfWorkFactory {
+    type Error = common::domain::Error;
+
+    async fn begin(&self) -> Result<common::application::unit_of_work::DynUnitOfWork<Self::Error>, Self::Error> {
+        let transaction = self
+            .db
+            .begin()
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)?;
+
+        let transaction = libs::sqlite::SharedTransaction::new(transaction, &self.db);
+
+        Ok(Box::new(SqliteUnitOfWork {
+            product_repository: Arc::new(backoffice::infrastructure::SqliteProductRepository::in_transaction(
+                transaction.clone(),
+            )),
+            product_event_repository: Arc::new(
+                backoffice::infrastructure::SqliteProductEventRepository::in_transaction(transaction.clone()),
+            ),
+            transaction,
+        }))
+    }
+}
+
+pub struct SqliteUnitOfWork {
+    transaction: libs::sqlite::SharedTransaction,
+    product_repository: backoffice::domain::product::DynProductRepository<common::domain::Error>,
+    product_event_repository: backoffice::domain::product_event::DynProductEventRepository<common::domain::Error>,
+}
+
+#[async_trait]
+impl common::application::unit_of_work::UnitOfWork for SqliteUnitOfWork {
+    type Error = common::domain::Error;
+
+    fn product_repository(&self) -> backoffice::domain::product::DynProductRepository<Self::Error> {
+        self.product_repository.clone()
+    }
+
+    fn product_event_repository(&self) -> backoffice::domain::product_event::DynProductEventRepository<Self::Error> {
+        self.product_event_repository.clone()
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), Self::Error> {
+        let Some(transaction) = self.transaction.lock().await.take() else {
+            return Ok(());
+        };
+
+        transaction
+            .commit()
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), Self::Error> {
+        let Some(transaction) = self.transaction.lock().await.take() else {
+            return Ok(());
+        };
+
+        transaction
+            .rollback()
+            .await
+            .inspect_err(|err| tracing::error!("{err}"))
+            .map_err(common::domain::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contexts::ecommerce::common::application::unit_of_work::UnitOfWorkFactory;
+
+    use super::*;
+
+    async fn compose_fixture() -> (
+        SqliteUnitOfWorkFactory,
+        backoffice::domain::product::DynProductRepository<common::domain::Error>,
+        libs::sqlite::fixture::SqliteDatabaseFixture,
+    ) {
+        let database =
+            libs::sqlite::fixture::SqliteDatabaseFixture::new(&backoffice::infrastructure::SQLITE_MIGRATOR).await;
+
+        (
+            SqliteUnitOfWorkFactory::new(database.pool.clone()),
+            Arc::new(backoffice::infrastructure::SqliteProductRepository::new(
+                database.pool.clone(),
+            )),
+            database,
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_commit_then_persist_product_and_event() {
+        let (factory, repository, _database) = compose_fixture().await;
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        let unit_of_work = factory.begin().await.unwrap();
+        unit_of_work.product_repository().save(&product).await.unwrap();
+
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_none());
+
+        let events = unit_of_work
+            .product_event_repository()
+            .get_last_sequence()
+            .await
+            .unwrap();
+        assert_eq!(events.to_primitive(), 1);
+
+        unit_of_work.commit().await.unwrap();
+
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_some());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_saved_product_when_rollback_then_discard_product() {
+        let (factory, repository, _database) = compose_fixture().await;
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        let unit_of_work = factory.begin().await.unwrap();
+        unit_of_work.product_repository().save(&product).await.unwrap();
+        unit_of_work.rollback().await.unwrap();
+
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_unfinished_unit_of_work_when_drop_then_discard_product() {
+        let (factory, repository, _database) = compose_fixture().await;
+        let product = backoffice::domain::product::fixture::ProductBuilder::default().to_entity();
+
+        let unit_of_work = factory.begin().await.unwrap();
+        unit_of_work.product_repository().save(&product).await.unwrap();
+        drop(unit_of_work);
+
+        assert!(repository.get_by_id(&product.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_finished_unit_of_work_when_use_repository_then_return_err() {
+        let (factory, _, _database) = compose_fixture().await;
+
+        let unit_of_work = factory.begin().await.unwrap();
+        let repository = unit_of_work.product_repository();
+        unit_of_work.commit().await.unwrap();
+
+        assert!(repository.get(None).await.is_err());
+    }
+}
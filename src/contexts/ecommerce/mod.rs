@@ -1,4 +1,7 @@
+use std::sync::Arc;
+
 use axum::Router;
+use utoipa::OpenApi;
 
 use crate::libs;
 
@@ -8,26 +11,99 @@ mod settings;
 
 pub struct HttpContext {
     pub router: Router,
+    pub openapi: utoipa::openapi::OpenApi,
+    pub jobs: BackgroundJobs,
+}
+
+/// Work running next to the http server, started apart from building the context so that doing
+/// so spawns nothing.
+pub struct BackgroundJobs {
+    apply_product_schedules: Arc<backoffice::application::usecases::ApplyProductSchedules>,
+    product_schedule_interval: std::time::Duration,
+}
+
+impl BackgroundJobs {
+    pub fn spawn(&self) {
+        backoffice::infrastructure::spawn_product_scheduler(
+            &self.apply_product_schedules,
+            self.product_schedule_interval,
+        );
+    }
 }
 
 impl HttpContext {
     pub async fn new() -> Self {
         let settings = settings::Settings::new();
 
-        let db = libs::postgres::ConnectionManager::new_pool(&settings.database_url, None)
-            .await
-            .expect("could not initialize postgres connection pool");
+        let services = Self::build_services(&settings).await;
 
-        let services = common::infrastructure::DependencyContainer::new(db);
+        let jobs = BackgroundJobs {
+            apply_product_schedules: services.apply_product_schedules_usecase.clone(),
+            product_schedule_interval: settings.product_schedule_interval,
+        };
 
         Self {
+            openapi: utoipa::openapi::OpenApiBuilder::new()
+                .build()
+                .nest("/ecommerce/backoffice", backoffice::infrastructure::ApiDoc::openapi()),
             router: Router::new().nest(
                 "/ecommerce",
                 Router::new().nest(
                     "/backoffice",
-                    backoffice::infrastructure::HttpController::build(services),
+                    backoffice::infrastructure::HttpController::build(services, &settings),
                 ),
             ),
+            jobs,
         }
     }
+
+    /// Picks the storage backend from the database url scheme, postgres unless it is `sqlite:`.
+    async fn build_services(settings: &settings::Settings) -> common::infrastructure::DependencyContainer {
+        if settings.database_url.starts_with("sqlite:") {
+            let db = libs::sqlite::ConnectionManager::new_pool(
+                &settings.database_url,
+                &libs::sqlite::PoolSettings {
+                    slow_query_threshold: settings.database_pool.slow_query_threshold,
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("could not initialize sqlite connection pool");
+
+            backoffice::infrastructure::SQLITE_MIGRATOR
+                .run(&*db)
+                .await
+                .expect("could not migrate sqlite database");
+
+            return common::infrastructure::DependencyContainer::new_sqlite(db, settings.currency_rounding);
+        }
+
+        let db = libs::postgres::ConnectionManager::new_pool(&settings.database_url, &settings.database_pool)
+            .await
+            .expect("could not initialize postgres connection pool");
+
+        let Some(replica) = &settings.database_replica else {
+            return common::infrastructure::DependencyContainer::new(
+                db,
+                settings.database_retry_policy.clone(),
+                settings.currency_rounding,
+            );
+        };
+
+        let replica_db = libs::postgres::ConnectionManager::new_lazy_pool(&replica.url, &settings.database_pool)
+            .expect("could not initialize postgres replica connection pool");
+
+        let replica_router = Arc::new(libs::postgres::ReplicaRouter::new(
+            db,
+            replica_db,
+            replica.read_your_writes_window,
+        ));
+        replica_router.spawn_health_check(replica.health_check_interval);
+
+        common::infrastructure::DependencyContainer::new_with_replica(
+            replica_router,
+            settings.database_retry_policy.clone(),
+            settings.currency_rounding,
+        )
+    }
 }
@@ -1,18 +1,237 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::contexts::ecommerce::{backoffice, common};
+use crate::libs;
+
 pub struct Settings {
-    pub gql_playground_enabled: bool,
+    pub graphql: GraphQLSettings,
     pub database_url: String,
+    pub database_retry_policy: libs::postgres::retry::RetryPolicy,
+    pub database_pool: libs::postgres::PoolSettings,
+    pub database_replica: Option<DatabaseReplicaSettings>,
+    pub currency_rounding: common::domain::RoundingMode,
+    pub product_schedule_interval: Duration,
+}
+
+#[derive(Clone)]
+pub struct DatabaseReplicaSettings {
+    pub url: String,
+    pub read_your_writes_window: Duration,
+    pub health_check_interval: Duration,
+}
+
+#[derive(Clone)]
+pub struct GraphQLSettings {
+    pub playground_enabled: bool,
+    pub introspection_enabled: bool,
+    pub depth_limit: usize,
+    pub complexity_limit: usize,
+    pub timeout: Duration,
+    pub persisted_queries_cache_size: usize,
+    pub persisted_queries_manifest: Option<PathBuf>,
+    pub persisted_queries_strict: bool,
 }
 
 impl Settings {
     pub fn new() -> Self {
-        let gql_playground_enabled: bool = std::env::var("GRAPHQL_PLAYGROUND_ENABLED")
+        Self {
+            graphql: GraphQLSettings::new(),
+            database_url: std::env::var("ECOMMERCE__DATABASE_URL").expect("ECOMMERCE__DATABASE_URL"),
+            database_retry_policy: Self::database_retry_policy(),
+            database_pool: Self::database_pool(),
+            database_replica: DatabaseReplicaSettings::new(),
+            currency_rounding: std::env::var("ECOMMERCE__CURRENCY_ROUNDING")
+                .ok()
+                .and_then(|value| value.parse::<common::domain::RoundingMode>().ok())
+                .unwrap_or_default(),
+            // a zero period would make the scheduler panic, it falls back to the default instead
+            product_schedule_interval: std::env::var("ECOMMERCE__PRODUCT_SCHEDULE_INTERVAL_MS")
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .filter(|ms| *ms > 0)
+                .map(Duration::from_millis)
+                .unwrap_or(backoffice::infrastructure::DEFAULT_PRODUCT_SCHEDULE_INTERVAL),
+        }
+    }
+
+    fn database_retry_policy() -> libs::postgres::retry::RetryPolicy {
+        let defaults = libs::postgres::retry::RetryPolicy::default();
+
+        let max_attempts: u32 = std::env::var("ECOMMERCE__DATABASE_RETRY_MAX_ATTEMPTS")
+            .ok()
+            .and_then(|value| value.parse::<u32>().ok())
+            .unwrap_or(defaults.max_attempts);
+
+        let base_delay = std::env::var("ECOMMERCE__DATABASE_RETRY_BASE_DELAY_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.base_delay);
+
+        let max_delay = std::env::var("ECOMMERCE__DATABASE_RETRY_MAX_DELAY_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.max_delay);
+
+        libs::postgres::retry::RetryPolicy::new(max_attempts, base_delay, max_delay)
+    }
+}
+
+impl Settings {
+    fn database_pool() -> libs::postgres::PoolSettings {
+        let defaults = libs::postgres::PoolSettings::default();
+
+        let min_connections: u32 = std::env::var("ECOMMERCE__DATABASE_MIN_CONNECTIONS")
+            .ok()
+            .and_then(|value| value.parse::<u32>().ok())
+            .unwrap_or(defaults.min_connections);
+
+        let max_connections: u32 = std::env::var("ECOMMERCE__DATABASE_MAX_CONNECTIONS")
+            .ok()
+            .and_then(|value| value.parse::<u32>().ok())
+            .unwrap_or(defaults.max_connections);
+
+        // 0 disables the timeout, as in postgres itself
+        let optional_duration = |name: &str, default: Option<Duration>| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(|millis| Some(Duration::from_millis(millis)).filter(|duration| !duration.is_zero()))
+                .unwrap_or(default)
+        };
+
+        let acquire_timeout = std::env::var("ECOMMERCE__DATABASE_ACQUIRE_TIMEOUT_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.acquire_timeout);
+
+        let application_name = std::env::var("ECOMMERCE__DATABASE_APPLICATION_NAME")
+            .ok()
+            .filter(|value| !value.is_empty())
+            .unwrap_or(defaults.application_name);
+
+        let slow_query_threshold = std::env::var("ECOMMERCE__DATABASE_SLOW_QUERY_THRESHOLD_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.slow_query_threshold);
+
+        libs::postgres::PoolSettings {
+            min_connections,
+            max_connections,
+            idle_timeout: optional_duration("ECOMMERCE__DATABASE_IDLE_TIMEOUT_MS", defaults.idle_timeout),
+            max_lifetime: optional_duration("ECOMMERCE__DATABASE_MAX_LIFETIME_MS", defaults.max_lifetime),
+            acquire_timeout,
+            statement_timeout: optional_duration(
+                "ECOMMERCE__DATABASE_STATEMENT_TIMEOUT_MS",
+                defaults.statement_timeout,
+            ),
+            application_name,
+            slow_query_threshold,
+        }
+    }
+}
+
+impl DatabaseReplicaSettings {
+    /// Replica settings, or `None` when `ECOMMERCE__DATABASE_REPLICA_URL` is unset and every
+    /// statement goes to the primary.
+    pub fn new() -> Option<Self> {
+        let url = std::env::var("ECOMMERCE__DATABASE_REPLICA_URL")
+            .ok()
+            .filter(|value| !value.is_empty())?;
+
+        let read_your_writes_window = std::env::var("ECOMMERCE__DATABASE_READ_YOUR_WRITES_WINDOW_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(Duration::from_secs(5));
+
+        let health_check_interval = std::env::var("ECOMMERCE__DATABASE_REPLICA_HEALTH_CHECK_INTERVAL_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(Duration::from_secs(5));
+
+        Some(Self {
+            url,
+            read_your_writes_window,
+            health_check_interval,
+        })
+    }
+}
+
+impl GraphQLSettings {
+    pub fn new() -> Self {
+        let defaults = Self::default();
+
+        let playground_enabled: bool = std::env::var("GRAPHQL_PLAYGROUND_ENABLED")
+            .unwrap_or(String::from("false"))
+            .parse::<bool>()
+            .unwrap_or(false);
+
+        let introspection_enabled: bool = std::env::var("GRAPHQL_INTROSPECTION_ENABLED")
+            .unwrap_or(String::from("false"))
+            .parse::<bool>()
+            .unwrap_or(false);
+
+        let depth_limit: usize = std::env::var("GRAPHQL_DEPTH_LIMIT")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(defaults.depth_limit);
+
+        let complexity_limit: usize = std::env::var("GRAPHQL_COMPLEXITY_LIMIT")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(defaults.complexity_limit);
+
+        let timeout = std::env::var("GRAPHQL_TIMEOUT_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.timeout);
+
+        let persisted_queries_cache_size: usize = std::env::var("GRAPHQL_PERSISTED_QUERIES_CACHE_SIZE")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(defaults.persisted_queries_cache_size);
+
+        let persisted_queries_manifest = std::env::var("GRAPHQL_PERSISTED_QUERIES_MANIFEST")
+            .ok()
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from);
+
+        let persisted_queries_strict: bool = std::env::var("GRAPHQL_PERSISTED_QUERIES_STRICT")
             .unwrap_or(String::from("false"))
             .parse::<bool>()
             .unwrap_or(false);
 
         Self {
-            gql_playground_enabled,
-            database_url: std::env::var("ECOMMERCE__DATABASE_URL").expect("ECOMMERCE__DATABASE_URL"),
+            playground_enabled,
+            introspection_enabled,
+            depth_limit,
+            complexity_limit,
+            timeout,
+            persisted_queries_cache_size,
+            persisted_queries_manifest,
+            persisted_queries_strict,
+        }
+    }
+}
+
+impl Default for GraphQLSettings {
+    fn default() -> Self {
+        Self {
+            playground_enabled: false,
+            introspection_enabled: false,
+            depth_limit: 10,
+            complexity_limit: 1_000,
+            timeout: Duration::from_secs(5),
+            persisted_queries_cache_size: 1_000,
+            persisted_queries_manifest: None,
+            persisted_queries_strict: false,
         }
     }
 }
@@ -0,0 +1,8 @@
+/// Whether the failure happened on the way to the database rather than in it, which is transient whatever the
+/// backend. A closed pool or a crashed worker never comes back, so neither counts.
+pub fn is_connection_failure(error: &sqlx::Error) -> bool {
+    matches!(
+        error,
+        sqlx::Error::Io(_) | sqlx::Error::Tls(_) | sqlx::Error::PoolTimedOut
+    )
+}
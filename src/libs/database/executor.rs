@@ -0,0 +1,309 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use sqlx::pool::PoolConnection;
+use sqlx::Database;
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::libs;
+
+use super::monitoring::HoldTimer;
+
+/// Transaction shared by every repository taking part in a unit of work, emptied once it finishes.
+pub struct SharedTransaction<DB: Database> {
+    transaction: Arc<Mutex<Option<sqlx::Transaction<'static, DB>>>>,
+    slow_query_threshold: Duration,
+}
+
+impl<DB: Database> SharedTransaction<DB> {
+    /// Shares `transaction`, begun on `pool`, whose slow query threshold it keeps.
+    pub fn new(transaction: sqlx::Transaction<'static, DB>, pool: &libs::database::ConnectionPool<DB>) -> Self {
+        Self {
+            transaction: Arc::new(Mutex::new(Some(transaction))),
+            slow_query_threshold: pool.slow_query_threshold(),
+        }
+    }
+}
+
+impl<DB: Database> Clone for SharedTransaction<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            transaction: self.transaction.clone(),
+            slow_query_threshold: self.slow_query_threshold,
+        }
+    }
+}
+
+impl<DB: Database> Deref for SharedTransaction<DB> {
+    type Target = Mutex<Option<sqlx::Transaction<'static, DB>>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.transaction
+    }
+}
+
+/// Where repository statements run: straight on the pool, inside an open transaction, or split
+/// between a primary and a read replica.
+pub enum Executor<DB: Database> {
+    Pool(libs::database::ConnectionPool<DB>),
+    Transaction(SharedTransaction<DB>),
+    Routed(Arc<libs::database::ReplicaRouter<DB>>),
+}
+
+/// Connection checked out for one repository call, reported when held past the slow query threshold.
+pub struct Connection<'a, DB: Database> {
+    source: ConnectionSource<'a, DB>,
+    _timer: HoldTimer,
+}
+
+enum ConnectionSource<'a, DB: Database> {
+    Pool(Box<PoolConnection<DB>>),
+    Transaction(MutexGuard<'a, Option<sqlx::Transaction<'static, DB>>>),
+}
+
+impl<DB: Database> Executor<DB> {
+    /// Connection for statements that write, always on the primary. `operation` names the repository
+    /// call in the warnings about it.
+    pub async fn acquire(&self, operation: &'static str) -> Result<Connection<'_, DB>, sqlx::Error> {
+        match self {
+            Self::Pool(pool) => acquire_from(pool, operation).await,
+            Self::Routed(router) => acquire_from(router.primary(), operation).await,
+            Self::Transaction(transaction) => {
+                let guard = transaction.lock().await;
+
+                if guard.is_none() {
+                    return Err(sqlx::Error::Protocol(String::from("transaction already finished")));
+                }
+
+                Ok(Connection {
+                    source: ConnectionSource::Transaction(guard),
+                    _timer: HoldTimer::start(operation, transaction.slow_query_threshold),
+                })
+            }
+        }
+    }
+
+    /// Connection for read-only statements, which may be served by the replica.
+    pub async fn acquire_read(&self, operation: &'static str) -> Result<Connection<'_, DB>, sqlx::Error> {
+        match self {
+            Self::Routed(router) => router.acquire_for_read(operation).await,
+            _ => self.acquire(operation).await,
+        }
+    }
+
+    /// Tells the replica router, if any, that the current caller has just written.
+    pub fn record_write(&self) {
+        if let Self::Routed(router) = self {
+            router.record_write();
+        }
+    }
+}
+
+/// Waiting longer than this for a connection while none was idle means the pool is saturated.
+const SATURATION_WAIT: Duration = Duration::from_millis(100);
+
+/// Acquires a connection from `pool` for `operation`, warning when it had to queue behind busy connections.
+pub async fn acquire_from<DB: Database>(
+    pool: &libs::database::ConnectionPool<DB>,
+    operation: &'static str,
+) -> Result<Connection<'static, DB>, sqlx::Error> {
+    let idle = pool.num_idle();
+    let started_at = Instant::now();
+
+    let connection = pool.acquire().await?;
+
+    let waited = started_at.elapsed();
+    if idle == 0 && waited > SATURATION_WAIT {
+        tracing::warn!(
+            db.operation = operation,
+            db.pool.size = pool.size(),
+            db.pool.wait_ms = waited.as_millis() as u64,
+            "connection pool saturated, waited for a free connection"
+        );
+    }
+
+    Ok(Connection {
+        source: ConnectionSource::Pool(Box::new(connection)),
+        _timer: HoldTimer::start(operation, pool.slow_query_threshold()),
+    })
+}
+
+impl<DB: Database> Clone for Executor<DB> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Pool(pool) => Self::Pool(pool.clone()),
+            Self::Transaction(transaction) => Self::Transaction(transaction.clone()),
+            Self::Routed(router) => Self::Routed(router.clone()),
+        }
+    }
+}
+
+impl<DB: Database> From<libs::database::ConnectionPool<DB>> for Executor<DB> {
+    fn from(pool: libs::database::ConnectionPool<DB>) -> Self {
+        Self::Pool(pool)
+    }
+}
+
+impl<DB: Database> Deref for Connection<'_, DB> {
+    type Target = DB::Connection;
+
+    fn deref(&self) -> &Self::Target {
+        match &self.source {
+            ConnectionSource::Pool(connection) => connection,
+            ConnectionSource::Transaction(guard) => guard.as_ref().expect("transaction checked on acquire"),
+        }
+    }
+}
+
+impl<DB: Database> DerefMut for Connection<'_, DB> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.source {
+            ConnectionSource::Pool(connection) => connection,
+            ConnectionSource::Transaction(guard) => guard.as_mut().expect("transaction checked on acquire"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    /// Collects the messages and operations of warnings raised while it is the default subscriber.
+    #[derive(Clone, Default)]
+    struct Warnings(Arc<std::sync::Mutex<Vec<Warning>>>);
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for Warnings {
+        fn on_event(&self, event: &tracing::Event<'_>, _: tracing_subscriber::layer::Context<'_, S>) {
+            if *event.metadata().level() == tracing::Level::WARN {
+                let mut warning = Warning::default();
+                event.record(&mut warning);
+                self.0.lock().unwrap().push(warning);
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Warning {
+        message: String,
+        operation: String,
+    }
+
+    impl tracing::field::Visit for Warning {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            if field.name() == "db.operation" {
+                self.operation = value.to_string();
+            }
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.message = format!("{value:?}");
+            }
+        }
+    }
+
+    impl Warnings {
+        fn recorded(&self) -> Vec<(String, String)> {
+            self.0
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|warning| (warning.message.clone(), warning.operation.clone()))
+                .collect()
+        }
+    }
+
+    const SLOW_QUERY_THRESHOLD: Duration = Duration::from_millis(150);
+
+    async fn compose_pool() -> libs::sqlite::ConnectionPool {
+        let settings = libs::sqlite::PoolSettings {
+            max_connections: 1,
+            slow_query_threshold: SLOW_QUERY_THRESHOLD,
+        };
+
+        libs::sqlite::ConnectionManager::new_pool("sqlite::memory:", &settings)
+            .await
+            .unwrap()
+    }
+
+    /// Holds the only connection of `pool` for `duration` while `waiting` queues for it.
+    async fn hold_while<T>(
+        pool: &libs::sqlite::ConnectionPool,
+        duration: Duration,
+        waiting: impl Future<Output = T>,
+    ) -> T {
+        let held = pool.acquire().await.unwrap();
+        let release = async move {
+            tokio::time::sleep(duration).await;
+            drop(held);
+        };
+
+        tokio::join!(release, waiting).1
+    }
+
+    #[tokio::test]
+    async fn given_busy_pool_when_acquire_then_warn_saturation() {
+        let warnings = Warnings::default();
+        let _subscriber = tracing::subscriber::set_default(tracing_subscriber::registry().with(warnings.clone()));
+        let pool = compose_pool().await;
+
+        hold_while(&pool, SATURATION_WAIT * 2, acquire_from(&pool, "get_products"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            warnings.recorded(),
+            vec![(
+                String::from("connection pool saturated, waited for a free connection"),
+                String::from("get_products")
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn given_connection_held_past_pool_threshold_when_released_then_warn_slow_hold() {
+        let warnings = Warnings::default();
+        let _subscriber = tracing::subscriber::set_default(tracing_subscriber::registry().with(warnings.clone()));
+        let executor = Executor::from(compose_pool().await);
+
+        let connection = executor.acquire("save_product").await.unwrap();
+        tokio::time::sleep(SLOW_QUERY_THRESHOLD + Duration::from_millis(50)).await;
+        drop(connection);
+
+        assert_eq!(
+            warnings.recorded(),
+            vec![(
+                String::from("database connection held past the slow query threshold"),
+                String::from("save_product")
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn given_slow_acquire_when_query_fast_then_warn_saturation_only() {
+        let warnings = Warnings::default();
+        let _subscriber = tracing::subscriber::set_default(tracing_subscriber::registry().with(warnings.clone()));
+        let pool = compose_pool().await;
+        let executor = Executor::from(pool.clone());
+
+        let wait = SLOW_QUERY_THRESHOLD + Duration::from_millis(50);
+        hold_while(&pool, wait, async {
+            let mut connection = executor.acquire("get_products").await.unwrap();
+            sqlx::query("SELECT 1").execute(&mut *connection).await.unwrap();
+        })
+        .await;
+
+        assert_eq!(
+            warnings.recorded(),
+            vec![(
+                String::from("connection pool saturated, waited for a free connection"),
+                String::from("get_products")
+            )]
+        );
+    }
+}
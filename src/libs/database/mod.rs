@@ -0,0 +1,11 @@
+pub use errors::*;
+pub use executor::*;
+pub use monitoring::*;
+pub use pool::*;
+pub use replica::*;
+
+mod errors;
+mod executor;
+mod monitoring;
+mod pool;
+mod replica;
@@ -0,0 +1,35 @@
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_SLOW_QUERY_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// Measures how long a repository call holds its connection, started once it was acquired so that
+/// waiting for the pool doesn't count, and warns on drop when that exceeds `threshold`.
+pub(super) struct HoldTimer {
+    operation: &'static str,
+    threshold: Duration,
+    started_at: Instant,
+}
+
+impl HoldTimer {
+    pub(super) fn start(operation: &'static str, threshold: Duration) -> Self {
+        Self {
+            operation,
+            threshold,
+            started_at: Instant::now(),
+        }
+    }
+}
+
+impl Drop for HoldTimer {
+    fn drop(&mut self) {
+        let elapsed = self.started_at.elapsed();
+
+        if elapsed > self.threshold {
+            tracing::warn!(
+                db.operation = self.operation,
+                db.duration_ms = elapsed.as_millis() as u64,
+                "database connection held past the slow query threshold"
+            );
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::ops::Deref;
+use std::time::Duration;
+
+use sqlx::{Database, Pool};
+
+/// Connection pool along with how long a repository call may hold one of its connections before it
+/// gets reported as slow.
+pub struct ConnectionPool<DB: Database> {
+    pool: Pool<DB>,
+    slow_query_threshold: Duration,
+}
+
+impl<DB: Database> ConnectionPool<DB> {
+    pub fn new(pool: Pool<DB>, slow_query_threshold: Duration) -> Self {
+        Self {
+            pool,
+            slow_query_threshold,
+        }
+    }
+
+    pub fn slow_query_threshold(&self) -> Duration {
+        self.slow_query_threshold
+    }
+}
+
+impl<DB: Database> Clone for ConnectionPool<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            slow_query_threshold: self.slow_query_threshold,
+        }
+    }
+}
+
+impl<DB: Database> Deref for ConnectionPool<DB> {
+    type Target = Pool<DB>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pool
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+
+use sqlx::{Connection, Database};
+
+use crate::libs;
+
+tokio::task_local! {
+    static CALLER: Option<String>;
+}
+
+/// Runs `future` on behalf of `caller`, so the writes it makes are read back from the primary.
+pub async fn with_caller<F: Future>(caller: Option<String>, future: F) -> F::Output {
+    CALLER.scope(caller, future).await
+}
+
+pub fn current_caller() -> Option<String> {
+    CALLER.try_with(Clone::clone).ok().flatten()
+}
+
+/// Sends reads to a replica and writes to the primary.
+///
+/// Reads fall back to the primary while the replica is unhealthy, and for a caller that wrote
+/// less than `read_your_writes_window` ago, since the replica may not have caught up yet.
+pub struct ReplicaRouter<DB: Database> {
+    primary: libs::database::ConnectionPool<DB>,
+    replica: libs::database::ConnectionPool<DB>,
+    replica_healthy: AtomicBool,
+    read_your_writes_window: Duration,
+    last_writes: Mutex<HashMap<String, Instant>>,
+}
+
+impl<DB: Database> ReplicaRouter<DB> {
+    pub fn new(
+        primary: libs::database::ConnectionPool<DB>,
+        replica: libs::database::ConnectionPool<DB>,
+        read_your_writes_window: Duration,
+    ) -> Self {
+        Self {
+            primary,
+            replica,
+            replica_healthy: AtomicBool::new(true),
+            read_your_writes_window,
+            last_writes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn primary(&self) -> &libs::database::ConnectionPool<DB> {
+        &self.primary
+    }
+
+    pub fn is_replica_healthy(&self) -> bool {
+        self.replica_healthy.load(Ordering::Relaxed)
+    }
+
+    /// Pins the reads of the current caller to the primary for the read-your-writes window.
+    pub fn record_write(&self) {
+        let Some(caller) = current_caller() else {
+            return;
+        };
+
+        let now = Instant::now();
+        let mut last_writes = self.last_writes.lock().unwrap();
+
+        last_writes.retain(|_, written_at| now.duration_since(*written_at) < self.read_your_writes_window);
+        last_writes.insert(caller, now);
+    }
+
+    fn wrote_recently(&self, caller: &str) -> bool {
+        self.last_writes
+            .lock()
+            .unwrap()
+            .get(caller)
+            .is_some_and(|written_at| written_at.elapsed() < self.read_your_writes_window)
+    }
+
+    pub async fn acquire_for_read(
+        &self,
+        operation: &'static str,
+    ) -> Result<libs::database::Connection<'static, DB>, sqlx::Error> {
+        if !self.is_replica_healthy() {
+            return libs::database::acquire_from(&self.primary, operation).await;
+        }
+
+        if current_caller().is_some_and(|caller| self.wrote_recently(&caller)) {
+            tracing::debug!("reading from primary within the read-your-writes window");
+            return libs::database::acquire_from(&self.primary, operation).await;
+        }
+
+        match libs::database::acquire_from(&self.replica, operation).await {
+            Ok(connection) => Ok(connection),
+            Err(err) => {
+                tracing::warn!("replica unavailable, failing over to primary: {err}");
+                self.replica_healthy.store(false, Ordering::Relaxed);
+                libs::database::acquire_from(&self.primary, operation).await
+            }
+        }
+    }
+
+    /// Pings the replica and records whether reads may go back to it.
+    pub async fn check_replica(&self) -> bool {
+        let healthy = match self.replica.acquire().await {
+            Ok(mut connection) => connection.ping().await.is_ok(),
+            Err(_) => false,
+        };
+
+        if healthy != self.replica_healthy.swap(healthy, Ordering::Relaxed) {
+            tracing::warn!(healthy, "replica health changed");
+        }
+
+        healthy
+    }
+
+    /// Checks the replica every `interval` until the router is dropped.
+    pub fn spawn_health_check(self: &Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let router: Weak<Self> = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+
+            loop {
+                ticker.tick().await;
+
+                let Some(router) = router.upgrade() else {
+                    return;
+                };
+
+                router.check_replica().await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn compose_pool(name: &str) -> libs::sqlite::ConnectionPool {
+        let settings = libs::sqlite::PoolSettings {
+            max_connections: 1,
+            ..libs::sqlite::PoolSettings::default()
+        };
+        let pool = libs::sqlite::ConnectionManager::new_pool("sqlite::memory:", &settings)
+            .await
+            .unwrap();
+
+        sqlx::query("CREATE TABLE node (name TEXT NOT NULL)")
+            .execute(&*pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO node (name) VALUES (?)")
+            .bind(name)
+            .execute(&*pool)
+            .await
+            .unwrap();
+
+        pool
+    }
+
+    async fn compose_router(window: Duration) -> ReplicaRouter<sqlx::Sqlite> {
+        ReplicaRouter::new(compose_pool("primary").await, compose_pool("replica").await, window)
+    }
+
+    async fn read_node(router: &ReplicaRouter<sqlx::Sqlite>) -> String {
+        let mut connection = router.acquire_for_read("read_node").await.unwrap();
+
+        sqlx::query_scalar("SELECT name FROM node")
+            .fetch_one(&mut *connection)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn given_no_recent_write_when_read_then_use_replica() {
+        let router = compose_router(Duration::from_secs(60)).await;
+
+        assert_eq!(
+            with_caller(Some(String::from("alice")), read_node(&router)).await,
+            "replica"
+        );
+    }
+
+    #[tokio::test]
+    async fn given_recent_write_when_read_then_use_primary_only_for_same_caller() {
+        let router = compose_router(Duration::from_secs(60)).await;
+
+        with_caller(Some(String::from("alice")), async { router.record_write() }).await;
+
+        assert_eq!(
+            with_caller(Some(String::from("alice")), read_node(&router)).await,
+            "primary"
+        );
+        assert_eq!(
+            with_caller(Some(String::from("bob")), read_node(&router)).await,
+            "replica"
+        );
+        assert_eq!(with_caller(None, read_node(&router)).await, "replica");
+    }
+
+    #[tokio::test]
+    async fn given_expired_window_when_read_then_use_replica() {
+        let router = compose_router(Duration::from_millis(10)).await;
+
+        with_caller(Some(String::from("alice")), async { router.record_write() }).await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        assert_eq!(
+            with_caller(Some(String::from("alice")), read_node(&router)).await,
+            "replica"
+        );
+    }
+
+    #[tokio::test]
+    async fn given_replica_down_when_read_then_fail_over_to_primary() {
+        let primary = compose_pool("primary").await;
+        let replica = compose_pool("replica").await;
+        let router = ReplicaRouter::new(primary, replica.clone(), Duration::from_secs(60));
+
+        replica.close().await;
+
+        assert_eq!(read_node(&router).await, "primary");
+        assert!(!router.is_replica_healthy());
+        assert!(!router.check_replica().await);
+        assert_eq!(read_node(&router).await, "primary");
+    }
+}
@@ -1,4 +1,6 @@
+pub mod database;
 pub mod encoding;
 pub mod postgres;
 pub mod problem_details;
 pub mod random;
+pub mod sqlite;
@@ -1,14 +1,253 @@
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 // https://www.postgresql.org/docs/current/errcodes-appendix.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Class {
+    SuccessfulCompletion,
+    Warning,
+    NoData,
+    SqlStatementNotYetComplete,
+    ConnectionException,
+    TriggeredActionException,
+    FeatureNotSupported,
+    InvalidTransactionInitiation,
+    LocatorException,
+    InvalidGrantor,
+    InvalidRoleSpecification,
+    DiagnosticsException,
+    CaseNotFound,
+    CardinalityViolation,
+    DataException,
+    IntegrityConstraintViolation,
+    InvalidCursorState,
+    InvalidTransactionState,
+    InvalidSqlStatementName,
+    TriggeredDataChangeViolation,
+    InvalidAuthorizationSpecification,
+    DependentPrivilegeDescriptorsStillExist,
+    InvalidTransactionTermination,
+    SqlRoutineException,
+    InvalidCursorName,
+    ExternalRoutineException,
+    ExternalRoutineInvocationException,
+    SavepointException,
+    InvalidCatalogName,
+    InvalidSchemaName,
+    TransactionRollback,
+    SyntaxErrorOrAccessRuleViolation,
+    WithCheckOptionViolation,
+    InsufficientResources,
+    ProgramLimitExceeded,
+    ObjectNotInPrerequisiteState,
+    OperatorIntervention,
+    SystemError,
+    SnapshotFailure,
+    ConfigurationFileError,
+    ForeignDataWrapperError,
+    PlpgsqlError,
+    InternalError,
+    Unknown,
+}
+
+impl Class {
+    pub fn from_code(code: &str) -> Self {
+        match code.get(0..2).unwrap_or_default() {
+            "00" => Self::SuccessfulCompletion,
+            "01" => Self::Warning,
+            "02" => Self::NoData,
+            "03" => Self::SqlStatementNotYetComplete,
+            "08" => Self::ConnectionException,
+            "09" => Self::TriggeredActionException,
+            "0A" => Self::FeatureNotSupported,
+            "0B" => Self::InvalidTransactionInitiation,
+            "0F" => Self::LocatorException,
+            "0L" => Self::InvalidGrantor,
+            "0P" => Self::InvalidRoleSpecification,
+            "0Z" => Self::DiagnosticsException,
+            "20" => Self::CaseNotFound,
+            "21" => Self::CardinalityViolation,
+            "22" => Self::DataException,
+            "23" => Self::IntegrityConstraintViolation,
+            "24" => Self::InvalidCursorState,
+            "25" => Self::InvalidTransactionState,
+            "26" => Self::InvalidSqlStatementName,
+            "27" => Self::TriggeredDataChangeViolation,
+            "28" => Self::InvalidAuthorizationSpecification,
+            "2B" => Self::DependentPrivilegeDescriptorsStillExist,
+            "2D" => Self::InvalidTransactionTermination,
+            "2F" => Self::SqlRoutineException,
+            "34" => Self::InvalidCursorName,
+            "38" => Self::ExternalRoutineException,
+            "39" => Self::ExternalRoutineInvocationException,
+            "3B" => Self::SavepointException,
+            "3D" => Self::InvalidCatalogName,
+            "3F" => Self::InvalidSchemaName,
+            "40" => Self::TransactionRollback,
+            "42" => Self::SyntaxErrorOrAccessRuleViolation,
+            "44" => Self::WithCheckOptionViolation,
+            "53" => Self::InsufficientResources,
+            "54" => Self::ProgramLimitExceeded,
+            "55" => Self::ObjectNotInPrerequisiteState,
+            "57" => Self::OperatorIntervention,
+            "58" => Self::SystemError,
+            "72" => Self::SnapshotFailure,
+            "F0" => Self::ConfigurationFileError,
+            "HV" => Self::ForeignDataWrapperError,
+            "P0" => Self::PlpgsqlError,
+            "XX" => Self::InternalError,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Codes {
     UniqueViolation,
+    ForeignKeyViolation,
+    CheckViolation,
+    NotNullViolation,
+    RestrictViolation,
+    ExclusionViolation,
+    StringDataRightTruncation,
+    NumericValueOutOfRange,
+    InvalidTextRepresentation,
+    InvalidDatetimeFormat,
+    SerializationFailure,
+    DeadlockDetected,
+    LockNotAvailable,
+    QueryCanceled,
+    AdminShutdown,
+    CrashShutdown,
+    CannotConnectNow,
+    TooManyConnections,
+    ConnectionException,
+    ConnectionDoesNotExist,
+    ConnectionFailure,
+    UnableToEstablishConnection,
+    RejectedEstablishmentOfConnection,
+    Other(String),
+}
+
+impl Codes {
+    pub fn class(&self) -> Class {
+        Class::from_code(&self.to_string())
+    }
+
+    /// Whether running the same statement again may succeed without any change from the caller.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::SerializationFailure
+            | Self::DeadlockDetected
+            | Self::LockNotAvailable
+            | Self::AdminShutdown
+            | Self::CrashShutdown
+            | Self::CannotConnectNow
+            | Self::TooManyConnections => true,
+            _ => self.class() == Class::ConnectionException,
+        }
+    }
 }
 
 impl Display for Codes {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::UniqueViolation => write!(f, "23505"),
+            Self::ForeignKeyViolation => write!(f, "23503"),
+            Self::CheckViolation => write!(f, "23514"),
+            Self::NotNullViolation => write!(f, "23502"),
+            Self::RestrictViolation => write!(f, "23001"),
+            Self::ExclusionViolation => write!(f, "23P01"),
+            Self::StringDataRightTruncation => write!(f, "22001"),
+            Self::NumericValueOutOfRange => write!(f, "22003"),
+            Self::InvalidTextRepresentation => write!(f, "22P02"),
+            Self::InvalidDatetimeFormat => write!(f, "22007"),
+            Self::SerializationFailure => write!(f, "40001"),
+            Self::DeadlockDetected => write!(f, "40P01"),
+            Self::LockNotAvailable => write!(f, "55P03"),
+            Self::QueryCanceled => write!(f, "57014"),
+            Self::AdminShutdown => write!(f, "57P01"),
+            Self::CrashShutdown => write!(f, "57P02"),
+            Self::CannotConnectNow => write!(f, "57P03"),
+            Self::TooManyConnections => write!(f, "53300"),
+            Self::ConnectionException => write!(f, "08000"),
+            Self::ConnectionDoesNotExist => write!(f, "08003"),
+            Self::ConnectionFailure => write!(f, "08006"),
+            Self::UnableToEstablishConnection => write!(f, "08001"),
+            Self::RejectedEstablishmentOfConnection => write!(f, "08004"),
+            Self::Other(code) => write!(f, "{code}"),
+        }
+    }
+}
+
+impl FromStr for Codes {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "23505" => Self::UniqueViolation,
+            "23503" => Self::ForeignKeyViolation,
+            "23514" => Self::CheckViolation,
+            "23502" => Self::NotNullViolation,
+            "23001" => Self::RestrictViolation,
+            "23P01" => Self::ExclusionViolation,
+            "22001" => Self::StringDataRightTruncation,
+            "22003" => Self::NumericValueOutOfRange,
+            "22P02" => Self::InvalidTextRepresentation,
+            "22007" => Self::InvalidDatetimeFormat,
+            "40001" => Self::SerializationFailure,
+            "40P01" => Self::DeadlockDetected,
+            "55P03" => Self::LockNotAvailable,
+            "57014" => Self::QueryCanceled,
+            "57P01" => Self::AdminShutdown,
+            "57P02" => Self::CrashShutdown,
+            "57P03" => Self::CannotConnectNow,
+            "53300" => Self::TooManyConnections,
+            "08000" => Self::ConnectionException,
+            "08003" => Self::ConnectionDoesNotExist,
+            "08006" => Self::ConnectionFailure,
+            "08001" => Self::UnableToEstablishConnection,
+            "08004" => Self::RejectedEstablishmentOfConnection,
+            other => Self::Other(other.to_string()),
+        })
+    }
+}
+
+impl From<&dyn sqlx::error::DatabaseError> for Codes {
+    fn from(error: &dyn sqlx::error::DatabaseError) -> Self {
+        error
+            .code()
+            .and_then(|code| code.parse().ok())
+            .unwrap_or_else(|| Self::Other(String::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_known_code_when_parse_then_round_trip() {
+        for code in ["23505", "23503", "23514", "23502", "40001", "40P01", "57014", "08006"] {
+            assert_eq!(code.parse::<Codes>().unwrap().to_string(), code);
         }
     }
+
+    #[test]
+    fn given_unknown_code_when_parse_then_keep_code_and_class() {
+        let code = "42P01".parse::<Codes>().unwrap();
+
+        assert_eq!(code, Codes::Other(String::from("42P01")));
+        assert_eq!(code.class(), Class::SyntaxErrorOrAccessRuleViolation);
+        assert!(!code.is_retryable());
+    }
+
+    #[test]
+    fn given_transient_codes_when_check_retryable_then_return_true() {
+        assert!(Codes::SerializationFailure.is_retryable());
+        assert!(Codes::DeadlockDetected.is_retryable());
+        assert!("08P01".parse::<Codes>().unwrap().is_retryable());
+        assert!(!Codes::UniqueViolation.is_retryable());
+        assert!(!Codes::QueryCanceled.is_retryable());
+    }
 }
@@ -1,23 +1,94 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::libs;
+
 pub mod errcodes;
+pub mod retry;
+
+pub type ConnectionPool = libs::database::ConnectionPool<sqlx::Postgres>;
+pub type Executor = libs::database::Executor<sqlx::Postgres>;
+pub type SharedTransaction = libs::database::SharedTransaction<sqlx::Postgres>;
+pub type ReplicaRouter = libs::database::ReplicaRouter<sqlx::Postgres>;
+
+/// Sizing and per-session defaults of a connection pool.
+#[derive(Clone, Debug)]
+pub struct PoolSettings {
+    pub min_connections: u32,
+    pub max_connections: u32,
+    pub idle_timeout: Option<Duration>,
+    pub max_lifetime: Option<Duration>,
+    pub acquire_timeout: Duration,
+    /// Server side limit for every statement of a session, `None` lets queries run unbounded.
+    pub statement_timeout: Option<Duration>,
+    pub application_name: String,
+    /// How long a repository call may hold a connection before it gets reported as slow.
+    pub slow_query_threshold: Duration,
+}
+
+impl Default for PoolSettings {
+    fn default() -> Self {
+        Self {
+            min_connections: 0,
+            max_connections: 12,
+            idle_timeout: Some(Duration::from_secs(10 * 60)),
+            max_lifetime: Some(Duration::from_secs(30 * 60)),
+            acquire_timeout: Duration::from_secs(3),
+            statement_timeout: Some(Duration::from_secs(30)),
+            application_name: String::from(env!("CARGO_PKG_NAME")),
+            slow_query_threshold: libs::database::DEFAULT_SLOW_QUERY_THRESHOLD,
+        }
+    }
+}
+
+impl PoolSettings {
+    fn pool_options(&self) -> sqlx::postgres::PgPoolOptions {
+        sqlx::postgres::PgPoolOptions::new()
+            .min_connections(self.min_connections)
+            .max_connections(self.max_connections)
+            .idle_timeout(self.idle_timeout)
+            .max_lifetime(self.max_lifetime)
+            .acquire_timeout(self.acquire_timeout)
+    }
 
-pub type ConnectionPool = sqlx::PgPool;
+    fn connect_options(&self, url: &str) -> Result<sqlx::postgres::PgConnectOptions, sqlx::error::Error> {
+        let mut options = sqlx::postgres::PgConnectOptions::from_str(url)?.application_name(&self.application_name);
+
+        if let Some(statement_timeout) = self.statement_timeout {
+            options = options.options([("statement_timeout", statement_timeout.as_millis().to_string())]);
+        }
+
+        Ok(options)
+    }
+}
 
 pub struct ConnectionManager;
 
 impl ConnectionManager {
     pub async fn new_pool(
         url: impl Into<String>,
-        max_connections: Option<u32>,
+        settings: &PoolSettings,
     ) -> Result<ConnectionPool, sqlx::error::Error> {
-        let pool = sqlx::postgres::PgPoolOptions::new()
-            .max_connections(max_connections.unwrap_or(12))
-            .acquire_timeout(std::time::Duration::from_secs(3))
-            .connect(url.into().as_str())
+        let pool = settings
+            .pool_options()
+            .connect_with(settings.connect_options(url.into().as_str())?)
             .await?;
 
         tracing::debug!("Initialized postgres connection");
 
-        Ok(pool)
+        Ok(ConnectionPool::new(pool, settings.slow_query_threshold))
+    }
+
+    /// Pool that connects on first use, so a server that is down at startup does not abort it.
+    pub fn new_lazy_pool(
+        url: impl Into<String>,
+        settings: &PoolSettings,
+    ) -> Result<ConnectionPool, sqlx::error::Error> {
+        let pool = settings
+            .pool_options()
+            .connect_lazy_with(settings.connect_options(url.into().as_str())?);
+
+        Ok(ConnectionPool::new(pool, settings.slow_query_threshold))
     }
 }
 
@@ -69,14 +140,17 @@ pub mod fixture {
 
             let template_database = std::env::var("DATABASE_TEMPLATE").unwrap();
 
-            let pool = ConnectionManager::new_pool(PostgresDatabaseFixtureConfiguration::default().build_uri(), None)
-                .await
-                .expect("error creating postgres fixture pool");
+            let pool = ConnectionManager::new_pool(
+                PostgresDatabaseFixtureConfiguration::default().build_uri(),
+                &PoolSettings::default(),
+            )
+            .await
+            .expect("error creating postgres fixture pool");
 
             sqlx::query(&format!(
                 "CREATE DATABASE {generated_database_name} TEMPLATE '{template_database}'"
             ))
-            .execute(&pool)
+            .execute(&*pool)
             .await
             .expect("error trying to terminate template database connections");
 
@@ -89,7 +163,10 @@ pub mod fixture {
                 .await
                 .expect("error creating postgres fixture pool");
 
-            Self { pool, configuration }
+            Self {
+                pool: ConnectionPool::new(pool, libs::database::DEFAULT_SLOW_QUERY_THRESHOLD),
+                configuration,
+            }
         }
 
         #[allow(dead_code)]
@@ -97,7 +174,7 @@ pub mod fixture {
             sqlx::query(&format!(
                 "SELECT pg_terminate_backend(pid) FROM pg_stat_activity WHERE datname = '{template_database}'"
             ))
-            .execute(pool)
+            .execute(&**pool)
             .await
             .expect("error trying to terminate template database connections");
         }
@@ -120,7 +197,7 @@ pub mod fixture {
         pub async fn dispose(&self) {
             let configuration = PostgresDatabaseFixtureConfiguration::default();
 
-            let pool = ConnectionManager::new_pool(&configuration.build_uri(), None)
+            let pool = ConnectionManager::new_pool(&configuration.build_uri(), &PoolSettings::default())
                 .await
                 .expect("error creating postgres fixture pool");
 
@@ -128,14 +205,60 @@ pub mod fixture {
                 "SELECT pg_terminate_backend(pid) FROM pg_stat_activity WHERE datname = '{}'",
                 self.configuration.name
             ))
-            .execute(&pool)
+            .execute(&*pool)
             .await
             .expect("error trying to terminate test database connections");
 
             sqlx::query(&format!("DROP DATABASE IF EXISTS {}", self.configuration.name))
-                .execute(&pool)
+                .execute(&*pool)
                 .await
                 .expect("error trying to drop test database");
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn compose_pool(settings: PoolSettings) -> (fixture::PostgresDatabaseFixture, ConnectionPool) {
+        let database = fixture::PostgresDatabaseFixture::new().await;
+        let pool = ConnectionManager::new_pool(database.configuration.build_uri(), &settings)
+            .await
+            .unwrap();
+
+        (database, pool)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_statement_timeout_when_query_runs_longer_then_cancel_it() {
+        let settings = PoolSettings {
+            statement_timeout: Some(Duration::from_millis(50)),
+            ..PoolSettings::default()
+        };
+        let (_database, pool) = compose_pool(settings).await;
+
+        let error = sqlx::query("SELECT pg_sleep(1)").execute(&*pool).await.unwrap_err();
+
+        assert_eq!(
+            error.as_database_error().map(errcodes::Codes::from),
+            Some(errcodes::Codes::QueryCanceled)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_application_name_when_connect_then_report_it_to_server() {
+        let settings = PoolSettings {
+            application_name: String::from("backoffice-test"),
+            ..PoolSettings::default()
+        };
+        let (_database, pool) = compose_pool(settings).await;
+
+        let application_name: String = sqlx::query_scalar("SELECT current_setting('application_name')")
+            .fetch_one(&*pool)
+            .await
+            .unwrap();
+
+        assert_eq!(application_name, "backoffice-test");
+    }
+}
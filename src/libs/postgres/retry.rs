@@ -0,0 +1,247 @@
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use rand::Rng;
+use tracing::Instrument;
+
+use crate::libs;
+
+use super::errcodes::Codes;
+
+/// Whether repeating an operation has the same effect as running it once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Idempotency {
+    Idempotent,
+    NonIdempotent,
+}
+
+/// Whether the failure is transient, so running the same statement again may succeed.
+pub fn is_retryable(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Database(error) => Codes::from(error.as_ref()).is_retryable(),
+        _ => libs::database::is_connection_failure(error),
+    }
+}
+
+/// Repeats idempotent database operations failing with a transient error, waiting a jittered
+/// exponential backoff between attempts.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+            max_delay: max_delay.max(base_delay),
+        }
+    }
+
+    /// Statements inside a transaction can't be repeated on their own, the whole unit of work has to.
+    pub fn disabled() -> Self {
+        Self::new(1, Duration::ZERO, Duration::ZERO)
+    }
+
+    pub async fn run<T, F, Fut>(
+        &self,
+        operation: &'static str,
+        idempotency: Idempotency,
+        mut f: F,
+    ) -> Result<T, sqlx::Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, sqlx::Error>>,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let query = f().instrument(tracing::debug_span!("Database query", db.operation = operation));
+
+            let error = match query.await {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            if attempt >= self.max_attempts || idempotency == Idempotency::NonIdempotent || !is_retryable(&error) {
+                return Err(error);
+            }
+
+            let delay = self.delay(attempt);
+            let code = error
+                .as_database_error()
+                .map(|error| Codes::from(error).to_string())
+                .unwrap_or_default();
+
+            tracing::warn!(
+                db.operation = operation,
+                db.retry.attempt = attempt,
+                db.retry.delay_ms = delay.as_millis() as u64,
+                db.sqlstate = code,
+                "retrying database operation: {error}"
+            );
+
+            retries().add(
+                &opentelemetry::Context::current(),
+                1,
+                &[
+                    opentelemetry::KeyValue::new("db.operation", operation),
+                    opentelemetry::KeyValue::new("db.sqlstate", code),
+                ],
+            );
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    // full jitter: a random delay up to the exponential backoff, so concurrent callers spread out
+    fn delay(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_delay);
+
+        Duration::from_millis(rand::thread_rng().gen_range(0..=backoff.as_millis() as u64))
+    }
+}
+
+/// Counter of repeated operations, created once on the first retry, after telemetry got set up.
+fn retries() -> &'static opentelemetry::metrics::Counter<u64> {
+    static RETRIES: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
+
+    RETRIES.get_or_init(|| {
+        opentelemetry::global::meter("postgres")
+            .u64_counter("db.client.retries")
+            .with_description("Database operations repeated after a transient failure")
+            .init()
+    })
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(50), Duration::from_secs(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::libs;
+
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(2))
+    }
+
+    #[tokio::test]
+    async fn given_transient_error_when_run_idempotent_then_retry_until_success() {
+        let calls = AtomicU32::new(0);
+
+        let result = policy()
+            .run("test", Idempotency::Idempotent, || async {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(sqlx::Error::PoolTimedOut),
+                    _ => Ok(()),
+                }
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn given_persistent_transient_error_when_run_then_stop_after_max_attempts() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), _> = policy()
+            .run("test", Idempotency::Idempotent, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(sqlx::Error::PoolTimedOut)
+            })
+            .await;
+
+        assert!(matches!(result, Err(sqlx::Error::PoolTimedOut)));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn given_non_idempotent_operation_when_run_then_never_retry() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), _> = policy()
+            .run("test", Idempotency::NonIdempotent, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(sqlx::Error::PoolTimedOut)
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn given_permanent_error_when_run_then_never_retry() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), _> = policy()
+            .run("test", Idempotency::Idempotent, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(sqlx::Error::RowNotFound)
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn given_closed_pool_when_run_then_never_retry() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), _> = policy()
+            .run("test", Idempotency::Idempotent, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(sqlx::Error::PoolClosed)
+            })
+            .await;
+
+        assert!(matches!(result, Err(sqlx::Error::PoolClosed)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn given_serialization_failure_when_run_then_retry() {
+        let database = libs::postgres::fixture::PostgresDatabaseFixture::new().await;
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), _> = policy()
+            .run("test", Idempotency::Idempotent, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                sqlx::query("DO $$ BEGIN RAISE EXCEPTION 'conflict' USING ERRCODE = '40001'; END $$")
+                    .execute(&*database.pool)
+                    .await
+                    .map(|_| ())
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn given_attempts_when_delay_then_never_exceed_max_delay() {
+        let policy = RetryPolicy::new(10, Duration::from_millis(100), Duration::from_millis(300));
+
+        for attempt in 1..10 {
+            assert!(policy.delay(attempt) <= Duration::from_millis(300));
+        }
+    }
+}
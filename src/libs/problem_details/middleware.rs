@@ -0,0 +1,147 @@
+use axum::http::{header, Uri};
+use axum::response::{IntoResponse, Response};
+
+use super::*;
+
+/// Rewrites error responses produced outside the handlers (method mismatch, timeouts, body limits,
+/// plain-text rejections) as Problem Details, keeping their status and headers.
+pub async fn normalize(response: Response) -> Response {
+    let status = response.status();
+
+    if !(status.is_client_error() || status.is_server_error()) {
+        return response;
+    }
+
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value.starts_with(PROBLEM_DETAILS_CONTENT_TYPE) || value.starts_with(mime::APPLICATION_JSON.as_ref())
+        })
+        .unwrap_or(false);
+
+    if is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+
+    let mut problem_details = ProblemDetails::from_status(status);
+    if let Ok(body) = hyper::body::to_bytes(body).await {
+        if let Ok(detail) = std::str::from_utf8(&body) {
+            if !detail.trim().is_empty() {
+                problem_details.set_detail(detail.trim());
+            }
+        }
+    }
+
+    tracing::debug!("normalized {status} response as problem details");
+
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.remove(header::CONTENT_TYPE);
+
+    let mut response = problem_details.into_response();
+    for (name, value) in parts.headers.iter() {
+        response.headers_mut().entry(name).or_insert(value.clone());
+    }
+
+    response
+}
+
+pub async fn fallback(uri: Uri) -> ProblemDetails {
+    let mut problem_details = ProblemDetails::from_404();
+    problem_details
+        .set_detail(format!("No route for {}", uri.path()))
+        .set_instance(uri.path());
+    problem_details
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::body::Body;
+    use axum::http::{HeaderMap, Request, StatusCode};
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn router() -> Router {
+        Router::new()
+            .route("/ok", get(|| async { "ok" }))
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    "slow"
+                }),
+            )
+            .route(
+                "/text",
+                get(|| async { (StatusCode::BAD_REQUEST, "plain text rejection") }),
+            )
+            .layer(tower_http::timeout::TimeoutLayer::new(Duration::from_millis(10)))
+            .fallback(fallback)
+            .layer(middleware::map_response(normalize))
+    }
+
+    async fn send(method: &str, uri: &str) -> (StatusCode, HeaderMap, Value) {
+        let response = router()
+            .oneshot(Request::builder().method(method).uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, headers, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn given_unknown_route_when_request_then_return_404_problem_details() {
+        let (status, headers, body) = send("GET", "/unknown").await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(headers[header::CONTENT_TYPE], PROBLEM_DETAILS_CONTENT_TYPE);
+        assert_eq!(body["instance"], "/unknown");
+    }
+
+    #[tokio::test]
+    async fn given_wrong_method_when_request_then_return_405_problem_details_with_allow_header() {
+        let (status, headers, body) = send("DELETE", "/ok").await;
+
+        assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(headers[header::CONTENT_TYPE], PROBLEM_DETAILS_CONTENT_TYPE);
+        assert!(headers.contains_key(header::ALLOW));
+        assert_eq!(body["status"], 405);
+    }
+
+    #[tokio::test]
+    async fn given_slow_handler_when_request_then_return_408_problem_details() {
+        let (status, _, body) = send("GET", "/slow").await;
+
+        assert_eq!(status, StatusCode::REQUEST_TIMEOUT);
+        assert_eq!(body["title"], "Request Timeout");
+    }
+
+    #[tokio::test]
+    async fn given_plain_text_error_when_request_then_keep_text_as_detail() {
+        let (status, _, body) = send("GET", "/text").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["detail"], "plain text rejection");
+    }
+
+    #[tokio::test]
+    async fn given_successful_response_when_request_then_leave_untouched() {
+        let (status, _, body) = send("GET", "/ok").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, Value::Null);
+    }
+}
@@ -1,12 +1,37 @@
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub use middleware::*;
+
+mod middleware;
+
+pub const PROBLEM_DETAILS_CONTENT_TYPE: &str = "application/problem+json";
+
+/// RFC 9457 Problem Details object.
+#[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct ProblemDetails {
     #[serde(rename = "type")]
     pub ttype: String,
     pub title: String,
     pub detail: String,
     pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ProblemDetailsError>,
+    /// Extension members, serialized next to the standard ones.
+    #[serde(flatten)]
+    pub extensions: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProblemDetailsError {
+    /// JSON Pointer (RFC 6901) to the offending member of the request body.
+    pub pointer: String,
+    pub code: String,
+    pub message: String,
 }
 
 impl ProblemDetails {
@@ -46,6 +71,126 @@ impl ProblemDetails {
         problem_details
     }
 
+    pub fn from_404() -> Self {
+        let msg = "Not Found";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-404-not-found")
+            .set_status(404)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_405() -> Self {
+        let msg = "Method Not Allowed";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-405-method-not-allowed")
+            .set_status(405)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_408() -> Self {
+        let msg = "Request Timeout";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-408-request-timeout")
+            .set_status(408)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_409() -> Self {
+        let msg = "Conflict";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-409-conflict")
+            .set_status(409)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_412() -> Self {
+        let msg = "Precondition Failed";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-412-precondition-failed")
+            .set_status(412)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_413() -> Self {
+        let msg = "Content Too Large";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-413-content-too-large")
+            .set_status(413)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_415() -> Self {
+        let msg = "Unsupported Media Type";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-415-unsupported-media-type")
+            .set_status(415)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_422() -> Self {
+        let msg = "Unprocessable Content";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-422-unprocessable-content")
+            .set_status(422)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_429() -> Self {
+        let msg = "Too Many Requests";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc6585.html#section-4")
+            .set_status(429)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_500() -> Self {
+        let msg = "Internal Server Error";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-500-internal-server-error")
+            .set_status(500)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
     pub fn from_503() -> Self {
         let msg = "Service Unavailable";
 
@@ -58,6 +203,49 @@ impl ProblemDetails {
         problem_details
     }
 
+    pub fn from_504() -> Self {
+        let msg = "Gateway Timeout";
+
+        let mut problem_details = Self::default();
+        problem_details
+            .set_type("https://www.rfc-editor.org/rfc/rfc9110.html#name-504-gateway-timeout")
+            .set_status(504)
+            .set_title(msg)
+            .set_detail(msg);
+        problem_details
+    }
+
+    pub fn from_status(status: StatusCode) -> Self {
+        match status.as_u16() {
+            400 => Self::from_400(),
+            401 => Self::from_401(),
+            403 => Self::from_403(),
+            404 => Self::from_404(),
+            405 => Self::from_405(),
+            408 => Self::from_408(),
+            409 => Self::from_409(),
+            412 => Self::from_412(),
+            413 => Self::from_413(),
+            415 => Self::from_415(),
+            422 => Self::from_422(),
+            429 => Self::from_429(),
+            500 => Self::from_500(),
+            503 => Self::from_503(),
+            504 => Self::from_504(),
+            code => {
+                let msg = status.canonical_reason().unwrap_or("Unknown Error");
+
+                let mut problem_details = Self::default();
+                problem_details
+                    .set_type("about:blank")
+                    .set_status(code)
+                    .set_title(msg)
+                    .set_detail(msg);
+                problem_details
+            }
+        }
+    }
+
     pub fn set_type(&mut self, value: impl ToString) -> &mut Self {
         self.ttype = value.to_string();
         self
@@ -77,4 +265,93 @@ impl ProblemDetails {
         self.status = value;
         self
     }
+
+    pub fn set_instance(&mut self, value: impl ToString) -> &mut Self {
+        self.instance = Some(value.to_string());
+        self
+    }
+
+    pub fn set_extension(&mut self, key: impl ToString, value: impl Serialize) -> &mut Self {
+        let value = serde_json::to_value(value).unwrap_or(serde_json::Value::Null);
+        self.extensions.insert(key.to_string(), value);
+        self
+    }
+
+    pub fn push_error(&mut self, pointer: impl ToString, code: impl ToString, message: impl ToString) -> &mut Self {
+        self.errors.push(ProblemDetailsError {
+            pointer: pointer.to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+        });
+        self
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let _e = tracing::debug_span!("Response as Problem Details").entered();
+
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body = serde_json::to_string(&self).unwrap_or("{}".to_string());
+        tracing::debug!("{body}");
+
+        (
+            status,
+            [(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(PROBLEM_DETAILS_CONTENT_TYPE),
+            )],
+            body,
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn given_extensions_and_errors_when_serialize_then_flatten_members() {
+        let mut problem_details = ProblemDetails::from_422();
+        problem_details
+            .set_instance("/ecommerce/backoffice/product")
+            .set_extension("correlation_id", "abc")
+            .push_error("/price", "INVALID_PRODUCT_PRICE", "invalid product price");
+
+        assert_eq!(
+            serde_json::to_value(&problem_details).unwrap(),
+            json!({
+                "type": "https://www.rfc-editor.org/rfc/rfc9110.html#name-422-unprocessable-content",
+                "title": "Unprocessable Content",
+                "detail": "Unprocessable Content",
+                "status": 422,
+                "instance": "/ecommerce/backoffice/product",
+                "errors": [{ "pointer": "/price", "code": "INVALID_PRODUCT_PRICE", "message": "invalid product price" }],
+                "correlation_id": "abc"
+            })
+        );
+    }
+
+    #[test]
+    fn given_minimal_problem_details_when_serialize_then_omit_optional_members() {
+        let value = serde_json::to_value(ProblemDetails::from_404()).unwrap();
+
+        assert!(value.get("instance").is_none());
+        assert!(value.get("errors").is_none());
+    }
+
+    #[tokio::test]
+    async fn given_problem_details_when_into_response_then_use_problem_json_content_type() {
+        let response = ProblemDetails::from_409().into_response();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            PROBLEM_DETAILS_CONTENT_TYPE
+        );
+    }
 }
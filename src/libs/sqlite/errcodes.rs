@@ -0,0 +1,118 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// https://www.sqlite.org/rescode.html, sqlx reports extended result codes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Codes {
+    Busy,
+    BusyRecovery,
+    BusySnapshot,
+    BusyTimeout,
+    Locked,
+    LockedSharedCache,
+    Interrupt,
+    TooBig,
+    Mismatch,
+    ConstraintCheck,
+    ConstraintForeignKey,
+    ConstraintNotNull,
+    ConstraintPrimaryKey,
+    ConstraintUnique,
+    Other(i32),
+}
+
+impl Codes {
+    fn to_primitive(&self) -> i32 {
+        match self {
+            Self::Busy => 5,
+            Self::BusyRecovery => 261,
+            Self::BusySnapshot => 517,
+            Self::BusyTimeout => 773,
+            Self::Locked => 6,
+            Self::LockedSharedCache => 262,
+            Self::Interrupt => 9,
+            Self::TooBig => 18,
+            Self::Mismatch => 20,
+            Self::ConstraintCheck => 275,
+            Self::ConstraintForeignKey => 787,
+            Self::ConstraintNotNull => 1299,
+            Self::ConstraintPrimaryKey => 1555,
+            Self::ConstraintUnique => 2067,
+            Self::Other(code) => *code,
+        }
+    }
+
+    /// Primary result code, the lower byte of an extended one.
+    pub fn primary(&self) -> i32 {
+        self.to_primitive() & 0xff
+    }
+
+    /// Whether running the same statement again may succeed without any change from the caller.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.primary(), 5 | 6)
+    }
+}
+
+impl Display for Codes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_primitive())
+    }
+}
+
+impl From<i32> for Codes {
+    fn from(code: i32) -> Self {
+        match code {
+            5 => Self::Busy,
+            261 => Self::BusyRecovery,
+            517 => Self::BusySnapshot,
+            773 => Self::BusyTimeout,
+            6 => Self::Locked,
+            262 => Self::LockedSharedCache,
+            9 => Self::Interrupt,
+            18 => Self::TooBig,
+            20 => Self::Mismatch,
+            275 => Self::ConstraintCheck,
+            787 => Self::ConstraintForeignKey,
+            1299 => Self::ConstraintNotNull,
+            1555 => Self::ConstraintPrimaryKey,
+            2067 => Self::ConstraintUnique,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl FromStr for Codes {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value.parse::<i32>()?))
+    }
+}
+
+impl From<&dyn sqlx::error::DatabaseError> for Codes {
+    fn from(error: &dyn sqlx::error::DatabaseError) -> Self {
+        error
+            .code()
+            .and_then(|code| code.parse().ok())
+            .unwrap_or(Self::Other(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_known_code_when_parse_then_round_trip() {
+        for code in ["5", "6", "275", "787", "1299", "1555", "2067"] {
+            assert_eq!(code.parse::<Codes>().unwrap().to_string(), code);
+        }
+    }
+
+    #[test]
+    fn given_extended_busy_code_when_check_retryable_then_return_true() {
+        assert!(Codes::from(773).is_retryable());
+        assert_eq!(Codes::from(3338).primary(), 10);
+        assert!(!Codes::ConstraintUnique.is_retryable());
+    }
+}
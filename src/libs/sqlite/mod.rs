@@ -0,0 +1,86 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::libs;
+
+pub mod errcodes;
+
+pub type ConnectionPool = libs::database::ConnectionPool<sqlx::Sqlite>;
+pub type Executor = libs::database::Executor<sqlx::Sqlite>;
+pub type SharedTransaction = libs::database::SharedTransaction<sqlx::Sqlite>;
+
+/// Sizing of a connection pool and when its connections count as held too long.
+#[derive(Clone, Debug)]
+pub struct PoolSettings {
+    pub max_connections: u32,
+    pub slow_query_threshold: Duration,
+}
+
+impl Default for PoolSettings {
+    fn default() -> Self {
+        Self {
+            max_connections: 4,
+            slow_query_threshold: libs::database::DEFAULT_SLOW_QUERY_THRESHOLD,
+        }
+    }
+}
+
+pub struct ConnectionManager;
+
+impl ConnectionManager {
+    pub async fn new_pool(
+        url: impl Into<String>,
+        settings: &PoolSettings,
+    ) -> Result<ConnectionPool, sqlx::error::Error> {
+        let options = sqlx::sqlite::SqliteConnectOptions::from_str(url.into().as_str())?
+            .create_if_missing(true)
+            .foreign_keys(true)
+            .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal);
+
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(settings.max_connections)
+            .acquire_timeout(std::time::Duration::from_secs(3))
+            .connect_with(options)
+            .await?;
+
+        tracing::debug!("Initialized sqlite connection");
+
+        Ok(ConnectionPool::new(pool, settings.slow_query_threshold))
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use super::*;
+
+    /// Keep the fixture alive for as long as the pool is used, the database files are deleted when it drops.
+    pub struct SqliteDatabaseFixture {
+        pub pool: ConnectionPool,
+        _directory: tempfile::TempDir,
+    }
+
+    impl SqliteDatabaseFixture {
+        /// Creates a throwaway database file with every migration applied.
+        pub async fn new(migrator: &sqlx::migrate::Migrator) -> Self {
+            let directory = tempfile::Builder::new()
+                .prefix("ecommerce-")
+                .tempdir()
+                .expect("error creating sqlite fixture directory");
+            let path = directory.path().join("ecommerce.db");
+
+            let pool = ConnectionManager::new_pool(format!("sqlite://{}", path.display()), &PoolSettings::default())
+                .await
+                .expect("error creating sqlite fixture pool");
+
+            migrator
+                .run(&*pool)
+                .await
+                .expect("error running sqlite fixture migrations");
+
+            Self {
+                pool,
+                _directory: directory,
+            }
+        }
+    }
+}
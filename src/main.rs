@@ -1,8 +1,7 @@
-#![feature(result_option_inspect)]
-
 mod app;
 mod contexts;
 mod libs;
+mod openapi;
 mod settings;
 mod telemetry;
 
@@ -22,8 +21,11 @@ async fn main() {
     let addr = std::net::SocketAddr::from(([0, 0, 0, 0], 8080));
     tracing::debug!("listening on {}", addr);
 
+    let app = app::App::http(settings).await;
+    app.ecommerce_jobs.spawn();
+
     axum::Server::bind(&addr)
-        .serve(app::App::http(settings).await.into_make_service())
+        .serve(app.router.into_make_service())
         .await
         .unwrap();
 }
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use axum::extract::Path;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use utoipa::openapi::security::{AuthorizationCode, Flow, HttpAuthScheme, HttpBuilder, OAuth2, Scopes, SecurityScheme};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::{oauth, Config};
+
+use crate::settings;
+
+const SWAGGER_UI_PATH: &str = "/swagger-ui/";
+
+#[derive(OpenApi)]
+#[openapi(info(title = "HTTP API"), paths(healthz))]
+pub struct ApiDoc;
+
+impl ApiDoc {
+    /// Merges the contexts documents and registers the `Identity` security scheme their operations refer to.
+    pub fn build(contexts: Vec<utoipa::openapi::OpenApi>, settings: &settings::Settings) -> utoipa::openapi::OpenApi {
+        let mut openapi = contexts
+            .into_iter()
+            .fold(Self::openapi(), |openapi, context| openapi.merge_from(context));
+
+        openapi
+            .components
+            .get_or_insert_with(Default::default)
+            .add_security_scheme("Identity", Self::identity(settings));
+
+        openapi
+    }
+
+    fn identity(settings: &settings::Settings) -> SecurityScheme {
+        match (&settings.oauth_authorization_url, &settings.oauth_token_url) {
+            (Some(authorization_url), Some(token_url)) => {
+                SecurityScheme::OAuth2(OAuth2::new([Flow::AuthorizationCode(AuthorizationCode::new(
+                    authorization_url,
+                    token_url,
+                    Scopes::new(),
+                ))]))
+            }
+            _ => SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        }
+    }
+}
+
+/// Public health check endpoint.
+#[utoipa::path(get, path = "/healthz", responses((status = 200, description = "OK", body = String)))]
+pub async fn healthz() -> impl IntoResponse {
+    (StatusCode::OK, "OK")
+}
+
+pub fn router(openapi: utoipa::openapi::OpenApi, settings: &settings::Settings) -> Router {
+    let mut router = Router::new().route("/openapi.json", get(move || async move { Json(openapi) }));
+
+    if settings.swagger_ui_enabled {
+        let config = Arc::new(swagger_ui_config(settings));
+        let index_config = config.clone();
+
+        // the page loads its assets relative to itself, so it is only served below the trailing slash
+        router = router
+            .route("/swagger-ui", get(|| async { Redirect::permanent(SWAGGER_UI_PATH) }))
+            .route(
+                SWAGGER_UI_PATH,
+                get(move || async move { swagger_ui_file("", index_config) }),
+            )
+            .route(
+                "/swagger-ui/*file",
+                get(move |Path(file): Path<String>| async move { swagger_ui_file(&file, config) }),
+            );
+    }
+
+    router
+}
+
+fn swagger_ui_config(settings: &settings::Settings) -> Config<'static> {
+    match &settings.oauth_client_id {
+        Some(client_id) => Config::with_oauth_config(
+            ["/openapi.json"],
+            oauth::Config::new()
+                .client_id(client_id)
+                .use_pkce_with_authorization_code_grant(true),
+        ),
+        None => Config::new(["/openapi.json"]),
+    }
+}
+
+/// Serves the Swagger UI assets embedded in the binary.
+fn swagger_ui_file(file: &str, config: Arc<Config<'static>>) -> Response {
+    match utoipa_swagger_ui::serve(file, config) {
+        Ok(Some(file)) => ([(header::CONTENT_TYPE, file.content_type)], file.bytes.into_owned()).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(err) => {
+            tracing::error!("could not serve swagger ui: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn settings(swagger_ui_enabled: bool) -> settings::Settings {
+        settings::Settings {
+            cors_origin: vec![],
+            telemetry_enabled: false,
+            http_timeout: std::time::Duration::from_secs(10),
+            swagger_ui_enabled,
+            oauth_client_id: None,
+            oauth_authorization_url: None,
+            oauth_token_url: None,
+        }
+    }
+
+    async fn get(router: Router, uri: &str) -> (StatusCode, String) {
+        let response = router
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn given_openapi_router_when_get_openapi_json_then_return_3_1_document() {
+        let settings = settings(false);
+
+        let (status, body) = get(router(ApiDoc::build(vec![], &settings), &settings), "/openapi.json").await;
+
+        assert_eq!(status, StatusCode::OK);
+
+        let spec: Value = serde_json::from_str(&body).unwrap();
+        assert!(spec["openapi"].as_str().unwrap().starts_with("3.1"));
+        assert!(spec["paths"]["/healthz"]["get"].is_object());
+        assert_eq!(spec["components"]["securitySchemes"]["Identity"]["scheme"], "bearer");
+    }
+
+    #[tokio::test]
+    async fn given_swagger_ui_disabled_when_get_swagger_ui_then_return_404() {
+        let settings = settings(false);
+
+        let (status, _) = get(router(ApiDoc::build(vec![], &settings), &settings), "/swagger-ui").await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn given_swagger_ui_enabled_when_get_swagger_ui_then_serve_embedded_page() {
+        let settings = settings(true);
+        let router = router(ApiDoc::build(vec![], &settings), &settings);
+
+        let (status, _) = get(router.clone(), "/swagger-ui").await;
+        assert_eq!(status, StatusCode::PERMANENT_REDIRECT);
+
+        let (status, body) = get(router.clone(), "/swagger-ui/").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("./swagger-ui-bundle.js"));
+        assert!(!body.contains("https://"));
+
+        let (status, _) = get(router, "/swagger-ui/swagger-ui-bundle.js").await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn given_oauth_client_id_when_get_swagger_initializer_then_point_to_openapi_json_with_pkce() {
+        let settings = settings::Settings {
+            oauth_client_id: Some(String::from("swagger")),
+            ..settings(true)
+        };
+
+        let (status, body) = get(
+            router(ApiDoc::build(vec![], &settings), &settings),
+            "/swagger-ui/swagger-initializer.js",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("\"url\": \"/openapi.json\""));
+        assert!(body.contains("\"clientId\": \"swagger\""));
+        assert!(body.contains("\"usePkceWithAuthorizationCodeGrant\": true"));
+    }
+
+    #[test]
+    fn given_oauth_urls_when_build_then_use_authorization_code_flow() {
+        let settings = settings::Settings {
+            oauth_authorization_url: Some(String::from("https://auth.example.com/authorize")),
+            oauth_token_url: Some(String::from("https://auth.example.com/oauth/token")),
+            ..settings(false)
+        };
+
+        let spec = serde_json::to_value(ApiDoc::build(vec![], &settings)).unwrap();
+
+        let identity = &spec["components"]["securitySchemes"]["Identity"];
+        assert_eq!(identity["type"], "oauth2");
+        assert_eq!(
+            identity["flows"]["authorizationCode"]["tokenUrl"],
+            "https://auth.example.com/oauth/token"
+        );
+    }
+}
@@ -1,9 +1,16 @@
+use std::time::Duration;
+
 use axum::http::HeaderValue;
 
 #[derive(Clone)]
 pub struct Settings {
     pub cors_origin: Vec<HeaderValue>,
     pub telemetry_enabled: bool,
+    pub http_timeout: Duration,
+    pub swagger_ui_enabled: bool,
+    pub oauth_client_id: Option<String>,
+    pub oauth_authorization_url: Option<String>,
+    pub oauth_token_url: Option<String>,
 }
 
 impl Settings {
@@ -19,9 +26,31 @@ impl Settings {
             .parse::<bool>()
             .unwrap_or(false);
 
+        let http_timeout = std::env::var("HTTP_TIMEOUT_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(Duration::from_secs(10));
+
+        let swagger_ui_enabled: bool = std::env::var("SWAGGER_UI_ENABLED")
+            .unwrap_or(String::from("false"))
+            .parse::<bool>()
+            .unwrap_or(false);
+
+        let oauth_client_id = std::env::var("OAUTH_CLIENT_ID").ok().filter(|value| !value.is_empty());
+        let oauth_authorization_url = std::env::var("OAUTH_AUTHORIZATION_URL")
+            .ok()
+            .filter(|value| !value.is_empty());
+        let oauth_token_url = std::env::var("OAUTH_TOKEN_URL").ok().filter(|value| !value.is_empty());
+
         Self {
             cors_origin,
             telemetry_enabled,
+            http_timeout,
+            swagger_ui_enabled,
+            oauth_client_id,
+            oauth_authorization_url,
+            oauth_token_url,
         }
     }
 }
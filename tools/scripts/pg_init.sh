@@ -19,10 +19,24 @@ psql -U root -d $DATABASE_TEMPLATE -f "$SOURCE_ROOT/contexts/ecommerce/common/in
 
 # add schema
 psql -U root -d $DATABASE_NAME \
-    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product.sql"
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product_event.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product_price_history.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product_price.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/exchange_rate.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/category.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/variant.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/inventory.sql"
 
 psql -U root -d $DATABASE_TEMPLATE \
-    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product.sql"
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product_event.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product_price_history.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/product_price.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/exchange_rate.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/category.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/variant.sql" \
+    -f "$SOURCE_ROOT/contexts/ecommerce/backoffice/infrastructure/schema/inventory.sql"
 
 # add seeds
 psql -U root -d $DATABASE_NAME \